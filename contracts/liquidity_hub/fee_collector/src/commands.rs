@@ -1,17 +1,20 @@
 use cosmwasm_std::{
     to_binary, Addr, BalanceResponse, BankQuery, Coin, CosmosMsg, DepsMut, Env, MessageInfo,
-    QueryRequest, Response, StdResult, Storage, Uint128, WasmMsg, WasmQuery,
+    QueryRequest, ReplyOn, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg, WasmQuery,
 };
 use cw20::{Cw20ExecuteMsg, Cw20QueryMsg};
 
 use pool_network::asset::AssetInfo;
 use pool_network::factory::{PairsResponse, QueryMsg};
+use pool_network::querier::query_pair_info_from_pair;
 use pool_network::router;
 use pool_network::router::{ExecuteMsg, SwapOperation};
 use vault_network::vault_factory::VaultsResponse;
 
-use crate::msg::{ContractType, FactoryType, FeesFor};
-use crate::state::{read_temporal_asset_infos, store_temporal_asset_info, Config, CONFIG};
+use crate::msg::{ContractType, FactoryType, FeesFor, AGGREGATE_FEES_REPLY_ID};
+use crate::state::{
+    read_temporal_asset_infos, store_temporal_asset_info, Config, CONFIG, TMP_ASK_ASSET_INFO,
+};
 use crate::ContractError;
 
 /// Collects fees accrued by the pools and vaults. If a factory is provided then it only collects the
@@ -24,9 +27,69 @@ pub fn collect_fees(
     // only the owner can trigger the fees collection
     validate_owner(deps.storage, info.sender)?;
 
+    let collect_fees_messages = collect_fees_messages(&deps, collect_fees_for)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "collect_fees")
+        .add_messages(collect_fees_messages))
+}
+
+/// Collects the fees accrued by the pools and vaults and aggregates them into the given
+/// ask_asset_info. The fees are collected via submessages, and aggregated in the reply of the
+/// last one, once all the collected fees have been transferred to the fee collector.
+pub fn collect_and_aggregate(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    ask_asset_info: AssetInfo,
+    fees_for: FeesFor,
+) -> Result<Response, ContractError> {
+    // only the owner can collect and aggregate the fees
+    validate_owner(deps.storage, info.sender)?;
+
+    store_fees_asset_infos(deps.branch(), fees_for.clone())?;
+
+    let mut collect_fees_messages: Vec<SubMsg> = collect_fees_messages(&deps, fees_for)?
+        .into_iter()
+        .map(SubMsg::new)
+        .collect();
+
+    // aggregate the fees once the last collection has been performed
+    if let Some(last_collect_fees_message) = collect_fees_messages.last_mut() {
+        last_collect_fees_message.id = AGGREGATE_FEES_REPLY_ID;
+        last_collect_fees_message.reply_on = ReplyOn::Success;
+        TMP_ASK_ASSET_INFO.save(deps.storage, &ask_asset_info)?;
+
+        return Ok(Response::new()
+            .add_attribute("action", "collect_and_aggregate")
+            .add_submessages(collect_fees_messages));
+    }
+
+    // there is nothing to collect, aggregate the fees right away
+    let aggregate_fees_messages = aggregate_fees_messages(&mut deps, &env, ask_asset_info)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "collect_and_aggregate")
+        .add_messages(aggregate_fees_messages))
+}
+
+/// Aggregates the fees collected by [collect_and_aggregate] into the stored ask asset.
+pub fn aggregate_fees_reply(mut deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let ask_asset_info = TMP_ASK_ASSET_INFO.load(deps.storage)?;
+    TMP_ASK_ASSET_INFO.remove(deps.storage);
+
+    let aggregate_fees_messages = aggregate_fees_messages(&mut deps, &env, ask_asset_info)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "aggregate_fees")
+        .add_messages(aggregate_fees_messages))
+}
+
+/// Builds the messages to collect the fees based on the configuration indicated by [FeesFor]
+fn collect_fees_messages(deps: &DepsMut, fees_for: FeesFor) -> StdResult<Vec<CosmosMsg>> {
     let mut collect_fees_messages: Vec<CosmosMsg> = Vec::new();
 
-    match collect_fees_for {
+    match fees_for {
         FeesFor::Contracts { contracts } => {
             for contract in contracts {
                 collect_fees_messages.push(collect_fees_for_contract(
@@ -40,13 +103,11 @@ pub fn collect_fees(
             factory_type,
         } => {
             let factory = deps.api.addr_validate(factory_addr.as_str())?;
-            collect_fees_messages = collect_fees_for_factory(&deps, &factory, factory_type)?;
+            collect_fees_messages = collect_fees_for_factory(deps, &factory, factory_type)?;
         }
     }
 
-    Ok(Response::new()
-        .add_attribute("action", "collect_fees")
-        .add_messages(collect_fees_messages))
+    Ok(collect_fees_messages)
 }
 
 /// Builds the message to collect the fees for the given contract
@@ -159,12 +220,42 @@ pub fn aggregate_fees(
 ) -> Result<Response, ContractError> {
     // only the owner can aggregate the fees
     validate_owner(deps.storage, info.sender)?;
-    let config: Config = CONFIG.load(deps.storage)?;
 
-    let mut aggregate_fees_messages: Vec<CosmosMsg> = Vec::new();
+    if let FeesFor::Contracts { .. } = aggregate_fees_for {
+        return Err(ContractError::InvalidContractsFeeAggregation {});
+    }
+
+    store_fees_asset_infos(deps.branch(), aggregate_fees_for)?;
+    let aggregate_fees_messages = aggregate_fees_messages(&mut deps, &env, ask_asset_info)?;
 
-    match aggregate_fees_for {
-        FeesFor::Contracts { .. } => return Err(ContractError::InvalidContractsFeeAggregation {}),
+    Ok(Response::new()
+        .add_attribute("action", "aggregate_fees")
+        .add_messages(aggregate_fees_messages))
+}
+
+/// Stores the assets in which the fees indicated by [FeesFor] are collected, so they can be
+/// aggregated.
+fn store_fees_asset_infos(mut deps: DepsMut, fees_for: FeesFor) -> Result<(), ContractError> {
+    match fees_for {
+        FeesFor::Contracts { contracts } => {
+            for contract in contracts {
+                let contract_addr = deps.api.addr_validate(contract.address.as_str())?;
+                match contract.contract_type {
+                    ContractType::Vault {} => {
+                        let config: vault_network::vault::Config = deps.querier.query_wasm_smart(
+                            contract_addr,
+                            &vault_network::vault::QueryMsg::Config {},
+                        )?;
+                        store_temporal_asset_info(deps.branch(), config.asset_info)?;
+                    }
+                    ContractType::Pool {} => {
+                        let pair_info = query_pair_info_from_pair(&deps.querier, contract_addr)?;
+                        store_temporal_asset_info(deps.branch(), pair_info.asset_infos[0].clone())?;
+                        store_temporal_asset_info(deps.branch(), pair_info.asset_infos[1].clone())?;
+                    }
+                }
+            }
+        }
         FeesFor::Factory {
             factory_addr,
             factory_type,
@@ -202,7 +293,20 @@ pub fn aggregate_fees(
         }
     }
 
-    let asset_infos: Vec<AssetInfo> = read_temporal_asset_infos(&mut deps)?;
+    Ok(())
+}
+
+/// Builds the messages swapping the fees stored by [store_fees_asset_infos] into the given
+/// ask_asset_info.
+fn aggregate_fees_messages(
+    deps: &mut DepsMut,
+    env: &Env,
+    ask_asset_info: AssetInfo,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    let mut aggregate_fees_messages: Vec<CosmosMsg> = Vec::new();
+
+    let asset_infos: Vec<AssetInfo> = read_temporal_asset_infos(deps)?;
 
     for offer_asset_info in asset_infos {
         if offer_asset_info == ask_asset_info {
@@ -300,7 +404,5 @@ pub fn aggregate_fees(
         }
     }
 
-    Ok(Response::new()
-        .add_attribute("action", "aggregate_fees")
-        .add_messages(aggregate_fees_messages))
+    Ok(aggregate_fees_messages)
 }
@@ -1,11 +1,13 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult,
+};
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, AGGREGATE_FEES_REPLY_ID};
 use crate::state::{Config, CONFIG};
 use crate::ContractError::MigrateInvalidVersion;
 use crate::{commands, migrations, queries};
@@ -52,6 +54,21 @@ pub fn execute(
             asset_info,
             aggregate_fees_for,
         } => commands::aggregate_fees(deps, info, env, asset_info, aggregate_fees_for),
+        ExecuteMsg::CollectAndAggregate {
+            ask_asset_info,
+            fees_for,
+        } => commands::collect_and_aggregate(deps, info, env, ask_asset_info, fees_for),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        AGGREGATE_FEES_REPLY_ID => commands::aggregate_fees_reply(deps, env),
+        _ => Err(ContractError::Std(StdError::generic_err(format!(
+            "Did not handle message reply of id '{}'",
+            msg.id
+        )))),
     }
 }
 
@@ -62,10 +79,12 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Fees {
             query_fees_for,
             all_time,
+            epochs,
         } => to_binary(&queries::query_fees(
             deps,
             query_fees_for,
             all_time.unwrap_or(false),
+            epochs,
         )?),
     }
 }
@@ -17,6 +17,12 @@ pub enum ExecuteMsg {
         asset_info: AssetInfo,
         aggregate_fees_for: FeesFor,
     },
+    /// Collects the fees based on the configuration indicated by [FeesFor] and aggregates them
+    /// into the given [AssetInfo] once they have been collected, in a single transaction.
+    CollectAndAggregate {
+        ask_asset_info: AssetInfo,
+        fees_for: FeesFor,
+    },
     /// Updates the config
     UpdateConfig {
         owner: Option<String>,
@@ -41,17 +47,31 @@ pub enum QueryMsg {
     /// Queries the configuration of this contract
     #[returns(ConfigResponse)]
     Config {},
-    /// Queries fees collected by a given factory's children or individual contracts
+    /// Queries fees collected by a given factory's children or individual contracts. If `epochs`
+    /// is provided, queries the protocol fees accrued in that range of epochs instead, regardless
+    /// of `all_time`.
     #[returns(Vec<Asset>)]
     Fees {
         query_fees_for: FeesFor,
         all_time: Option<bool>,
+        epochs: Option<EpochRange>,
     },
 }
 
+/// A range of epochs, `start_epoch` and `end_epoch` included.
+#[cw_serde]
+pub struct EpochRange {
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+}
+
 #[cw_serde]
 pub struct MigrateMsg {}
 
+/// The `reply` code ID for the submessage collecting the last fees of a
+/// [ExecuteMsg::CollectAndAggregate].
+pub const AGGREGATE_FEES_REPLY_ID: u64 = 1;
+
 #[cw_serde]
 pub enum FactoryType {
     /// Vault Factory
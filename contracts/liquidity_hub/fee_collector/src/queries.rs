@@ -1,12 +1,13 @@
-use cosmwasm_std::{to_binary, Addr, Deps, QueryRequest, StdResult, WasmQuery};
+use cosmwasm_std::{to_binary, Addr, Binary, Deps, QueryRequest, StdResult, WasmQuery};
 
 use pool_network::asset::Asset;
+use pool_network::epoch::ProtocolFeesByEpochResponse;
 use pool_network::factory::PairsResponse;
 use pool_network::pair::ProtocolFeesResponse as ProtocolPairFeesResponse;
 use vault_network::vault::ProtocolFeesResponse as ProtocolVaultFeesResponse;
 use vault_network::vault_factory::VaultsResponse;
 
-use crate::msg::{ContractType, FactoryType, FeesFor};
+use crate::msg::{ContractType, EpochRange, FactoryType, FeesFor};
 use crate::state::{ConfigResponse, CONFIG};
 
 /// Queries the [Config], which contains the owner address
@@ -15,8 +16,14 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     Ok(config)
 }
 
-/// Queries the fees in [Asset] for contracts or Factories defined by [FeesFor]
-pub fn query_fees(deps: Deps, query_fees_for: FeesFor, all_time: bool) -> StdResult<Vec<Asset>> {
+/// Queries the fees in [Asset] for contracts or Factories defined by [FeesFor]. If `epochs` is
+/// provided, queries the protocol fees accrued in that range of epochs instead.
+pub fn query_fees(
+    deps: Deps,
+    query_fees_for: FeesFor,
+    all_time: bool,
+    epochs: Option<EpochRange>,
+) -> StdResult<Vec<Asset>> {
     let mut fees: Vec<Asset> = Vec::new();
 
     match query_fees_for {
@@ -26,16 +33,24 @@ pub fn query_fees(deps: Deps, query_fees_for: FeesFor, all_time: bool) -> StdRes
             for contract in contracts {
                 match contract.contract_type {
                     ContractType::Pool {} => {
-                        let mut pair_fee =
-                            query_fees_for_pair(&deps, contract.address.clone(), all_time)?;
+                        let mut pair_fee = query_fees_for_pair(
+                            &deps,
+                            contract.address.clone(),
+                            all_time,
+                            &epochs,
+                        )?;
 
                         fees.append(&mut pair_fee);
                     }
                     ContractType::Vault {} => {
-                        let vault_fee =
-                            query_fees_for_vault(&deps, contract.address.clone(), all_time)?;
-
-                        fees.push(vault_fee);
+                        let mut vault_fee = query_fees_for_vault(
+                            &deps,
+                            contract.address.clone(),
+                            all_time,
+                            &epochs,
+                        )?;
+
+                        fees.append(&mut vault_fee);
                     }
                 }
             }
@@ -45,7 +60,8 @@ pub fn query_fees(deps: Deps, query_fees_for: FeesFor, all_time: bool) -> StdRes
             factory_type,
         } => {
             let factory = deps.api.addr_validate(factory_addr.as_str())?;
-            let mut assets = query_fees_for_factory(&deps, &factory, factory_type, all_time)?;
+            let mut assets =
+                query_fees_for_factory(&deps, &factory, factory_type, all_time, &epochs)?;
 
             fees.append(&mut assets);
         }
@@ -69,7 +85,23 @@ pub fn query_fees(deps: Deps, query_fees_for: FeesFor, all_time: bool) -> StdRes
 }
 
 /// Queries the fees for a given vault
-fn query_fees_for_vault(deps: &Deps, vault: String, all_time: bool) -> StdResult<Asset> {
+fn query_fees_for_vault(
+    deps: &Deps,
+    vault: String,
+    all_time: bool,
+    epochs: &Option<EpochRange>,
+) -> StdResult<Vec<Asset>> {
+    if let Some(epochs) = epochs {
+        return query_fees_by_epoch(
+            deps,
+            vault,
+            to_binary(&vault_network::vault::QueryMsg::ProtocolFeesByEpoch {
+                start_epoch: epochs.start_epoch,
+                end_epoch: epochs.end_epoch,
+            })?,
+        );
+    }
+
     let fees = deps
         .querier
         .query::<ProtocolVaultFeesResponse>(&QueryRequest::Wasm(WasmQuery::Smart {
@@ -78,11 +110,27 @@ fn query_fees_for_vault(deps: &Deps, vault: String, all_time: bool) -> StdResult
         }))?
         .fees;
 
-    Ok(fees)
+    Ok(vec![fees])
 }
 
 /// Queries the fees for a given pair
-fn query_fees_for_pair(deps: &Deps, pair: String, all_time: bool) -> StdResult<Vec<Asset>> {
+fn query_fees_for_pair(
+    deps: &Deps,
+    pair: String,
+    all_time: bool,
+    epochs: &Option<EpochRange>,
+) -> StdResult<Vec<Asset>> {
+    if let Some(epochs) = epochs {
+        return query_fees_by_epoch(
+            deps,
+            pair,
+            to_binary(&pool_network::pair::QueryMsg::ProtocolFeesByEpoch {
+                start_epoch: epochs.start_epoch,
+                end_epoch: epochs.end_epoch,
+            })?,
+        );
+    }
+
     let fees = deps
         .querier
         .query::<ProtocolPairFeesResponse>(&QueryRequest::Wasm(WasmQuery::Smart {
@@ -97,12 +145,27 @@ fn query_fees_for_pair(deps: &Deps, pair: String, all_time: bool) -> StdResult<V
     Ok(fees)
 }
 
+/// Queries the protocol fees accrued by a pair or vault in a range of epochs, with the given
+/// `ProtocolFeesByEpoch` query message
+fn query_fees_by_epoch(deps: &Deps, contract_addr: String, msg: Binary) -> StdResult<Vec<Asset>> {
+    let response: ProtocolFeesByEpochResponse = deps
+        .querier
+        .query(&QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }))?;
+
+    Ok(response
+        .epochs
+        .into_iter()
+        .flat_map(|epoch_fees| epoch_fees.protocol_fees)
+        .collect())
+}
+
 /// Queries the fees collected by the children of the given factory
 fn query_fees_for_factory(
     deps: &Deps,
     factory: &Addr,
     factory_type: FactoryType,
     all_time: bool,
+    epochs: &Option<EpochRange>,
 ) -> StdResult<Vec<Asset>> {
     let mut fees: Vec<Asset> = Vec::new();
 
@@ -118,8 +181,8 @@ fn query_fees_for_factory(
                 }))?;
 
             for vault_info in response.vaults {
-                let vault_fee = query_fees_for_vault(deps, vault_info.vault, all_time)?;
-                fees.push(vault_fee);
+                let mut vault_fee = query_fees_for_vault(deps, vault_info.vault, all_time, epochs)?;
+                fees.append(&mut vault_fee);
             }
         }
         FactoryType::Pool { start_after, limit } => {
@@ -130,7 +193,8 @@ fn query_fees_for_factory(
                 }))?;
 
             for pair in response.pairs {
-                let mut pair_fees = query_fees_for_pair(deps, pair.contract_addr, all_time)?;
+                let mut pair_fees =
+                    query_fees_for_pair(deps, pair.contract_addr, all_time, epochs)?;
                 fees.append(&mut pair_fees);
             }
         }
@@ -13,6 +13,9 @@ pub type ConfigResponse = Config;
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const TMP_ASSET_INFOS: Map<String, AssetInfo> = Map::new("tmp_asset_infos");
+/// Used to temporarily store the asset to aggregate the fees into between `collect_and_aggregate`
+/// and the `reply` callback
+pub const TMP_ASK_ASSET_INFO: Item<AssetInfo> = Item::new("tmp_ask_asset_info");
 
 pub fn store_temporal_asset_info(deps: DepsMut, asset_info: AssetInfo) -> StdResult<()> {
     let key = asset_info
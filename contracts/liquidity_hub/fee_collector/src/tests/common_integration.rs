@@ -2,7 +2,7 @@ use cosmwasm_std::testing::mock_info;
 use cosmwasm_std::{Addr, Coin, MessageInfo, Uint128};
 use cw_multi_test::{App, AppBuilder, BankKeeper, ContractWrapper, Executor};
 
-use crate::contract::{execute, instantiate, migrate, query};
+use crate::contract::{execute, instantiate, migrate, query, reply};
 
 use super::dummy_contract::create_dummy_flash_loan_contract;
 
@@ -47,7 +47,9 @@ pub fn store_pool_factory_code(app: &mut App) -> u64 {
 /// Stores the fee collector contract to the app
 pub fn store_fee_collector_code(app: &mut App) -> u64 {
     let contract = Box::new(
-        ContractWrapper::new_with_empty(execute, instantiate, query).with_migrate(migrate),
+        ContractWrapper::new_with_empty(execute, instantiate, query)
+            .with_reply(reply)
+            .with_migrate(migrate),
     );
 
     app.store_code(contract)
@@ -9,11 +9,13 @@ use pool_network::factory::ExecuteMsg::{AddNativeTokenDecimals, CreatePair};
 use pool_network::factory::PairsResponse;
 use pool_network::pair::{PoolFee, PoolResponse, ProtocolFeesResponse};
 use pool_network::router::{SwapOperation, SwapRoute};
-use vault_network::vault_factory::ExecuteMsg;
 use nico_teen::fee::{Fee, VaultFee};
+use vault_network::vault_factory::ExecuteMsg;
 
-use crate::msg::ExecuteMsg::{AggregateFees, CollectFees, UpdateConfig};
-use crate::msg::{Contract, ContractType, FactoryType, FeesFor, InstantiateMsg, QueryMsg};
+use crate::msg::ExecuteMsg::{AggregateFees, CollectAndAggregate, CollectFees, UpdateConfig};
+use crate::msg::{
+    Contract, ContractType, EpochRange, FactoryType, FeesFor, InstantiateMsg, QueryMsg,
+};
 use crate::tests::common_integration::{
     increase_allowance, mock_app, mock_app_with_balance, mock_creator,
     store_dummy_flash_loan_contract, store_fee_collector_code, store_pair_code,
@@ -105,6 +107,7 @@ fn collect_all_factories_cw20_fees_successfully() {
                         minter: creator.clone().sender.to_string(),
                         cap: None,
                     }),
+                    marketing: None,
                 },
                 &[],
                 "cw20 token",
@@ -143,6 +146,8 @@ fn collect_all_factories_cw20_fees_successfully() {
                         },
                     },
                     pair_type: PairType::ConstantProduct,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 },
                 &[],
             )
@@ -381,7 +386,8 @@ fn collect_all_factories_cw20_fees_successfully() {
         }
     }
 
-    // Make sure protocol fees in the pools are zero, as they have been collected
+    // Make sure the token protocol fees in the pools are zero, as they have been collected. The
+    // swaps aggregating the tokens go through the same pools, accruing new native protocol fees.
     for pair_token in pair_tokens {
         let protocol_fees_res: ProtocolFeesResponse = app
             .wrap()
@@ -413,6 +419,8 @@ fn collect_all_factories_cw20_fees_successfully() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: cw20_tokens[i as usize].to_string(),
                 },
+                pair_type: None,
+                fee_tier: None,
             };
             swap_operations.push(swap_operation);
         }
@@ -541,6 +549,7 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
                         minter: creator.clone().sender.to_string(),
                         cap: None,
                     }),
+                    marketing: None,
                 },
                 &[],
                 "cw20 token",
@@ -580,6 +589,8 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
                         },
                     },
                     pair_type: PairType::ConstantProduct,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 },
                 &[],
             )
@@ -673,6 +684,7 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
                     }],
                 },
                 all_time: None,
+                epochs: None,
             },
         )
         .unwrap();
@@ -793,6 +805,7 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
                     }],
                 },
                 all_time: None,
+                epochs: None,
             },
         )
         .unwrap();
@@ -901,6 +914,7 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
                     }],
                 },
                 all_time: None,
+                epochs: None,
             },
         )
         .unwrap();
@@ -1013,6 +1027,7 @@ fn collect_pools_native_fees_successfully() {
                         minter: creator.clone().sender.to_string(),
                         cap: None,
                     }),
+                    marketing: None,
                 },
                 &[],
                 "cw20 token",
@@ -1051,6 +1066,8 @@ fn collect_pools_native_fees_successfully() {
                         },
                     },
                     pair_type: PairType::ConstantProduct,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 },
                 &[],
             )
@@ -1125,6 +1142,7 @@ fn collect_pools_native_fees_successfully() {
                     },
                 },
                 all_time: None,
+                epochs: None,
             },
         )
         .unwrap();
@@ -1251,9 +1269,41 @@ fn collect_pools_native_fees_successfully() {
                     },
                 },
                 all_time: Some(true),
+                epochs: None,
+            },
+        )
+        .unwrap();
+
+    // All the fees were accrued in the current epoch, so the fees by epoch match the all time fees
+    let current_epoch = app.block_info().time.seconds() / 604_800u64;
+    let fee_collector_epoch_fees_query: Vec<Asset> = app
+        .wrap()
+        .query_wasm_smart(
+            fee_collector_address.clone(),
+            &QueryMsg::Fees {
+                query_fees_for: FeesFor::Factory {
+                    factory_addr: pool_factory_address.clone().to_string(),
+                    factory_type: FactoryType::Pool {
+                        start_after: None,
+                        limit: None,
+                    },
+                },
+                all_time: None,
+                epochs: Some(EpochRange {
+                    start_epoch: current_epoch,
+                    end_epoch: current_epoch,
+                }),
             },
         )
         .unwrap();
+    let accrued_fees: Vec<&Asset> = fee_collector_fees_query
+        .iter()
+        .filter(|asset| !asset.amount.is_zero())
+        .collect();
+    assert_eq!(fee_collector_epoch_fees_query.len(), accrued_fees.len());
+    for asset in accrued_fees {
+        assert!(fee_collector_epoch_fees_query.contains(asset));
+    }
 
     // Make sure the fee collector's balance for the assets in which fees were collected increased,
     // and matches the amount the pool reported to have collected
@@ -1300,7 +1350,8 @@ fn collect_pools_native_fees_successfully() {
         }
     }
 
-    // Make sure protocol fees in the pools are zero, as they have been collected
+    // Make sure the token protocol fees in the pools are zero, as they have been collected. The
+    // swaps aggregating the tokens go through the same pools, accruing new native protocol fees.
     for pair_token in pair_tokens {
         let protocol_fees_res: ProtocolFeesResponse = app
             .wrap()
@@ -1332,6 +1383,7 @@ fn collect_pools_native_fees_successfully() {
                     },
                 },
                 all_time: None,
+                epochs: None,
             },
         )
         .unwrap();
@@ -1355,12 +1407,16 @@ fn collect_pools_native_fees_successfully() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "native".to_string(),
                 },
+                pair_type: None,
+                fee_tier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::NativeToken {
                     denom: "native".to_string(),
                 },
                 ask_asset_info: ask_asset.clone(),
+                pair_type: None,
+                fee_tier: None,
             },
         ];
 
@@ -1387,6 +1443,8 @@ fn collect_pools_native_fees_successfully() {
             denom: "native".to_string(),
         },
         ask_asset_info: ask_asset.clone(),
+        pair_type: None,
+        fee_tier: None,
     }];
 
     let swap_routes: Vec<SwapRoute> = vec![SwapRoute {
@@ -1539,6 +1597,7 @@ fn collect_fees_with_pagination_successfully() {
                         minter: creator.clone().sender.to_string(),
                         cap: None,
                     }),
+                    marketing: None,
                 },
                 &[],
                 "cw20 token",
@@ -1577,6 +1636,8 @@ fn collect_fees_with_pagination_successfully() {
                         },
                     },
                     pair_type: PairType::ConstantProduct,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 },
                 &[],
             )
@@ -1794,7 +1855,8 @@ fn collect_fees_with_pagination_successfully() {
         }
     }
 
-    // Make sure protocol fees in the pools are zero, as they have been collected
+    // Make sure the token protocol fees in the pools are zero, as they have been collected. The
+    // swaps aggregating the tokens go through the same pools, accruing new native protocol fees.
     for pair_token in pair_tokens {
         let protocol_fees_res: ProtocolFeesResponse = app
             .wrap()
@@ -1901,6 +1963,9 @@ fn collect_fees_for_vault() {
                             share: Decimal::zero(),
                         },
                     },
+                    label: None,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 },
                 &[],
             )
@@ -1923,6 +1988,7 @@ fn collect_fees_for_vault() {
             vaults[i].clone(),
             &vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(400_000_000u128),
+                receiver: None,
             },
             &[Coin {
                 denom: coin.clone().denom,
@@ -1960,6 +2026,7 @@ fn collect_fees_for_vault() {
                     },
                 },
                 all_time: None,
+                epochs: None,
             },
         )
         .unwrap();
@@ -2070,6 +2137,7 @@ fn collect_fees_for_vault() {
                     },
                 },
                 all_time: None,
+                epochs: None,
             },
         )
         .unwrap();
@@ -2093,6 +2161,7 @@ fn collect_fees_for_vault() {
                     }],
                 },
                 all_time: Some(true),
+                epochs: None,
             },
         )
         .unwrap();
@@ -2262,6 +2331,9 @@ fn aggregate_fees_for_vault() {
                             share: Decimal::zero(),
                         },
                     },
+                    label: None,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 },
                 &[],
             )
@@ -2284,6 +2356,7 @@ fn aggregate_fees_for_vault() {
             vaults[i].clone(),
             &vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(400_000_000u128),
+                receiver: None,
             },
             &[Coin {
                 denom: coin.clone().denom,
@@ -2321,6 +2394,7 @@ fn aggregate_fees_for_vault() {
                     },
                 },
                 all_time: None,
+                epochs: None,
             },
         )
         .unwrap();
@@ -2431,6 +2505,7 @@ fn aggregate_fees_for_vault() {
                     },
                 },
                 all_time: None,
+                epochs: None,
             },
         )
         .unwrap();
@@ -2454,6 +2529,7 @@ fn aggregate_fees_for_vault() {
                     }],
                 },
                 all_time: Some(true),
+                epochs: None,
             },
         )
         .unwrap();
@@ -2533,6 +2609,8 @@ fn aggregate_fees_for_vault() {
                     },
                 },
                 pair_type: PairType::ConstantProduct,
+                lp_token_type: None,
+                lp_token_info: None,
             },
             &[],
         )
@@ -2591,6 +2669,8 @@ fn aggregate_fees_for_vault() {
             denom: "ujuno".to_string(),
         },
         ask_asset_info: ask_asset.clone(),
+        pair_type: None,
+        fee_tier: None,
     }];
 
     let swap_routes: Vec<SwapRoute> = vec![SwapRoute {
@@ -2645,6 +2725,337 @@ fn aggregate_fees_for_vault() {
     }
 }
 
+#[test]
+fn collect_and_aggregate_fees_for_contracts_successfully() {
+    const TOKEN_AMOUNT: u8 = 2;
+
+    let creator = mock_creator();
+    let balances = vec![(
+        creator.clone().sender,
+        coins(1_000_000_000u128, "native".to_string()),
+    )];
+
+    let mut app = mock_app_with_balance(balances);
+
+    let fee_collector_id = store_fee_collector_code(&mut app);
+    let pool_factory_id = store_pool_factory_code(&mut app);
+    let pool_router_id = store_pool_router_code(&mut app);
+    let pair_id = store_pair_code(&mut app);
+    let token_id = store_token_code(&mut app);
+
+    let fee_collector_address = app
+        .instantiate_contract(
+            fee_collector_id,
+            creator.clone().sender,
+            &InstantiateMsg {},
+            &[],
+            "fee_collector",
+            None,
+        )
+        .unwrap();
+
+    let pool_factory_address = app
+        .instantiate_contract(
+            pool_factory_id,
+            creator.clone().sender,
+            &pool_network::factory::InstantiateMsg {
+                pair_code_id: pair_id,
+                token_code_id: token_id,
+                fee_collector_addr: fee_collector_address.to_string(),
+            },
+            &[],
+            "fee_collector",
+            None,
+        )
+        .unwrap();
+
+    let pool_router_address = app
+        .instantiate_contract(
+            pool_router_id,
+            creator.clone().sender,
+            &pool_network::router::InstantiateMsg {
+                terraswap_factory: pool_factory_address.to_string(),
+            },
+            &[],
+            "pool_router",
+            None,
+        )
+        .unwrap();
+
+    // add pool router address to the fee collector to be able to aggregate fees
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        &UpdateConfig {
+            owner: None,
+            pool_router: Some(pool_router_address.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // add native token to the factory
+    app.execute_contract(
+        creator.sender.clone(),
+        pool_factory_address.clone(),
+        &AddNativeTokenDecimals {
+            denom: "native".to_string(),
+            decimals: 6,
+        },
+        &[Coin {
+            denom: "native".to_string(),
+            amount: Uint128::new(1u128),
+        }],
+    )
+    .unwrap();
+
+    // Create few tokens to create pools with
+    let mut cw20_tokens: Vec<Addr> = Vec::new();
+    for i in 0..TOKEN_AMOUNT {
+        let symbol = format!("token{}", (i + b'a') as char);
+        let token_address = app
+            .instantiate_contract(
+                token_id,
+                creator.clone().sender,
+                &pool_network::token::InstantiateMsg {
+                    name: format!("token{}", i),
+                    symbol: symbol.clone(),
+                    decimals: 6,
+                    initial_balances: vec![Cw20Coin {
+                        address: creator.clone().sender.to_string(),
+                        amount: Uint128::new(1_000_000_000_000u128),
+                    }],
+                    mint: Some(MinterResponse {
+                        minter: creator.clone().sender.to_string(),
+                        cap: None,
+                    }),
+                    marketing: None,
+                },
+                &[],
+                "cw20 token",
+                None,
+            )
+            .unwrap();
+
+        cw20_tokens.push(token_address);
+    }
+
+    // Create a pool for each token, provide liquidity and perform some swaps
+    let mut pair_tokens: Vec<Addr> = Vec::new();
+    for cw20_token in cw20_tokens.clone() {
+        let res = app
+            .execute_contract(
+                creator.sender.clone(),
+                pool_factory_address.clone(),
+                &CreatePair {
+                    asset_infos: [
+                        AssetInfo::NativeToken {
+                            denom: "native".to_string(),
+                        },
+                        AssetInfo::Token {
+                            contract_addr: cw20_token.to_string(),
+                        },
+                    ],
+                    pool_fees: PoolFee {
+                        protocol_fee: Fee {
+                            share: Decimal::percent(5u64),
+                        },
+                        swap_fee: Fee {
+                            share: Decimal::percent(7u64),
+                        },
+                        burn_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                    },
+                    pair_type: PairType::ConstantProduct,
+                    lp_token_type: None,
+                    lp_token_info: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let pair_address = Addr::unchecked(
+            res.events
+                .last()
+                .unwrap()
+                .attributes
+                .clone()
+                .get(1)
+                .unwrap()
+                .clone()
+                .value,
+        );
+
+        increase_allowance(
+            &mut app,
+            creator.sender.clone(),
+            cw20_token.clone(),
+            pair_address.clone(),
+        );
+
+        app.execute_contract(
+            creator.sender.clone(),
+            pair_address.clone(),
+            &pool_network::pair::ExecuteMsg::ProvideLiquidity {
+                assets: [
+                    Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "native".to_string(),
+                        },
+                        amount: Uint128::new(500_000u128),
+                    },
+                    Asset {
+                        info: AssetInfo::Token {
+                            contract_addr: cw20_token.to_string(),
+                        },
+                        amount: Uint128::new(500_000u128),
+                    },
+                ],
+                slippage_tolerance: None,
+                receiver: None,
+            },
+            &[Coin {
+                denom: "native".to_string(),
+                amount: Uint128::new(500_000u128),
+            }],
+        )
+        .unwrap();
+
+        // swap cw20 -> native so the pool accrues fees in native
+        app.execute_contract(
+            creator.sender.clone(),
+            cw20_token.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: pair_address.to_string(),
+                amount: Uint128::new(200_000u128),
+                msg: to_binary(&pool_network::pair::Cw20HookMsg::Swap {
+                    belief_price: None,
+                    max_spread: None,
+                    to: None,
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // swap native -> cw20 so the pool accrues fees in the token
+        app.execute_contract(
+            creator.sender.clone(),
+            pair_address.clone(),
+            &pool_network::pair::ExecuteMsg::Swap {
+                offer_asset: Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "native".to_string(),
+                    },
+                    amount: Uint128::new(200_000u128),
+                },
+                belief_price: None,
+                max_spread: None,
+                to: None,
+            },
+            &[Coin {
+                denom: "native".to_string(),
+                amount: Uint128::new(200_000u128),
+            }],
+        )
+        .unwrap();
+
+        pair_tokens.push(pair_address);
+    }
+
+    // Add token -> native swap routes to the router to aggregate fees
+    let ask_asset = AssetInfo::NativeToken {
+        denom: "native".to_string(),
+    };
+    for cw20_token in cw20_tokens.clone() {
+        let offer_asset_info = AssetInfo::Token {
+            contract_addr: cw20_token.to_string(),
+        };
+
+        app.execute_contract(
+            creator.sender.clone(),
+            pool_router_address.clone(),
+            &pool_network::router::ExecuteMsg::AddSwapRoutes {
+                swap_routes: vec![SwapRoute {
+                    offer_asset_info: offer_asset_info.clone(),
+                    ask_asset_info: ask_asset.clone(),
+                    swap_operations: vec![SwapOperation::TerraSwap {
+                        offer_asset_info,
+                        ask_asset_info: ask_asset.clone(),
+                        pair_type: None,
+                        fee_tier: None,
+                    }],
+                }],
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Collect and aggregate the fees of the pools in a single transaction
+    app.execute_contract(
+        creator.sender,
+        fee_collector_address.clone(),
+        &CollectAndAggregate {
+            ask_asset_info: ask_asset,
+            fees_for: FeesFor::Contracts {
+                contracts: pair_tokens
+                    .iter()
+                    .map(|pair| Contract {
+                        address: pair.to_string(),
+                        contract_type: ContractType::Pool {},
+                    })
+                    .collect(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Make sure the token protocol fees in the pools are zero, as they have been collected. The
+    // swaps aggregating the tokens go through the same pools, accruing new native protocol fees.
+    for pair_token in pair_tokens {
+        let protocol_fees_res: ProtocolFeesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &pair_token,
+                &pool_network::pair::QueryMsg::ProtocolFees {
+                    asset_id: None,
+                    all_time: None,
+                },
+            )
+            .unwrap();
+
+        for fee in protocol_fees_res.fees {
+            if let AssetInfo::Token { .. } = fee.info {
+                assert_eq!(fee.amount, Uint128::zero());
+            }
+        }
+    }
+
+    // Make sure the token fees were aggregated into the native token
+    for cw20_token in cw20_tokens {
+        let balance_res: BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &cw20_token,
+                &cw20::Cw20QueryMsg::Balance {
+                    address: fee_collector_address.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(balance_res.balance, Uint128::zero());
+    }
+
+    let balance_res = app
+        .wrap()
+        .query_balance(fee_collector_address.to_string(), "native")
+        .unwrap();
+    assert!(balance_res.amount > Uint128::zero());
+}
+
 fn accumulate_fee(assets_collected: &mut HashMap<String, Asset>, asset: Asset) {
     let asset_id = asset.clone().get_id();
     if let Some(collected) = assets_collected.clone().get(asset_id.clone().as_str()) {
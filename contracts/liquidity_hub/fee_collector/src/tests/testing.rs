@@ -7,7 +7,7 @@ use std::env;
 use crate::contract::{execute, instantiate, migrate, query};
 use pool_network::mock_querier::mock_dependencies;
 
-use crate::msg::ExecuteMsg::AggregateFees;
+use crate::msg::ExecuteMsg::{AggregateFees, CollectAndAggregate};
 use crate::msg::{ExecuteMsg, FeesFor, InstantiateMsg, MigrateMsg, QueryMsg};
 use crate::state::ConfigResponse;
 use crate::ContractError;
@@ -145,3 +145,27 @@ fn test_aggregate_fee_for_contracts_err() {
         _ => panic!("should return ContractError::InvalidContractsFeeAggregation"),
     }
 }
+
+#[test]
+fn collect_and_aggregate_unsuccessfully_unauthorized() {
+    let mut deps = mock_dependencies(&[]);
+    let info = mock_info("owner", &[]);
+    mock_instantiation(deps.as_mut(), info).unwrap();
+
+    // unauthorized tries to collect and aggregate fees
+    let info = mock_info("unauthorized", &[]);
+    let msg = CollectAndAggregate {
+        ask_asset_info: AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        },
+        fees_for: FeesFor::Contracts { contracts: vec![] },
+    };
+
+    let res = execute(deps.as_mut(), mock_env(), info, msg);
+
+    match res {
+        Ok(_) => panic!("should return ContractError::Unauthorized"),
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+}
@@ -0,0 +1,14 @@
+use cosmwasm_schema::write_api;
+
+use governance::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        name: "governance",
+        version: "1.0.0",
+        instantiate: InstantiateMsg,
+        query: QueryMsg,
+        execute: ExecuteMsg,
+        migrate: MigrateMsg,
+    }
+}
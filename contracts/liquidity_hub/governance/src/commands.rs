@@ -0,0 +1,369 @@
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response,
+    StdResult, Uint128, WasmMsg,
+};
+use cw20::Cw20ReceiveMsg;
+
+use pool_network::asset::{Asset, AssetInfo};
+
+use crate::error::ContractError;
+use crate::msg::{Cw20HookMsg, ExecuteMsg, ProposalMsg, ProposalStatus, VoteOption};
+use crate::state::{
+    next_proposal_id, Config, Proposal, Vote, CONFIG, PROPOSALS, STAKES, TOTAL_STAKED, VOTES,
+};
+
+/// Stakes the cw20 governance tokens sent to the contract
+pub fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::Stake {} => {
+            let sent_asset_info = AssetInfo::Token {
+                contract_addr: info.sender.to_string(),
+            };
+            if !sent_asset_info.equal(&config.governance_token) {
+                return Err(ContractError::AssetMismatch {});
+            }
+
+            let staker = deps.api.addr_validate(&cw20_msg.sender)?;
+            stake(deps, env, staker, cw20_msg.amount)
+        }
+    }
+}
+
+/// Stakes the native governance tokens sent along
+pub fn stake_native(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let AssetInfo::NativeToken { denom } = config.governance_token else {
+        return Err(ContractError::AssetMismatch {});
+    };
+    let amount = match info.funds.as_slice() {
+        [coin] if coin.denom == denom => coin.amount,
+        _ => return Err(ContractError::AssetMismatch {}),
+    };
+
+    stake(deps, env, info.sender, amount)
+}
+
+/// Adds `amount` to the stake of `staker`, taking effect for the proposals created from the next
+/// block on
+fn stake(
+    deps: DepsMut,
+    env: Env,
+    staker: Addr,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let stake = STAKES
+        .may_load(deps.storage, &staker)?
+        .unwrap_or_default()
+        .checked_add(amount)?;
+    STAKES.save(deps.storage, &staker, &stake, env.block.height)?;
+
+    let total_staked = TOTAL_STAKED
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_add(amount)?;
+    TOTAL_STAKED.save(deps.storage, &total_staked, env.block.height)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "stake".to_string()),
+        ("staker", staker.to_string()),
+        ("amount", amount.to_string()),
+        ("stake", stake.to_string()),
+    ]))
+}
+
+/// Unstakes `amount` from the stake of the sender, sending the governance tokens back. The votes
+/// already cast with the stake are kept.
+pub fn unstake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+
+    let stake = STAKES
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default()
+        .checked_sub(amount)
+        .map_err(|_| ContractError::InsufficientStake {})?;
+    if stake.is_zero() {
+        STAKES.remove(deps.storage, &info.sender, env.block.height)?;
+    } else {
+        STAKES.save(deps.storage, &info.sender, &stake, env.block.height)?;
+    }
+
+    let total_staked = TOTAL_STAKED.load(deps.storage)?.checked_sub(amount)?;
+    TOTAL_STAKED.save(deps.storage, &total_staked, env.block.height)?;
+
+    Ok(Response::new()
+        .add_message(
+            Asset {
+                info: config.governance_token,
+                amount,
+            }
+            .into_msg(info.sender.clone())?,
+        )
+        .add_attributes(vec![
+            ("action", "unstake".to_string()),
+            ("staker", info.sender.to_string()),
+            ("amount", amount.to_string()),
+            ("stake", stake.to_string()),
+        ]))
+}
+
+/// Creates a proposal, open to vote for the voting period with the stakes at the start of the
+/// current block. The proposer must have had the minimum proposal stake at the start of the block.
+pub fn propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    messages: Vec<ProposalMsg>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // only stakers can propose, with the stake they had before the current block so the stake
+    // can't be added in the same transaction
+    let stake = STAKES
+        .may_load_at_height(deps.storage, &info.sender, env.block.height)?
+        .unwrap_or_default();
+    if stake.is_zero() {
+        return Err(ContractError::NoStake {});
+    }
+
+    if stake < config.proposal_min_stake {
+        return Err(ContractError::InsufficientProposalStake {
+            stake,
+            min_stake: config.proposal_min_stake,
+        });
+    }
+
+    let proposal_id = next_proposal_id(deps.storage)?;
+    let voting_end = env.block.time.plus_seconds(config.voting_period);
+    let proposal = Proposal {
+        proposal_id,
+        proposer: info.sender,
+        title,
+        description,
+        messages,
+        start_height: env.block.height,
+        voting_end,
+        execution_time: voting_end.plus_seconds(config.timelock_period),
+        quorum: config.quorum,
+        threshold: config.threshold,
+        total_staked: TOTAL_STAKED
+            .may_load_at_height(deps.storage, env.block.height)?
+            .unwrap_or_default(),
+        yes_votes: Uint128::zero(),
+        no_votes: Uint128::zero(),
+        abstain_votes: Uint128::zero(),
+        executed: false,
+    };
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "propose".to_string()),
+        ("proposal_id", proposal_id.to_string()),
+        ("proposer", proposal.proposer.to_string()),
+        ("voting_end", proposal.voting_end.to_string()),
+    ]))
+}
+
+/// Votes on an open proposal, weighted by the stake of the sender at the start of the proposal
+pub fn vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    vote: VoteOption,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+
+    if proposal.status(env.block.time) != ProposalStatus::Open {
+        return Err(ContractError::VotingClosed { proposal_id });
+    }
+
+    if VOTES.has(deps.storage, (proposal_id, &info.sender)) {
+        return Err(ContractError::AlreadyVoted { proposal_id });
+    }
+
+    let weight = STAKES
+        .may_load_at_height(deps.storage, &info.sender, proposal.start_height)?
+        .unwrap_or_default();
+    if weight.is_zero() {
+        return Err(ContractError::NoVotingPower {});
+    }
+
+    match vote {
+        VoteOption::Yes => proposal.yes_votes = proposal.yes_votes.checked_add(weight)?,
+        VoteOption::No => proposal.no_votes = proposal.no_votes.checked_add(weight)?,
+        VoteOption::Abstain => {
+            proposal.abstain_votes = proposal.abstain_votes.checked_add(weight)?
+        }
+    }
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    VOTES.save(
+        deps.storage,
+        (proposal_id, &info.sender),
+        &Vote {
+            vote: vote.clone(),
+            weight,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "vote".to_string()),
+        ("proposal_id", proposal_id.to_string()),
+        ("voter", info.sender.to_string()),
+        ("vote", format!("{vote:?}").to_lowercase()),
+        ("weight", weight.to_string()),
+    ]))
+}
+
+/// Executes the messages of a passed proposal once its timelock is over
+pub fn execute_proposal(
+    deps: DepsMut,
+    env: Env,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+
+    match proposal.status(env.block.time) {
+        ProposalStatus::Open => return Err(ContractError::VotingNotOver { proposal_id }),
+        ProposalStatus::Rejected => return Err(ContractError::ProposalNotPassed { proposal_id }),
+        ProposalStatus::Executed => {
+            return Err(ContractError::ProposalAlreadyExecuted { proposal_id })
+        }
+        ProposalStatus::Passed => {}
+    }
+
+    if env.block.time < proposal.execution_time {
+        return Err(ContractError::TimelockNotExpired {
+            execution_time: proposal.execution_time,
+        });
+    }
+
+    proposal.executed = true;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    let messages = proposal
+        .messages
+        .into_iter()
+        .map(|msg| proposal_msg(&config, &env, msg))
+        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "execute_proposal".to_string()),
+        ("proposal_id", proposal_id.to_string()),
+    ]))
+}
+
+/// Creates the message executing a [ProposalMsg]
+fn proposal_msg(config: &Config, env: &Env, msg: ProposalMsg) -> StdResult<CosmosMsg> {
+    let (contract_addr, msg) = match msg {
+        ProposalMsg::PoolFactory(msg) => (config.pool_factory_addr.to_string(), to_binary(&msg)?),
+        ProposalMsg::VaultFactory(msg) => (config.vault_factory_addr.to_string(), to_binary(&msg)?),
+        ProposalMsg::UpdateConfig {
+            quorum,
+            threshold,
+            voting_period,
+            timelock_period,
+            proposal_min_stake,
+        } => (
+            env.contract.address.to_string(),
+            to_binary(&ExecuteMsg::UpdateConfig {
+                quorum,
+                threshold,
+                voting_period,
+                timelock_period,
+                proposal_min_stake,
+            })?,
+        ),
+    };
+
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr,
+        msg,
+        funds: vec![],
+    }))
+}
+
+/// Updates the configuration of the contract. Only the contract itself can update it, through a
+/// proposal.
+#[allow(clippy::too_many_arguments)]
+pub fn update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    quorum: Option<Decimal>,
+    threshold: Option<Decimal>,
+    voting_period: Option<u64>,
+    timelock_period: Option<u64>,
+    proposal_min_stake: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if let Some(quorum) = quorum {
+        config.quorum = quorum;
+    }
+
+    if let Some(threshold) = threshold {
+        config.threshold = threshold;
+    }
+
+    if let Some(voting_period) = voting_period {
+        config.voting_period = voting_period;
+    }
+
+    if let Some(timelock_period) = timelock_period {
+        config.timelock_period = timelock_period;
+    }
+
+    if let Some(proposal_min_stake) = proposal_min_stake {
+        config.proposal_min_stake = proposal_min_stake;
+    }
+
+    validate_config(&config)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+/// Validates the voting parameters of the config
+pub fn validate_config(config: &Config) -> Result<(), ContractError> {
+    if config.quorum.is_zero() || config.quorum > Decimal::one() {
+        return Err(ContractError::InvalidQuorum {});
+    }
+
+    if config.threshold >= Decimal::one() {
+        return Err(ContractError::InvalidThreshold {});
+    }
+
+    if config.voting_period == 0 {
+        return Err(ContractError::InvalidVotingPeriod {});
+    }
+
+    Ok(())
+}
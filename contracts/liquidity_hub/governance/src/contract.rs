@@ -0,0 +1,130 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
+
+use crate::error::ContractError;
+use crate::error::ContractError::MigrateInvalidVersion;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{Config, CONFIG, PROPOSAL_COUNT};
+use crate::{commands, queries};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "nico_teen-governance";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        governance_token: msg.governance_token,
+        pool_factory_addr: deps.api.addr_validate(&msg.pool_factory_addr)?,
+        vault_factory_addr: deps.api.addr_validate(&msg.vault_factory_addr)?,
+        quorum: msg.quorum,
+        threshold: msg.threshold,
+        voting_period: msg.voting_period,
+        timelock_period: msg.timelock_period,
+        proposal_min_stake: msg.proposal_min_stake,
+    };
+    commands::validate_config(&config)?;
+
+    CONFIG.save(deps.storage, &config)?;
+    PROPOSAL_COUNT.save(deps.storage, &0u64)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "instantiate".to_string()),
+        ("governance_token", config.governance_token.to_string()),
+        ("quorum", config.quorum.to_string()),
+        ("threshold", config.threshold.to_string()),
+        ("voting_period", config.voting_period.to_string()),
+        ("timelock_period", config.timelock_period.to_string()),
+        ("proposal_min_stake", config.proposal_min_stake.to_string()),
+    ]))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Receive(msg) => commands::receive_cw20(deps, env, info, msg),
+        ExecuteMsg::Stake {} => commands::stake_native(deps, env, info),
+        ExecuteMsg::Unstake { amount } => commands::unstake(deps, env, info, amount),
+        ExecuteMsg::Propose {
+            title,
+            description,
+            messages,
+        } => commands::propose(deps, env, info, title, description, messages),
+        ExecuteMsg::Vote { proposal_id, vote } => {
+            commands::vote(deps, env, info, proposal_id, vote)
+        }
+        ExecuteMsg::ExecuteProposal { proposal_id } => {
+            commands::execute_proposal(deps, env, proposal_id)
+        }
+        ExecuteMsg::UpdateConfig {
+            quorum,
+            threshold,
+            voting_period,
+            timelock_period,
+            proposal_min_stake,
+        } => commands::update_config(
+            deps,
+            env,
+            info,
+            quorum,
+            threshold,
+            voting_period,
+            timelock_period,
+            proposal_min_stake,
+        ),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    match msg {
+        QueryMsg::Config {} => Ok(to_binary(&queries::query_config(deps)?)?),
+        QueryMsg::Proposal { proposal_id } => Ok(to_binary(&queries::query_proposal(
+            deps,
+            env,
+            proposal_id,
+        )?)?),
+        QueryMsg::Proposals { start_after, limit } => Ok(to_binary(&queries::query_proposals(
+            deps,
+            env,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::Vote { proposal_id, voter } => {
+            Ok(to_binary(&queries::query_vote(deps, proposal_id, voter)?)?)
+        }
+        QueryMsg::Stake { address } => Ok(to_binary(&queries::query_stake(deps, address)?)?),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let version: Version = CONTRACT_VERSION.parse()?;
+    let storage_version: Version = get_contract_version(deps.storage)?.version.parse()?;
+
+    if storage_version >= version {
+        return Err(MigrateInvalidVersion {
+            current_version: storage_version,
+            new_version: version,
+        });
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
@@ -0,0 +1,75 @@
+use cosmwasm_std::{OverflowError, StdError, Timestamp, Uint128};
+use semver::Version;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("Attempt to migrate to version {new_version}, but contract is on a higher version {current_version}")]
+    MigrateInvalidVersion {
+        new_version: Version,
+        current_version: Version,
+    },
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Invalid quorum, it must be greater than 0 and lower than or equal to 1")]
+    InvalidQuorum {},
+
+    #[error("Invalid threshold, it must be lower than 1")]
+    InvalidThreshold {},
+
+    #[error("The voting period can't be zero")]
+    InvalidVotingPeriod {},
+
+    #[error("Can't stake or unstake a zero amount")]
+    InvalidZeroAmount {},
+
+    #[error("The asset sent is not the governance token")]
+    AssetMismatch {},
+
+    #[error("Can't unstake more than the stake")]
+    InsufficientStake {},
+
+    #[error("Only stakers can create proposals")]
+    NoStake {},
+
+    #[error("A stake of {min_stake} is required to create proposals, the sender had a stake of {stake} at the start of the block")]
+    InsufficientProposalStake { stake: Uint128, min_stake: Uint128 },
+
+    #[error("The sender had no stake when the proposal was created")]
+    NoVotingPower {},
+
+    #[error("The sender already voted on proposal {proposal_id}")]
+    AlreadyVoted { proposal_id: u64 },
+
+    #[error("The vote on proposal {proposal_id} is closed")]
+    VotingClosed { proposal_id: u64 },
+
+    #[error("The vote on proposal {proposal_id} is not over")]
+    VotingNotOver { proposal_id: u64 },
+
+    #[error("Proposal {proposal_id} didn't pass")]
+    ProposalNotPassed { proposal_id: u64 },
+
+    #[error("Proposal {proposal_id} was already executed")]
+    ProposalAlreadyExecuted { proposal_id: u64 },
+
+    #[error("The timelock of the proposal is not over, it can be executed from {execution_time}")]
+    TimelockNotExpired { execution_time: Timestamp },
+}
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
+}
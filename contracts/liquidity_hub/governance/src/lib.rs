@@ -0,0 +1,12 @@
+mod commands;
+pub mod contract;
+mod error;
+pub mod msg;
+mod queries;
+pub mod state;
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tests;
+
+pub use crate::error::ContractError;
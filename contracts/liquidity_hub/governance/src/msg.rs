@@ -0,0 +1,146 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+use pool_network::asset::AssetInfo;
+use pool_network::factory::ExecuteMsg as PoolFactoryExecuteMsg;
+use vault_network::vault_factory::ExecuteMsg as VaultFactoryExecuteMsg;
+
+use crate::state::{ConfigResponse, Proposal, Vote};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The token staked to vote on the proposals
+    pub governance_token: AssetInfo,
+    pub pool_factory_addr: String,
+    pub vault_factory_addr: String,
+    /// The share of the total staked that has to vote on a proposal for it to pass
+    pub quorum: Decimal,
+    /// The share of yes votes, abstentions excluded, a proposal has to exceed to pass
+    pub threshold: Decimal,
+    /// The time a proposal is open to vote, in seconds
+    pub voting_period: u64,
+    /// The time a passed proposal has to wait after the end of the vote before it can be executed,
+    /// in seconds
+    pub timelock_period: u64,
+    /// The stake a staker must have at the start of the block to create a proposal, so proposals
+    /// can't be spammed with dust or flash stakes
+    pub proposal_min_stake: Uint128,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Used to trigger the [Cw20HookMsg] messages
+    Receive(Cw20ReceiveMsg),
+    /// Stakes the native governance tokens sent along. cw20 governance tokens are staked with
+    /// [Cw20HookMsg::Stake] instead.
+    Stake {},
+    /// Unstakes the given amount of governance tokens, sending them back to the sender
+    Unstake { amount: Uint128 },
+    /// Creates a proposal executing the given messages if it passes. Only stakers with at least the
+    /// minimum proposal stake at the start of the block can propose.
+    Propose {
+        title: String,
+        description: String,
+        messages: Vec<ProposalMsg>,
+    },
+    /// Votes on an open proposal, with the stake the sender had when the proposal was created
+    Vote { proposal_id: u64, vote: VoteOption },
+    /// Executes the messages of a passed proposal once its timelock is over. Anyone can execute a
+    /// proposal.
+    ExecuteProposal { proposal_id: u64 },
+    /// Updates the configuration of the contract. This message can only be called by the contract
+    /// itself, i.e. through a proposal with [ProposalMsg::UpdateConfig].
+    UpdateConfig {
+        quorum: Option<Decimal>,
+        threshold: Option<Decimal>,
+        voting_period: Option<u64>,
+        timelock_period: Option<u64>,
+        proposal_min_stake: Option<Uint128>,
+    },
+}
+
+#[cw_serde]
+pub enum Cw20HookMsg {
+    /// Stakes the governance tokens sent
+    Stake {},
+}
+
+/// A message executed by a proposal
+#[cw_serde]
+pub enum ProposalMsg {
+    /// Executes a message on the pool factory
+    PoolFactory(PoolFactoryExecuteMsg),
+    /// Executes a message on the vault factory
+    VaultFactory(VaultFactoryExecuteMsg),
+    /// Updates the configuration of the governance contract
+    UpdateConfig {
+        quorum: Option<Decimal>,
+        threshold: Option<Decimal>,
+        voting_period: Option<u64>,
+        timelock_period: Option<u64>,
+        proposal_min_stake: Option<Uint128>,
+    },
+}
+
+#[cw_serde]
+pub enum VoteOption {
+    Yes,
+    No,
+    Abstain,
+}
+
+#[cw_serde]
+pub enum ProposalStatus {
+    /// The proposal is open to vote
+    Open,
+    /// The vote is over and the proposal passed. It can be executed once its timelock is over.
+    Passed,
+    /// The vote is over and the proposal didn't pass
+    Rejected,
+    /// The proposal passed and was executed
+    Executed,
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Retrieves the configuration of the contract.
+    #[returns(ConfigResponse)]
+    Config {},
+    /// Retrieves a proposal along with its status.
+    #[returns(ProposalResponse)]
+    Proposal { proposal_id: u64 },
+    /// Retrieves the proposals, from the oldest to the newest.
+    #[returns(ProposalsResponse)]
+    Proposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the vote of `voter` on a proposal, if any.
+    #[returns(Option<Vote>)]
+    Vote { proposal_id: u64, voter: String },
+    /// Retrieves the stake of `address` along with the total staked.
+    #[returns(StakeResponse)]
+    Stake { address: String },
+}
+
+#[cw_serde]
+pub struct ProposalResponse {
+    pub proposal: Proposal,
+    pub status: ProposalStatus,
+}
+
+#[cw_serde]
+pub struct ProposalsResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
+
+#[cw_serde]
+pub struct StakeResponse {
+    pub stake: Uint128,
+    pub total_staked: Uint128,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,57 @@
+use cosmwasm_std::{Deps, Env};
+
+use crate::error::ContractError;
+use crate::msg::{ProposalResponse, ProposalsResponse, StakeResponse};
+use crate::state::{
+    read_proposals, ConfigResponse, Vote, CONFIG, PROPOSALS, STAKES, TOTAL_STAKED, VOTES,
+};
+
+/// Queries the [Config] of the contract
+pub fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
+    Ok(CONFIG.load(deps.storage)?)
+}
+
+/// Queries a proposal along with its current status
+pub fn query_proposal(
+    deps: Deps,
+    env: Env,
+    proposal_id: u64,
+) -> Result<ProposalResponse, ContractError> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    let status = proposal.status(env.block.time);
+
+    Ok(ProposalResponse { proposal, status })
+}
+
+/// Queries the proposals along with their current status, from the oldest to the newest
+pub fn query_proposals(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<ProposalsResponse, ContractError> {
+    Ok(ProposalsResponse {
+        proposals: read_proposals(deps.storage, env.block.time, start_after, limit)?,
+    })
+}
+
+/// Queries the vote of `voter` on a proposal, if any
+pub fn query_vote(
+    deps: Deps,
+    proposal_id: u64,
+    voter: String,
+) -> Result<Option<Vote>, ContractError> {
+    let voter = deps.api.addr_validate(&voter)?;
+
+    Ok(VOTES.may_load(deps.storage, (proposal_id, &voter))?)
+}
+
+/// Queries the current stake of `address` along with the total staked
+pub fn query_stake(deps: Deps, address: String) -> Result<StakeResponse, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+
+    Ok(StakeResponse {
+        stake: STAKES.may_load(deps.storage, &address)?.unwrap_or_default(),
+        total_staked: TOTAL_STAKED.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
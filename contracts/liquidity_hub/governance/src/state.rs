@@ -0,0 +1,143 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Item, Map, SnapshotItem, SnapshotMap, Strategy};
+
+use pool_network::asset::AssetInfo;
+
+use crate::msg::{ProposalMsg, ProposalResponse, ProposalStatus, VoteOption};
+
+#[cw_serde]
+pub struct Config {
+    /// The token staked to vote on the proposals
+    pub governance_token: AssetInfo,
+    pub pool_factory_addr: Addr,
+    pub vault_factory_addr: Addr,
+    /// The share of the total staked that has to vote on a proposal for it to pass
+    pub quorum: Decimal,
+    /// The share of yes votes, abstentions excluded, a proposal has to exceed to pass
+    pub threshold: Decimal,
+    /// The time a proposal is open to vote, in seconds
+    pub voting_period: u64,
+    /// The time a passed proposal has to wait after the end of the vote before it can be executed,
+    /// in seconds
+    pub timelock_period: u64,
+    /// The stake a staker must have at the start of the block to create a proposal, so proposals
+    /// can't be spammed with dust or flash stakes
+    pub proposal_min_stake: Uint128,
+}
+
+pub type ConfigResponse = Config;
+
+#[cw_serde]
+pub struct Proposal {
+    pub proposal_id: u64,
+    pub proposer: Addr,
+    pub title: String,
+    pub description: String,
+    pub messages: Vec<ProposalMsg>,
+    /// The height of the block the proposal was created in. The votes are weighted by the stakes
+    /// at the start of that block.
+    pub start_height: u64,
+    /// The time the vote ends at
+    pub voting_end: Timestamp,
+    /// The time from which the proposal can be executed if it passed
+    pub execution_time: Timestamp,
+    /// The quorum and threshold of the config at the creation of the proposal
+    pub quorum: Decimal,
+    pub threshold: Decimal,
+    /// The total staked at the start of the vote
+    pub total_staked: Uint128,
+    pub yes_votes: Uint128,
+    pub no_votes: Uint128,
+    pub abstain_votes: Uint128,
+    pub executed: bool,
+}
+
+impl Proposal {
+    /// Gets the status of the proposal at the given time
+    pub fn status(&self, now: Timestamp) -> ProposalStatus {
+        if self.executed {
+            ProposalStatus::Executed
+        } else if now < self.voting_end {
+            ProposalStatus::Open
+        } else if self.passed() {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Rejected
+        }
+    }
+
+    /// Whether the votes cast reach the quorum and the yes votes exceed the threshold
+    fn passed(&self) -> bool {
+        let total_votes = self.yes_votes + self.no_votes + self.abstain_votes;
+        if self.total_staked.is_zero() || self.yes_votes.is_zero() {
+            return false;
+        }
+
+        Decimal::from_ratio(total_votes, self.total_staked) >= self.quorum
+            && Decimal::from_ratio(self.yes_votes, self.yes_votes + self.no_votes) > self.threshold
+    }
+}
+
+/// A vote cast on a proposal
+#[cw_serde]
+pub struct Vote {
+    pub vote: VoteOption,
+    /// The stake of the voter at the start of the vote
+    pub weight: Uint128,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The stakes of the governance token, snapshotted at every block so the votes can be weighted by
+/// the stakes at the start of a proposal
+pub const STAKES: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
+    "stakes",
+    "stakes__checkpoints",
+    "stakes__changelog",
+    Strategy::EveryBlock,
+);
+pub const TOTAL_STAKED: SnapshotItem<Uint128> = SnapshotItem::new(
+    "total_staked",
+    "total_staked__checkpoints",
+    "total_staked__changelog",
+    Strategy::EveryBlock,
+);
+
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+pub const VOTES: Map<(u64, &Addr), Vote> = Map::new("votes");
+
+/// Gets the id of the next proposal, incrementing the proposal count
+pub fn next_proposal_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let proposal_id = PROPOSAL_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    PROPOSAL_COUNT.save(storage, &proposal_id)?;
+
+    Ok(proposal_id)
+}
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+/// Reads the proposals along with their status at the given time, from the oldest to the newest
+pub fn read_proposals(
+    storage: &dyn Storage,
+    now: Timestamp,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<ProposalResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    PROPOSALS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, proposal) = item?;
+            let status = proposal.status(now);
+
+            Ok(ProposalResponse { proposal, status })
+        })
+        .collect()
+}
@@ -0,0 +1,807 @@
+use cosmwasm_std::{coins, to_binary, Addr, Decimal, Empty, Uint128};
+use cw20::Cw20ExecuteMsg;
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+
+use liquidity_hub_testing::{native, TestSuite};
+use pool_network::asset::AssetInfo;
+use pool_network::factory::{
+    ConfigResponse as PoolFactoryConfigResponse, ExecuteMsg as PoolFactoryExecuteMsg,
+    NativeTokenDecimalsResponse, QueryMsg as PoolFactoryQueryMsg,
+};
+use vault_network::vault_factory::{
+    Config as VaultFactoryConfig, ExecuteMsg as VaultFactoryExecuteMsg,
+    QueryMsg as VaultFactoryQueryMsg,
+};
+
+use crate::contract::{execute, instantiate, migrate, query};
+use crate::msg::{
+    Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, ProposalMsg, ProposalResponse,
+    ProposalStatus, ProposalsResponse, QueryMsg, StakeResponse, VoteOption,
+};
+use crate::state::{ConfigResponse, Vote};
+use crate::ContractError;
+
+/// The voting period and the timelock period of the governance in the tests
+const DAY: u64 = 86_400u64;
+/// The minimum proposal stake of the governance in the tests, more than carol's stake
+const PROPOSAL_MIN_STAKE: u128 = 200u128;
+
+fn governance_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(execute, instantiate, query).with_migrate(migrate))
+}
+
+struct GovernanceSuite {
+    suite: TestSuite,
+    code_id: u64,
+    governance_addr: Addr,
+    /// The cw20 governance token
+    governance_token: AssetInfo,
+}
+
+fn instantiate_msg(suite: &TestSuite, governance_token: AssetInfo) -> InstantiateMsg {
+    InstantiateMsg {
+        governance_token,
+        pool_factory_addr: suite.pool_factory_addr.to_string(),
+        vault_factory_addr: suite.vault_factory_addr.to_string(),
+        quorum: Decimal::percent(30),
+        threshold: Decimal::percent(50),
+        voting_period: DAY,
+        timelock_period: DAY,
+        proposal_min_stake: Uint128::new(PROPOSAL_MIN_STAKE),
+    }
+}
+
+/// Creates the governance contract with a cw20 governance token and hands it the ownership of the
+/// pool and vault factories. alice, bob and carol stake 600, 300 and 100 tokens respectively, dave
+/// holds 1_000 unstaked tokens.
+fn mock_suite() -> GovernanceSuite {
+    let mut suite = TestSuite::builder().build();
+
+    let governance_token = suite.create_token(
+        "gov",
+        &[
+            ("alice", 600),
+            ("bob", 300),
+            ("carol", 100),
+            ("dave", 1_000),
+        ],
+    );
+
+    let code_id = suite.app.store_code(governance_contract());
+    let governance_addr = suite
+        .app
+        .instantiate_contract(
+            code_id,
+            suite.creator.clone(),
+            &instantiate_msg(&suite, governance_token.clone()),
+            &[],
+            "governance",
+            Some(suite.creator.to_string()),
+        )
+        .unwrap();
+
+    suite
+        .app
+        .execute_contract(
+            suite.creator.clone(),
+            suite.pool_factory_addr.clone(),
+            &PoolFactoryExecuteMsg::UpdateConfig {
+                owner: Some(governance_addr.to_string()),
+                fee_collector_addr: None,
+                token_code_id: None,
+                pair_code_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+    suite
+        .app
+        .execute_contract(
+            suite.creator.clone(),
+            suite.vault_factory_addr.clone(),
+            &VaultFactoryExecuteMsg::UpdateConfig {
+                owner: Some(governance_addr.to_string()),
+                fee_collector_addr: None,
+                vault_id: None,
+                token_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let mut suite = GovernanceSuite {
+        suite,
+        code_id,
+        governance_addr,
+        governance_token,
+    };
+    for (staker, amount) in [("alice", 600), ("bob", 300), ("carol", 100)] {
+        suite.stake(staker, amount).unwrap();
+    }
+    suite.next_block(5);
+
+    suite
+}
+
+/// The messages of a proposal updating the fee collector of both factories
+fn update_fee_collector_messages(fee_collector_addr: &str) -> Vec<ProposalMsg> {
+    vec![
+        ProposalMsg::PoolFactory(PoolFactoryExecuteMsg::UpdateConfig {
+            owner: None,
+            fee_collector_addr: Some(fee_collector_addr.to_string()),
+            token_code_id: None,
+            pair_code_id: None,
+        }),
+        ProposalMsg::VaultFactory(VaultFactoryExecuteMsg::UpdateConfig {
+            owner: None,
+            fee_collector_addr: Some(fee_collector_addr.to_string()),
+            vault_id: None,
+            token_id: None,
+        }),
+    ]
+}
+
+impl GovernanceSuite {
+    fn stake(&mut self, staker: &str, amount: u128) -> Result<(), ContractError> {
+        let AssetInfo::Token { contract_addr } = self.governance_token.clone() else {
+            panic!("the governance token should be a cw20 token");
+        };
+
+        self.suite
+            .app
+            .execute_contract(
+                Addr::unchecked(staker),
+                Addr::unchecked(contract_addr),
+                &Cw20ExecuteMsg::Send {
+                    contract: self.governance_addr.to_string(),
+                    amount: Uint128::new(amount),
+                    msg: to_binary(&Cw20HookMsg::Stake {}).unwrap(),
+                },
+                &[],
+            )
+            .map(|_| ())
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    fn execute(&mut self, sender: &str, msg: &ExecuteMsg) -> Result<(), ContractError> {
+        self.suite
+            .app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.governance_addr.clone(),
+                msg,
+                &[],
+            )
+            .map(|_| ())
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    fn propose(
+        &mut self,
+        proposer: &str,
+        messages: Vec<ProposalMsg>,
+    ) -> Result<u64, ContractError> {
+        let res = self
+            .suite
+            .app
+            .execute_contract(
+                Addr::unchecked(proposer),
+                self.governance_addr.clone(),
+                &ExecuteMsg::Propose {
+                    title: "Proposal".to_string(),
+                    description: "A proposal".to_string(),
+                    messages,
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast::<ContractError>().unwrap())?;
+
+        Ok(res
+            .events
+            .iter()
+            .flat_map(|event| event.attributes.iter())
+            .find(|attribute| attribute.key == "proposal_id")
+            .unwrap()
+            .value
+            .parse()
+            .unwrap())
+    }
+
+    fn vote(
+        &mut self,
+        voter: &str,
+        proposal_id: u64,
+        vote: VoteOption,
+    ) -> Result<(), ContractError> {
+        self.execute(voter, &ExecuteMsg::Vote { proposal_id, vote })
+    }
+
+    fn execute_proposal(&mut self, proposal_id: u64) -> Result<(), ContractError> {
+        self.execute("carol", &ExecuteMsg::ExecuteProposal { proposal_id })
+    }
+
+    fn query_proposal(&self, proposal_id: u64) -> ProposalResponse {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.governance_addr, &QueryMsg::Proposal { proposal_id })
+            .unwrap()
+    }
+
+    fn query_stake(&self, address: &str) -> StakeResponse {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.governance_addr,
+                &QueryMsg::Stake {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    fn query_config(&self) -> ConfigResponse {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.governance_addr, &QueryMsg::Config {})
+            .unwrap()
+    }
+
+    fn next_block(&mut self, seconds: u64) {
+        self.suite.app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(seconds);
+        });
+    }
+}
+
+#[test]
+fn proper_initialization() {
+    let suite = mock_suite();
+
+    let config = suite.query_config();
+    assert_eq!(config.governance_token, suite.governance_token);
+    assert_eq!(config.pool_factory_addr, suite.suite.pool_factory_addr);
+    assert_eq!(config.vault_factory_addr, suite.suite.vault_factory_addr);
+    assert_eq!(config.quorum, Decimal::percent(30));
+    assert_eq!(config.threshold, Decimal::percent(50));
+    assert_eq!(config.voting_period, DAY);
+    assert_eq!(config.timelock_period, DAY);
+    assert_eq!(config.proposal_min_stake, Uint128::new(PROPOSAL_MIN_STAKE));
+}
+
+#[test]
+fn cant_instantiate_with_invalid_parameters() {
+    let mut suite = mock_suite();
+
+    let mut invalid_msgs = vec![];
+    let mut msg = instantiate_msg(&suite.suite, native("uwhale"));
+    msg.quorum = Decimal::zero();
+    invalid_msgs.push((msg, ContractError::InvalidQuorum {}));
+    let mut msg = instantiate_msg(&suite.suite, native("uwhale"));
+    msg.quorum = Decimal::percent(101);
+    invalid_msgs.push((msg, ContractError::InvalidQuorum {}));
+    let mut msg = instantiate_msg(&suite.suite, native("uwhale"));
+    msg.threshold = Decimal::one();
+    invalid_msgs.push((msg, ContractError::InvalidThreshold {}));
+    let mut msg = instantiate_msg(&suite.suite, native("uwhale"));
+    msg.voting_period = 0;
+    invalid_msgs.push((msg, ContractError::InvalidVotingPeriod {}));
+
+    for (msg, expected_err) in invalid_msgs {
+        let err: ContractError = suite
+            .suite
+            .app
+            .instantiate_contract(
+                suite.code_id,
+                suite.suite.creator.clone(),
+                &msg,
+                &[],
+                "governance",
+                None,
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, expected_err);
+    }
+}
+
+#[test]
+fn stake_and_unstake() {
+    let mut suite = mock_suite();
+
+    assert_eq!(
+        suite.query_stake("alice"),
+        StakeResponse {
+            stake: Uint128::new(600),
+            total_staked: Uint128::new(1_000),
+        }
+    );
+
+    suite.stake("dave", 1_000).unwrap();
+    assert_eq!(suite.query_stake("dave").stake, Uint128::new(1_000));
+    assert_eq!(suite.query_stake("dave").total_staked, Uint128::new(2_000));
+
+    let err = suite
+        .execute(
+            "alice",
+            &ExecuteMsg::Unstake {
+                amount: Uint128::new(601),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::InsufficientStake {});
+
+    suite
+        .execute(
+            "alice",
+            &ExecuteMsg::Unstake {
+                amount: Uint128::new(600),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        suite.query_stake("alice"),
+        StakeResponse {
+            stake: Uint128::zero(),
+            total_staked: Uint128::new(1_400),
+        }
+    );
+    assert_eq!(
+        suite.suite.query_balance("alice", &suite.governance_token),
+        Uint128::new(600)
+    );
+
+    // only the governance token can be staked
+    let err: ContractError = suite
+        .suite
+        .app
+        .execute_contract(
+            Addr::unchecked("alice"),
+            suite.governance_addr.clone(),
+            &ExecuteMsg::Stake {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AssetMismatch {});
+}
+
+#[test]
+fn stake_native_governance_token() {
+    let mut suite = mock_suite();
+    suite.suite.fund("alice", coins(1_000, "uwhale"));
+
+    let governance_addr = suite
+        .suite
+        .app
+        .instantiate_contract(
+            suite.code_id,
+            suite.suite.creator.clone(),
+            &instantiate_msg(&suite.suite, native("uwhale")),
+            &[],
+            "governance",
+            None,
+        )
+        .unwrap();
+
+    suite
+        .suite
+        .app
+        .execute_contract(
+            Addr::unchecked("alice"),
+            governance_addr.clone(),
+            &ExecuteMsg::Stake {},
+            &coins(1_000, "uwhale"),
+        )
+        .unwrap();
+
+    let stake: StakeResponse = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &governance_addr,
+            &QueryMsg::Stake {
+                address: "alice".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(stake.stake, Uint128::new(1_000));
+
+    suite
+        .suite
+        .app
+        .execute_contract(
+            Addr::unchecked("alice"),
+            governance_addr,
+            &ExecuteMsg::Unstake {
+                amount: Uint128::new(400),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        suite.suite.query_balance("alice", &native("uwhale")),
+        Uint128::new(400)
+    );
+}
+
+#[test]
+fn passed_proposal_is_executed_after_timelock() {
+    let mut suite = mock_suite();
+
+    let proposal_id = suite
+        .propose("alice", update_fee_collector_messages("new_fee_collector"))
+        .unwrap();
+
+    // 600 yes and 300 no, with 90% of the stake voting
+    suite.vote("alice", proposal_id, VoteOption::Yes).unwrap();
+    suite.vote("bob", proposal_id, VoteOption::No).unwrap();
+
+    let res = suite.query_proposal(proposal_id);
+    assert_eq!(res.status, ProposalStatus::Open);
+    assert_eq!(res.proposal.yes_votes, Uint128::new(600));
+    assert_eq!(res.proposal.no_votes, Uint128::new(300));
+    assert_eq!(res.proposal.total_staked, Uint128::new(1_000));
+
+    let vote: Option<Vote> = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.governance_addr,
+            &QueryMsg::Vote {
+                proposal_id,
+                voter: "bob".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        vote,
+        Some(Vote {
+            vote: VoteOption::No,
+            weight: Uint128::new(300),
+        })
+    );
+
+    let err = suite.execute_proposal(proposal_id).unwrap_err();
+    assert_eq!(err, ContractError::VotingNotOver { proposal_id });
+
+    suite.next_block(DAY);
+    assert_eq!(
+        suite.query_proposal(proposal_id).status,
+        ProposalStatus::Passed
+    );
+
+    // the vote is closed once the voting period is over
+    let err = suite
+        .vote("carol", proposal_id, VoteOption::No)
+        .unwrap_err();
+    assert_eq!(err, ContractError::VotingClosed { proposal_id });
+
+    let err = suite.execute_proposal(proposal_id).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TimelockNotExpired {
+            execution_time: res.proposal.execution_time,
+        }
+    );
+
+    suite.next_block(DAY);
+    suite.execute_proposal(proposal_id).unwrap();
+
+    assert_eq!(
+        suite.query_proposal(proposal_id).status,
+        ProposalStatus::Executed
+    );
+    let pool_factory_config: PoolFactoryConfigResponse = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.suite.pool_factory_addr,
+            &PoolFactoryQueryMsg::Config {},
+        )
+        .unwrap();
+    assert_eq!(pool_factory_config.fee_collector_addr, "new_fee_collector");
+    let vault_factory_config: VaultFactoryConfig = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.suite.vault_factory_addr,
+            &VaultFactoryQueryMsg::Config {},
+        )
+        .unwrap();
+    assert_eq!(
+        vault_factory_config.fee_collector_addr,
+        Addr::unchecked("new_fee_collector")
+    );
+
+    let err = suite.execute_proposal(proposal_id).unwrap_err();
+    assert_eq!(err, ContractError::ProposalAlreadyExecuted { proposal_id });
+}
+
+#[test]
+fn proposals_without_quorum_or_majority_are_rejected() {
+    let mut suite = mock_suite();
+
+    // only 10% of the stake votes
+    let no_quorum_id = suite
+        .propose("alice", update_fee_collector_messages("new_fee_collector"))
+        .unwrap();
+    suite.vote("carol", no_quorum_id, VoteOption::Yes).unwrap();
+
+    // 300 yes against 600 no
+    let no_majority_id = suite
+        .propose("alice", update_fee_collector_messages("new_fee_collector"))
+        .unwrap();
+    suite.vote("alice", no_majority_id, VoteOption::No).unwrap();
+    suite.vote("bob", no_majority_id, VoteOption::Yes).unwrap();
+
+    // abstentions count towards the quorum only, 300 yes against 100 no
+    let abstained_id = suite
+        .propose("alice", update_fee_collector_messages("new_fee_collector"))
+        .unwrap();
+    suite
+        .vote("alice", abstained_id, VoteOption::Abstain)
+        .unwrap();
+    suite.vote("bob", abstained_id, VoteOption::Yes).unwrap();
+    suite.vote("carol", abstained_id, VoteOption::No).unwrap();
+
+    suite.next_block(2 * DAY);
+
+    for proposal_id in [no_quorum_id, no_majority_id] {
+        assert_eq!(
+            suite.query_proposal(proposal_id).status,
+            ProposalStatus::Rejected
+        );
+        let err = suite.execute_proposal(proposal_id).unwrap_err();
+        assert_eq!(err, ContractError::ProposalNotPassed { proposal_id });
+    }
+    assert_eq!(
+        suite.query_proposal(abstained_id).status,
+        ProposalStatus::Passed
+    );
+
+    let proposals: ProposalsResponse = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.governance_addr,
+            &QueryMsg::Proposals {
+                start_after: Some(no_quorum_id),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        proposals
+            .proposals
+            .iter()
+            .map(|res| (res.proposal.proposal_id, res.status.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (no_majority_id, ProposalStatus::Rejected),
+            (abstained_id, ProposalStatus::Passed),
+        ]
+    );
+}
+
+#[test]
+fn votes_are_weighted_by_the_stakes_at_the_start_of_the_proposal() {
+    let mut suite = mock_suite();
+
+    // dave stakes in the same block the proposal is created in
+    suite.stake("dave", 1_000).unwrap();
+    let proposal_id = suite
+        .propose("alice", update_fee_collector_messages("new_fee_collector"))
+        .unwrap();
+    assert_eq!(
+        suite.query_proposal(proposal_id).proposal.total_staked,
+        Uint128::new(1_000)
+    );
+
+    suite.next_block(5);
+    let err = suite.vote("dave", proposal_id, VoteOption::No).unwrap_err();
+    assert_eq!(err, ContractError::NoVotingPower {});
+
+    // unstaking after the start of the proposal doesn't change the voting power
+    suite
+        .execute(
+            "alice",
+            &ExecuteMsg::Unstake {
+                amount: Uint128::new(600),
+            },
+        )
+        .unwrap();
+    suite.vote("alice", proposal_id, VoteOption::Yes).unwrap();
+    assert_eq!(
+        suite.query_proposal(proposal_id).proposal.yes_votes,
+        Uint128::new(600)
+    );
+
+    let err = suite
+        .vote("alice", proposal_id, VoteOption::No)
+        .unwrap_err();
+    assert_eq!(err, ContractError::AlreadyVoted { proposal_id });
+
+    // and the unstaked tokens can't be used to vote again
+    let AssetInfo::Token { contract_addr } = suite.governance_token.clone() else {
+        panic!("the governance token should be a cw20 token");
+    };
+    suite
+        .suite
+        .app
+        .execute_contract(
+            Addr::unchecked("alice"),
+            Addr::unchecked(contract_addr),
+            &Cw20ExecuteMsg::Transfer {
+                recipient: "erin".to_string(),
+                amount: Uint128::new(600),
+            },
+            &[],
+        )
+        .unwrap();
+    suite.stake("erin", 600).unwrap();
+    suite.next_block(5);
+    let err = suite
+        .vote("erin", proposal_id, VoteOption::Yes)
+        .unwrap_err();
+    assert_eq!(err, ContractError::NoVotingPower {});
+}
+
+#[test]
+fn only_stakers_can_propose() {
+    let mut suite = mock_suite();
+
+    let err = suite
+        .propose("dave", update_fee_collector_messages("new_fee_collector"))
+        .unwrap_err();
+    assert_eq!(err, ContractError::NoStake {});
+
+    // the stake added in the current block doesn't count
+    suite.stake("dave", 1_000).unwrap();
+    let err = suite
+        .propose("dave", update_fee_collector_messages("new_fee_collector"))
+        .unwrap_err();
+    assert_eq!(err, ContractError::NoStake {});
+    suite.next_block(5);
+    suite
+        .propose("dave", update_fee_collector_messages("new_fee_collector"))
+        .unwrap();
+
+    // a stake lower than the minimum proposal stake isn't enough
+    let err = suite
+        .propose("carol", update_fee_collector_messages("new_fee_collector"))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientProposalStake {
+            stake: Uint128::new(100),
+            min_stake: Uint128::new(PROPOSAL_MIN_STAKE),
+        }
+    );
+}
+
+#[test]
+fn config_is_updated_through_proposals() {
+    let mut suite = mock_suite();
+
+    let update_config = ExecuteMsg::UpdateConfig {
+        quorum: Some(Decimal::percent(50)),
+        threshold: None,
+        voting_period: Some(2 * DAY),
+        timelock_period: Some(0),
+        proposal_min_stake: None,
+    };
+    let err = suite.execute("alice", &update_config).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let proposal_id = suite
+        .propose(
+            "alice",
+            vec![ProposalMsg::UpdateConfig {
+                quorum: Some(Decimal::percent(50)),
+                threshold: None,
+                voting_period: Some(2 * DAY),
+                timelock_period: Some(0),
+                proposal_min_stake: Some(Uint128::new(100)),
+            }],
+        )
+        .unwrap();
+    suite.vote("alice", proposal_id, VoteOption::Yes).unwrap();
+    suite.next_block(2 * DAY);
+    suite.execute_proposal(proposal_id).unwrap();
+
+    let config = suite.query_config();
+    assert_eq!(config.quorum, Decimal::percent(50));
+    assert_eq!(config.threshold, Decimal::percent(50));
+    assert_eq!(config.voting_period, 2 * DAY);
+    assert_eq!(config.timelock_period, 0);
+    assert_eq!(config.proposal_min_stake, Uint128::new(100));
+
+    // carol's stake is now enough to propose
+    suite
+        .propose("carol", update_fee_collector_messages("new_fee_collector"))
+        .unwrap();
+}
+
+#[test]
+fn factories_are_only_governed_through_proposals() {
+    let mut suite = mock_suite();
+    let pool_factory = suite.suite.pool_factory_addr.to_string();
+    suite.suite.fund(&pool_factory, coins(1, "uatom"));
+
+    // the creator is no longer the owner of the factories
+    assert!(suite
+        .suite
+        .app
+        .execute_contract(
+            suite.suite.creator.clone(),
+            suite.suite.pool_factory_addr.clone(),
+            &PoolFactoryExecuteMsg::AddNativeTokenDecimals {
+                denom: "uatom".to_string(),
+                decimals: 6,
+            },
+            &[],
+        )
+        .is_err());
+
+    let proposal_id = suite
+        .propose(
+            "alice",
+            vec![ProposalMsg::PoolFactory(
+                PoolFactoryExecuteMsg::AddNativeTokenDecimals {
+                    denom: "uatom".to_string(),
+                    decimals: 6,
+                },
+            )],
+        )
+        .unwrap();
+    suite.vote("alice", proposal_id, VoteOption::Yes).unwrap();
+    suite.next_block(2 * DAY);
+    suite.execute_proposal(proposal_id).unwrap();
+
+    let res: NativeTokenDecimalsResponse = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.suite.pool_factory_addr,
+            &PoolFactoryQueryMsg::NativeTokenDecimals {
+                denom: "uatom".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.decimals, 6);
+}
+
+#[test]
+fn can_migrate_contract() {
+    let mut suite = mock_suite();
+
+    // should not be able to migrate as the version is not higher
+    let err: ContractError = suite
+        .suite
+        .app
+        .migrate_contract(
+            suite.suite.creator.clone(),
+            suite.governance_addr.clone(),
+            &MigrateMsg {},
+            suite.code_id,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    match err {
+        ContractError::MigrateInvalidVersion { .. } => (),
+        _ => panic!("should return ContractError::MigrateInvalidVersion"),
+    }
+}
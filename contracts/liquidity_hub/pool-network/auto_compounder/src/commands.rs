@@ -0,0 +1,540 @@
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, Coin, CosmosMsg, Decimal, DepsMut, Env, Fraction, MessageInfo,
+    Response, StdResult, Uint128, WasmMsg,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+
+use pool_network::asset::{Asset, AssetInfo, MINIMUM_LIQUIDITY_AMOUNT};
+use pool_network::auto_compounder::{
+    CallbackMsg, Config, Cw20HookMsg, ExecuteMsg, IncentiveExecuteMsg, RewardRoute,
+};
+use pool_network::lp_token::{burn_lp_token_msg, mint_lp_token_msgs, query_lp_total_supply};
+use pool_network::pair::{ExecuteMsg as PairExecuteMsg, PoolResponse, QueryMsg as PairQueryMsg};
+use pool_network::router::{
+    Cw20HookMsg as RouterCw20HookMsg, ExecuteMsg as RouterExecuteMsg, SwapOperation,
+};
+
+use crate::error::ContractError;
+use crate::state::CONFIG;
+
+/// Deposits the LP tokens or withdraws the shares sent to the contract
+pub fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let sent_asset_info = AssetInfo::Token {
+        contract_addr: info.sender.to_string(),
+    };
+    let sender = deps.api.addr_validate(&cw20_msg.sender)?;
+
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::Deposit {} => {
+            if !sent_asset_info.equal(&config.liquidity_token) {
+                return Err(ContractError::AssetMismatch {});
+            }
+
+            deposit(deps, env, config, sender, cw20_msg.amount)
+        }
+        Cw20HookMsg::Withdraw {} => {
+            if !sent_asset_info.equal(&config.share_token) {
+                return Err(ContractError::AssetMismatch {});
+            }
+
+            withdraw(deps, env, config, sender, cw20_msg.amount)
+        }
+    }
+}
+
+/// Deposits the native LP tokens sent along
+pub fn deposit_native(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !config.liquidity_token.is_native_token() {
+        return Err(ContractError::AssetMismatch {});
+    }
+
+    Asset {
+        info: config.liquidity_token.clone(),
+        amount,
+    }
+    .assert_sent_native_token_balance(&info)?;
+
+    deposit(deps, env, config, info.sender, amount)
+}
+
+/// Mints shares for the LP tokens deposited, which the contract already holds. The shares are
+/// minted in proportion to the LP tokens held before the deposit, i.e. including the compounded
+/// rewards. The first deposit locks [MINIMUM_LIQUIDITY_AMOUNT] shares in the contract, so the
+/// share price can't be inflated by donating LP tokens to an almost empty contract.
+fn deposit(
+    deps: DepsMut,
+    env: Env,
+    config: Config,
+    sender: Addr,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let total_shares = query_lp_total_supply(&deps.querier, &config.share_token)?;
+    let lp_balance =
+        config
+            .liquidity_token
+            .query_pool(&deps.querier, deps.api, env.contract.address.clone())?;
+    let prev_lp_balance = lp_balance.checked_sub(amount)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let shares = if total_shares.is_zero() {
+        messages.extend(mint_lp_token_msgs(
+            &config.share_token,
+            &env.contract.address,
+            env.contract.address.to_string(),
+            MINIMUM_LIQUIDITY_AMOUNT,
+        )?);
+
+        let shares = amount
+            .checked_sub(MINIMUM_LIQUIDITY_AMOUNT)
+            .map_err(|_| ContractError::InvalidInitialDepositAmount(MINIMUM_LIQUIDITY_AMOUNT))?;
+        if shares.is_zero() {
+            return Err(ContractError::InvalidInitialDepositAmount(
+                MINIMUM_LIQUIDITY_AMOUNT,
+            ));
+        }
+
+        shares
+    } else if prev_lp_balance.is_zero() {
+        amount
+    } else {
+        amount.multiply_ratio(total_shares, prev_lp_balance)
+    };
+
+    if shares.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_messages(mint_lp_token_msgs(
+            &config.share_token,
+            &env.contract.address,
+            sender.to_string(),
+            shares,
+        )?)
+        .add_attributes(vec![
+            ("action", "deposit"),
+            ("sender", sender.as_str()),
+            ("lp_amount", &amount.to_string()),
+            ("shares", &shares.to_string()),
+        ]))
+}
+
+/// Burns the shares sent to the contract, sending the LP tokens they are entitled to to the sender
+fn withdraw(
+    deps: DepsMut,
+    env: Env,
+    config: Config,
+    sender: Addr,
+    shares: Uint128,
+) -> Result<Response, ContractError> {
+    if shares.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    // the shares sent are not burned yet, so they are part of the total
+    let total_shares = query_lp_total_supply(&deps.querier, &config.share_token)?;
+    let lp_balance =
+        config
+            .liquidity_token
+            .query_pool(&deps.querier, deps.api, env.contract.address.clone())?;
+    let lp_amount = lp_balance.multiply_ratio(shares, total_shares);
+
+    let mut messages: Vec<CosmosMsg> = vec![burn_lp_token_msg(
+        &config.share_token,
+        &env.contract.address,
+        shares,
+    )?];
+    if !lp_amount.is_zero() {
+        messages.push(
+            Asset {
+                info: config.liquidity_token,
+                amount: lp_amount,
+            }
+            .into_msg(sender.clone())?,
+        );
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "withdraw"),
+        ("sender", sender.as_str()),
+        ("shares", &shares.to_string()),
+        ("lp_amount", &lp_amount.to_string()),
+    ]))
+}
+
+/// Claims the rewards from the incentive contract, if any, and compounds the rewards held by the
+/// contract via the [CallbackMsg::SwapRewards] and [CallbackMsg::ProvideLiquidity] callbacks
+pub fn harvest(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if let Some(incentive_contract) = config.incentive_contract {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: incentive_contract.to_string(),
+            msg: to_binary(&IncentiveExecuteMsg::Claim {})?,
+            funds: vec![],
+        }));
+    }
+
+    for callback in [
+        CallbackMsg::SwapRewards {},
+        CallbackMsg::ProvideLiquidity {},
+    ] {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::Callback(callback))?,
+            funds: vec![],
+        }));
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "harvest"))
+}
+
+pub fn callback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: CallbackMsg,
+) -> Result<Response, ContractError> {
+    // callback can only be called by contract
+    if info.sender != env.contract.address {
+        return Err(ContractError::ExternalCallback {});
+    }
+
+    match msg {
+        CallbackMsg::SwapRewards {} => swap_rewards(deps, env),
+        CallbackMsg::ProvideLiquidity {} => provide_liquidity(deps, env),
+    }
+}
+
+/// Swaps the reward assets held by the contract through the pool router, half into each asset of
+/// the pair. The half routed to the reward asset itself is kept as is. Each swap has to return at
+/// least the minimum price of its route set by the owner, as anyone can harvest.
+fn swap_rewards(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut attributes: Vec<(&str, String)> = vec![("action", "swap_rewards".to_string())];
+    for reward_route in config.reward_routes {
+        let balance = reward_route.reward_asset_info.query_pool(
+            &deps.querier,
+            deps.api,
+            env.contract.address.clone(),
+        )?;
+        if balance.is_zero() {
+            continue;
+        }
+
+        let half = balance.multiply_ratio(1u128, 2u128);
+        let [operations_0, operations_1] = reward_route.swap_operations;
+        let [min_price_0, min_price_1] = reward_route.min_prices;
+        for (amount, operations, min_price) in [
+            (half, operations_0, min_price_0),
+            (balance - half, operations_1, min_price_1),
+        ] {
+            if amount.is_zero() || operations.is_empty() {
+                continue;
+            }
+
+            messages.push(swap_msg(
+                &config.pool_router,
+                Asset {
+                    info: reward_route.reward_asset_info.clone(),
+                    amount,
+                },
+                operations,
+                amount * min_price,
+            )?);
+        }
+
+        attributes.push((
+            "reward",
+            format!("{balance}{}", reward_route.reward_asset_info),
+        ));
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attributes(attributes))
+}
+
+/// Creates the message swapping the offer asset through the pool router, back to the contract
+fn swap_msg(
+    pool_router: &Addr,
+    offer_asset: Asset,
+    operations: Vec<SwapOperation>,
+    minimum_receive: Uint128,
+) -> StdResult<CosmosMsg> {
+    match offer_asset.info {
+        AssetInfo::NativeToken { denom } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: pool_router.to_string(),
+            funds: vec![Coin {
+                denom,
+                amount: offer_asset.amount,
+            }],
+            msg: to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                operations,
+                minimum_receive: Some(minimum_receive),
+                to: None,
+            })?,
+        })),
+        AssetInfo::Token { contract_addr } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                contract: pool_router.to_string(),
+                amount: offer_asset.amount,
+                msg: to_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+                    operations,
+                    minimum_receive: Some(minimum_receive),
+                    to: None,
+                })?,
+            })?,
+        })),
+    }
+}
+
+/// Provides the assets of the pair held by the contract as liquidity to the pair, at the reference
+/// price. The pair rejects the liquidity if its price is beyond the slippage tolerance of the
+/// reference price. The LP tokens minted are kept by the contract.
+fn provide_liquidity(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let pool: PoolResponse = deps
+        .querier
+        .query_wasm_smart(config.pair_address.to_string(), &PairQueryMsg::Pool {})?;
+
+    let balance_0 =
+        config.asset_infos[0].query_pool(&deps.querier, deps.api, env.contract.address.clone())?;
+    let balance_1 =
+        config.asset_infos[1].query_pool(&deps.querier, deps.api, env.contract.address.clone())?;
+
+    if pool.assets.iter().any(|asset| asset.amount.is_zero()) {
+        return Ok(
+            Response::new().add_attributes(vec![("action", "provide_liquidity"), ("amount", "0")])
+        );
+    }
+
+    // match the reference price rather than the pool reserves, which can be moved before the
+    // harvest, keeping what's left for the next harvest
+    let balance_1_for_balance_0 = balance_0 * config.reference_price;
+    let (amount_0, amount_1) = if balance_1 <= balance_1_for_balance_0 {
+        (
+            balance_1 * config.reference_price.inv().unwrap_or_default(),
+            balance_1,
+        )
+    } else {
+        (balance_0, balance_1_for_balance_0)
+    };
+
+    if amount_0.is_zero() || amount_1.is_zero() {
+        return Ok(
+            Response::new().add_attributes(vec![("action", "provide_liquidity"), ("amount", "0")])
+        );
+    }
+
+    let [asset_info_0, asset_info_1] = config.asset_infos;
+    let assets = [
+        Asset {
+            info: asset_info_0,
+            amount: amount_0,
+        },
+        Asset {
+            info: asset_info_1,
+            amount: amount_1,
+        },
+    ];
+
+    // cw20 tokens are pulled by the pair, native tokens are sent along with the message
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut funds: Vec<Coin> = vec![];
+    for asset in assets.iter() {
+        match &asset.info {
+            AssetInfo::Token { contract_addr } => messages.push(
+                WasmMsg::Execute {
+                    contract_addr: contract_addr.clone(),
+                    msg: to_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                        spender: config.pair_address.to_string(),
+                        amount: asset.amount,
+                        expires: None,
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+            ),
+            AssetInfo::NativeToken { denom } => funds.push(Coin {
+                denom: denom.clone(),
+                amount: asset.amount,
+            }),
+        }
+    }
+    funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+    messages.push(
+        WasmMsg::Execute {
+            contract_addr: config.pair_address.to_string(),
+            msg: to_binary(&PairExecuteMsg::ProvideLiquidity {
+                assets,
+                slippage_tolerance: Some(config.slippage_tolerance),
+                receiver: None,
+            })?,
+            funds,
+        }
+        .into(),
+    );
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "provide_liquidity".to_string()),
+        ("amount_0", amount_0.to_string()),
+        ("amount_1", amount_1.to_string()),
+    ]))
+}
+
+/// Updates the owner, the pool router, the incentive contract, the reward routes, the reference
+/// price and the slippage tolerance of the contract
+#[allow(clippy::too_many_arguments)]
+pub fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    owner: Option<String>,
+    pool_router: Option<String>,
+    incentive_contract: Option<String>,
+    reward_routes: Option<Vec<RewardRoute>>,
+    reference_price: Option<Decimal>,
+    slippage_tolerance: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // only the owner can update the config
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(owner) = owner {
+        config.owner = deps.api.addr_validate(&owner)?;
+    }
+
+    if let Some(pool_router) = pool_router {
+        config.pool_router = deps.api.addr_validate(&pool_router)?;
+    }
+
+    if let Some(incentive_contract) = incentive_contract {
+        config.incentive_contract = Some(deps.api.addr_validate(&incentive_contract)?);
+    }
+
+    if let Some(reward_routes) = reward_routes {
+        validate_reward_routes(&config.asset_infos, &config.liquidity_token, &reward_routes)?;
+        config.reward_routes = reward_routes;
+    }
+
+    if let Some(reference_price) = reference_price {
+        validate_reference_price(reference_price)?;
+        config.reference_price = reference_price;
+    }
+
+    if let Some(slippage_tolerance) = slippage_tolerance {
+        validate_slippage_tolerance(slippage_tolerance)?;
+        config.slippage_tolerance = slippage_tolerance;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+/// Validates each reward route swaps the reward into each asset of the pair at a non-zero minimum
+/// price, with an empty route to the reward asset itself. The LP token can't be a reward, as it's
+/// what the contract holds.
+pub fn validate_reward_routes(
+    asset_infos: &[AssetInfo; 2],
+    liquidity_token: &AssetInfo,
+    reward_routes: &[RewardRoute],
+) -> Result<(), ContractError> {
+    for reward_route in reward_routes {
+        let invalid_reward_route = || ContractError::InvalidRewardRoute {
+            reward_asset: reward_route.reward_asset_info.to_string(),
+        };
+
+        if reward_route.reward_asset_info.equal(liquidity_token) {
+            return Err(invalid_reward_route());
+        }
+
+        for ((asset_info, operations), min_price) in asset_infos
+            .iter()
+            .zip(reward_route.swap_operations.iter())
+            .zip(reward_route.min_prices.iter())
+        {
+            // the reward is provided as is when it's an asset of the pair
+            if reward_route.reward_asset_info.equal(asset_info) && !operations.is_empty() {
+                return Err(invalid_reward_route());
+            }
+
+            // the minimum price is what bounds the swaps, as anyone can harvest
+            if !operations.is_empty() && min_price.is_zero() {
+                return Err(invalid_reward_route());
+            }
+
+            let mut target_asset_info = reward_route.reward_asset_info.clone();
+            for operation in operations {
+                match operation {
+                    SwapOperation::TerraSwap {
+                        offer_asset_info,
+                        ask_asset_info,
+                        ..
+                    } => {
+                        if !offer_asset_info.equal(&target_asset_info) {
+                            return Err(invalid_reward_route());
+                        }
+
+                        target_asset_info = ask_asset_info.clone();
+                    }
+                }
+            }
+
+            // an empty route only leads to the reward asset itself
+            if !target_asset_info.equal(asset_info) {
+                return Err(invalid_reward_route());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the reference price the harvested assets are provided at is not zero
+pub fn validate_reference_price(reference_price: Decimal) -> Result<(), ContractError> {
+    if reference_price.is_zero() {
+        return Err(ContractError::InvalidZeroReferencePrice {});
+    }
+
+    Ok(())
+}
+
+/// Validates the slippage tolerance of the liquidity provided is lower than 1
+pub fn validate_slippage_tolerance(slippage_tolerance: Decimal) -> Result<(), ContractError> {
+    if slippage_tolerance >= Decimal::one() {
+        return Err(ContractError::InvalidSlippageTolerance {});
+    }
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, ReplyOn, Response, StdError,
+    StdResult, SubMsg, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw20::MinterResponse;
+use protobuf::Message;
+use semver::Version;
+
+use pool_network::asset::{AssetInfo, PairInfo};
+use pool_network::auto_compounder::{
+    Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, INSTANTIATE_SHARE_TOKEN_REPLY_ID,
+};
+use pool_network::querier::query_pair_info_from_pair;
+
+use crate::error::ContractError;
+use crate::error::ContractError::MigrateInvalidVersion;
+use crate::response::MsgInstantiateContractResponse;
+use crate::state::CONFIG;
+use crate::{commands, queries};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "nico_teen-auto_compounder";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The name and symbol of the share token
+const SHARE_TOKEN_NAME: &str = "WW auto-compounded LP share";
+const SHARE_TOKEN_SYMBOL: &str = "ACLP";
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let pair_address = deps.api.addr_validate(&msg.pair_address)?;
+    let pair_info: PairInfo = query_pair_info_from_pair(&deps.querier, pair_address.clone())?;
+
+    commands::validate_reward_routes(
+        &pair_info.asset_infos,
+        &pair_info.liquidity_token,
+        &msg.reward_routes,
+    )?;
+    commands::validate_reference_price(msg.reference_price)?;
+    commands::validate_slippage_tolerance(msg.slippage_tolerance)?;
+
+    let config = Config {
+        owner: deps.api.addr_validate(&msg.owner)?,
+        pair_address,
+        asset_infos: pair_info.asset_infos,
+        liquidity_token: pair_info.liquidity_token,
+        // we patch this in the INSTANTIATE_SHARE_TOKEN_REPLY
+        share_token: AssetInfo::Token {
+            contract_addr: "".to_string(),
+        },
+        pool_router: deps.api.addr_validate(&msg.pool_router)?,
+        incentive_contract: msg
+            .incentive_contract
+            .map(|incentive_contract| deps.api.addr_validate(&incentive_contract))
+            .transpose()?,
+        reward_routes: msg.reward_routes,
+        reference_price: msg.reference_price,
+        slippage_tolerance: msg.slippage_tolerance,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    let share_token_instantiate_msg = SubMsg {
+        id: INSTANTIATE_SHARE_TOKEN_REPLY_ID,
+        gas_limit: None,
+        reply_on: ReplyOn::Success,
+        msg: WasmMsg::Instantiate {
+            admin: None,
+            code_id: msg.token_code_id,
+            msg: to_binary(&pool_network::token::InstantiateMsg {
+                name: SHARE_TOKEN_NAME.to_string(),
+                symbol: SHARE_TOKEN_SYMBOL.to_string(),
+                decimals: 6,
+                initial_balances: vec![],
+                mint: Some(MinterResponse {
+                    minter: env.contract.address.to_string(),
+                    cap: None,
+                }),
+                marketing: None,
+            })?,
+            funds: vec![],
+            label: SHARE_TOKEN_NAME.to_string(),
+        }
+        .into(),
+    };
+
+    Ok(Response::new()
+        .add_submessage(share_token_instantiate_msg)
+        .add_attributes(vec![
+            ("action", "instantiate"),
+            ("owner", config.owner.as_str()),
+            ("pair_address", config.pair_address.as_str()),
+        ]))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Receive(msg) => commands::receive_cw20(deps, env, info, msg),
+        ExecuteMsg::Deposit { amount } => commands::deposit_native(deps, env, info, amount),
+        ExecuteMsg::Harvest {} => commands::harvest(deps, env),
+        ExecuteMsg::UpdateConfig {
+            owner,
+            pool_router,
+            incentive_contract,
+            reward_routes,
+            reference_price,
+            slippage_tolerance,
+        } => commands::update_config(
+            deps,
+            info,
+            owner,
+            pool_router,
+            incentive_contract,
+            reward_routes,
+            reference_price,
+            slippage_tolerance,
+        ),
+        ExecuteMsg::Callback(msg) => commands::callback(deps, env, info, msg),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+    match msg.id {
+        INSTANTIATE_SHARE_TOKEN_REPLY_ID => store_share_token(deps, msg),
+        _ => Err(StdError::generic_err(format!(
+            "Did not handle message reply of id '{}'",
+            msg.id
+        ))),
+    }
+}
+
+/// Stores the address of the share token instantiated
+fn store_share_token(deps: DepsMut, msg: Reply) -> StdResult<Response> {
+    let data = msg
+        .result
+        .into_result()
+        .map_err(|_| StdError::generic_err("Failed to get result of share token instantiation"))?
+        .data
+        .ok_or_else(|| {
+            StdError::generic_err("Failed to read binary data of share token instantiation")
+        })?;
+
+    let res: MsgInstantiateContractResponse =
+        Message::parse_from_bytes(data.as_slice()).map_err(|_| {
+            StdError::parse_err("MsgInstantiateContractResponse", "failed to parse data")
+        })?;
+    let share_token = deps.api.addr_validate(&res.address)?;
+
+    CONFIG.update::<_, StdError>(deps.storage, |mut config| {
+        config.share_token = AssetInfo::Token {
+            contract_addr: share_token.to_string(),
+        };
+
+        Ok(config)
+    })?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "reply_share_token_instantiate"),
+        ("share_token_addr", share_token.as_str()),
+    ]))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    match msg {
+        QueryMsg::Config {} => Ok(to_binary(&queries::query_config(deps)?)?),
+        QueryMsg::Share { amount } => Ok(to_binary(&queries::query_share(deps, env, amount)?)?),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let version: Version = CONTRACT_VERSION.parse()?;
+    let storage_version: Version = get_contract_version(deps.storage)?.version.parse()?;
+
+    if storage_version >= version {
+        return Err(MigrateInvalidVersion {
+            current_version: storage_version,
+            new_version: version,
+        });
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
@@ -0,0 +1,51 @@
+use cosmwasm_std::{OverflowError, StdError, Uint128};
+use semver::Version;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("Attempt to migrate to version {new_version}, but contract is on a higher version {current_version}")]
+    MigrateInvalidVersion {
+        new_version: Version,
+        current_version: Version,
+    },
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Callbacks can only be invoked by the contract itself")]
+    ExternalCallback {},
+
+    #[error("Can't deposit or withdraw a zero amount")]
+    InvalidZeroAmount {},
+
+    #[error("Initial deposit amount must be over {0}")]
+    InvalidInitialDepositAmount(Uint128),
+
+    #[error("The slippage tolerance must be lower than 1")]
+    InvalidSlippageTolerance {},
+
+    #[error("The reference price can't be zero")]
+    InvalidZeroReferencePrice {},
+
+    #[error("The asset sent doesn't match the LP token of the pair or the share token")]
+    AssetMismatch {},
+
+    #[error("Invalid reward route for {reward_asset}, it has to route the reward to each asset of the pair with a non-zero minimum price")]
+    InvalidRewardRoute { reward_asset: String },
+}
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
+}
@@ -0,0 +1,30 @@
+use cosmwasm_std::{Deps, Env, Uint128};
+
+use pool_network::auto_compounder::ConfigResponse;
+use pool_network::lp_token::query_lp_total_supply;
+
+use crate::error::ContractError;
+use crate::state::CONFIG;
+
+/// Queries the [Config] of the contract
+pub fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
+    Ok(CONFIG.load(deps.storage)?)
+}
+
+/// Queries the amount of LP tokens held by the contract the given amount of shares is entitled to
+pub fn query_share(deps: Deps, env: Env, amount: Uint128) -> Result<Uint128, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let total_shares = query_lp_total_supply(&deps.querier, &config.share_token)?;
+    if total_shares.is_zero() {
+        return Ok(Uint128::zero());
+    }
+
+    let lp_balance =
+        config
+            .liquidity_token
+            .query_pool(&deps.querier, deps.api, env.contract.address)?;
+
+    // the same amount the shares are withdrawn for
+    Ok(lp_balance.multiply_ratio(amount, total_shares))
+}
@@ -0,0 +1,5 @@
+use cw_storage_plus::Item;
+
+use pool_network::auto_compounder::Config;
+
+pub const CONFIG: Item<Config> = Item::new("config");
@@ -0,0 +1,875 @@
+use cosmwasm_std::{
+    coin, coins, to_binary, Addr, BankMsg, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult, Uint128,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+
+use liquidity_hub_testing::{native, PairBuilder, TestSuite};
+use pool_network::asset::{Asset, AssetInfo, PairInfo, MINIMUM_LIQUIDITY_AMOUNT};
+use pool_network::auto_compounder::{
+    CallbackMsg, ConfigResponse, Cw20HookMsg, ExecuteMsg, IncentiveExecuteMsg, InstantiateMsg,
+    MigrateMsg, QueryMsg, RewardRoute,
+};
+use pool_network::pair::ExecuteMsg as PairExecuteMsg;
+use pool_network::router::SwapOperation;
+
+use crate::contract::{execute, instantiate, migrate, query, reply};
+use crate::ContractError;
+
+/// The amount of each asset provided to the pairs
+const POOL_LIQUIDITY: u128 = 1_000_000_000u128;
+
+fn auto_compounder_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(execute, instantiate, query)
+            .with_reply(reply)
+            .with_migrate(migrate),
+    )
+}
+
+/// A mock incentive contract sending its whole balance to whoever claims
+fn incentive_contract() -> Box<dyn Contract<Empty>> {
+    fn instantiate(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn execute(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        _: IncentiveExecuteMsg,
+    ) -> StdResult<Response> {
+        let balances = deps.querier.query_all_balances(env.contract.address)?;
+        if balances.is_empty() {
+            return Ok(Response::new());
+        }
+
+        Ok(Response::new().add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: balances,
+        }))
+    }
+
+    fn query(_: Deps, _: Env, _: Empty) -> StdResult<Binary> {
+        Err(StdError::generic_err("not supported"))
+    }
+
+    Box::new(ContractWrapper::new_with_empty(execute, instantiate, query))
+}
+
+struct AutoCompounderSuite {
+    suite: TestSuite,
+    code_id: u64,
+    compounder_addr: Addr,
+    /// The uluna-uwhale pair the LP tokens of are compounded
+    pair_info: PairInfo,
+    /// The cw20 token of the uwhale-asset pair
+    asset: AssetInfo,
+}
+
+fn swap_operation(offer_asset_info: &AssetInfo, ask_asset_info: &AssetInfo) -> SwapOperation {
+    SwapOperation::TerraSwap {
+        offer_asset_info: offer_asset_info.clone(),
+        ask_asset_info: ask_asset_info.clone(),
+        pair_type: None,
+        fee_tier: None,
+    }
+}
+
+/// The routes of the uwhale and asset rewards into the assets of the uluna-uwhale pair, the pairs
+/// returning about 1 ask asset per offer asset
+fn reward_routes(asset: &AssetInfo) -> Vec<RewardRoute> {
+    vec![
+        RewardRoute {
+            reward_asset_info: native("uwhale"),
+            swap_operations: [
+                vec![swap_operation(&native("uwhale"), &native("uluna"))],
+                vec![],
+            ],
+            min_prices: [Decimal::percent(90), Decimal::zero()],
+        },
+        RewardRoute {
+            reward_asset_info: asset.clone(),
+            swap_operations: [
+                vec![
+                    swap_operation(asset, &native("uwhale")),
+                    swap_operation(&native("uwhale"), &native("uluna")),
+                ],
+                vec![swap_operation(asset, &native("uwhale"))],
+            ],
+            min_prices: [Decimal::percent(90); 2],
+        },
+    ]
+}
+
+/// Creates the uluna-uwhale and uwhale-asset pairs and the auto-compounder on the uluna-uwhale pair.
+/// alice provides the liquidity of the pairs, bob is funded with the assets.
+fn mock_suite() -> AutoCompounderSuite {
+    let funds = vec![
+        coin(10 * POOL_LIQUIDITY, "uluna"),
+        coin(10 * POOL_LIQUIDITY, "uwhale"),
+    ];
+    let mut suite = TestSuite::builder()
+        .with_funded_user("alice", funds.clone())
+        .with_funded_user("bob", funds)
+        .build();
+
+    let asset = suite.create_token(
+        "asset",
+        &[("alice", 10 * POOL_LIQUIDITY), ("bob", 10 * POOL_LIQUIDITY)],
+    );
+
+    let pair_info = suite.create_pair(
+        PairBuilder::new([native("uluna"), native("uwhale")])
+            .with_liquidity("alice", [Uint128::new(POOL_LIQUIDITY); 2]),
+    );
+    suite.create_pair(
+        PairBuilder::new([native("uwhale"), asset.clone()])
+            .with_liquidity("alice", [Uint128::new(POOL_LIQUIDITY); 2]),
+    );
+
+    let code_id = suite.app.store_code(auto_compounder_contract());
+    let mut suite = AutoCompounderSuite {
+        suite,
+        code_id,
+        compounder_addr: Addr::unchecked(""),
+        pair_info,
+        asset,
+    };
+    let routes = reward_routes(&suite.asset);
+    suite.compounder_addr = suite.instantiate(routes).unwrap();
+
+    suite
+}
+
+impl AutoCompounderSuite {
+    fn instantiate(&mut self, reward_routes: Vec<RewardRoute>) -> Result<Addr, ContractError> {
+        self.suite
+            .app
+            .instantiate_contract(
+                self.code_id,
+                self.suite.creator.clone(),
+                &InstantiateMsg {
+                    owner: self.suite.creator.to_string(),
+                    pair_address: self.pair_info.contract_addr.clone(),
+                    pool_router: self.suite.pool_router_addr.to_string(),
+                    token_code_id: self.suite.token_code_id,
+                    incentive_contract: None,
+                    reward_routes,
+                    reference_price: Decimal::one(),
+                    slippage_tolerance: Decimal::percent(1),
+                },
+                &[],
+                "auto-compounder",
+                Some(self.suite.creator.to_string()),
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    fn execute(&mut self, sender: &str, msg: &ExecuteMsg) -> Result<(), ContractError> {
+        self.suite
+            .app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.compounder_addr.clone(),
+                msg,
+                &[],
+            )
+            .map(|_| ())
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    /// Sends cw20 tokens to the auto-compounder with the given hook message
+    fn send(
+        &mut self,
+        sender: &str,
+        token: &AssetInfo,
+        amount: Uint128,
+        msg: &Cw20HookMsg,
+    ) -> Result<(), ContractError> {
+        let AssetInfo::Token { contract_addr } = token else {
+            panic!("the token should be a cw20 token");
+        };
+
+        self.suite
+            .app
+            .execute_contract(
+                Addr::unchecked(sender),
+                Addr::unchecked(contract_addr),
+                &Cw20ExecuteMsg::Send {
+                    contract: self.compounder_addr.to_string(),
+                    amount,
+                    msg: to_binary(msg).unwrap(),
+                },
+                &[],
+            )
+            .map(|_| ())
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    fn deposit(&mut self, sender: &str, amount: Uint128) -> Result<(), ContractError> {
+        let liquidity_token = self.pair_info.liquidity_token.clone();
+        self.send(sender, &liquidity_token, amount, &Cw20HookMsg::Deposit {})
+    }
+
+    fn withdraw(&mut self, sender: &str, shares: Uint128) -> Result<(), ContractError> {
+        let share_token = self.query_config().share_token;
+        self.send(sender, &share_token, shares, &Cw20HookMsg::Withdraw {})
+    }
+
+    fn update_config(&mut self, msg: ExecuteMsg) -> Result<(), ContractError> {
+        let creator = self.suite.creator.to_string();
+        self.execute(&creator, &msg)
+    }
+
+    fn harvest(&mut self) -> Result<(), String> {
+        self.suite
+            .app
+            .execute_contract(
+                Addr::unchecked("bob"),
+                self.compounder_addr.clone(),
+                &ExecuteMsg::Harvest {},
+                &[],
+            )
+            .map(|_| ())
+            .map_err(|err| err.root_cause().to_string())
+    }
+
+    fn query_config(&self) -> ConfigResponse {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.compounder_addr, &QueryMsg::Config {})
+            .unwrap()
+    }
+
+    fn query_share(&self, amount: Uint128) -> Uint128 {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.compounder_addr, &QueryMsg::Share { amount })
+            .unwrap()
+    }
+
+    fn lp_balance(&self, address: &str) -> Uint128 {
+        self.suite
+            .query_balance(address, &self.pair_info.liquidity_token)
+    }
+
+    fn share_balance(&self, address: &str) -> Uint128 {
+        self.suite
+            .query_balance(address, &self.query_config().share_token)
+    }
+}
+
+#[test]
+fn proper_initialization() {
+    let suite = mock_suite();
+
+    let config = suite.query_config();
+    assert_eq!(config.owner, suite.suite.creator);
+    assert_eq!(config.pair_address, suite.pair_info.contract_addr);
+    assert_eq!(config.asset_infos, suite.pair_info.asset_infos);
+    assert_eq!(config.liquidity_token, suite.pair_info.liquidity_token);
+    assert_eq!(config.pool_router, suite.suite.pool_router_addr);
+    assert_eq!(config.incentive_contract, None);
+    assert_eq!(config.reward_routes, reward_routes(&suite.asset));
+    assert_eq!(config.reference_price, Decimal::one());
+    assert_eq!(config.slippage_tolerance, Decimal::percent(1));
+
+    // the share token is instantiated with the contract as minter
+    let AssetInfo::Token { contract_addr } = config.share_token else {
+        panic!("the share token should be a cw20 token");
+    };
+    let minter: Option<cw20::MinterResponse> = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(contract_addr, &cw20::Cw20QueryMsg::Minter {})
+        .unwrap();
+    assert_eq!(minter.unwrap().minter, suite.compounder_addr.to_string());
+}
+
+#[test]
+fn deposit_and_withdraw() {
+    let mut suite = mock_suite();
+    let lp_balance = suite.lp_balance("alice");
+
+    // the first deposit has to be over the shares locked in the contract
+    let err = suite
+        .deposit("alice", MINIMUM_LIQUIDITY_AMOUNT)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidInitialDepositAmount(MINIMUM_LIQUIDITY_AMOUNT)
+    );
+
+    suite.deposit("alice", Uint128::new(10_000)).unwrap();
+
+    // the first deposit mints as many shares as LP tokens, minus the shares locked in the contract
+    let compounder = suite.compounder_addr.to_string();
+    assert_eq!(suite.share_balance("alice"), Uint128::new(9_000));
+    assert_eq!(suite.share_balance(&compounder), MINIMUM_LIQUIDITY_AMOUNT);
+    assert_eq!(suite.lp_balance("alice"), lp_balance - Uint128::new(10_000));
+    assert_eq!(suite.lp_balance(&compounder), Uint128::new(10_000));
+    assert_eq!(suite.query_share(Uint128::new(4_000)), Uint128::new(4_000));
+
+    suite.withdraw("alice", Uint128::new(4_000)).unwrap();
+
+    assert_eq!(suite.share_balance("alice"), Uint128::new(5_000));
+    assert_eq!(suite.lp_balance("alice"), lp_balance - Uint128::new(6_000));
+    assert_eq!(suite.lp_balance(&compounder), Uint128::new(6_000));
+}
+
+#[test]
+fn first_deposit_locks_minimum_shares() {
+    let mut suite = mock_suite();
+    let compounder = suite.compounder_addr.to_string();
+
+    let AssetInfo::Token { contract_addr } = suite.pair_info.liquidity_token.clone() else {
+        panic!("the LP token should be a cw20 token");
+    };
+    let transfer_lp = |suite: &mut AutoCompounderSuite, recipient: &str, amount: u128| {
+        suite
+            .suite
+            .app
+            .execute_contract(
+                Addr::unchecked("alice"),
+                Addr::unchecked(contract_addr.clone()),
+                &Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount: Uint128::new(amount),
+                },
+                &[],
+            )
+            .unwrap();
+    };
+    transfer_lp(&mut suite, "bob", 50_000);
+
+    // alice deposits just over the locked shares and donates LP tokens to inflate the share price
+    suite
+        .deposit("alice", MINIMUM_LIQUIDITY_AMOUNT + Uint128::one())
+        .unwrap();
+    assert_eq!(suite.share_balance("alice"), Uint128::one());
+    transfer_lp(&mut suite, &compounder, 100_000);
+
+    // the next depositor still gets shares worth about the LP tokens deposited, as the donation
+    // mostly accrues to the locked shares
+    suite.deposit("bob", Uint128::new(50_000)).unwrap();
+    let shares = suite.share_balance("bob");
+    assert!(!shares.is_zero());
+    assert!(suite.query_share(shares) > Uint128::new(49_900));
+}
+
+#[test]
+fn cant_deposit_other_tokens() {
+    let mut suite = mock_suite();
+
+    // the asset token is not the LP token of the pair
+    let asset = suite.asset.clone();
+    let err = suite
+        .send("bob", &asset, Uint128::new(1_000), &Cw20HookMsg::Deposit {})
+        .unwrap_err();
+    assert_eq!(err, ContractError::AssetMismatch {});
+
+    // LP tokens are withdrawn with shares only
+    suite.deposit("alice", Uint128::new(10_000)).unwrap();
+    let liquidity_token = suite.pair_info.liquidity_token.clone();
+    let err = suite
+        .send(
+            "alice",
+            &liquidity_token,
+            Uint128::new(1_000),
+            &Cw20HookMsg::Withdraw {},
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::AssetMismatch {});
+
+    // the LP token of the pair is a cw20 token, so it can't be deposited natively
+    let err: ContractError = suite
+        .suite
+        .app
+        .execute_contract(
+            Addr::unchecked("bob"),
+            suite.compounder_addr.clone(),
+            &ExecuteMsg::Deposit {
+                amount: Uint128::new(1_000),
+            },
+            &coins(1_000, "uwhale"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AssetMismatch {});
+}
+
+#[test]
+fn harvest_compounds_rewards() {
+    let mut suite = mock_suite();
+
+    suite.deposit("alice", Uint128::new(100_000)).unwrap();
+
+    // rewards are airdropped to the auto-compounder
+    let compounder = suite.compounder_addr.to_string();
+    suite.suite.fund(&compounder, coins(10_000, "uwhale"));
+    let AssetInfo::Token { contract_addr } = suite.asset.clone() else {
+        panic!("the asset should be a cw20 token");
+    };
+    suite
+        .suite
+        .app
+        .execute_contract(
+            Addr::unchecked("bob"),
+            Addr::unchecked(contract_addr),
+            &Cw20ExecuteMsg::Transfer {
+                recipient: compounder.clone(),
+                amount: Uint128::new(10_000),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // anyone can harvest
+    suite.execute("bob", &ExecuteMsg::Harvest {}).unwrap();
+
+    // the rewards are swapped and provided as liquidity, without touching the shares
+    let lp_balance = suite.lp_balance(&compounder);
+    assert!(lp_balance > Uint128::new(100_000));
+    assert_eq!(suite.share_balance("alice"), Uint128::new(99_000));
+    assert_eq!(suite.query_share(Uint128::new(100_000)), lp_balance);
+    assert_eq!(
+        suite.suite.query_balance(&compounder, &suite.asset),
+        Uint128::zero()
+    );
+
+    // the limiting asset of the pool ratio is provided entirely, the other is kept for the next
+    // harvest
+    let uluna = suite.suite.query_balance(&compounder, &native("uluna"));
+    let uwhale = suite.suite.query_balance(&compounder, &native("uwhale"));
+    assert!(uluna.is_zero() || uwhale.is_zero());
+
+    // deposits after the harvest get fewer shares per LP token
+    suite.deposit("alice", Uint128::new(100_000)).unwrap();
+    let shares = suite.share_balance("alice") - Uint128::new(99_000);
+    assert_eq!(
+        shares,
+        Uint128::new(100_000).multiply_ratio(100_000u128, lp_balance)
+    );
+
+    // withdrawing all the shares returns all the LP tokens but the ones of the locked shares
+    let alice_shares = suite.share_balance("alice");
+    let alice_lp_balance = suite.lp_balance("alice");
+    let compounder_lp_balance = suite.lp_balance(&compounder);
+    let locked_lp_amount = compounder_lp_balance
+        - compounder_lp_balance
+            .multiply_ratio(alice_shares, alice_shares + MINIMUM_LIQUIDITY_AMOUNT);
+    suite.withdraw("alice", alice_shares).unwrap();
+    assert_eq!(
+        suite.lp_balance("alice"),
+        alice_lp_balance + compounder_lp_balance - locked_lp_amount
+    );
+    assert_eq!(suite.lp_balance(&compounder), locked_lp_amount);
+}
+
+#[test]
+fn harvest_swaps_are_bounded_by_the_minimum_prices_of_the_reward_routes() {
+    let mut suite = mock_suite();
+    let compounder = suite.compounder_addr.to_string();
+
+    suite.deposit("alice", Uint128::new(100_000)).unwrap();
+    suite.suite.fund(&compounder, coins(10_000, "uwhale"));
+
+    // the uluna-uwhale pair returns less than 1 uluna per uwhale after fees
+    let mut routes = reward_routes(&suite.asset);
+    routes[0].min_prices[0] = Decimal::one();
+    suite
+        .update_config(ExecuteMsg::UpdateConfig {
+            owner: None,
+            pool_router: None,
+            incentive_contract: None,
+            reward_routes: Some(routes),
+            reference_price: None,
+            slippage_tolerance: None,
+        })
+        .unwrap();
+    let err = suite.harvest().unwrap_err();
+    assert!(err.starts_with("Assertion failed; minimum receive amount: 5000"));
+    assert_eq!(suite.lp_balance(&compounder), Uint128::new(100_000));
+
+    let routes = reward_routes(&suite.asset);
+    suite
+        .update_config(ExecuteMsg::UpdateConfig {
+            owner: None,
+            pool_router: None,
+            incentive_contract: None,
+            reward_routes: Some(routes),
+            reference_price: None,
+            slippage_tolerance: None,
+        })
+        .unwrap();
+    suite.harvest().unwrap();
+    assert!(suite.lp_balance(&compounder) > Uint128::new(100_000));
+}
+
+#[test]
+fn harvest_provides_liquidity_within_the_slippage_tolerance_of_the_reference_price() {
+    let mut suite = mock_suite();
+    let compounder = suite.compounder_addr.to_string();
+
+    suite.deposit("alice", Uint128::new(100_000)).unwrap();
+    suite.suite.fund(&compounder, coins(10_000, "uwhale"));
+
+    // moving the pair price by about 2% stops the harvested assets from being provided
+    suite
+        .suite
+        .app
+        .execute_contract(
+            Addr::unchecked("bob"),
+            Addr::unchecked(suite.pair_info.contract_addr.clone()),
+            &PairExecuteMsg::Swap {
+                offer_asset: Asset {
+                    info: native("uwhale"),
+                    amount: Uint128::new(POOL_LIQUIDITY / 100),
+                },
+                belief_price: None,
+                max_spread: None,
+                to: None,
+            },
+            &coins(POOL_LIQUIDITY / 100, "uwhale"),
+        )
+        .unwrap();
+    let err = suite.harvest().unwrap_err();
+    assert_eq!(err, "Slippage tolerance exceeded");
+    assert_eq!(suite.lp_balance(&compounder), Uint128::new(100_000));
+
+    // the owner sets the reference price to the new price of the pair
+    suite
+        .update_config(ExecuteMsg::UpdateConfig {
+            owner: None,
+            pool_router: None,
+            incentive_contract: None,
+            reward_routes: None,
+            reference_price: Some(Decimal::from_ratio(
+                POOL_LIQUIDITY + POOL_LIQUIDITY / 100,
+                POOL_LIQUIDITY - POOL_LIQUIDITY / 100,
+            )),
+            slippage_tolerance: None,
+        })
+        .unwrap();
+    suite.harvest().unwrap();
+    assert!(suite.lp_balance(&compounder) > Uint128::new(100_000));
+}
+
+#[test]
+fn harvest_claims_from_incentive_contract() {
+    let mut suite = mock_suite();
+
+    let incentive_code_id = suite.suite.app.store_code(incentive_contract());
+    let incentive_addr = suite
+        .suite
+        .app
+        .instantiate_contract(
+            incentive_code_id,
+            suite.suite.creator.clone(),
+            &Empty {},
+            &[],
+            "incentive",
+            None,
+        )
+        .unwrap();
+    suite
+        .suite
+        .fund(incentive_addr.as_str(), coins(10_000, "uwhale"));
+
+    let creator = suite.suite.creator.to_string();
+    suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateConfig {
+                owner: None,
+                pool_router: None,
+                incentive_contract: Some(incentive_addr.to_string()),
+                reward_routes: None,
+                reference_price: None,
+                slippage_tolerance: None,
+            },
+        )
+        .unwrap();
+
+    suite.deposit("alice", Uint128::new(100_000)).unwrap();
+    suite.execute("bob", &ExecuteMsg::Harvest {}).unwrap();
+
+    assert_eq!(
+        suite
+            .suite
+            .query_balance(incentive_addr.as_str(), &native("uwhale")),
+        Uint128::zero()
+    );
+    let compounder = suite.compounder_addr.to_string();
+    assert!(suite.lp_balance(&compounder) > Uint128::new(100_000));
+}
+
+#[test]
+fn harvest_without_rewards() {
+    let mut suite = mock_suite();
+
+    suite.deposit("alice", Uint128::new(100_000)).unwrap();
+    suite.execute("bob", &ExecuteMsg::Harvest {}).unwrap();
+
+    let compounder = suite.compounder_addr.to_string();
+    assert_eq!(suite.lp_balance(&compounder), Uint128::new(100_000));
+}
+
+#[test]
+fn cant_instantiate_with_invalid_reward_routes() {
+    let mut suite = mock_suite();
+    let asset = suite.asset.clone();
+
+    // the route doesn't end at the asset of the pair
+    let err = suite
+        .instantiate(vec![RewardRoute {
+            reward_asset_info: asset.clone(),
+            swap_operations: [
+                vec![swap_operation(&asset, &native("uwhale"))],
+                vec![swap_operation(&asset, &native("uwhale"))],
+            ],
+            min_prices: [Decimal::percent(90); 2],
+        }])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidRewardRoute {
+            reward_asset: asset.to_string()
+        }
+    );
+
+    // the route to the reward asset itself must be empty
+    let err = suite
+        .instantiate(vec![RewardRoute {
+            reward_asset_info: native("uwhale"),
+            swap_operations: [
+                vec![swap_operation(&native("uwhale"), &native("uluna"))],
+                vec![swap_operation(&native("uwhale"), &native("uwhale"))],
+            ],
+            min_prices: [Decimal::percent(90); 2],
+        }])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidRewardRoute {
+            reward_asset: "uwhale".to_string()
+        }
+    );
+
+    // the operations must be chained
+    let err = suite
+        .instantiate(vec![RewardRoute {
+            reward_asset_info: asset.clone(),
+            swap_operations: [
+                vec![
+                    swap_operation(&asset, &native("uwhale")),
+                    swap_operation(&asset, &native("uluna")),
+                ],
+                vec![swap_operation(&asset, &native("uwhale"))],
+            ],
+            min_prices: [Decimal::percent(90); 2],
+        }])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidRewardRoute {
+            reward_asset: asset.to_string()
+        }
+    );
+
+    // the swaps must be bounded by a minimum price
+    let err = suite
+        .instantiate(vec![RewardRoute {
+            reward_asset_info: native("uwhale"),
+            swap_operations: [
+                vec![swap_operation(&native("uwhale"), &native("uluna"))],
+                vec![],
+            ],
+            min_prices: [Decimal::zero(); 2],
+        }])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidRewardRoute {
+            reward_asset: "uwhale".to_string()
+        }
+    );
+
+    // the LP token can't be a reward
+    let liquidity_token = suite.pair_info.liquidity_token.clone();
+    let err = suite
+        .instantiate(vec![RewardRoute {
+            reward_asset_info: liquidity_token.clone(),
+            swap_operations: [vec![], vec![]],
+            min_prices: [Decimal::zero(); 2],
+        }])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidRewardRoute {
+            reward_asset: liquidity_token.to_string()
+        }
+    );
+}
+
+#[test]
+fn callbacks_are_internal() {
+    let mut suite = mock_suite();
+
+    for callback in [
+        CallbackMsg::SwapRewards {},
+        CallbackMsg::ProvideLiquidity {},
+    ] {
+        let err = suite
+            .execute("bob", &ExecuteMsg::Callback(callback))
+            .unwrap_err();
+        assert_eq!(err, ContractError::ExternalCallback {});
+    }
+}
+
+#[test]
+fn update_config() {
+    let mut suite = mock_suite();
+    let creator = suite.suite.creator.to_string();
+
+    let err = suite
+        .execute(
+            "bob",
+            &ExecuteMsg::UpdateConfig {
+                owner: Some("bob".to_string()),
+                pool_router: None,
+                incentive_contract: None,
+                reward_routes: None,
+                reference_price: None,
+                slippage_tolerance: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // invalid reward routes are rejected
+    let err = suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateConfig {
+                owner: None,
+                pool_router: None,
+                incentive_contract: None,
+                reward_routes: Some(vec![RewardRoute {
+                    reward_asset_info: native("uwhale"),
+                    swap_operations: [vec![], vec![]],
+                    min_prices: [Decimal::zero(); 2],
+                }]),
+                reference_price: None,
+                slippage_tolerance: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidRewardRoute {
+            reward_asset: "uwhale".to_string()
+        }
+    );
+
+    // the reference price can't be zero and the slippage tolerance has to be lower than 1
+    let err = suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateConfig {
+                owner: None,
+                pool_router: None,
+                incentive_contract: None,
+                reward_routes: None,
+                reference_price: Some(Decimal::zero()),
+                slippage_tolerance: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidZeroReferencePrice {});
+
+    let err = suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateConfig {
+                owner: None,
+                pool_router: None,
+                incentive_contract: None,
+                reward_routes: None,
+                reference_price: None,
+                slippage_tolerance: Some(Decimal::one()),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidSlippageTolerance {});
+
+    suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateConfig {
+                owner: Some("bob".to_string()),
+                pool_router: Some("new_router".to_string()),
+                incentive_contract: Some("incentive".to_string()),
+                reward_routes: Some(vec![]),
+                reference_price: Some(Decimal::percent(150)),
+                slippage_tolerance: Some(Decimal::percent(5)),
+            },
+        )
+        .unwrap();
+
+    let config = suite.query_config();
+    assert_eq!(config.owner, Addr::unchecked("bob"));
+    assert_eq!(config.pool_router, Addr::unchecked("new_router"));
+    assert_eq!(
+        config.incentive_contract,
+        Some(Addr::unchecked("incentive"))
+    );
+    assert_eq!(config.reward_routes, vec![]);
+    assert_eq!(config.reference_price, Decimal::percent(150));
+    assert_eq!(config.slippage_tolerance, Decimal::percent(5));
+
+    // the previous owner can't update the config anymore
+    let err = suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateConfig {
+                owner: None,
+                pool_router: None,
+                incentive_contract: None,
+                reward_routes: None,
+                reference_price: None,
+                slippage_tolerance: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn can_migrate_contract() {
+    let mut suite = mock_suite();
+
+    // should not be able to migrate as the version is not higher
+    let err: ContractError = suite
+        .suite
+        .app
+        .migrate_contract(
+            suite.suite.creator.clone(),
+            suite.compounder_addr.clone(),
+            &MigrateMsg {},
+            suite.code_id,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    match err {
+        ContractError::MigrateInvalidVersion { .. } => (),
+        _ => panic!("should return ContractError::MigrateInvalidVersion"),
+    }
+}
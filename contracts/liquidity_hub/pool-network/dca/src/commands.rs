@@ -0,0 +1,461 @@
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response,
+    StdResult, Storage, Uint128, WasmMsg,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+
+use pool_network::asset::{Asset, AssetInfo};
+use pool_network::dca::{Cw20HookMsg, ExecuteMsg, Position, PositionExecution};
+use pool_network::router::{
+    Cw20HookMsg as RouterCw20HookMsg, ExecuteMsg as RouterExecuteMsg, SwapOperation,
+};
+
+use crate::error::ContractError;
+use crate::state::{
+    assert_no_position_executing, next_position_id, positions, CONFIG, EXECUTING_POSITION,
+    POSITION_HISTORY,
+};
+
+/// Creates a position depositing the cw20 tokens sent to the contract
+pub fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::CreatePosition {
+            operations,
+            amount_per_swap,
+            interval,
+            min_price,
+        } => {
+            let owner = deps.api.addr_validate(&cw20_msg.sender)?;
+            let deposit = Asset {
+                info: AssetInfo::Token {
+                    contract_addr: info.sender.to_string(),
+                },
+                amount: cw20_msg.amount,
+            };
+
+            create_position(
+                deps,
+                env,
+                owner,
+                deposit,
+                operations,
+                amount_per_swap,
+                interval,
+                min_price,
+            )
+        }
+    }
+}
+
+/// Creates a position swapping the escrowed deposit through the given operations. The first swap
+/// is due right away.
+#[allow(clippy::too_many_arguments)]
+pub fn create_position(
+    deps: DepsMut,
+    env: Env,
+    owner: Addr,
+    deposit: Asset,
+    operations: Vec<SwapOperation>,
+    amount_per_swap: Uint128,
+    interval: u64,
+    min_price: Decimal,
+) -> Result<Response, ContractError> {
+    if deposit.amount.is_zero() || amount_per_swap.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    if interval == 0 {
+        return Err(ContractError::InvalidZeroInterval {});
+    }
+
+    // the minimum price is what bounds the swaps, as anyone can execute them
+    if min_price.is_zero() {
+        return Err(ContractError::InvalidZeroMinPrice {});
+    }
+
+    assert_no_position_executing(deps.storage)?;
+
+    let ask_asset_info = validate_operations(&deposit.info, &operations)?;
+
+    let position = Position {
+        position_id: next_position_id(deps.storage)?,
+        owner,
+        deposit,
+        ask_asset_info,
+        operations,
+        amount_per_swap,
+        interval,
+        min_price,
+        next_execution: env.block.time,
+        paused: false,
+        executions: 0,
+    };
+    positions().save(deps.storage, position.position_id, &position)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "create_position"),
+        ("position_id", &position.position_id.to_string()),
+        ("owner", position.owner.as_str()),
+        ("deposit", &position.deposit.to_string()),
+        ("ask_asset", &position.ask_asset_info.to_string()),
+        ("amount_per_swap", &position.amount_per_swap.to_string()),
+        ("interval", &position.interval.to_string()),
+        ("min_price", &position.min_price.to_string()),
+    ]))
+}
+
+/// Validates the swap operations route the deposit to a single ask asset, other than the deposit,
+/// returning it
+fn validate_operations(
+    deposit_info: &AssetInfo,
+    operations: &[SwapOperation],
+) -> Result<AssetInfo, ContractError> {
+    if operations.is_empty() {
+        return Err(ContractError::NoSwapOperationsProvided {});
+    }
+
+    let mut target_asset_info = deposit_info.clone();
+    for operation in operations {
+        match operation {
+            SwapOperation::TerraSwap {
+                offer_asset_info,
+                ask_asset_info,
+                ..
+            } => {
+                if !offer_asset_info.equal(&target_asset_info) {
+                    return Err(ContractError::InvalidSwapOperations {});
+                }
+
+                target_asset_info = ask_asset_info.clone();
+            }
+        }
+    }
+
+    // the proceeds are measured by the balance of the ask asset, which can't be the deposit
+    if target_asset_info.equal(deposit_info) {
+        return Err(ContractError::InvalidSwapOperations {});
+    }
+
+    Ok(target_asset_info)
+}
+
+/// Executes the due swap of a position through the pool router. The proceeds are settled with
+/// [ExecuteMsg::SettleExecution] once the swap is performed, locking the contract until then.
+pub fn execute_position(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    position_id: u64,
+) -> Result<Response, ContractError> {
+    assert_no_position_executing(deps.storage)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let mut position = positions().load(deps.storage, position_id)?;
+
+    if position.paused {
+        return Err(ContractError::PositionPaused { position_id });
+    }
+
+    if position.deposit.amount.is_zero() {
+        return Err(ContractError::PositionDepleted { position_id });
+    }
+
+    if env.block.time < position.next_execution {
+        return Err(ContractError::ExecutionNotDue {
+            next_execution: position.next_execution,
+        });
+    }
+
+    let offer_amount = position.amount_per_swap.min(position.deposit.amount);
+
+    // the swap is bounded by the minimum price set by the owner of the position, which the keeper
+    // can't move. The router asserts the minimum receive when swapping.
+    let minimum_receive = offer_amount * position.min_price;
+
+    let prev_balance = position.ask_asset_info.query_pool(
+        &deps.querier,
+        deps.api,
+        env.contract.address.clone(),
+    )?;
+
+    position.deposit.amount = position.deposit.amount.checked_sub(offer_amount)?;
+    position.next_execution = env.block.time.plus_seconds(position.interval);
+    positions().save(deps.storage, position_id, &position)?;
+    EXECUTING_POSITION.save(deps.storage, &position_id)?;
+
+    let offer_asset = Asset {
+        info: position.deposit.info.clone(),
+        amount: offer_amount,
+    };
+
+    Ok(Response::new()
+        .add_message(swap_msg(
+            &config.pool_router,
+            offer_asset,
+            position.operations,
+            minimum_receive,
+        )?)
+        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::SettleExecution {
+                position_id,
+                offer_amount,
+                prev_balance,
+                keeper: info.sender.to_string(),
+            })?,
+            funds: vec![],
+        }))
+        .add_attributes(vec![
+            ("action", "execute_position"),
+            ("position_id", &position_id.to_string()),
+            ("offer_amount", &offer_amount.to_string()),
+            ("keeper", info.sender.as_str()),
+        ]))
+}
+
+/// Creates the message swapping the offer asset through the pool router
+fn swap_msg(
+    pool_router: &Addr,
+    offer_asset: Asset,
+    operations: Vec<SwapOperation>,
+    minimum_receive: Uint128,
+) -> StdResult<CosmosMsg> {
+    match offer_asset.info {
+        AssetInfo::NativeToken { denom } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: pool_router.to_string(),
+            funds: vec![Coin {
+                denom,
+                amount: offer_asset.amount,
+            }],
+            msg: to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                operations,
+                minimum_receive: Some(minimum_receive),
+                to: None,
+            })?,
+        })),
+        AssetInfo::Token { contract_addr } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                contract: pool_router.to_string(),
+                amount: offer_asset.amount,
+                msg: to_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+                    operations,
+                    minimum_receive: Some(minimum_receive),
+                    to: None,
+                })?,
+            })?,
+        })),
+    }
+}
+
+/// Settles an executed swap of a position. The keeper tip is sent to the keeper and the rest of the
+/// proceeds to the owner of the position, and the swap is recorded in its history. The contract is
+/// unlocked once settled.
+#[allow(clippy::too_many_arguments)]
+pub fn settle_execution(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    position_id: u64,
+    offer_amount: Uint128,
+    prev_balance: Uint128,
+    keeper: String,
+) -> Result<Response, ContractError> {
+    // only the contract itself can settle the executions
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut position = positions().load(deps.storage, position_id)?;
+
+    let balance = position.ask_asset_info.query_pool(
+        &deps.querier,
+        deps.api,
+        env.contract.address.clone(),
+    )?;
+    let return_amount = balance.checked_sub(prev_balance)?;
+
+    let keeper_tip = return_amount * CONFIG.load(deps.storage)?.keeper_tip;
+    let owner_amount = return_amount.checked_sub(keeper_tip)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if !keeper_tip.is_zero() {
+        messages.push(
+            Asset {
+                info: position.ask_asset_info.clone(),
+                amount: keeper_tip,
+            }
+            .into_msg(deps.api.addr_validate(&keeper)?)?,
+        );
+    }
+    if !owner_amount.is_zero() {
+        messages.push(
+            Asset {
+                info: position.ask_asset_info.clone(),
+                amount: owner_amount,
+            }
+            .into_msg(position.owner.clone())?,
+        );
+    }
+
+    position.executions += 1;
+    positions().save(deps.storage, position_id, &position)?;
+    POSITION_HISTORY.save(
+        deps.storage,
+        (position_id, position.executions),
+        &PositionExecution {
+            execution_id: position.executions,
+            executed_at: env.block.time,
+            offer_amount,
+            return_amount,
+            keeper_tip,
+        },
+    )?;
+    EXECUTING_POSITION.remove(deps.storage);
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "settle_execution"),
+        ("position_id", &position_id.to_string()),
+        ("owner", position.owner.as_str()),
+        ("offer_amount", &offer_amount.to_string()),
+        ("return_amount", &return_amount.to_string()),
+        ("keeper", &keeper),
+        ("keeper_tip", &keeper_tip.to_string()),
+    ]))
+}
+
+/// Pauses a position, so its swaps can't be executed until it is resumed
+pub fn pause_position(
+    deps: DepsMut,
+    info: MessageInfo,
+    position_id: u64,
+) -> Result<Response, ContractError> {
+    let mut position = load_owned_position(deps.storage, &info.sender, position_id)?;
+
+    if position.paused {
+        return Err(ContractError::PositionPaused { position_id });
+    }
+
+    position.paused = true;
+    positions().save(deps.storage, position_id, &position)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "pause_position"),
+        ("position_id", &position_id.to_string()),
+    ]))
+}
+
+/// Resumes a paused position. The next swap is due right away if its interval went by while it
+/// was paused.
+pub fn resume_position(
+    deps: DepsMut,
+    info: MessageInfo,
+    position_id: u64,
+) -> Result<Response, ContractError> {
+    let mut position = load_owned_position(deps.storage, &info.sender, position_id)?;
+
+    if !position.paused {
+        return Err(ContractError::PositionNotPaused { position_id });
+    }
+
+    position.paused = false;
+    positions().save(deps.storage, position_id, &position)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "resume_position"),
+        ("position_id", &position_id.to_string()),
+    ]))
+}
+
+/// Cancels a position, refunding what's left of the deposit to its owner. The history of the
+/// position is kept.
+pub fn cancel_position(
+    deps: DepsMut,
+    info: MessageInfo,
+    position_id: u64,
+) -> Result<Response, ContractError> {
+    assert_no_position_executing(deps.storage)?;
+
+    let position = load_owned_position(deps.storage, &info.sender, position_id)?;
+
+    positions().remove(deps.storage, position_id)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if !position.deposit.amount.is_zero() {
+        messages.push(position.deposit.clone().into_msg(position.owner)?);
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "cancel_position"),
+        ("position_id", &position_id.to_string()),
+        ("refund_asset", &position.deposit.to_string()),
+    ]))
+}
+
+/// Loads a position, checking the sender is its owner
+fn load_owned_position(
+    storage: &dyn Storage,
+    sender: &Addr,
+    position_id: u64,
+) -> Result<Position, ContractError> {
+    let position = positions().load(storage, position_id)?;
+
+    if *sender != position.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    Ok(position)
+}
+
+/// Updates the owner, the pool router and the keeper tip of the contract
+pub fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    owner: Option<String>,
+    pool_router: Option<String>,
+    keeper_tip: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // only the owner can update the config
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(owner) = owner {
+        config.owner = deps.api.addr_validate(&owner)?;
+    }
+
+    if let Some(pool_router) = pool_router {
+        config.pool_router = deps.api.addr_validate(&pool_router)?;
+    }
+
+    if let Some(keeper_tip) = keeper_tip {
+        validate_keeper_tip(keeper_tip)?;
+        config.keeper_tip = keeper_tip;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_config"),
+        ("owner", config.owner.as_str()),
+        ("pool_router", config.pool_router.as_str()),
+        ("keeper_tip", &config.keeper_tip.to_string()),
+    ]))
+}
+
+/// Validates the keeper tip is lower than 1, so the owner of a position gets part of the proceeds
+pub fn validate_keeper_tip(keeper_tip: Decimal) -> Result<(), ContractError> {
+    if keeper_tip >= Decimal::one() {
+        return Err(ContractError::InvalidKeeperTip {});
+    }
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
+
+use pool_network::dca::{Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+use crate::error::ContractError;
+use crate::error::ContractError::MigrateInvalidVersion;
+use crate::state::CONFIG;
+use crate::{commands, queries};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "nico_teen-dca";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    commands::validate_keeper_tip(msg.keeper_tip)?;
+
+    let config = Config {
+        owner: info.sender,
+        pool_router: deps.api.addr_validate(&msg.pool_router)?,
+        keeper_tip: msg.keeper_tip,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "instantiate"),
+        ("owner", config.owner.as_str()),
+        ("pool_router", config.pool_router.as_str()),
+        ("keeper_tip", &config.keeper_tip.to_string()),
+    ]))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Receive(msg) => commands::receive_cw20(deps, env, info, msg),
+        ExecuteMsg::CreatePosition {
+            deposit,
+            operations,
+            amount_per_swap,
+            interval,
+            min_price,
+        } => {
+            if !deposit.is_native_token() {
+                return Err(ContractError::Cw20DepositNotReceived {});
+            }
+            deposit.assert_sent_native_token_balance(&info)?;
+
+            commands::create_position(
+                deps,
+                env,
+                info.sender,
+                deposit,
+                operations,
+                amount_per_swap,
+                interval,
+                min_price,
+            )
+        }
+        ExecuteMsg::ExecutePosition { position_id } => {
+            commands::execute_position(deps, env, info, position_id)
+        }
+        ExecuteMsg::SettleExecution {
+            position_id,
+            offer_amount,
+            prev_balance,
+            keeper,
+        } => commands::settle_execution(
+            deps,
+            env,
+            info,
+            position_id,
+            offer_amount,
+            prev_balance,
+            keeper,
+        ),
+        ExecuteMsg::PausePosition { position_id } => {
+            commands::pause_position(deps, info, position_id)
+        }
+        ExecuteMsg::ResumePosition { position_id } => {
+            commands::resume_position(deps, info, position_id)
+        }
+        ExecuteMsg::CancelPosition { position_id } => {
+            commands::cancel_position(deps, info, position_id)
+        }
+        ExecuteMsg::UpdateConfig {
+            owner,
+            pool_router,
+            keeper_tip,
+        } => commands::update_config(deps, info, owner, pool_router, keeper_tip),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    match msg {
+        QueryMsg::Config {} => Ok(to_binary(&queries::query_config(deps)?)?),
+        QueryMsg::Position { position_id } => {
+            Ok(to_binary(&queries::query_position(deps, position_id)?)?)
+        }
+        QueryMsg::PositionsByOwner {
+            owner,
+            start_after,
+            limit,
+        } => Ok(to_binary(&queries::query_positions_by_owner(
+            deps,
+            owner,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::PositionHistory {
+            position_id,
+            start_after,
+            limit,
+        } => Ok(to_binary(&queries::query_position_history(
+            deps,
+            position_id,
+            start_after,
+            limit,
+        )?)?),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let version: Version = CONTRACT_VERSION.parse()?;
+    let storage_version: Version = get_contract_version(deps.storage)?.version.parse()?;
+
+    if storage_version >= version {
+        return Err(MigrateInvalidVersion {
+            current_version: storage_version,
+            new_version: version,
+        });
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
@@ -0,0 +1,66 @@
+use cosmwasm_std::{OverflowError, StdError, Timestamp};
+use semver::Version;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("Attempt to migrate to version {new_version}, but contract is on a higher version {current_version}")]
+    MigrateInvalidVersion {
+        new_version: Version,
+        current_version: Version,
+    },
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Invalid keeper tip, it must be lower than 1")]
+    InvalidKeeperTip {},
+
+    #[error("A position is being executed, positions can't be created, executed or cancelled until it is settled")]
+    PositionBeingExecuted {},
+
+    #[error("The deposit and the amount per swap of a position can't be zero")]
+    InvalidZeroAmount {},
+
+    #[error("The interval of a position can't be zero")]
+    InvalidZeroInterval {},
+
+    #[error("The minimum price of a position can't be zero")]
+    InvalidZeroMinPrice {},
+
+    #[error("Must provide swap operations to create a position")]
+    NoSwapOperationsProvided {},
+
+    #[error("The swap operations don't route the deposit to a single ask asset")]
+    InvalidSwapOperations {},
+
+    #[error("Positions depositing cw20 tokens are created by sending the tokens to the contract")]
+    Cw20DepositNotReceived {},
+
+    #[error("Position {position_id} is paused")]
+    PositionPaused { position_id: u64 },
+
+    #[error("Position {position_id} is not paused")]
+    PositionNotPaused { position_id: u64 },
+
+    #[error("Position {position_id} has no deposit left to swap")]
+    PositionDepleted { position_id: u64 },
+
+    #[error("The next swap of the position can't be executed before {next_execution}")]
+    ExecutionNotDue { next_execution: Timestamp },
+}
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
+}
@@ -0,0 +1,42 @@
+use cosmwasm_std::Deps;
+
+use pool_network::dca::{ConfigResponse, Position, PositionHistoryResponse, PositionsResponse};
+
+use crate::error::ContractError;
+use crate::state::{positions, read_position_history, read_positions_by_owner, CONFIG};
+
+/// Queries the [Config], which contains the owner, the pool router and the keeper tip
+pub fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
+    Ok(CONFIG.load(deps.storage)?)
+}
+
+/// Queries a position by id
+pub fn query_position(deps: Deps, position_id: u64) -> Result<Position, ContractError> {
+    Ok(positions().load(deps.storage, position_id)?)
+}
+
+/// Queries the positions created by the given owner
+pub fn query_positions_by_owner(
+    deps: Deps,
+    owner: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PositionsResponse, ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+
+    Ok(PositionsResponse {
+        positions: read_positions_by_owner(deps.storage, owner, start_after, limit)?,
+    })
+}
+
+/// Queries the executions of the given position
+pub fn query_position_history(
+    deps: Deps,
+    position_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PositionHistoryResponse, ContractError> {
+    Ok(PositionHistoryResponse {
+        executions: read_position_history(deps.storage, position_id, start_after, limit)?,
+    })
+}
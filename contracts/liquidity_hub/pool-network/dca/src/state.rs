@@ -0,0 +1,98 @@
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+
+use pool_network::dca::{Config, Position, PositionExecution};
+
+use crate::error::ContractError;
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const POSITION_COUNTER: Item<u64> = Item::new("position_counter");
+/// The position being executed and not settled yet. The settlement accounts the proceeds of the
+/// swap by the balance of the contract, so no other position can be created, cancelled or executed
+/// meanwhile.
+pub const EXECUTING_POSITION: Item<u64> = Item::new("executing_position");
+/// The executions of the positions, keyed by position id and execution id
+pub const POSITION_HISTORY: Map<(u64, u64), PositionExecution> = Map::new("position_history");
+
+pub struct PositionIndexes<'a> {
+    pub owner: MultiIndex<'a, Addr, Position, u64>,
+}
+
+impl<'a> IndexList<Position> for PositionIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Position>> + '_> {
+        let v: Vec<&dyn Index<Position>> = vec![&self.owner];
+        Box::new(v.into_iter())
+    }
+}
+
+/// The positions, indexed by owner
+pub fn positions<'a>() -> IndexedMap<'a, u64, Position, PositionIndexes<'a>> {
+    let indexes = PositionIndexes {
+        owner: MultiIndex::new(
+            |_pk, position| position.owner.clone(),
+            "positions",
+            "positions__owner",
+        ),
+    };
+
+    IndexedMap::new("positions", indexes)
+}
+
+/// Returns the id for the next position, increasing the counter
+pub fn next_position_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let position_id = POSITION_COUNTER.may_load(storage)?.unwrap_or_default() + 1;
+    POSITION_COUNTER.save(storage, &position_id)?;
+
+    Ok(position_id)
+}
+
+/// Asserts no position is being executed, see [EXECUTING_POSITION]
+pub fn assert_no_position_executing(storage: &dyn Storage) -> Result<(), ContractError> {
+    if EXECUTING_POSITION.may_load(storage)?.is_some() {
+        return Err(ContractError::PositionBeingExecuted {});
+    }
+
+    Ok(())
+}
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+/// Reads the positions created by the given owner, sorted by id
+pub fn read_positions_by_owner(
+    storage: &dyn Storage,
+    owner: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Position>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    positions()
+        .idx
+        .owner
+        .prefix(owner)
+        .range(storage, start, None, cosmwasm_std::Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, position)| position))
+        .collect()
+}
+
+/// Reads the executions of the given position, from the oldest to the newest
+pub fn read_position_history(
+    storage: &dyn Storage,
+    position_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<PositionExecution>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    POSITION_HISTORY
+        .prefix(position_id)
+        .range(storage, start, None, cosmwasm_std::Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, execution)| execution))
+        .collect()
+}
@@ -0,0 +1,840 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{coin, coins, to_binary, Addr, Decimal, Empty, Uint128};
+use cw20::Cw20ExecuteMsg;
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+
+use liquidity_hub_testing::{native, PairBuilder, TestSuite};
+use pool_network::asset::{Asset, AssetInfo};
+use pool_network::dca::{
+    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, Position,
+    PositionHistoryResponse, PositionsResponse, QueryMsg,
+};
+use pool_network::router::{
+    QueryMsg as RouterQueryMsg, SimulateSwapOperationsResponse, SwapOperation,
+};
+
+use crate::commands;
+use crate::contract::{execute, instantiate, migrate, query};
+use crate::state::EXECUTING_POSITION;
+use crate::ContractError;
+
+/// The amount of each asset provided to the pairs
+const POOL_LIQUIDITY: u128 = 1_000_000_000u128;
+/// The interval of the positions in the tests
+const DAY: u64 = 86_400u64;
+
+fn dca_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(execute, instantiate, query).with_migrate(migrate))
+}
+
+struct DcaSuite {
+    suite: TestSuite,
+    dca_addr: Addr,
+    /// The cw20 token of the uwhale-asset pair
+    asset: AssetInfo,
+}
+
+/// Creates the uluna-uwhale and uwhale-asset pairs and the dca contract, with a 1% keeper tip.
+/// alice and bob are funded with the assets.
+fn mock_suite() -> DcaSuite {
+    let funds = vec![
+        coin(10 * POOL_LIQUIDITY, "uluna"),
+        coin(10 * POOL_LIQUIDITY, "uwhale"),
+    ];
+    let mut suite = TestSuite::builder()
+        .with_funded_user("alice", funds.clone())
+        .with_funded_user("bob", funds)
+        .build();
+
+    let asset = suite.create_token(
+        "asset",
+        &[("alice", 10 * POOL_LIQUIDITY), ("bob", 10 * POOL_LIQUIDITY)],
+    );
+
+    for asset_infos in [
+        [native("uluna"), native("uwhale")],
+        [native("uwhale"), asset.clone()],
+    ] {
+        suite.create_pair(
+            PairBuilder::new(asset_infos)
+                .with_liquidity("alice", [Uint128::new(POOL_LIQUIDITY); 2]),
+        );
+    }
+
+    let code_id = suite.app.store_code(dca_contract());
+    let dca_addr = suite
+        .app
+        .instantiate_contract(
+            code_id,
+            suite.creator.clone(),
+            &InstantiateMsg {
+                pool_router: suite.pool_router_addr.to_string(),
+                keeper_tip: Decimal::percent(1),
+            },
+            &[],
+            "dca",
+            Some(suite.creator.to_string()),
+        )
+        .unwrap();
+
+    DcaSuite {
+        suite,
+        dca_addr,
+        asset,
+    }
+}
+
+fn swap_operation(offer_asset_info: &AssetInfo, ask_asset_info: &AssetInfo) -> SwapOperation {
+    SwapOperation::TerraSwap {
+        offer_asset_info: offer_asset_info.clone(),
+        ask_asset_info: ask_asset_info.clone(),
+        pair_type: None,
+        fee_tier: None,
+    }
+}
+
+/// The minimum price of the positions in the tests, the pairs returning about 1 ask asset per
+/// offer asset
+fn min_price() -> Decimal {
+    Decimal::percent(90)
+}
+
+fn uwhale(amount: u128) -> Asset {
+    Asset {
+        info: native("uwhale"),
+        amount: Uint128::new(amount),
+    }
+}
+
+impl DcaSuite {
+    fn create_native_position(
+        &mut self,
+        owner: &str,
+        deposit: Asset,
+        operations: Vec<SwapOperation>,
+        amount_per_swap: u128,
+        min_price: Decimal,
+    ) -> Result<u64, ContractError> {
+        let funds = match &deposit.info {
+            AssetInfo::NativeToken { denom } => coins(deposit.amount.u128(), denom),
+            AssetInfo::Token { .. } => vec![],
+        };
+
+        let res = self
+            .suite
+            .app
+            .execute_contract(
+                Addr::unchecked(owner),
+                self.dca_addr.clone(),
+                &ExecuteMsg::CreatePosition {
+                    deposit,
+                    operations,
+                    amount_per_swap: Uint128::new(amount_per_swap),
+                    interval: DAY,
+                    min_price,
+                },
+                &funds,
+            )
+            .map_err(|err| err.downcast::<ContractError>().unwrap())?;
+
+        Ok(position_id_from_events(&res.events))
+    }
+
+    fn create_token_position(
+        &mut self,
+        owner: &str,
+        deposit: u128,
+        operations: Vec<SwapOperation>,
+        amount_per_swap: u128,
+    ) -> u64 {
+        let AssetInfo::Token { contract_addr } = self.asset.clone() else {
+            panic!("the asset should be a cw20 token");
+        };
+
+        let res = self
+            .suite
+            .app
+            .execute_contract(
+                Addr::unchecked(owner),
+                Addr::unchecked(contract_addr),
+                &Cw20ExecuteMsg::Send {
+                    contract: self.dca_addr.to_string(),
+                    amount: Uint128::new(deposit),
+                    msg: to_binary(&Cw20HookMsg::CreatePosition {
+                        operations,
+                        amount_per_swap: Uint128::new(amount_per_swap),
+                        interval: DAY,
+                        min_price: min_price(),
+                    })
+                    .unwrap(),
+                },
+                &[],
+            )
+            .unwrap();
+
+        position_id_from_events(&res.events)
+    }
+
+    fn execute(&mut self, sender: &str, msg: &ExecuteMsg) -> Result<(), ContractError> {
+        self.suite
+            .app
+            .execute_contract(Addr::unchecked(sender), self.dca_addr.clone(), msg, &[])
+            .map(|_| ())
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    fn query_position(&self, position_id: u64) -> Option<Position> {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.dca_addr, &QueryMsg::Position { position_id })
+            .ok()
+    }
+
+    fn query_history(&self, position_id: u64) -> PositionHistoryResponse {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.dca_addr,
+                &QueryMsg::PositionHistory {
+                    position_id,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+    }
+
+    fn simulate(&self, offer_amount: u128, operations: Vec<SwapOperation>) -> Uint128 {
+        let res: SimulateSwapOperationsResponse = self
+            .suite
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.suite.pool_router_addr,
+                &RouterQueryMsg::SimulateSwapOperations {
+                    offer_amount: Uint128::new(offer_amount),
+                    operations,
+                },
+            )
+            .unwrap();
+
+        res.amount
+    }
+
+    fn skip_interval(&mut self) {
+        self.suite
+            .app
+            .update_block(|block| block.time = block.time.plus_seconds(DAY));
+    }
+}
+
+fn position_id_from_events(events: &[cosmwasm_std::Event]) -> u64 {
+    events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == "position_id")
+        .unwrap()
+        .value
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn proper_initialization() {
+    let suite = mock_suite();
+
+    let config: ConfigResponse = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.dca_addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.owner, suite.suite.creator);
+    assert_eq!(config.pool_router, suite.suite.pool_router_addr);
+    assert_eq!(config.keeper_tip, Decimal::percent(1));
+}
+
+#[test]
+fn create_and_query_positions() {
+    let mut suite = mock_suite();
+    let operations = vec![swap_operation(&native("uwhale"), &native("uluna"))];
+
+    let first_position_id = suite
+        .create_native_position("bob", uwhale(3_000), operations.clone(), 1_000, min_price())
+        .unwrap();
+    let second_position_id = suite
+        .create_native_position(
+            "bob",
+            uwhale(5_000),
+            operations.clone(),
+            2_000,
+            Decimal::percent(95),
+        )
+        .unwrap();
+    assert_eq!((first_position_id, second_position_id), (1u64, 2u64));
+
+    // the deposits are escrowed
+    assert_eq!(
+        suite
+            .suite
+            .query_balance(suite.dca_addr.as_str(), &native("uwhale")),
+        Uint128::new(8_000)
+    );
+
+    let block_time = suite.suite.app.block_info().time;
+    assert_eq!(
+        suite.query_position(second_position_id).unwrap(),
+        Position {
+            position_id: second_position_id,
+            owner: Addr::unchecked("bob"),
+            deposit: uwhale(5_000),
+            ask_asset_info: native("uluna"),
+            operations,
+            amount_per_swap: Uint128::new(2_000),
+            interval: DAY,
+            min_price: Decimal::percent(95),
+            next_execution: block_time,
+            paused: false,
+            executions: 0,
+        }
+    );
+
+    let res: PositionsResponse = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.dca_addr,
+            &QueryMsg::PositionsByOwner {
+                owner: "bob".to_string(),
+                start_after: Some(first_position_id),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.positions
+            .into_iter()
+            .map(|position| position.position_id)
+            .collect::<Vec<u64>>(),
+        vec![second_position_id]
+    );
+}
+
+#[test]
+fn cannot_create_invalid_positions() {
+    let mut suite = mock_suite();
+    let asset = suite.asset.clone();
+
+    let err = suite
+        .create_native_position("bob", uwhale(3_000), vec![], 1_000, min_price())
+        .unwrap_err();
+    assert_eq!(err, ContractError::NoSwapOperationsProvided {});
+
+    // the route has to start from the deposit
+    let err = suite
+        .create_native_position(
+            "bob",
+            uwhale(3_000),
+            vec![swap_operation(&native("uluna"), &native("uwhale"))],
+            1_000,
+            min_price(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidSwapOperations {});
+
+    // and can't lead back to it
+    let err = suite
+        .create_native_position(
+            "bob",
+            uwhale(3_000),
+            vec![
+                swap_operation(&native("uwhale"), &asset),
+                swap_operation(&asset, &native("uwhale")),
+            ],
+            1_000,
+            min_price(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidSwapOperations {});
+
+    let err = suite
+        .create_native_position(
+            "bob",
+            uwhale(3_000),
+            vec![swap_operation(&native("uwhale"), &native("uluna"))],
+            0,
+            min_price(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidZeroAmount {});
+
+    let err = suite
+        .execute(
+            "bob",
+            &ExecuteMsg::CreatePosition {
+                deposit: uwhale(0),
+                operations: vec![swap_operation(&native("uwhale"), &native("uluna"))],
+                amount_per_swap: Uint128::new(1_000),
+                interval: 0,
+                min_price: min_price(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidZeroAmount {});
+
+    let err: ContractError = suite
+        .suite
+        .app
+        .execute_contract(
+            Addr::unchecked("bob"),
+            suite.dca_addr.clone(),
+            &ExecuteMsg::CreatePosition {
+                deposit: uwhale(3_000),
+                operations: vec![swap_operation(&native("uwhale"), &native("uluna"))],
+                amount_per_swap: Uint128::new(1_000),
+                interval: 0,
+                min_price: min_price(),
+            },
+            &coins(3_000, "uwhale"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidZeroInterval {});
+
+    let err = suite
+        .create_native_position(
+            "bob",
+            uwhale(3_000),
+            vec![swap_operation(&native("uwhale"), &native("uluna"))],
+            1_000,
+            Decimal::zero(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidZeroMinPrice {});
+
+    // cw20 deposits have to be sent to the contract
+    let err = suite
+        .create_native_position(
+            "bob",
+            Asset {
+                info: asset.clone(),
+                amount: Uint128::new(3_000),
+            },
+            vec![swap_operation(&asset, &native("uwhale"))],
+            1_000,
+            min_price(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Cw20DepositNotReceived {});
+}
+
+#[test]
+fn execute_positions_on_schedule() {
+    let mut suite = mock_suite();
+    let operations = vec![swap_operation(&native("uwhale"), &native("uluna"))];
+
+    let position_id = suite
+        .create_native_position(
+            "bob",
+            uwhale(2_500_000),
+            operations.clone(),
+            1_000_000,
+            min_price(),
+        )
+        .unwrap();
+    let start_time = suite.suite.app.block_info().time;
+
+    let mut return_amounts: Vec<Uint128> = vec![];
+    let mut remaining_deposit = 2_500_000u128;
+    for offer_amount in [1_000_000u128, 1_000_000u128, 500_000u128] {
+        let return_amount = suite.simulate(offer_amount, operations.clone());
+        let bob_balance = suite.suite.query_balance("bob", &native("uluna"));
+        let keeper_balance = suite.suite.query_balance("keeper", &native("uluna"));
+
+        suite
+            .execute("keeper", &ExecuteMsg::ExecutePosition { position_id })
+            .unwrap();
+
+        // the keeper gets 1% of the proceeds and bob the rest
+        let keeper_tip = return_amount * Decimal::percent(1);
+        assert_eq!(
+            suite.suite.query_balance("keeper", &native("uluna")),
+            keeper_balance + keeper_tip
+        );
+        assert_eq!(
+            suite.suite.query_balance("bob", &native("uluna")),
+            bob_balance + return_amount - keeper_tip
+        );
+        return_amounts.push(return_amount);
+
+        // the next swap is due after the interval, the last swap depletes the position
+        remaining_deposit -= offer_amount;
+        if remaining_deposit > 0 {
+            let next_execution = suite.suite.app.block_info().time.plus_seconds(DAY);
+            let err = suite
+                .execute("keeper", &ExecuteMsg::ExecutePosition { position_id })
+                .unwrap_err();
+            assert_eq!(err, ContractError::ExecutionNotDue { next_execution });
+        }
+
+        suite.skip_interval();
+    }
+
+    // the whole deposit was swapped
+    let err = suite
+        .execute("keeper", &ExecuteMsg::ExecutePosition { position_id })
+        .unwrap_err();
+    assert_eq!(err, ContractError::PositionDepleted { position_id });
+    assert_eq!(
+        suite
+            .suite
+            .query_balance(suite.dca_addr.as_str(), &native("uwhale")),
+        Uint128::zero()
+    );
+
+    let position = suite.query_position(position_id).unwrap();
+    assert_eq!(position.deposit, uwhale(0));
+    assert_eq!(position.executions, 3);
+
+    let history = suite.query_history(position_id);
+    assert_eq!(history.executions.len(), 3);
+    for (index, execution) in history.executions.iter().enumerate() {
+        assert_eq!(execution.execution_id, index as u64 + 1);
+        assert_eq!(
+            execution.executed_at,
+            start_time.plus_seconds(index as u64 * DAY)
+        );
+        assert_eq!(execution.return_amount, return_amounts[index]);
+        assert_eq!(
+            execution.keeper_tip,
+            return_amounts[index] * Decimal::percent(1)
+        );
+    }
+    assert_eq!(
+        history
+            .executions
+            .iter()
+            .map(|execution| execution.offer_amount.u128())
+            .collect::<Vec<u128>>(),
+        vec![1_000_000, 1_000_000, 500_000]
+    );
+}
+
+#[test]
+fn execute_cw20_position_through_multiple_pairs() {
+    let mut suite = mock_suite();
+    let asset = suite.asset.clone();
+    let operations = vec![
+        swap_operation(&asset, &native("uwhale")),
+        swap_operation(&native("uwhale"), &native("uluna")),
+    ];
+
+    let position_id =
+        suite.create_token_position("alice", 2_000_000, operations.clone(), 1_000_000);
+
+    let return_amount = suite.simulate(1_000_000, operations);
+    let alice_balance = suite.suite.query_balance("alice", &native("uluna"));
+    suite
+        .execute("keeper", &ExecuteMsg::ExecutePosition { position_id })
+        .unwrap();
+
+    let keeper_tip = return_amount * Decimal::percent(1);
+    assert_eq!(
+        suite.suite.query_balance("keeper", &native("uluna")),
+        keeper_tip
+    );
+    assert_eq!(
+        suite.suite.query_balance("alice", &native("uluna")),
+        alice_balance + return_amount - keeper_tip
+    );
+    assert_eq!(
+        suite.suite.query_balance(suite.dca_addr.as_str(), &asset),
+        Uint128::new(1_000_000)
+    );
+    // nothing is left in the contract but the deposit
+    assert_eq!(
+        suite
+            .suite
+            .query_balance(suite.dca_addr.as_str(), &native("uwhale")),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn cannot_execute_below_the_minimum_price() {
+    let mut suite = mock_suite();
+
+    // the pair returns about 1 uluna per uwhale
+    let position_id = suite
+        .create_native_position(
+            "bob",
+            uwhale(2_000_000),
+            vec![swap_operation(&native("uwhale"), &native("uluna"))],
+            1_000_000,
+            Decimal::percent(110),
+        )
+        .unwrap();
+
+    // the router asserts the swap returns the minimum price of the position
+    let err = suite
+        .suite
+        .app
+        .execute_contract(
+            Addr::unchecked("keeper"),
+            suite.dca_addr.clone(),
+            &ExecuteMsg::ExecutePosition { position_id },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .starts_with("Assertion failed; minimum receive amount: 1100000"));
+
+    assert_eq!(
+        suite.query_position(position_id).unwrap().deposit,
+        uwhale(2_000_000)
+    );
+    assert!(suite.query_history(position_id).executions.is_empty());
+}
+
+#[test]
+fn pause_and_resume_position() {
+    let mut suite = mock_suite();
+
+    let position_id = suite
+        .create_native_position(
+            "bob",
+            uwhale(2_000_000),
+            vec![swap_operation(&native("uwhale"), &native("uluna"))],
+            1_000_000,
+            min_price(),
+        )
+        .unwrap();
+
+    // only the owner can pause the position
+    let err = suite
+        .execute("alice", &ExecuteMsg::PausePosition { position_id })
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    suite
+        .execute("bob", &ExecuteMsg::PausePosition { position_id })
+        .unwrap();
+    assert!(suite.query_position(position_id).unwrap().paused);
+
+    let err = suite
+        .execute("bob", &ExecuteMsg::PausePosition { position_id })
+        .unwrap_err();
+    assert_eq!(err, ContractError::PositionPaused { position_id });
+
+    let err = suite
+        .execute("keeper", &ExecuteMsg::ExecutePosition { position_id })
+        .unwrap_err();
+    assert_eq!(err, ContractError::PositionPaused { position_id });
+
+    // only the owner can resume the position
+    let err = suite
+        .execute("alice", &ExecuteMsg::ResumePosition { position_id })
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    suite
+        .execute("bob", &ExecuteMsg::ResumePosition { position_id })
+        .unwrap();
+
+    let err = suite
+        .execute("bob", &ExecuteMsg::ResumePosition { position_id })
+        .unwrap_err();
+    assert_eq!(err, ContractError::PositionNotPaused { position_id });
+
+    suite
+        .execute("keeper", &ExecuteMsg::ExecutePosition { position_id })
+        .unwrap();
+    assert_eq!(suite.query_history(position_id).executions.len(), 1);
+}
+
+#[test]
+fn cancel_position() {
+    let mut suite = mock_suite();
+
+    let position_id = suite
+        .create_native_position(
+            "bob",
+            uwhale(2_500_000),
+            vec![swap_operation(&native("uwhale"), &native("uluna"))],
+            1_000_000,
+            min_price(),
+        )
+        .unwrap();
+    suite
+        .execute("keeper", &ExecuteMsg::ExecutePosition { position_id })
+        .unwrap();
+
+    // only the owner can cancel the position
+    let err = suite
+        .execute("alice", &ExecuteMsg::CancelPosition { position_id })
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let bob_balance = suite.suite.query_balance("bob", &native("uwhale"));
+    suite
+        .execute("bob", &ExecuteMsg::CancelPosition { position_id })
+        .unwrap();
+
+    // what's left of the deposit is refunded
+    assert_eq!(
+        suite.suite.query_balance("bob", &native("uwhale")),
+        bob_balance + Uint128::new(1_500_000)
+    );
+    assert_eq!(
+        suite
+            .suite
+            .query_balance(suite.dca_addr.as_str(), &native("uwhale")),
+        Uint128::zero()
+    );
+    assert_eq!(suite.query_position(position_id), None);
+
+    // the history is kept
+    assert_eq!(suite.query_history(position_id).executions.len(), 1);
+}
+
+#[test]
+fn cannot_settle_execution_externally() {
+    let mut suite = mock_suite();
+
+    let position_id = suite
+        .create_native_position(
+            "bob",
+            uwhale(2_000_000),
+            vec![swap_operation(&native("uwhale"), &native("uluna"))],
+            1_000_000,
+            min_price(),
+        )
+        .unwrap();
+
+    let err = suite
+        .execute(
+            "keeper",
+            &ExecuteMsg::SettleExecution {
+                position_id,
+                offer_amount: Uint128::new(1_000_000),
+                prev_balance: Uint128::zero(),
+                keeper: "keeper".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn cannot_create_cancel_or_execute_positions_while_a_position_is_executed() {
+    let mut deps = mock_dependencies();
+    EXECUTING_POSITION.save(deps.as_mut().storage, &1).unwrap();
+
+    let err = commands::create_position(
+        deps.as_mut(),
+        mock_env(),
+        Addr::unchecked("bob"),
+        uwhale(2_000_000),
+        vec![swap_operation(&native("uwhale"), &native("uluna"))],
+        Uint128::new(1_000_000),
+        DAY,
+        min_price(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::PositionBeingExecuted {});
+
+    let err = commands::cancel_position(deps.as_mut(), mock_info("bob", &[]), 2).unwrap_err();
+    assert_eq!(err, ContractError::PositionBeingExecuted {});
+
+    let err = commands::execute_position(deps.as_mut(), mock_env(), mock_info("keeper", &[]), 2)
+        .unwrap_err();
+    assert_eq!(err, ContractError::PositionBeingExecuted {});
+}
+
+#[test]
+fn update_config() {
+    let mut suite = mock_suite();
+    let creator = suite.suite.creator.to_string();
+
+    let err = suite
+        .execute(
+            "alice",
+            &ExecuteMsg::UpdateConfig {
+                owner: Some("alice".to_string()),
+                pool_router: None,
+                keeper_tip: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err = suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateConfig {
+                owner: None,
+                pool_router: None,
+                keeper_tip: Some(Decimal::one()),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidKeeperTip {});
+
+    suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateConfig {
+                owner: Some("alice".to_string()),
+                pool_router: Some("new_router".to_string()),
+                keeper_tip: Some(Decimal::percent(2)),
+            },
+        )
+        .unwrap();
+
+    let config: ConfigResponse = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.dca_addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.owner, Addr::unchecked("alice"));
+    assert_eq!(config.pool_router, Addr::unchecked("new_router"));
+    assert_eq!(config.keeper_tip, Decimal::percent(2));
+}
+
+#[test]
+fn can_migrate_contract() {
+    let mut suite = mock_suite();
+
+    let code_id = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_contract_info(&suite.dca_addr)
+        .unwrap()
+        .code_id;
+
+    // should not be able to migrate as the version is not higher
+    let err: ContractError = suite
+        .suite
+        .app
+        .migrate_contract(
+            suite.suite.creator.clone(),
+            suite.dca_addr.clone(),
+            &MigrateMsg {},
+            code_id,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    match err {
+        ContractError::MigrateInvalidVersion { .. } => (),
+        _ => panic!("should return ContractError::MigrateInvalidVersion"),
+    }
+}
@@ -0,0 +1,327 @@
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, Uint128, WasmMsg,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+
+use pool_network::asset::{Asset, AssetInfo, PairInfo};
+use pool_network::factory::{AssetPairsResponse, QueryMsg as FactoryQueryMsg};
+use pool_network::limit_order::{Cw20HookMsg, ExecuteMsg, Order};
+use pool_network::pair::{ExecuteMsg as PairExecuteMsg, SimulationResponse};
+use pool_network::querier::{query_pair_info_from_pair, simulate};
+
+use crate::error::ContractError;
+use crate::state::{assert_no_order_executing, next_order_id, orders, CONFIG, EXECUTING_ORDER};
+
+/// Places an order selling the cw20 tokens sent to the contract
+pub fn receive_cw20(
+    deps: DepsMut,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::PlaceOrder {
+            pair_address,
+            price,
+        } => {
+            let owner = deps.api.addr_validate(&cw20_msg.sender)?;
+            let offer_asset = Asset {
+                info: AssetInfo::Token {
+                    contract_addr: info.sender.to_string(),
+                },
+                amount: cw20_msg.amount,
+            };
+
+            place_order(deps, owner, offer_asset, pair_address, price)
+        }
+    }
+}
+
+/// Places an order selling the escrowed offer asset in the given pair, once it reaches the given
+/// price. The pair has to be registered in the pool factory.
+pub fn place_order(
+    deps: DepsMut,
+    owner: Addr,
+    offer_asset: Asset,
+    pair_address: String,
+    price: Decimal,
+) -> Result<Response, ContractError> {
+    if offer_asset.amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    if price.is_zero() {
+        return Err(ContractError::InvalidZeroPrice {});
+    }
+
+    assert_no_order_executing(deps.storage)?;
+
+    let pair_address = deps.api.addr_validate(&pair_address)?;
+    let pair_info: PairInfo = query_pair_info_from_pair(&deps.querier, pair_address.clone())?;
+    assert_registered_pair(deps.as_ref(), &pair_info)?;
+    let ask_asset_info = if offer_asset.info.equal(&pair_info.asset_infos[0]) {
+        pair_info.asset_infos[1].clone()
+    } else if offer_asset.info.equal(&pair_info.asset_infos[1]) {
+        pair_info.asset_infos[0].clone()
+    } else {
+        return Err(ContractError::AssetMismatch {});
+    };
+
+    let order = Order {
+        order_id: next_order_id(deps.storage)?,
+        owner,
+        pair_address,
+        offer_asset,
+        ask_asset_info,
+        price,
+    };
+    orders().save(deps.storage, order.order_id, &order)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "place_order"),
+        ("order_id", &order.order_id.to_string()),
+        ("owner", order.owner.as_str()),
+        ("pair_address", order.pair_address.as_str()),
+        ("offer_asset", &order.offer_asset.to_string()),
+        ("ask_asset", &order.ask_asset_info.to_string()),
+        ("price", &order.price.to_string()),
+    ]))
+}
+
+/// Asserts the pair is registered in the pool factory, as any contract can answer the pair queries
+fn assert_registered_pair(deps: Deps, pair_info: &PairInfo) -> Result<(), ContractError> {
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
+    let asset_pairs: AssetPairsResponse = deps.querier.query_wasm_smart(
+        factory_addr,
+        &FactoryQueryMsg::AssetPairs {
+            asset_infos: pair_info.asset_infos.clone(),
+        },
+    )?;
+
+    if !asset_pairs
+        .pairs
+        .iter()
+        .any(|asset_pair| asset_pair.pair_info.contract_addr == pair_info.contract_addr)
+    {
+        return Err(ContractError::UnregisteredPair {});
+    }
+
+    Ok(())
+}
+
+/// Cancels an order, refunding the escrowed offer asset to its owner
+pub fn cancel_order(
+    deps: DepsMut,
+    info: MessageInfo,
+    order_id: u64,
+) -> Result<Response, ContractError> {
+    assert_no_order_executing(deps.storage)?;
+
+    let order = orders().load(deps.storage, order_id)?;
+
+    // only the owner can cancel the order
+    if info.sender != order.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    orders().remove(deps.storage, order_id)?;
+
+    Ok(Response::new()
+        .add_message(order.offer_asset.clone().into_msg(order.owner)?)
+        .add_attributes(vec![
+            ("action", "cancel_order"),
+            ("order_id", &order_id.to_string()),
+            ("refund_asset", &order.offer_asset.to_string()),
+        ]))
+}
+
+/// Executes an order if the pair returns at least its minimum return, swapping the escrowed offer
+/// asset in the pair. The proceeds are settled with [ExecuteMsg::SettleOrder] once the swap is
+/// performed, locking the contract until then.
+pub fn execute_order(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    order_id: u64,
+) -> Result<Response, ContractError> {
+    assert_no_order_executing(deps.storage)?;
+
+    let order = orders().load(deps.storage, order_id)?;
+
+    let simulation: SimulationResponse = simulate(
+        &deps.querier,
+        order.pair_address.clone(),
+        &order.offer_asset,
+    )?;
+    let minimum_return = order.minimum_return();
+    if simulation.return_amount < minimum_return {
+        return Err(ContractError::TargetPriceNotReached {
+            minimum_return,
+            return_amount: simulation.return_amount,
+        });
+    }
+
+    let prev_balance =
+        order
+            .ask_asset_info
+            .query_pool(&deps.querier, deps.api, env.contract.address.clone())?;
+    EXECUTING_ORDER.save(deps.storage, &order_id)?;
+
+    Ok(Response::new()
+        .add_message(swap_msg(&order)?)
+        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::SettleOrder {
+                order_id,
+                prev_balance,
+                keeper: info.sender.to_string(),
+            })?,
+            funds: vec![],
+        }))
+        .add_attributes(vec![
+            ("action", "execute_order"),
+            ("order_id", &order_id.to_string()),
+            ("keeper", info.sender.as_str()),
+        ]))
+}
+
+/// Creates the message swapping the offer asset of the order in its pair. The minimum return of the
+/// order is asserted when settling it, so the pair isn't given any spread to assert.
+fn swap_msg(order: &Order) -> StdResult<CosmosMsg> {
+    let offer_asset = order.offer_asset.clone();
+    match offer_asset.info.clone() {
+        AssetInfo::NativeToken { denom } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: order.pair_address.to_string(),
+            funds: vec![Coin {
+                denom,
+                amount: offer_asset.amount,
+            }],
+            msg: to_binary(&PairExecuteMsg::Swap {
+                offer_asset,
+                belief_price: None,
+                max_spread: None,
+                to: None,
+            })?,
+        })),
+        AssetInfo::Token { contract_addr } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                contract: order.pair_address.to_string(),
+                amount: offer_asset.amount,
+                msg: to_binary(&pool_network::pair::Cw20HookMsg::Swap {
+                    belief_price: None,
+                    max_spread: None,
+                    to: None,
+                })?,
+            })?,
+        })),
+    }
+}
+
+/// Settles an executed order, asserting the swap returned at least its minimum return. The keeper
+/// tip is sent to the keeper and the rest of the proceeds to the owner of the order, unlocking the
+/// contract.
+pub fn settle_order(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    order_id: u64,
+    prev_balance: Uint128,
+    keeper: String,
+) -> Result<Response, ContractError> {
+    // only the contract itself can settle the orders
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let order = orders().load(deps.storage, order_id)?;
+
+    let balance = order
+        .ask_asset_info
+        .query_pool(&deps.querier, deps.api, env.contract.address)?;
+    let return_amount = balance.checked_sub(prev_balance)?;
+    let minimum_return = order.minimum_return();
+    if return_amount < minimum_return {
+        return Err(ContractError::TargetPriceNotReached {
+            minimum_return,
+            return_amount,
+        });
+    }
+
+    let keeper_tip = return_amount * CONFIG.load(deps.storage)?.keeper_tip;
+    let owner_amount = return_amount.checked_sub(keeper_tip)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if !keeper_tip.is_zero() {
+        messages.push(
+            Asset {
+                info: order.ask_asset_info.clone(),
+                amount: keeper_tip,
+            }
+            .into_msg(deps.api.addr_validate(&keeper)?)?,
+        );
+    }
+    if !owner_amount.is_zero() {
+        messages.push(
+            Asset {
+                info: order.ask_asset_info.clone(),
+                amount: owner_amount,
+            }
+            .into_msg(order.owner.clone())?,
+        );
+    }
+
+    orders().remove(deps.storage, order_id)?;
+    EXECUTING_ORDER.remove(deps.storage);
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "settle_order"),
+        ("order_id", &order_id.to_string()),
+        ("owner", order.owner.as_str()),
+        ("return_amount", &return_amount.to_string()),
+        ("keeper", &keeper),
+        ("keeper_tip", &keeper_tip.to_string()),
+    ]))
+}
+
+/// Updates the owner and the keeper tip of the contract
+pub fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    owner: Option<String>,
+    keeper_tip: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // only the owner can update the config
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(owner) = owner {
+        config.owner = deps.api.addr_validate(&owner)?;
+    }
+
+    if let Some(keeper_tip) = keeper_tip {
+        validate_keeper_tip(keeper_tip)?;
+        config.keeper_tip = keeper_tip;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_config"),
+        ("owner", config.owner.as_str()),
+        ("keeper_tip", &config.keeper_tip.to_string()),
+    ]))
+}
+
+/// Validates the keeper tip is lower than 1, so the owner of an order gets part of the proceeds
+pub fn validate_keeper_tip(keeper_tip: Decimal) -> Result<(), ContractError> {
+    if keeper_tip >= Decimal::one() {
+        return Err(ContractError::InvalidKeeperTip {});
+    }
+
+    Ok(())
+}
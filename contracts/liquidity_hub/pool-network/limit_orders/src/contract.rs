@@ -0,0 +1,121 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
+
+use pool_network::limit_order::{Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+use crate::error::ContractError;
+use crate::error::ContractError::MigrateInvalidVersion;
+use crate::state::CONFIG;
+use crate::{commands, queries};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "nico_teen-limit_orders";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    commands::validate_keeper_tip(msg.keeper_tip)?;
+
+    let config = Config {
+        owner: info.sender,
+        factory_addr: deps.api.addr_validate(&msg.factory_addr)?,
+        keeper_tip: msg.keeper_tip,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "instantiate"),
+        ("owner", config.owner.as_str()),
+        ("factory_addr", config.factory_addr.as_str()),
+        ("keeper_tip", &config.keeper_tip.to_string()),
+    ]))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Receive(msg) => commands::receive_cw20(deps, info, msg),
+        ExecuteMsg::PlaceOrder {
+            offer_asset,
+            pair_address,
+            price,
+        } => {
+            if !offer_asset.is_native_token() {
+                return Err(ContractError::Cw20OrderNotReceived {});
+            }
+            offer_asset.assert_sent_native_token_balance(&info)?;
+
+            commands::place_order(deps, info.sender, offer_asset, pair_address, price)
+        }
+        ExecuteMsg::CancelOrder { order_id } => commands::cancel_order(deps, info, order_id),
+        ExecuteMsg::ExecuteOrder { order_id } => commands::execute_order(deps, env, info, order_id),
+        ExecuteMsg::SettleOrder {
+            order_id,
+            prev_balance,
+            keeper,
+        } => commands::settle_order(deps, env, info, order_id, prev_balance, keeper),
+        ExecuteMsg::UpdateConfig { owner, keeper_tip } => {
+            commands::update_config(deps, info, owner, keeper_tip)
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    match msg {
+        QueryMsg::Config {} => Ok(to_binary(&queries::query_config(deps)?)?),
+        QueryMsg::Order { order_id } => Ok(to_binary(&queries::query_order(deps, order_id)?)?),
+        QueryMsg::OrdersByOwner {
+            owner,
+            start_after,
+            limit,
+        } => Ok(to_binary(&queries::query_orders_by_owner(
+            deps,
+            owner,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::OrdersByPair {
+            pair_address,
+            start_after,
+            limit,
+        } => Ok(to_binary(&queries::query_orders_by_pair(
+            deps,
+            pair_address,
+            start_after,
+            limit,
+        )?)?),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let version: Version = CONTRACT_VERSION.parse()?;
+    let storage_version: Version = get_contract_version(deps.storage)?.version.parse()?;
+
+    if storage_version >= version {
+        return Err(MigrateInvalidVersion {
+            current_version: storage_version,
+            new_version: version,
+        });
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
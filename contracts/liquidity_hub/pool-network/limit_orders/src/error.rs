@@ -0,0 +1,59 @@
+use cosmwasm_std::{OverflowError, StdError, Uint128};
+use semver::Version;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("Attempt to migrate to version {new_version}, but contract is on a higher version {current_version}")]
+    MigrateInvalidVersion {
+        new_version: Version,
+        current_version: Version,
+    },
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Invalid keeper tip, it must be lower than 1")]
+    InvalidKeeperTip {},
+
+    #[error("An order can't be placed with a zero amount")]
+    InvalidZeroAmount {},
+
+    #[error("An order can't be placed with a zero target price")]
+    InvalidZeroPrice {},
+
+    #[error("The offer asset of the order is not in the pair")]
+    AssetMismatch {},
+
+    #[error("The pair is not registered in the pool factory")]
+    UnregisteredPair {},
+
+    #[error("An order is being executed, orders can't be placed or cancelled until it is settled")]
+    OrderBeingExecuted {},
+
+    #[error("Orders selling cw20 tokens are placed by sending the tokens to the contract")]
+    Cw20OrderNotReceived {},
+
+    #[error(
+        "The target price of the order is not reached; minimum return: {minimum_return}, return amount: {return_amount}"
+    )]
+    TargetPriceNotReached {
+        minimum_return: Uint128,
+        return_amount: Uint128,
+    },
+}
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
+}
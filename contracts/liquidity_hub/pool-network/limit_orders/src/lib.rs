@@ -0,0 +1,11 @@
+mod commands;
+pub mod contract;
+mod error;
+mod queries;
+pub mod state;
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tests;
+
+pub use crate::error::ContractError;
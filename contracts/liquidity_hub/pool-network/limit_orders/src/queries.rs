@@ -0,0 +1,50 @@
+use cosmwasm_std::Deps;
+
+use pool_network::limit_order::{ConfigResponse, Order, OrdersResponse};
+
+use crate::error::ContractError;
+use crate::state::{orders, read_orders, CONFIG};
+
+/// Queries the [Config], which contains the owner, the pool factory and the keeper tip
+pub fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
+    Ok(CONFIG.load(deps.storage)?)
+}
+
+/// Queries an order by id
+pub fn query_order(deps: Deps, order_id: u64) -> Result<Order, ContractError> {
+    Ok(orders().load(deps.storage, order_id)?)
+}
+
+/// Queries the orders placed by the given owner
+pub fn query_orders_by_owner(
+    deps: Deps,
+    owner: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<OrdersResponse, ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+
+    Ok(OrdersResponse {
+        orders: read_orders(deps.storage, &orders().idx.owner, owner, start_after, limit)?,
+    })
+}
+
+/// Queries the orders placed against the given pair
+pub fn query_orders_by_pair(
+    deps: Deps,
+    pair_address: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<OrdersResponse, ContractError> {
+    let pair_address = deps.api.addr_validate(&pair_address)?;
+
+    Ok(OrdersResponse {
+        orders: read_orders(
+            deps.storage,
+            &orders().idx.pair,
+            pair_address,
+            start_after,
+            limit,
+        )?,
+    })
+}
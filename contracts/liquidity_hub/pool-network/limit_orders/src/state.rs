@@ -0,0 +1,78 @@
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, MultiIndex};
+
+use pool_network::limit_order::{Config, Order};
+
+use crate::error::ContractError;
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const ORDER_COUNTER: Item<u64> = Item::new("order_counter");
+/// The order being executed and not settled yet. The settlement accounts the proceeds of the order
+/// by the balance of the contract, so no other order can be placed, cancelled or executed meanwhile.
+pub const EXECUTING_ORDER: Item<u64> = Item::new("executing_order");
+
+pub struct OrderIndexes<'a> {
+    pub owner: MultiIndex<'a, Addr, Order, u64>,
+    pub pair: MultiIndex<'a, Addr, Order, u64>,
+}
+
+impl<'a> IndexList<Order> for OrderIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Order>> + '_> {
+        let v: Vec<&dyn Index<Order>> = vec![&self.owner, &self.pair];
+        Box::new(v.into_iter())
+    }
+}
+
+/// The resting orders, indexed by owner and by pair
+pub fn orders<'a>() -> IndexedMap<'a, u64, Order, OrderIndexes<'a>> {
+    let indexes = OrderIndexes {
+        owner: MultiIndex::new(|_pk, order| order.owner.clone(), "orders", "orders__owner"),
+        pair: MultiIndex::new(
+            |_pk, order| order.pair_address.clone(),
+            "orders",
+            "orders__pair",
+        ),
+    };
+
+    IndexedMap::new("orders", indexes)
+}
+
+/// Returns the id for the next order, increasing the counter
+pub fn next_order_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let order_id = ORDER_COUNTER.may_load(storage)?.unwrap_or_default() + 1;
+    ORDER_COUNTER.save(storage, &order_id)?;
+
+    Ok(order_id)
+}
+
+/// Asserts no order is being executed, see [EXECUTING_ORDER]
+pub fn assert_no_order_executing(storage: &dyn Storage) -> Result<(), ContractError> {
+    if EXECUTING_ORDER.may_load(storage)?.is_some() {
+        return Err(ContractError::OrderBeingExecuted {});
+    }
+
+    Ok(())
+}
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+/// Reads the orders under the given key of an index of [orders], sorted by id
+pub fn read_orders(
+    storage: &dyn Storage,
+    index: &MultiIndex<Addr, Order, u64>,
+    key: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Order>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    index
+        .prefix(key)
+        .range(storage, start, None, cosmwasm_std::Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, order)| order))
+        .collect()
+}
@@ -0,0 +1,736 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{coin, coins, to_binary, Addr, Decimal, Empty, Uint128};
+use cw20::Cw20ExecuteMsg;
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+
+use liquidity_hub_testing::contracts::pair_contract;
+use liquidity_hub_testing::{native, PairBuilder, TestSuite};
+use pool_network::asset::{Asset, AssetInfo, PairType};
+use pool_network::limit_order::{
+    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, Order, OrdersResponse,
+    QueryMsg,
+};
+use pool_network::pair::{
+    ExecuteMsg as PairExecuteMsg, InstantiateMsg as PairInstantiateMsg, PoolFee,
+    QueryMsg as PairQueryMsg, SimulationResponse,
+};
+use nico_teen::fee::Fee;
+
+use crate::commands;
+use crate::contract::{execute, instantiate, migrate, query};
+use crate::state::EXECUTING_ORDER;
+use crate::ContractError;
+
+/// The amount of each asset provided to the pairs
+const POOL_LIQUIDITY: u128 = 1_000_000_000u128;
+
+fn limit_orders_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(execute, instantiate, query).with_migrate(migrate))
+}
+
+struct LimitOrdersSuite {
+    suite: TestSuite,
+    limit_orders_addr: Addr,
+    /// The uluna-uwhale pair
+    native_pair_addr: Addr,
+    /// The uwhale-asset pair
+    token_pair_addr: Addr,
+    /// The cw20 token of the uwhale-asset pair
+    asset: AssetInfo,
+}
+
+/// Creates the uluna-uwhale and uwhale-asset pairs and the limit orders contract, with a 1% keeper
+/// tip. alice and bob are funded with the assets.
+fn mock_suite() -> LimitOrdersSuite {
+    let funds = vec![
+        coin(10 * POOL_LIQUIDITY, "uluna"),
+        coin(10 * POOL_LIQUIDITY, "uwhale"),
+    ];
+    let mut suite = TestSuite::builder()
+        .with_funded_user("alice", funds.clone())
+        .with_funded_user("bob", funds)
+        .build();
+
+    let asset = suite.create_token(
+        "asset",
+        &[("alice", 10 * POOL_LIQUIDITY), ("bob", 10 * POOL_LIQUIDITY)],
+    );
+
+    let native_pair = suite.create_pair(
+        PairBuilder::new([native("uluna"), native("uwhale")])
+            .with_liquidity("alice", [Uint128::new(POOL_LIQUIDITY); 2]),
+    );
+    let token_pair = suite.create_pair(
+        PairBuilder::new([native("uwhale"), asset.clone()])
+            .with_liquidity("alice", [Uint128::new(POOL_LIQUIDITY); 2]),
+    );
+
+    let code_id = suite.app.store_code(limit_orders_contract());
+    let limit_orders_addr = suite
+        .app
+        .instantiate_contract(
+            code_id,
+            suite.creator.clone(),
+            &InstantiateMsg {
+                factory_addr: suite.pool_factory_addr.to_string(),
+                keeper_tip: Decimal::percent(1),
+            },
+            &[],
+            "limit_orders",
+            Some(suite.creator.to_string()),
+        )
+        .unwrap();
+
+    LimitOrdersSuite {
+        suite,
+        limit_orders_addr,
+        native_pair_addr: Addr::unchecked(native_pair.contract_addr),
+        token_pair_addr: Addr::unchecked(token_pair.contract_addr),
+        asset,
+    }
+}
+
+impl LimitOrdersSuite {
+    fn place_native_order(
+        &mut self,
+        owner: &str,
+        offer_asset: Asset,
+        pair_address: &Addr,
+        price: Decimal,
+    ) -> Result<u64, ContractError> {
+        let funds = match &offer_asset.info {
+            AssetInfo::NativeToken { denom } if !offer_asset.amount.is_zero() => {
+                coins(offer_asset.amount.u128(), denom)
+            }
+            _ => vec![],
+        };
+
+        let res = self
+            .suite
+            .app
+            .execute_contract(
+                Addr::unchecked(owner),
+                self.limit_orders_addr.clone(),
+                &ExecuteMsg::PlaceOrder {
+                    offer_asset,
+                    pair_address: pair_address.to_string(),
+                    price,
+                },
+                &funds,
+            )
+            .map_err(|err| err.downcast::<ContractError>().unwrap())?;
+
+        Ok(order_id_from_events(&res.events))
+    }
+
+    fn place_token_order(&mut self, owner: &str, amount: u128, price: Decimal) -> u64 {
+        let AssetInfo::Token { contract_addr } = self.asset.clone() else {
+            panic!("the asset should be a cw20 token");
+        };
+
+        let res = self
+            .suite
+            .app
+            .execute_contract(
+                Addr::unchecked(owner),
+                Addr::unchecked(contract_addr),
+                &Cw20ExecuteMsg::Send {
+                    contract: self.limit_orders_addr.to_string(),
+                    amount: Uint128::new(amount),
+                    msg: to_binary(&Cw20HookMsg::PlaceOrder {
+                        pair_address: self.token_pair_addr.to_string(),
+                        price,
+                    })
+                    .unwrap(),
+                },
+                &[],
+            )
+            .unwrap();
+
+        order_id_from_events(&res.events)
+    }
+
+    fn execute(&mut self, sender: &str, msg: &ExecuteMsg) -> Result<(), ContractError> {
+        self.suite
+            .app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.limit_orders_addr.clone(),
+                msg,
+                &[],
+            )
+            .map(|_| ())
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    fn query_order(&self, order_id: u64) -> Option<Order> {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.limit_orders_addr, &QueryMsg::Order { order_id })
+            .ok()
+    }
+
+    fn query_orders(&self, msg: &QueryMsg) -> Vec<u64> {
+        let res: OrdersResponse = self
+            .suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.limit_orders_addr, msg)
+            .unwrap();
+
+        res.orders.into_iter().map(|order| order.order_id).collect()
+    }
+
+    fn simulate(&self, pair_address: &Addr, offer_asset: Asset) -> SimulationResponse {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(pair_address, &PairQueryMsg::Simulation { offer_asset })
+            .unwrap()
+    }
+}
+
+fn order_id_from_events(events: &[cosmwasm_std::Event]) -> u64 {
+    events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == "order_id")
+        .unwrap()
+        .value
+        .parse()
+        .unwrap()
+}
+
+fn uluna(amount: u128) -> Asset {
+    Asset {
+        info: native("uluna"),
+        amount: Uint128::new(amount),
+    }
+}
+
+#[test]
+fn proper_initialization() {
+    let suite = mock_suite();
+
+    let config: ConfigResponse = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.limit_orders_addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.owner, suite.suite.creator);
+    assert_eq!(config.factory_addr, suite.suite.pool_factory_addr);
+    assert_eq!(config.keeper_tip, Decimal::percent(1));
+}
+
+#[test]
+fn cannot_instantiate_with_a_keeper_tip_of_one() {
+    let mut suite = mock_suite();
+
+    let code_id = suite.suite.app.store_code(limit_orders_contract());
+    let err: ContractError = suite
+        .suite
+        .app
+        .instantiate_contract(
+            code_id,
+            suite.suite.creator.clone(),
+            &InstantiateMsg {
+                factory_addr: suite.suite.pool_factory_addr.to_string(),
+                keeper_tip: Decimal::one(),
+            },
+            &[],
+            "limit_orders",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidKeeperTip {});
+}
+
+#[test]
+fn place_and_query_orders() {
+    let mut suite = mock_suite();
+    let native_pair_addr = suite.native_pair_addr.clone();
+
+    let first_order_id = suite
+        .place_native_order(
+            "bob",
+            uluna(1_000),
+            &native_pair_addr,
+            Decimal::percent(110),
+        )
+        .unwrap();
+    let second_order_id = suite
+        .place_native_order(
+            "bob",
+            uluna(2_000),
+            &native_pair_addr,
+            Decimal::percent(120),
+        )
+        .unwrap();
+    let third_order_id = suite.place_token_order("alice", 3_000, Decimal::percent(90));
+    assert_eq!(
+        (first_order_id, second_order_id, third_order_id),
+        (1u64, 2u64, 3u64)
+    );
+
+    // the offer assets are escrowed
+    assert_eq!(
+        suite
+            .suite
+            .query_balance(suite.limit_orders_addr.as_str(), &native("uluna")),
+        Uint128::new(3_000)
+    );
+    assert_eq!(
+        suite
+            .suite
+            .query_balance(suite.limit_orders_addr.as_str(), &suite.asset),
+        Uint128::new(3_000)
+    );
+
+    assert_eq!(
+        suite.query_order(second_order_id).unwrap(),
+        Order {
+            order_id: second_order_id,
+            owner: Addr::unchecked("bob"),
+            pair_address: native_pair_addr.clone(),
+            offer_asset: uluna(2_000),
+            ask_asset_info: native("uwhale"),
+            price: Decimal::percent(120),
+        }
+    );
+    let third_order = suite.query_order(third_order_id).unwrap();
+    assert_eq!(third_order.owner, Addr::unchecked("alice"));
+    assert_eq!(
+        third_order.offer_asset,
+        Asset {
+            info: suite.asset.clone(),
+            amount: Uint128::new(3_000),
+        }
+    );
+    assert_eq!(third_order.ask_asset_info, native("uwhale"));
+
+    assert_eq!(
+        suite.query_orders(&QueryMsg::OrdersByOwner {
+            owner: "bob".to_string(),
+            start_after: None,
+            limit: None,
+        }),
+        vec![first_order_id, second_order_id]
+    );
+    assert_eq!(
+        suite.query_orders(&QueryMsg::OrdersByOwner {
+            owner: "bob".to_string(),
+            start_after: Some(first_order_id),
+            limit: None,
+        }),
+        vec![second_order_id]
+    );
+    assert_eq!(
+        suite.query_orders(&QueryMsg::OrdersByOwner {
+            owner: "alice".to_string(),
+            start_after: None,
+            limit: None,
+        }),
+        vec![third_order_id]
+    );
+    assert_eq!(
+        suite.query_orders(&QueryMsg::OrdersByPair {
+            pair_address: native_pair_addr.to_string(),
+            start_after: None,
+            limit: Some(1),
+        }),
+        vec![first_order_id]
+    );
+    assert_eq!(
+        suite.query_orders(&QueryMsg::OrdersByPair {
+            pair_address: suite.token_pair_addr.to_string(),
+            start_after: None,
+            limit: None,
+        }),
+        vec![third_order_id]
+    );
+}
+
+#[test]
+fn cannot_place_invalid_orders() {
+    let mut suite = mock_suite();
+    let native_pair_addr = suite.native_pair_addr.clone();
+    let token_pair_addr = suite.token_pair_addr.clone();
+
+    let err = suite
+        .place_native_order("bob", uluna(1_000), &native_pair_addr, Decimal::zero())
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidZeroPrice {});
+
+    let err = suite
+        .place_native_order("bob", uluna(0), &native_pair_addr, Decimal::one())
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidZeroAmount {});
+
+    // uluna is not in the uwhale-asset pair
+    let err = suite
+        .place_native_order("bob", uluna(1_000), &token_pair_addr, Decimal::one())
+        .unwrap_err();
+    assert_eq!(err, ContractError::AssetMismatch {});
+
+    // cw20 tokens have to be sent to the contract
+    let asset = Asset {
+        info: suite.asset.clone(),
+        amount: Uint128::new(1_000),
+    };
+    let err = suite
+        .place_native_order("bob", asset, &token_pair_addr, Decimal::one())
+        .unwrap_err();
+    assert_eq!(err, ContractError::Cw20OrderNotReceived {});
+
+    // the offer asset has to be sent along
+    let err = suite
+        .execute(
+            "bob",
+            &ExecuteMsg::PlaceOrder {
+                offer_asset: uluna(1_000),
+                pair_address: native_pair_addr.to_string(),
+                price: Decimal::one(),
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(err, ContractError::Std(_)));
+}
+
+#[test]
+fn cannot_place_orders_against_unregistered_pairs() {
+    let mut suite = mock_suite();
+
+    // a uluna-uwhale pair instantiated outside of the pool factory
+    let pair_code_id = suite.suite.app.store_code(pair_contract());
+    let pair_addr = suite
+        .suite
+        .app
+        .instantiate_contract(
+            pair_code_id,
+            suite.suite.creator.clone(),
+            &PairInstantiateMsg {
+                asset_infos: [native("uluna"), native("uwhale")],
+                token_code_id: suite.suite.token_code_id,
+                asset_decimals: [6, 6],
+                pool_fees: PoolFee {
+                    protocol_fee: Fee {
+                        share: Decimal::zero(),
+                    },
+                    swap_fee: Fee {
+                        share: Decimal::zero(),
+                    },
+                    burn_fee: Fee {
+                        share: Decimal::zero(),
+                    },
+                },
+                fee_collector_addr: suite.suite.fee_collector_addr.to_string(),
+                pair_type: PairType::ConstantProduct,
+                lp_token_type: None,
+                lp_token_info: None,
+            },
+            &[],
+            "pair",
+            None,
+        )
+        .unwrap();
+
+    let err = suite
+        .place_native_order("bob", uluna(1_000), &pair_addr, Decimal::one())
+        .unwrap_err();
+    assert_eq!(err, ContractError::UnregisteredPair {});
+}
+
+#[test]
+fn cannot_place_cancel_or_execute_orders_while_an_order_is_executed() {
+    let mut deps = mock_dependencies();
+    EXECUTING_ORDER.save(deps.as_mut().storage, &1).unwrap();
+
+    let err = commands::place_order(
+        deps.as_mut(),
+        Addr::unchecked("bob"),
+        uluna(1_000),
+        "pair".to_string(),
+        Decimal::one(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::OrderBeingExecuted {});
+
+    let err = commands::cancel_order(deps.as_mut(), mock_info("bob", &[]), 2).unwrap_err();
+    assert_eq!(err, ContractError::OrderBeingExecuted {});
+
+    let err = commands::execute_order(deps.as_mut(), mock_env(), mock_info("keeper", &[]), 2)
+        .unwrap_err();
+    assert_eq!(err, ContractError::OrderBeingExecuted {});
+}
+
+#[test]
+fn cancel_order() {
+    let mut suite = mock_suite();
+    let native_pair_addr = suite.native_pair_addr.clone();
+
+    let order_id = suite
+        .place_native_order(
+            "bob",
+            uluna(1_000),
+            &native_pair_addr,
+            Decimal::percent(110),
+        )
+        .unwrap();
+    let bob_balance = suite.suite.query_balance("bob", &native("uluna"));
+
+    // only the owner can cancel the order
+    let err = suite
+        .execute("alice", &ExecuteMsg::CancelOrder { order_id })
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    suite
+        .execute("bob", &ExecuteMsg::CancelOrder { order_id })
+        .unwrap();
+
+    assert_eq!(suite.query_order(order_id), None);
+    assert_eq!(
+        suite.suite.query_balance("bob", &native("uluna")),
+        bob_balance + Uint128::new(1_000)
+    );
+    assert_eq!(
+        suite
+            .suite
+            .query_balance(suite.limit_orders_addr.as_str(), &native("uluna")),
+        Uint128::zero()
+    );
+
+    // the order can't be cancelled twice
+    let err = suite
+        .execute("bob", &ExecuteMsg::CancelOrder { order_id })
+        .unwrap_err();
+    assert!(matches!(err, ContractError::Std(_)));
+}
+
+#[test]
+fn execute_order_once_the_target_price_is_met() {
+    let mut suite = mock_suite();
+    let native_pair_addr = suite.native_pair_addr.clone();
+
+    let order_id = suite
+        .place_native_order(
+            "bob",
+            uluna(1_000_000),
+            &native_pair_addr,
+            Decimal::percent(110),
+        )
+        .unwrap();
+
+    // the pair returns about 1 uwhale per uluna
+    let err = suite
+        .execute("keeper", &ExecuteMsg::ExecuteOrder { order_id })
+        .unwrap_err();
+    let ContractError::TargetPriceNotReached {
+        minimum_return,
+        return_amount,
+    } = err
+    else {
+        panic!("should return ContractError::TargetPriceNotReached");
+    };
+    assert_eq!(minimum_return, Uint128::new(1_100_000));
+    assert!(return_amount < minimum_return);
+
+    // buying uluna raises its price above the target
+    suite
+        .suite
+        .app
+        .execute_contract(
+            Addr::unchecked("alice"),
+            native_pair_addr.clone(),
+            &PairExecuteMsg::Swap {
+                offer_asset: Asset {
+                    info: native("uwhale"),
+                    amount: Uint128::new(POOL_LIQUIDITY / 5),
+                },
+                belief_price: None,
+                max_spread: None,
+                to: None,
+            },
+            &coins(POOL_LIQUIDITY / 5, "uwhale"),
+        )
+        .unwrap();
+
+    let simulation = suite.simulate(&native_pair_addr, uluna(1_000_000));
+    assert!(simulation.return_amount > minimum_return);
+
+    let bob_balance = suite.suite.query_balance("bob", &native("uwhale"));
+    suite
+        .execute("keeper", &ExecuteMsg::ExecuteOrder { order_id })
+        .unwrap();
+
+    // the keeper gets 1% of the proceeds and bob the rest
+    let keeper_tip = simulation.return_amount * Decimal::percent(1);
+    assert_eq!(
+        suite.suite.query_balance("keeper", &native("uwhale")),
+        keeper_tip
+    );
+    assert_eq!(
+        suite.suite.query_balance("bob", &native("uwhale")),
+        bob_balance + simulation.return_amount - keeper_tip
+    );
+    assert_eq!(
+        suite
+            .suite
+            .query_balance(suite.limit_orders_addr.as_str(), &native("uluna")),
+        Uint128::zero()
+    );
+    assert_eq!(suite.query_order(order_id), None);
+    assert!(suite
+        .query_orders(&QueryMsg::OrdersByOwner {
+            owner: "bob".to_string(),
+            start_after: None,
+            limit: None,
+        })
+        .is_empty());
+
+    // the contract is unlocked once the order is settled
+    suite
+        .place_native_order("bob", uluna(1_000), &native_pair_addr, Decimal::one())
+        .unwrap();
+}
+
+#[test]
+fn execute_cw20_order() {
+    let mut suite = mock_suite();
+
+    // the pair returns about 1 uwhale per asset, above the target
+    let order_id = suite.place_token_order("bob", 1_000_000, Decimal::percent(90));
+    let simulation = suite.simulate(
+        &suite.token_pair_addr.clone(),
+        Asset {
+            info: suite.asset.clone(),
+            amount: Uint128::new(1_000_000),
+        },
+    );
+
+    let bob_balance = suite.suite.query_balance("bob", &native("uwhale"));
+    suite
+        .execute("keeper", &ExecuteMsg::ExecuteOrder { order_id })
+        .unwrap();
+
+    let keeper_tip = simulation.return_amount * Decimal::percent(1);
+    assert_eq!(
+        suite.suite.query_balance("keeper", &native("uwhale")),
+        keeper_tip
+    );
+    assert_eq!(
+        suite.suite.query_balance("bob", &native("uwhale")),
+        bob_balance + simulation.return_amount - keeper_tip
+    );
+    assert_eq!(
+        suite
+            .suite
+            .query_balance(suite.limit_orders_addr.as_str(), &suite.asset),
+        Uint128::zero()
+    );
+    assert_eq!(suite.query_order(order_id), None);
+}
+
+#[test]
+fn cannot_settle_order_externally() {
+    let mut suite = mock_suite();
+    let native_pair_addr = suite.native_pair_addr.clone();
+
+    let order_id = suite
+        .place_native_order("bob", uluna(1_000), &native_pair_addr, Decimal::percent(50))
+        .unwrap();
+
+    let err = suite
+        .execute(
+            "keeper",
+            &ExecuteMsg::SettleOrder {
+                order_id,
+                prev_balance: Uint128::zero(),
+                keeper: "keeper".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn update_config() {
+    let mut suite = mock_suite();
+    let creator = suite.suite.creator.to_string();
+
+    let err = suite
+        .execute(
+            "alice",
+            &ExecuteMsg::UpdateConfig {
+                owner: Some("alice".to_string()),
+                keeper_tip: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err = suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateConfig {
+                owner: None,
+                keeper_tip: Some(Decimal::percent(100)),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidKeeperTip {});
+
+    suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateConfig {
+                owner: Some("alice".to_string()),
+                keeper_tip: Some(Decimal::percent(2)),
+            },
+        )
+        .unwrap();
+
+    let config: ConfigResponse = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.limit_orders_addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.owner, Addr::unchecked("alice"));
+    assert_eq!(config.keeper_tip, Decimal::percent(2));
+}
+
+#[test]
+fn can_migrate_contract() {
+    let mut suite = mock_suite();
+
+    let code_id = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_contract_info(&suite.limit_orders_addr)
+        .unwrap()
+        .code_id;
+
+    // should not be able to migrate as the version is not higher
+    let err: ContractError = suite
+        .suite
+        .app
+        .migrate_contract(
+            suite.suite.creator.clone(),
+            suite.limit_orders_addr.clone(),
+            &MigrateMsg {},
+            code_id,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    match err {
+        ContractError::MigrateInvalidVersion { .. } => (),
+        _ => panic!("should return ContractError::MigrateInvalidVersion"),
+    }
+}
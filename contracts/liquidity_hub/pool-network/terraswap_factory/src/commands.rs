@@ -1,16 +1,24 @@
 use cosmwasm_std::{
-    to_binary, wasm_execute, CosmosMsg, DepsMut, Env, ReplyOn, Response, SubMsg, WasmMsg,
+    to_binary, wasm_execute, CosmosMsg, Decimal, DepsMut, Env, ReplyOn, Response, SubMsg, WasmMsg,
 };
 
 use pool_network::asset::{AssetInfo, PairType};
+use pool_network::burn_fee::BurnFeeTarget;
+use pool_network::epoch::EpochConfig;
+use pool_network::factory::NativeTokenDecimals;
+use pool_network::lp_token::LpTokenType;
 use pool_network::pair::{
     FeatureToggle, InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
 };
-use pool_network::querier::query_balance;
+use pool_network::querier::{query_balance, query_pair_info_from_pair};
+use pool_network::token::LpTokenInfo;
 
 use crate::error::ContractError;
 use crate::state::{
-    add_allow_native_token, pair_key, Config, TmpPairInfo, CONFIG, PAIRS, TMP_PAIR_INFO,
+    add_allow_native_token, calc_range_start, delete_pair, find_pairs, is_legacy_pair_key,
+    move_pair, pair_fee_tier, pair_key, read_asset_pairs, read_pairs_after_key,
+    read_raw_pairs_after_key, remove_allow_native_token, Config, TmpPairInfo, CONFIG,
+    MIGRATION_CURSOR, PAIRS, PAIR_KEYS_MIGRATION_CURSOR, TMP_PAIR_INFO,
 };
 
 /// Updates the contract's [Config]
@@ -47,7 +55,8 @@ pub fn update_config(
     Ok(Response::new().add_attribute("action", "update_config"))
 }
 
-/// Updates a pair config
+/// Updates a pair config. If the swap fee changes, the pair is moved to the key of its new fee tier.
+#[allow(clippy::too_many_arguments)]
 pub fn update_pair_config(
     deps: DepsMut,
     pair_addr: String,
@@ -55,21 +64,68 @@ pub fn update_pair_config(
     fee_collector_addr: Option<String>,
     pool_fees: Option<PoolFee>,
     feature_toggle: Option<FeatureToggle>,
+    burn_fee_target: Option<BurnFeeTarget>,
+    epoch_config: Option<EpochConfig>,
 ) -> Result<Response, ContractError> {
+    let pair_addr = deps.api.addr_validate(pair_addr.as_str())?;
+
+    if let Some(pool_fees) = &pool_fees {
+        let pair_info = query_pair_info_from_pair(&deps.querier, pair_addr.clone())?;
+        let raw_infos = [
+            pair_info.asset_infos[0].to_raw(deps.api)?,
+            pair_info.asset_infos[1].to_raw(deps.api)?,
+        ];
+        let contract_addr = deps.api.addr_canonicalize(pair_addr.as_str())?;
+
+        let registered_pair = read_asset_pairs(deps.storage, &raw_infos)?
+            .into_iter()
+            .find(|(_, pair)| pair.contract_addr == contract_addr);
+
+        // keep the pair on the key of its fee tier, so it can't drift out of it
+        if let Some((key, pair)) = registered_pair {
+            let new_pair_key = pair_key(&raw_infos, &pair.pair_type, pool_fees.swap_fee.share);
+
+            if new_pair_key != key {
+                if PAIRS.has(deps.storage, &new_pair_key) {
+                    return Err(ContractError::ExistingPair {});
+                }
+
+                move_pair(deps.storage, &key, &new_pair_key, &pair)?;
+            }
+        }
+    }
+
     Ok(Response::new()
         .add_message(wasm_execute(
-            deps.api.addr_validate(pair_addr.as_str())?.to_string(),
+            pair_addr.to_string(),
             &pool_network::pair::ExecuteMsg::UpdateConfig {
                 owner,
                 fee_collector_addr,
                 pool_fees,
                 feature_toggle,
+                burn_fee_target,
+                epoch_config,
             },
             vec![],
         )?)
         .add_attribute("action", "update_pair_config"))
 }
 
+/// Updates the LP token info of a pair
+pub fn update_pair_lp_token_info(
+    deps: DepsMut,
+    pair_addr: String,
+    lp_token_info: LpTokenInfo,
+) -> Result<Response, ContractError> {
+    Ok(Response::new()
+        .add_message(wasm_execute(
+            deps.api.addr_validate(pair_addr.as_str())?.to_string(),
+            &pool_network::pair::ExecuteMsg::UpdateLpTokenInfo { lp_token_info },
+            vec![],
+        )?)
+        .add_attribute("action", "update_pair_lp_token_info"))
+}
+
 /// Creates a Pair
 pub fn create_pair(
     deps: DepsMut,
@@ -77,6 +133,8 @@ pub fn create_pair(
     asset_infos: [AssetInfo; 2],
     pool_fees: PoolFee,
     pair_type: PairType,
+    lp_token_type: Option<LpTokenType>,
+    lp_token_info: Option<LpTokenInfo>,
 ) -> Result<Response, ContractError> {
     let config: Config = CONFIG.load(deps.storage)?;
 
@@ -111,10 +169,17 @@ pub fn create_pair(
 
     let asset_decimals = [asset_1_decimal, asset_2_decimal];
 
-    let pair_key = pair_key(&raw_infos);
-    if let Ok(Some(_)) = PAIRS.may_load(deps.storage, &pair_key) {
+    // the pairs not moved to their fee tier key yet are looked up too
+    let existing_pairs = find_pairs(
+        deps.as_ref(),
+        &raw_infos,
+        Some(&pair_type),
+        Some(pool_fees.swap_fee.share),
+    )?;
+    if !existing_pairs.is_empty() {
         return Err(ContractError::ExistingPair {});
     }
+    let pair_key = pair_key(&raw_infos, &pair_type, pool_fees.swap_fee.share);
 
     TMP_PAIR_INFO.save(
         deps.storage,
@@ -153,6 +218,8 @@ pub fn create_pair(
                     pool_fees,
                     fee_collector_addr: config.fee_collector_addr.to_string(),
                     pair_type,
+                    lp_token_type,
+                    lp_token_info,
                 })?,
             }),
             reply_on: ReplyOn::Success,
@@ -163,20 +230,24 @@ pub fn remove_pair(
     deps: DepsMut,
     _env: Env,
     asset_infos: [AssetInfo; 2],
+    pair_type: Option<PairType>,
+    fee_tier: Option<Decimal>,
 ) -> Result<Response, ContractError> {
     let raw_infos = [
         asset_infos[0].to_raw(deps.api)?,
         asset_infos[1].to_raw(deps.api)?,
     ];
 
-    let pair_key = pair_key(&raw_infos);
-    let pair = PAIRS.may_load(deps.storage, &pair_key)?;
+    let mut pairs = find_pairs(deps.as_ref(), &raw_infos, pair_type.as_ref(), fee_tier)?;
+    if pairs.len() > 1 {
+        return Err(ContractError::AmbiguousPair {});
+    }
 
-    let Some(pair) = pair else {
+    let Some((pair_key, pair)) = pairs.pop() else {
         return Err(ContractError::UnExistingPair {});
     };
 
-    PAIRS.remove(deps.storage, &pair_key);
+    delete_pair(deps.storage, &pair_key, &pair);
 
     Ok(Response::new().add_attributes(vec![
         ("action", "remove_pair"),
@@ -208,6 +279,41 @@ pub fn add_native_token_decimals(
     ]))
 }
 
+/// Adds multiple native/ibc tokens with decimals to the factory's whitelist at once
+pub fn add_native_token_decimals_batch(
+    deps: DepsMut,
+    env: Env,
+    native_tokens: Vec<NativeTokenDecimals>,
+) -> Result<Response, ContractError> {
+    let mut attributes = vec![("action", "add_allow_native_tokens".to_string())];
+    for NativeTokenDecimals { denom, decimals } in native_tokens {
+        let balance = query_balance(&deps.querier, env.contract.address.clone(), denom.clone())?;
+        if balance.is_zero() {
+            return Err(ContractError::InvalidVerificationBalance {});
+        }
+
+        add_allow_native_token(deps.storage, denom.clone(), decimals)?;
+
+        attributes.push(("denom", denom));
+        attributes.push(("decimals", decimals.to_string()));
+    }
+
+    Ok(Response::new().add_attributes(attributes))
+}
+
+/// Removes native/ibc token from the factory's whitelist
+pub fn remove_native_token_decimals(
+    deps: DepsMut,
+    denom: String,
+) -> Result<Response, ContractError> {
+    remove_allow_native_token(deps.storage, denom.clone())?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "remove_allow_native_token"),
+        ("denom", &denom),
+    ]))
+}
+
 pub fn execute_migrate_pair(
     deps: DepsMut,
     contract: String,
@@ -224,3 +330,122 @@ pub fn execute_migrate_pair(
         })),
     )
 }
+
+/// Migrates a batch of pairs to the given code_id, storing where the migration stopped so it can
+/// be resumed
+pub fn execute_migrate_pairs(
+    deps: DepsMut,
+    code_id: Option<u64>,
+    start_after: Option<[AssetInfo; 2]>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    let code_id = code_id.unwrap_or(config.pair_code_id);
+
+    // resume the migration to the same code id if no start_after is provided
+    let start_after = match start_after {
+        Some(asset_infos) => calc_range_start(Some([
+            asset_infos[0].to_raw(deps.api)?,
+            asset_infos[1].to_raw(deps.api)?,
+        ])),
+        None => MIGRATION_CURSOR
+            .may_load(deps.storage)?
+            .filter(|(cursor_code_id, _)| *cursor_code_id == code_id)
+            .map(|(_, last_migrated)| last_migrated),
+    };
+
+    let pairs = read_pairs_after_key(deps.storage, deps.api, start_after, limit)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut attributes = vec![
+        ("action", "migrate_pairs".to_string()),
+        ("code_id", code_id.to_string()),
+    ];
+    for (_, pair) in &pairs {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: pair.contract_addr.clone(),
+            new_code_id: code_id,
+            msg: to_binary(&PairMigrateMsg {})?,
+        }));
+        attributes.push(("pair_contract_addr", pair.contract_addr.clone()));
+    }
+
+    // store where the migration stopped so it can be resumed, or clear it if all pairs are migrated
+    let last_migrated = pairs.last().map(|(key, _)| key.clone());
+    let remaining_pairs = match &last_migrated {
+        Some(last_migrated) => {
+            read_pairs_after_key(deps.storage, deps.api, Some(last_migrated.clone()), Some(1))?
+        }
+        None => vec![],
+    };
+
+    match last_migrated {
+        Some(last_migrated) if !remaining_pairs.is_empty() => {
+            MIGRATION_CURSOR.save(deps.storage, &(code_id, last_migrated))?;
+            attributes.push(("migration_complete", "false".to_string()));
+        }
+        _ => {
+            MIGRATION_CURSOR.remove(deps.storage);
+            attributes.push(("migration_complete", "true".to_string()));
+        }
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attributes(attributes))
+}
+
+/// Moves a batch of pairs to the key of their fee tier, as part of the v1.5.0 migration, storing
+/// where the migration stopped so it can be resumed
+pub fn execute_migrate_pair_keys(
+    deps: DepsMut,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let mut attributes = vec![("action", "migrate_pair_keys".to_string())];
+
+    let Some(start_after) = PAIR_KEYS_MIGRATION_CURSOR.may_load(deps.storage)? else {
+        attributes.push(("migration_complete", "true".to_string()));
+        return Ok(Response::new().add_attributes(attributes));
+    };
+
+    let pairs = read_raw_pairs_after_key(deps.storage, start_after, limit)?;
+
+    let mut last_visited = None;
+    for (key, pair) in pairs {
+        if !is_legacy_pair_key(&key, &pair) {
+            last_visited = Some(key);
+            continue;
+        }
+
+        let fee_tier = pair_fee_tier(deps.as_ref(), &key, &pair)?;
+        let new_pair_key = pair_key(&pair.asset_infos, &pair.pair_type, fee_tier);
+        move_pair(deps.storage, &key, &new_pair_key, &pair)?;
+
+        attributes.push((
+            "pair_contract_addr",
+            deps.api.addr_humanize(&pair.contract_addr)?.into_string(),
+        ));
+        last_visited = Some(key);
+    }
+
+    // store where the migration stopped so it can be resumed, or clear it if all pairs are visited
+    let remaining_pairs = match &last_visited {
+        Some(last_visited) => {
+            read_raw_pairs_after_key(deps.storage, last_visited.clone(), Some(1))?
+        }
+        None => vec![],
+    };
+
+    match last_visited {
+        Some(last_visited) if !remaining_pairs.is_empty() => {
+            PAIR_KEYS_MIGRATION_CURSOR.save(deps.storage, &last_visited)?;
+            attributes.push(("migration_complete", "false".to_string()));
+        }
+        _ => {
+            PAIR_KEYS_MIGRATION_CURSOR.remove(deps.storage);
+            attributes.push(("migration_complete", "true".to_string()));
+        }
+    }
+
+    Ok(Response::new().add_attributes(attributes))
+}
@@ -14,7 +14,7 @@ use semver::Version;
 use crate::error::ContractError;
 use crate::error::ContractError::MigrateInvalidVersion;
 use crate::response::MsgInstantiateContractResponse;
-use crate::state::{Config, CONFIG, PAIRS, TMP_PAIR_INFO};
+use crate::state::{save_pair, Config, CONFIG, TMP_PAIR_INFO};
 use crate::{commands, queries};
 
 // version info for migration info
@@ -66,20 +66,48 @@ pub fn execute(
             asset_infos,
             pool_fees,
             pair_type,
-        } => commands::create_pair(deps, env, asset_infos, pool_fees, pair_type),
-        ExecuteMsg::RemovePair { asset_infos } => commands::remove_pair(deps, env, asset_infos),
+            lp_token_type,
+            lp_token_info,
+        } => commands::create_pair(
+            deps,
+            env,
+            asset_infos,
+            pool_fees,
+            pair_type,
+            lp_token_type,
+            lp_token_info,
+        ),
+        ExecuteMsg::RemovePair {
+            asset_infos,
+            pair_type,
+            fee_tier,
+        } => commands::remove_pair(deps, env, asset_infos, pair_type, fee_tier),
         ExecuteMsg::AddNativeTokenDecimals { denom, decimals } => {
             commands::add_native_token_decimals(deps, env, denom, decimals)
         }
+        ExecuteMsg::AddNativeTokenDecimalsBatch { native_tokens } => {
+            commands::add_native_token_decimals_batch(deps, env, native_tokens)
+        }
+        ExecuteMsg::RemoveNativeTokenDecimals { denom } => {
+            commands::remove_native_token_decimals(deps, denom)
+        }
         ExecuteMsg::MigratePair { contract, code_id } => {
             commands::execute_migrate_pair(deps, contract, code_id)
         }
+        ExecuteMsg::MigratePairs {
+            code_id,
+            start_after,
+            limit,
+        } => commands::execute_migrate_pairs(deps, code_id, start_after, limit),
+        ExecuteMsg::MigratePairKeys { limit } => commands::execute_migrate_pair_keys(deps, limit),
         ExecuteMsg::UpdatePairConfig {
             pair_addr,
             owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            burn_fee_target,
+            epoch_config,
         } => commands::update_pair_config(
             deps,
             pair_addr,
@@ -87,7 +115,13 @@ pub fn execute(
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            burn_fee_target,
+            epoch_config,
         ),
+        ExecuteMsg::UpdatePairLpTokenInfo {
+            pair_addr,
+            lp_token_info,
+        } => commands::update_pair_lp_token_info(deps, pair_addr, lp_token_info),
     }
 }
 
@@ -104,11 +138,11 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     let pair_contract = deps.api.addr_validate(&res.address)?;
     let pair_info = query_pair_info_from_pair(&deps.querier, pair_contract.clone())?;
 
-    PAIRS.save(
+    save_pair(
         deps.storage,
         &tmp_pair_info.pair_key,
         &PairInfoRaw {
-            liquidity_token: deps.api.addr_canonicalize(&pair_info.liquidity_token)?,
+            liquidity_token: pair_info.liquidity_token.to_raw(deps.api)?,
             contract_addr: deps.api.addr_canonicalize(pair_contract.as_str())?,
             asset_infos: tmp_pair_info.asset_infos,
             asset_decimals: tmp_pair_info.asset_decimals,
@@ -118,7 +152,10 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 
     Ok(Response::new().add_attributes(vec![
         ("pair_contract_addr", pair_contract.as_str()),
-        ("liquidity_token_addr", &pair_info.liquidity_token),
+        (
+            "liquidity_token_addr",
+            &pair_info.liquidity_token.to_string(),
+        ),
     ]))
 }
 
@@ -126,13 +163,44 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&queries::query_config(deps)?),
-        QueryMsg::Pair { asset_infos } => to_binary(&queries::query_pair(deps, asset_infos)?),
+        QueryMsg::Pair {
+            asset_infos,
+            pair_type,
+            fee_tier,
+        } => to_binary(&queries::query_pair(
+            deps,
+            asset_infos,
+            pair_type,
+            fee_tier,
+        )?),
+        QueryMsg::AssetPairs { asset_infos } => {
+            to_binary(&queries::query_asset_pairs(deps, asset_infos)?)
+        }
+        QueryMsg::PairsByAsset {
+            asset_info,
+            start_after,
+            limit,
+        } => to_binary(&queries::query_pairs_by_asset(
+            deps,
+            asset_info,
+            start_after,
+            limit,
+        )?),
         QueryMsg::Pairs { start_after, limit } => {
             to_binary(&queries::query_pairs(deps, start_after, limit)?)
         }
+        QueryMsg::PairsDetailed { start_after, limit } => {
+            to_binary(&queries::query_pairs_detailed(deps, start_after, limit)?)
+        }
         QueryMsg::NativeTokenDecimals { denom } => {
             to_binary(&queries::query_native_token_decimal(deps, denom)?)
         }
+        QueryMsg::MigrationStatus { start_after, limit } => {
+            to_binary(&queries::query_migration_status(deps, start_after, limit)?)
+        }
+        QueryMsg::ChildrenVersions { start_after, limit } => {
+            to_binary(&queries::query_children_versions(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -154,9 +222,18 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
     if storage_version <= Version::parse("1.0.8")? {
         migrations::migrate_to_v110(deps.branch())?;
     }
-    if storage_version <= Version::parse("1.2.0")? {
+    if storage_version < Version::parse("1.2.0")? {
         migrations::migrate_to_v120(deps.branch())?;
     }
+    if storage_version < Version::parse("1.5.0")? {
+        migrations::migrate_to_v150(deps.branch())?;
+    }
+    if storage_version < Version::parse("1.6.0")? {
+        migrations::migrate_to_v160(deps.branch())?;
+    }
+    if storage_version < Version::parse("1.8.0")? {
+        migrations::migrate_to_v180(deps.branch())?;
+    }
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
@@ -0,0 +1,122 @@
+use cosmwasm_std::{Binary, QuerierWrapper, QueryRequest, StdError, StdResult};
+use serde::Deserialize;
+
+/// The bank stargate query returning the metadata of a denom
+const DENOM_METADATA_PATH: &str = "/cosmos.bank.v1beta1.Query/DenomMetadata";
+
+#[derive(Deserialize)]
+struct QueryDenomMetadataResponse {
+    metadata: Metadata,
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    #[serde(default)]
+    denom_units: Vec<DenomUnit>,
+    #[serde(default)]
+    display: String,
+}
+
+#[derive(Deserialize)]
+struct DenomUnit {
+    denom: String,
+    #[serde(default)]
+    exponent: u32,
+}
+
+/// Queries the decimals of the given denom from its bank metadata, i.e. the exponent of its
+/// display unit. Fails if the chain doesn't expose the query or the denom has no metadata.
+pub fn query_denom_metadata_decimals(querier: &QuerierWrapper, denom: &str) -> StdResult<u8> {
+    let res: QueryDenomMetadataResponse = querier.query(&QueryRequest::Stargate {
+        path: DENOM_METADATA_PATH.to_string(),
+        data: encode_denom_metadata_request(denom),
+    })?;
+
+    get_display_decimals(&res.metadata)
+}
+
+/// Encodes the protobuf QueryDenomMetadataRequest, which only has the denom as field 1
+fn encode_denom_metadata_request(denom: &str) -> Binary {
+    let mut data = vec![0x0a];
+
+    // length of the denom as a varint
+    let mut len = denom.len();
+    while len >= 0x80 {
+        data.push((len as u8 & 0x7f) | 0x80);
+        len >>= 7;
+    }
+    data.push(len as u8);
+
+    data.extend_from_slice(denom.as_bytes());
+    Binary(data)
+}
+
+/// Gets the exponent of the display unit. Metadata without a display unit, or whose display unit is
+/// the base unit, i.e. with a zero exponent, doesn't tell the decimals of the denom.
+fn get_display_decimals(metadata: &Metadata) -> StdResult<u8> {
+    let exponent = metadata
+        .denom_units
+        .iter()
+        .find(|unit| unit.denom == metadata.display && unit.exponent > 0)
+        .map(|unit| unit.exponent)
+        .ok_or_else(|| StdError::generic_err("The denom metadata has no display unit"))?;
+
+    u8::try_from(exponent)
+        .map_err(|_| StdError::generic_err(format!("Invalid denom exponent {}", exponent)))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_slice;
+
+    use super::*;
+
+    #[test]
+    fn encodes_denom_metadata_request() {
+        assert_eq!(
+            encode_denom_metadata_request("uwhale"),
+            Binary(vec![0x0a, 6, b'u', b'w', b'h', b'a', b'l', b'e'])
+        );
+
+        let denom = format!("factory/{}/uwhale", "a".repeat(150));
+        let data = encode_denom_metadata_request(&denom);
+        assert_eq!(data.0[..3], [0x0a, 0xa5, 0x01]);
+        assert_eq!(data.0[3..], *denom.as_bytes());
+    }
+
+    #[test]
+    fn gets_display_decimals() {
+        let res: QueryDenomMetadataResponse = from_slice(
+            br#"{"metadata":{"description":"","denom_units":[
+                {"denom":"uwhale","exponent":0,"aliases":[]},
+                {"denom":"mwhale","exponent":3,"aliases":[]},
+                {"denom":"whale","exponent":6,"aliases":[]}
+            ],"base":"uwhale","display":"mwhale","name":"","symbol":""}}"#,
+        )
+        .unwrap();
+        assert_eq!(get_display_decimals(&res.metadata).unwrap(), 3u8);
+
+        // no display unit
+        let res: QueryDenomMetadataResponse = from_slice(
+            br#"{"metadata":{"denom_units":[
+                {"denom":"uwhale","exponent":0},
+                {"denom":"whale","exponent":6}
+            ],"base":"uwhale"}}"#,
+        )
+        .unwrap();
+        assert!(get_display_decimals(&res.metadata).is_err());
+
+        // the display unit is the base unit
+        let res: QueryDenomMetadataResponse = from_slice(
+            br#"{"metadata":{"denom_units":[
+                {"denom":"uwhale","exponent":0}
+            ],"base":"uwhale","display":"uwhale"}}"#,
+        )
+        .unwrap();
+        assert!(get_display_decimals(&res.metadata).is_err());
+
+        let res: QueryDenomMetadataResponse =
+            from_slice(br#"{"metadata":{"denom_units":[],"base":"uwhale"}}"#).unwrap();
+        assert!(get_display_decimals(&res.metadata).is_err());
+    }
+}
@@ -28,6 +28,9 @@ pub enum ContractError {
     #[error("Pair doesn't exist")]
     UnExistingPair {},
 
+    #[error("Multiple pairs exist for the given assets, the pair type and fee tier are required")]
+    AmbiguousPair {},
+
     #[error("A balance greater than zero is required by the factory to verify the asset")]
     InvalidVerificationBalance {},
 
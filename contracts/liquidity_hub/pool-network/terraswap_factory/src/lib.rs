@@ -1,5 +1,6 @@
 mod commands;
 pub mod contract;
+mod denom_metadata;
 mod error;
 mod queries;
 pub mod state;
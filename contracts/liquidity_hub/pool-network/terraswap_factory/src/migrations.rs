@@ -1,10 +1,12 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{CanonicalAddr, DepsMut, Order, StdError, Uint128};
+use cosmwasm_std::{CanonicalAddr, DepsMut, Empty, Order, StdError, Uint128};
 use cw_storage_plus::{Item, Map};
-use pool_network::asset::{AssetInfo, AssetInfoRaw, PairType};
+use pool_network::asset::{AssetInfo, AssetInfoRaw, PairInfoRaw, PairType};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::state::{ASSET_PAIRS, PAIRS, PAIR_KEYS_MIGRATION_CURSOR};
+
 /// Migrate state of the factory from PascalCase to snake_case for the following items:
 /// [`PairInfoRaw`], [`PairInfo`], [`AssetInfoRaw`], [`AssetRaw`], [`TmpPairInfo`]
 /// as identified by commit c8d8462c6933b93245acdc8abbe303287fdc1951 which changed the structs to use
@@ -173,3 +175,80 @@ pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
 
     Ok(())
 }
+
+/// The [PairInfoRaw] stored by the factory before the LP token became an [AssetInfoRaw] in v1.8.0
+#[cw_serde]
+struct PairInfoRawV170 {
+    pub asset_infos: [AssetInfoRaw; 2],
+    pub contract_addr: CanonicalAddr,
+    pub liquidity_token: CanonicalAddr,
+    pub asset_decimals: [u8; 2],
+    pub pair_type: PairType,
+}
+
+const PAIRS_V170: Map<&[u8], PairInfoRawV170> = Map::new("pair_info");
+
+/// Migrate state of the factory for the pool fee tiers. The pairs are moved from the key made of
+/// their assets to the key made of their assets, pair type and fee tier, i.e. their current swap
+/// fee, in batches with [ExecuteMsg::MigratePairKeys], as it requires querying each pair. This only
+/// sets the cursor of that batch migration.
+///
+/// [ExecuteMsg::MigratePairKeys]: pool_network::factory::ExecuteMsg::MigratePairKeys
+pub fn migrate_to_v150(deps: DepsMut) -> Result<(), StdError> {
+    PAIR_KEYS_MIGRATION_CURSOR.save(deps.storage, &vec![])?;
+
+    Ok(())
+}
+
+/// Migrate state of the factory for the asset index, indexing the existing pairs by their assets
+pub fn migrate_to_v160(deps: DepsMut) -> Result<(), StdError> {
+    let all_values = PAIRS_V170
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    all_values
+        .into_iter()
+        .try_for_each(|(key, pair)| -> Result<(), StdError> {
+            for asset_info in &pair.asset_infos {
+                ASSET_PAIRS.save(
+                    deps.storage,
+                    (asset_info.as_bytes(), key.as_slice()),
+                    &Empty {},
+                )?;
+            }
+
+            Ok(())
+        })?;
+
+    Ok(())
+}
+
+/// Migrate state of the factory for the token factory LP tokens, storing the liquidity token of
+/// the existing pairs as a cw20 [AssetInfoRaw]
+pub fn migrate_to_v180(deps: DepsMut) -> Result<(), StdError> {
+    let all_values = PAIRS_V170
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    all_values
+        .into_iter()
+        .try_for_each(|(key, pair)| -> Result<(), StdError> {
+            PAIRS.save(
+                deps.storage,
+                &key,
+                &PairInfoRaw {
+                    asset_infos: pair.asset_infos,
+                    contract_addr: pair.contract_addr,
+                    liquidity_token: AssetInfoRaw::Token {
+                        contract_addr: pair.liquidity_token,
+                    },
+                    asset_decimals: pair.asset_decimals,
+                    pair_type: pair.pair_type,
+                },
+            )?;
+
+            Ok(())
+        })?;
+
+    Ok(())
+}
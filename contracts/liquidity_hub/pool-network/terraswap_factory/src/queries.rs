@@ -1,7 +1,22 @@
-use crate::state::{pair_key, read_pairs, Config, ALLOW_NATIVE_TOKENS, CONFIG, PAIRS};
-use cosmwasm_std::{Deps, StdResult};
-use pool_network::asset::{AssetInfo, PairInfo, PairInfoRaw};
-use pool_network::factory::{ConfigResponse, NativeTokenDecimalsResponse, PairsResponse};
+use std::collections::BTreeMap;
+
+use crate::denom_metadata::query_denom_metadata_decimals;
+use crate::error::ContractError;
+use crate::state::{
+    find_pairs, pair_fee_tier, read_asset_pairs, read_pairs, read_pairs_by_asset, Config,
+    ALLOW_NATIVE_TOKENS, CONFIG, MIGRATION_CURSOR, PAIRS,
+};
+use cosmwasm_std::{Decimal, Deps, StdError, StdResult};
+use pool_network::asset::{AssetInfo, PairInfo, PairInfoRaw, PairType};
+use pool_network::factory::{
+    AssetPair, AssetPairsResponse, ChildVersion, ChildrenVersionsResponse, CodeIdPairs,
+    ConfigResponse, MigrationStatusResponse, NativeTokenDecimalsResponse, PairDetailed,
+    PairDetails, PairsDetailedResponse, PairsResponse,
+};
+use pool_network::pair::{
+    ConfigResponse as PairConfigResponse, PoolResponse, ProtocolFeesResponse,
+    QueryMsg as PairQueryMsg,
+};
 
 /// Queries [Config]
 pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
@@ -17,15 +32,49 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
 }
 
 /// Queries info about a given Pair
-pub fn query_pair(deps: Deps, asset_infos: [AssetInfo; 2]) -> StdResult<PairInfo> {
-    let pair_key = pair_key(&[
+pub fn query_pair(
+    deps: Deps,
+    asset_infos: [AssetInfo; 2],
+    pair_type: Option<PairType>,
+    fee_tier: Option<Decimal>,
+) -> StdResult<PairInfo> {
+    let raw_infos = [
         asset_infos[0].to_raw(deps.api)?,
         asset_infos[1].to_raw(deps.api)?,
-    ]);
-    let pair_info: PairInfoRaw = PAIRS.load(deps.storage, &pair_key)?;
+    ];
+    let mut pairs = find_pairs(deps, &raw_infos, pair_type.as_ref(), fee_tier)?;
+    if pairs.len() > 1 {
+        return Err(StdError::generic_err(
+            ContractError::AmbiguousPair {}.to_string(),
+        ));
+    }
+
+    let (_, pair_info) = pairs
+        .pop()
+        .ok_or_else(|| StdError::not_found("PairInfoRaw"))?;
     pair_info.to_normal(deps.api)
 }
 
+/// Queries all the pairs for the given assets
+pub fn query_asset_pairs(deps: Deps, asset_infos: [AssetInfo; 2]) -> StdResult<AssetPairsResponse> {
+    let raw_infos = [
+        asset_infos[0].to_raw(deps.api)?,
+        asset_infos[1].to_raw(deps.api)?,
+    ];
+
+    let pairs = read_asset_pairs(deps.storage, &raw_infos)?
+        .into_iter()
+        .map(|(key, pair)| {
+            Ok(AssetPair {
+                pair_info: pair.to_normal(deps.api)?,
+                fee_tier: pair_fee_tier(deps, &key, &pair)?,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(AssetPairsResponse { pairs })
+}
+
 /// Queries all the pairs created by the factory
 pub fn query_pairs(
     deps: Deps,
@@ -47,12 +96,164 @@ pub fn query_pairs(
     Ok(resp)
 }
 
+/// Queries the pairs created by the factory along with their pool state, config and accrued
+/// protocol fees. A pair failing to be queried doesn't fail the whole page, it is returned with
+/// the error instead.
+pub fn query_pairs_detailed(
+    deps: Deps,
+    start_after: Option<[AssetInfo; 2]>,
+    limit: Option<u32>,
+) -> StdResult<PairsDetailedResponse> {
+    let pairs = query_pairs(deps, start_after, limit)?
+        .pairs
+        .into_iter()
+        .map(
+            |pair_info| match query_pair_details(deps, &pair_info.contract_addr) {
+                Ok(details) => PairDetailed {
+                    pair_info,
+                    details: Some(details),
+                    error: None,
+                },
+                Err(err) => PairDetailed {
+                    pair_info,
+                    details: None,
+                    error: Some(err.to_string()),
+                },
+            },
+        )
+        .collect();
+
+    Ok(PairsDetailedResponse { pairs })
+}
+
+/// Queries the pool state, config and accrued protocol fees of a pair
+fn query_pair_details(deps: Deps, pair_addr: &str) -> StdResult<PairDetails> {
+    let pool: PoolResponse = deps
+        .querier
+        .query_wasm_smart(pair_addr, &PairQueryMsg::Pool {})?;
+    let config: PairConfigResponse = deps
+        .querier
+        .query_wasm_smart(pair_addr, &PairQueryMsg::Config {})?;
+    let protocol_fees: ProtocolFeesResponse = deps.querier.query_wasm_smart(
+        pair_addr,
+        &PairQueryMsg::ProtocolFees {
+            asset_id: None,
+            all_time: None,
+        },
+    )?;
+
+    Ok(PairDetails {
+        assets: pool.assets,
+        total_share: pool.total_share,
+        pool_fees: config.pool_fees,
+        feature_toggle: config.feature_toggle,
+        protocol_fees: protocol_fees.fees,
+    })
+}
+
+/// Queries the pairs containing the given asset
+pub fn query_pairs_by_asset(
+    deps: Deps,
+    asset_info: AssetInfo,
+    start_after: Option<[AssetInfo; 2]>,
+    limit: Option<u32>,
+) -> StdResult<PairsResponse> {
+    let start_after = if let Some(start_after) = start_after {
+        Some([
+            start_after[0].to_raw(deps.api)?,
+            start_after[1].to_raw(deps.api)?,
+        ])
+    } else {
+        None
+    };
+
+    let pairs = read_pairs_by_asset(
+        deps.storage,
+        deps.api,
+        &asset_info.to_raw(deps.api)?,
+        start_after,
+        limit,
+    )?;
+
+    Ok(PairsResponse { pairs })
+}
+
 /// Query the native token decimals
 pub fn query_native_token_decimal(
     deps: Deps,
     denom: String,
 ) -> StdResult<NativeTokenDecimalsResponse> {
-    let decimals = ALLOW_NATIVE_TOKENS.load(deps.storage, denom.as_bytes())?;
+    // the decimals registered by the owner take precedence, the bank metadata of the denom is only
+    // used for the denoms that are not registered
+    let decimals = match ALLOW_NATIVE_TOKENS.may_load(deps.storage, denom.as_bytes())? {
+        Some(decimals) => decimals,
+        None => query_denom_metadata_decimals(&deps.querier, &denom)?,
+    };
 
     Ok(NativeTokenDecimalsResponse { decimals })
 }
+
+/// Queries the in progress batch migration and the code ids a page of pairs are on
+pub fn query_migration_status(
+    deps: Deps,
+    start_after: Option<[AssetInfo; 2]>,
+    limit: Option<u32>,
+) -> StdResult<MigrationStatusResponse> {
+    let cursor = MIGRATION_CURSOR.may_load(deps.storage)?;
+
+    let last_migrated = match &cursor {
+        Some((_, last_migrated)) => {
+            let pair_info: PairInfoRaw = PAIRS.load(deps.storage, last_migrated)?;
+            Some([
+                pair_info.asset_infos[0].to_normal(deps.api)?,
+                pair_info.asset_infos[1].to_normal(deps.api)?,
+            ])
+        }
+        None => None,
+    };
+
+    let mut code_ids: BTreeMap<u64, Vec<String>> = BTreeMap::new();
+    for pair in query_pairs(deps, start_after, limit)?.pairs {
+        let contract_info = deps.querier.query_wasm_contract_info(&pair.contract_addr)?;
+        code_ids
+            .entry(contract_info.code_id)
+            .or_default()
+            .push(pair.contract_addr);
+    }
+
+    Ok(MigrationStatusResponse {
+        code_id: cursor.map(|(code_id, _)| code_id),
+        last_migrated,
+        code_ids: code_ids
+            .into_iter()
+            .map(|(code_id, pairs)| CodeIdPairs { code_id, pairs })
+            .collect(),
+    })
+}
+
+/// Queries the code id and the cw2 contract version of the pairs created by the factory
+pub fn query_children_versions(
+    deps: Deps,
+    start_after: Option<[AssetInfo; 2]>,
+    limit: Option<u32>,
+) -> StdResult<ChildrenVersionsResponse> {
+    let children = query_pairs(deps, start_after, limit)?
+        .pairs
+        .into_iter()
+        .map(|pair| {
+            let pair_addr = deps.api.addr_validate(&pair.contract_addr)?;
+            let contract_info = deps.querier.query_wasm_contract_info(&pair_addr)?;
+            let contract_version = cw2::CONTRACT.query(&deps.querier, pair_addr)?;
+
+            Ok(ChildVersion {
+                contract_addr: pair.contract_addr,
+                asset_infos: pair.asset_infos,
+                code_id: contract_info.code_id,
+                contract: contract_version.contract,
+                version: contract_version.version,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ChildrenVersionsResponse { children })
+}
@@ -1,7 +1,11 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Api, CanonicalAddr, Order, StdResult, Storage};
+use cosmwasm_std::{
+    Addr, Api, CanonicalAddr, Decimal, Deps, Empty, Order, StdError, StdResult, Storage, Uint128,
+};
 use cw_storage_plus::{Bound, Item, Map};
 use pool_network::asset::{AssetInfoRaw, PairInfo, PairInfoRaw, PairType};
+use pool_network::pair::{PoolFee, QueryMsg as PairQueryMsg};
+use serde::Deserialize;
 
 #[cw_serde]
 pub struct Config {
@@ -23,14 +27,188 @@ pub struct TmpPairInfo {
 
 pub const TMP_PAIR_INFO: Item<TmpPairInfo> = Item::new("tmp_pair_info");
 pub const PAIRS: Map<&[u8], PairInfoRaw> = Map::new("pair_info");
+/// Index of the pairs containing each asset. key: (asset, pair key)
+pub const ASSET_PAIRS: Map<(&[u8], &[u8]), Empty> = Map::new("asset_pairs");
 
-pub fn pair_key(asset_infos: &[AssetInfoRaw; 2]) -> Vec<u8> {
+/// The code id and the key of the last pair migrated by the in progress batch migration, used to
+/// resume it
+pub const MIGRATION_CURSOR: Item<(u64, Vec<u8>)> = Item::new("migration_cursor");
+
+/// The key of the last pair visited by the in progress v1.5.0 migration, moving the pairs to their
+/// fee tier key in batches. Set by the contract migration, and removed once all pairs are moved.
+pub const PAIR_KEYS_MIGRATION_CURSOR: Item<Vec<u8>> = Item::new("pair_keys_migration_cursor");
+
+/// Saves the pair under the given key, indexing it by its assets
+pub fn save_pair(storage: &mut dyn Storage, pair_key: &[u8], pair: &PairInfoRaw) -> StdResult<()> {
+    PAIRS.save(storage, pair_key, pair)?;
+    for asset_info in &pair.asset_infos {
+        ASSET_PAIRS.save(storage, (asset_info.as_bytes(), pair_key), &Empty {})?;
+    }
+
+    Ok(())
+}
+
+/// Removes the pair stored under the given key, along with its asset index entries
+pub fn delete_pair(storage: &mut dyn Storage, pair_key: &[u8], pair: &PairInfoRaw) {
+    PAIRS.remove(storage, pair_key);
+    for asset_info in &pair.asset_infos {
+        ASSET_PAIRS.remove(storage, (asset_info.as_bytes(), pair_key));
+    }
+}
+
+/// Moves the pair stored under the given key to a new key, keeping the batch migration cursor on it
+pub fn move_pair(
+    storage: &mut dyn Storage,
+    pair_key: &[u8],
+    new_pair_key: &[u8],
+    pair: &PairInfoRaw,
+) -> StdResult<()> {
+    delete_pair(storage, pair_key, pair);
+    save_pair(storage, new_pair_key, pair)?;
+
+    if let Some((code_id, last_migrated)) = MIGRATION_CURSOR.may_load(storage)? {
+        if last_migrated == pair_key {
+            MIGRATION_CURSOR.save(storage, &(code_id, new_pair_key.to_vec()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Gets the key of the pair with the given assets, pair type and fee tier, i.e. the swap fee of the
+/// pair at creation. The pairs of the same assets share the [asset_pair_key] prefix.
+pub fn pair_key(
+    asset_infos: &[AssetInfoRaw; 2],
+    pair_type: &PairType,
+    fee_tier: Decimal,
+) -> Vec<u8> {
+    [
+        asset_pair_key(asset_infos),
+        pair_tier_key(pair_type, fee_tier),
+    ]
+    .concat()
+}
+
+/// Gets the key prefix of the pairs with the given assets
+pub fn asset_pair_key(asset_infos: &[AssetInfoRaw; 2]) -> Vec<u8> {
     let mut asset_infos = asset_infos.to_vec();
     asset_infos.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
 
     [asset_infos[0].as_bytes(), asset_infos[1].as_bytes()].concat()
 }
 
+/// Gets the key suffix distinguishing the pairs of the same assets. It starts with a 0 byte so the
+/// pairs of the same assets are stored before the range start computed by [calc_range_start], and
+/// ends with the fee tier atomics so it can be read back with [fee_tier_from_key].
+fn pair_tier_key(pair_type: &PairType, fee_tier: Decimal) -> Vec<u8> {
+    let pair_type = match pair_type {
+        PairType::ConstantProduct => vec![0u8],
+        PairType::StableSwap { amp } => [&[1u8][..], &amp.to_be_bytes()[..]].concat(),
+    };
+
+    [
+        &[0u8][..],
+        pair_type.as_slice(),
+        &fee_tier.atomics().u128().to_be_bytes()[..],
+    ]
+    .concat()
+}
+
+/// Reads the fee tier from the given pair key
+pub fn fee_tier_from_key(key: &[u8]) -> StdResult<Decimal> {
+    let atomics: [u8; 16] = key
+        .len()
+        .checked_sub(16)
+        .and_then(|start| key[start..].try_into().ok())
+        .ok_or_else(|| StdError::generic_err("Invalid pair key"))?;
+
+    Decimal::from_atomics(Uint128::new(u128::from_be_bytes(atomics)), 18)
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+/// Checks if the pair is still stored under the key of its assets, i.e. it hasn't been moved to its
+/// fee tier key by the v1.5.0 migration yet
+pub fn is_legacy_pair_key(pair_key: &[u8], pair: &PairInfoRaw) -> bool {
+    pair_key == asset_pair_key(&pair.asset_infos)
+}
+
+/// Gets the fee tier of the pair stored under the given key. The pairs not moved to their fee tier
+/// key yet are on the fee tier of their current swap fee, as the v1.5.0 migration does.
+pub fn pair_fee_tier(deps: Deps, pair_key: &[u8], pair: &PairInfoRaw) -> StdResult<Decimal> {
+    if !is_legacy_pair_key(pair_key, pair) {
+        return fee_tier_from_key(pair_key);
+    }
+
+    // the pool fees of the pair config, which has gained fields since. Unknown fields are ignored
+    #[derive(Deserialize)]
+    struct PairConfigResponse {
+        pub pool_fees: PoolFee,
+    }
+
+    let config: PairConfigResponse = deps.querier.query_wasm_smart(
+        deps.api.addr_humanize(&pair.contract_addr)?,
+        &PairQueryMsg::Config {},
+    )?;
+
+    Ok(config.pool_fees.swap_fee.share)
+}
+
+/// Reads the pairs of the given assets, along with their keys
+pub fn read_asset_pairs(
+    storage: &dyn Storage,
+    asset_infos: &[AssetInfoRaw; 2],
+) -> StdResult<Vec<(Vec<u8>, PairInfoRaw)>> {
+    let prefix = asset_pair_key(asset_infos);
+    let start = [prefix.as_slice(), &[0u8][..]].concat();
+    let end = [prefix.as_slice(), &[1u8][..]].concat();
+
+    // the pair not moved to its fee tier key yet is stored under the key of its assets
+    let legacy_pair = PAIRS
+        .may_load(storage, &prefix)?
+        .filter(|pair| is_legacy_pair_key(&prefix, pair))
+        .map(|pair| Ok((prefix.clone(), pair)));
+
+    let pairs = PAIRS
+        .range(
+            storage,
+            Some(Bound::InclusiveRaw(start)),
+            Some(Bound::ExclusiveRaw(end)),
+            Order::Ascending,
+        )
+        // the concatenated asset bytes could be shared by different assets
+        .filter(|item| {
+            item.as_ref().map_or(true, |(_, pair)| {
+                asset_pair_key(&pair.asset_infos) == prefix
+            })
+        });
+
+    legacy_pair.into_iter().chain(pairs).collect()
+}
+
+/// Finds the pairs of the given assets matching the given pair type and fee tier
+pub fn find_pairs(
+    deps: Deps,
+    asset_infos: &[AssetInfoRaw; 2],
+    pair_type: Option<&PairType>,
+    fee_tier: Option<Decimal>,
+) -> StdResult<Vec<(Vec<u8>, PairInfoRaw)>> {
+    let mut pairs = vec![];
+    for (key, pair) in read_asset_pairs(deps.storage, asset_infos)? {
+        if pair_type.is_some_and(|pair_type| *pair_type != pair.pair_type) {
+            continue;
+        }
+        if let Some(fee_tier) = fee_tier {
+            if pair_fee_tier(deps, &key, &pair)? != fee_tier {
+                continue;
+            }
+        }
+
+        pairs.push((key, pair));
+    }
+
+    Ok(pairs)
+}
+
 // settings for pagination
 const MAX_LIMIT: u32 = 30;
 const DEFAULT_LIMIT: u32 = 10;
@@ -43,26 +221,104 @@ pub fn read_pairs(
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
     let start = calc_range_start(start_after).map(Bound::ExclusiveRaw);
 
+    let pairs = PAIRS
+        .range(storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, pair)| pair));
+
+    take_pairs(pairs, limit)?
+        .into_iter()
+        .map(|pair| pair.to_normal(api))
+        .collect::<StdResult<Vec<PairInfo>>>()
+}
+
+/// Reads the pairs containing the given asset
+pub fn read_pairs_by_asset(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    asset_info: &AssetInfoRaw,
+    start_after: Option<[AssetInfoRaw; 2]>,
+    limit: Option<u32>,
+) -> StdResult<Vec<PairInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = calc_range_start(start_after).map(Bound::ExclusiveRaw);
+
+    let pairs = ASSET_PAIRS
+        .prefix(asset_info.as_bytes())
+        .keys(storage, start, None, Order::Ascending)
+        .map(|item| PAIRS.load(storage, &item?));
+
+    take_pairs(pairs, limit)?
+        .into_iter()
+        .map(|pair| pair.to_normal(api))
+        .collect::<StdResult<Vec<PairInfo>>>()
+}
+
+/// Takes `limit` pairs, plus the remaining pairs of the same assets as the last one taken. Pages
+/// start after all the pairs of an asset pair, so they can't be split across pages.
+fn take_pairs(
+    pairs: impl Iterator<Item = StdResult<PairInfoRaw>>,
+    limit: usize,
+) -> StdResult<Vec<PairInfoRaw>> {
+    let mut taken: Vec<PairInfoRaw> = vec![];
+    for pair in pairs {
+        let pair = pair?;
+
+        if taken.len() >= limit
+            && taken.last().map(|last| asset_pair_key(&last.asset_infos))
+                != Some(asset_pair_key(&pair.asset_infos))
+        {
+            break;
+        }
+
+        taken.push(pair);
+    }
+
+    Ok(taken)
+}
+
+/// Reads the pairs stored after the given pair key, along with their keys
+pub fn read_pairs_after_key(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    start_after: Option<Vec<u8>>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Vec<u8>, PairInfo)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::ExclusiveRaw);
+
     PAIRS
         .range(storage, start, None, Order::Ascending)
         .take(limit)
         .map(|item| {
-            let (_, v) = item?;
-            v.to_normal(api)
+            let (k, v) = item?;
+            Ok((k, v.to_normal(api)?))
         })
-        .collect::<StdResult<Vec<PairInfo>>>()
+        .collect()
 }
 
-// this will set the first key after the provided key, by appending a 1 byte
-fn calc_range_start(start_after: Option<[AssetInfoRaw; 2]>) -> Option<Vec<u8>> {
-    start_after.map(|asset_infos| {
-        let mut asset_infos = asset_infos.to_vec();
-        asset_infos.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+/// Reads the pairs stored after the given pair key, along with their keys, without converting them
+pub fn read_raw_pairs_after_key(
+    storage: &dyn Storage,
+    start_after: Vec<u8>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Vec<u8>, PairInfoRaw)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    PAIRS
+        .range(
+            storage,
+            Some(Bound::ExclusiveRaw(start_after)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
 
-        let mut v = [asset_infos[0].as_bytes(), asset_infos[1].as_bytes()]
-            .concat()
-            .as_slice()
-            .to_vec();
+// this will set the first key after the pairs of the provided assets, by appending a 1 byte
+pub fn calc_range_start(start_after: Option<[AssetInfoRaw; 2]>) -> Option<Vec<u8>> {
+    start_after.map(|asset_infos| {
+        let mut v = asset_pair_key(&asset_infos);
         v.push(1);
         v
     })
@@ -78,6 +334,15 @@ pub fn add_allow_native_token(
     ALLOW_NATIVE_TOKENS.save(storage, denom.as_bytes(), &decimals)
 }
 
+pub fn remove_allow_native_token(storage: &mut dyn Storage, denom: String) -> StdResult<()> {
+    if !ALLOW_NATIVE_TOKENS.has(storage, denom.as_bytes()) {
+        return Err(StdError::not_found(format!("native token {}", denom)));
+    }
+
+    ALLOW_NATIVE_TOKENS.remove(storage, denom.as_bytes());
+    Ok(())
+}
+
 #[cfg(test)]
 mod allow_native_token {
 
@@ -2,23 +2,32 @@ use cosmwasm_std::testing::{
     mock_dependencies_with_balance, mock_env, mock_info, MockApi, MockStorage, MOCK_CONTRACT_ADDR,
 };
 use cosmwasm_std::{
-    attr, coin, from_binary, to_binary, Api, CanonicalAddr, CosmosMsg, Decimal, OwnedDeps, Reply,
-    ReplyOn, Response, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
+    attr, coin, from_binary, to_binary, Api, CosmosMsg, Decimal, Order, OwnedDeps, Reply, ReplyOn,
+    Response, StdError, StdResult, SubMsg, SubMsgResponse, SubMsgResult, Timestamp, Uint128,
+    WasmMsg,
 };
 
-use pool_network::asset::{AssetInfo, PairInfo, PairInfoRaw, PairType};
+use pool_network::asset::{AssetInfo, AssetInfoRaw, PairInfo, PairInfoRaw, PairType};
+use pool_network::burn_fee::BurnFeeTarget;
+use pool_network::epoch::EpochConfig;
 use pool_network::factory::{
-    ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, NativeTokenDecimalsResponse, QueryMsg,
+    AssetPairsResponse, ChildrenVersionsResponse, CodeIdPairs, ConfigResponse, ExecuteMsg,
+    InstantiateMsg, MigrateMsg, MigrationStatusResponse, NativeTokenDecimals,
+    NativeTokenDecimalsResponse, PairsDetailedResponse, PairsResponse, QueryMsg,
 };
 use pool_network::mock_querier::{mock_dependencies, WasmMockQuerier};
 use pool_network::pair::{
     InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
 };
+use pool_network::token::LpTokenInfo;
 use nico_teen::fee::Fee;
 
 use crate::contract::{execute, instantiate, migrate, query, reply};
 use crate::error::ContractError;
-use crate::state::{pair_key, TmpPairInfo, PAIRS, TMP_PAIR_INFO};
+use crate::state::{
+    asset_pair_key, fee_tier_from_key, pair_key, save_pair, TmpPairInfo, ASSET_PAIRS, PAIRS,
+    PAIR_KEYS_MIGRATION_CURSOR, TMP_PAIR_INFO,
+};
 
 #[test]
 fn proper_initialization() {
@@ -65,6 +74,76 @@ fn can_migrate_contract() {
     }
 }
 
+#[test]
+fn can_migrate_contract_from_v120_with_pairs() {
+    // the pair info stored by the factory at v1.2.0, which already has a pair type
+    #[cosmwasm_schema::cw_serde]
+    struct PairInfoRawV120 {
+        pub asset_infos: [AssetInfoRaw; 2],
+        pub contract_addr: cosmwasm_std::CanonicalAddr,
+        pub liquidity_token: cosmwasm_std::CanonicalAddr,
+        pub asset_decimals: [u8; 2],
+        pub pair_type: PairType,
+    }
+    const PAIRS_V120: cw_storage_plus::Map<&[u8], PairInfoRawV120> =
+        cw_storage_plus::Map::new("pair_info");
+
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        pair_code_id: 321u64,
+        token_code_id: 123u64,
+        fee_collector_addr: "collector".to_string(),
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    cw2::set_contract_version(&mut deps.storage, "terraswap-factory", "1.2.0").unwrap();
+
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        }
+        .to_raw(deps.as_ref().api)
+        .unwrap(),
+        AssetInfo::Token {
+            contract_addr: "asset0000".to_string(),
+        }
+        .to_raw(deps.as_ref().api)
+        .unwrap(),
+    ];
+    let key = [asset_infos[0].as_bytes(), asset_infos[1].as_bytes()].concat();
+    PAIRS_V120
+        .save(
+            &mut deps.storage,
+            &key,
+            &PairInfoRawV120 {
+                asset_infos: asset_infos.clone(),
+                contract_addr: deps.api.addr_canonicalize("pair0000").unwrap(),
+                liquidity_token: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+                asset_decimals: [6, 8],
+                pair_type: PairType::StableSwap { amp: 100 },
+            },
+        )
+        .unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    assert_eq!(
+        PAIRS.load(&deps.storage, &key).unwrap(),
+        PairInfoRaw {
+            asset_infos: asset_infos.clone(),
+            contract_addr: deps.api.addr_canonicalize("pair0000").unwrap(),
+            liquidity_token: AssetInfoRaw::Token {
+                contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+            },
+            asset_decimals: [6, 8],
+            pair_type: PairType::StableSwap { amp: 100 },
+        }
+    );
+    for asset_info in &asset_infos {
+        assert!(ASSET_PAIRS.has(&deps.storage, (asset_info.as_bytes(), key.as_slice())));
+    }
+}
+
 #[test]
 fn update_config() {
     let mut deps = mock_dependencies(&[]);
@@ -189,6 +268,8 @@ fn create_pair() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -226,7 +307,9 @@ fn create_pair() {
                         },
                     },
                     fee_collector_addr: "collector".to_string(),
-                    pair_type: PairType::ConstantProduct
+                    pair_type: PairType::ConstantProduct,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 })
                 .unwrap(),
                 code_id: 321u64,
@@ -247,7 +330,11 @@ fn create_pair() {
         TMP_PAIR_INFO.load(&deps.storage).unwrap(),
         TmpPairInfo {
             asset_infos: raw_infos.clone(),
-            pair_key: pair_key(&raw_infos),
+            pair_key: pair_key(
+                &raw_infos,
+                &PairType::ConstantProduct,
+                Decimal::percent(1u64)
+            ),
             asset_decimals: [6u8, 8u8],
             pair_type: PairType::ConstantProduct
         }
@@ -283,6 +370,8 @@ fn create_stableswap_pair() {
             },
         },
         pair_type: PairType::StableSwap { amp: 100 },
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -320,7 +409,9 @@ fn create_stableswap_pair() {
                         },
                     },
                     fee_collector_addr: "collector".to_string(),
-                    pair_type: PairType::StableSwap { amp: 100 }
+                    pair_type: PairType::StableSwap { amp: 100 },
+                    lp_token_type: None,
+                    lp_token_info: None,
                 })
                 .unwrap(),
                 code_id: 321u64,
@@ -341,7 +432,11 @@ fn create_stableswap_pair() {
         TMP_PAIR_INFO.load(&deps.storage).unwrap(),
         TmpPairInfo {
             asset_infos: raw_infos.clone(),
-            pair_key: pair_key(&raw_infos),
+            pair_key: pair_key(
+                &raw_infos,
+                &PairType::StableSwap { amp: 100 },
+                Decimal::percent(1u64)
+            ),
             asset_decimals: [6u8, 8u8],
             pair_type: PairType::StableSwap { amp: 100 }
         }
@@ -393,6 +488,8 @@ fn create_pair_native_token_and_ibc_token() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -431,6 +528,8 @@ fn create_pair_native_token_and_ibc_token() {
                     },
                     fee_collector_addr: "collector".to_string(),
                     pair_type: PairType::ConstantProduct,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 })
                 .unwrap(),
                 code_id: 321u64,
@@ -451,7 +550,11 @@ fn create_pair_native_token_and_ibc_token() {
         TMP_PAIR_INFO.load(&deps.storage).unwrap(),
         TmpPairInfo {
             asset_infos: raw_infos.clone(),
-            pair_key: pair_key(&raw_infos),
+            pair_key: pair_key(
+                &raw_infos,
+                &PairType::ConstantProduct,
+                Decimal::percent(1u64)
+            ),
             asset_decimals: [6u8, 6u8],
             pair_type: PairType::ConstantProduct,
         }
@@ -510,6 +613,8 @@ fn create_ibc_tokens_pair() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -548,6 +653,8 @@ fn create_ibc_tokens_pair() {
                     },
                     fee_collector_addr: "collector".to_string(),
                     pair_type: PairType::ConstantProduct,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 })
                 .unwrap(),
                 code_id: 321u64,
@@ -568,7 +675,11 @@ fn create_ibc_tokens_pair() {
         TMP_PAIR_INFO.load(&deps.storage).unwrap(),
         TmpPairInfo {
             asset_infos: raw_infos.clone(),
-            pair_key: pair_key(&raw_infos),
+            pair_key: pair_key(
+                &raw_infos,
+                &PairType::ConstantProduct,
+                Decimal::percent(1u64)
+            ),
             asset_decimals: [6u8, 6u8],
             pair_type: PairType::ConstantProduct,
         }
@@ -627,6 +738,8 @@ fn create_pair_ethereum_asset_and_ibc_token() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -664,7 +777,9 @@ fn create_pair_ethereum_asset_and_ibc_token() {
                         },
                     },
                     fee_collector_addr: "collector".to_string(),
-                    pair_type: PairType::ConstantProduct
+                    pair_type: PairType::ConstantProduct,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 })
                 .unwrap(),
                 code_id: 321u64,
@@ -685,7 +800,11 @@ fn create_pair_ethereum_asset_and_ibc_token() {
         TMP_PAIR_INFO.load(&deps.storage).unwrap(),
         TmpPairInfo {
             asset_infos: raw_infos.clone(),
-            pair_key: pair_key(&raw_infos),
+            pair_key: pair_key(
+                &raw_infos,
+                &PairType::ConstantProduct,
+                Decimal::percent(1u64)
+            ),
             asset_decimals: [6u8, 6u8],
             pair_type: PairType::ConstantProduct
         }
@@ -720,6 +839,8 @@ fn fail_to_create_same_pair() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -762,6 +883,8 @@ fn fail_to_create_existing_pair() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -772,14 +895,20 @@ fn fail_to_create_existing_pair() {
         asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
         asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
     ];
-    let pair_key = pair_key(&raw_infos);
+    let pair_key = pair_key(
+        &raw_infos,
+        &PairType::ConstantProduct,
+        Decimal::percent(1u64),
+    );
 
     PAIRS
         .save(
             &mut deps.storage,
             &pair_key,
             &PairInfoRaw {
-                liquidity_token: deps.api.addr_canonicalize("lp_token").unwrap(),
+                liquidity_token: AssetInfoRaw::Token {
+                    contract_addr: deps.api.addr_canonicalize("lp_token").unwrap(),
+                },
                 contract_addr: deps.api.addr_canonicalize("pair_contract").unwrap(),
                 asset_infos: raw_infos,
                 asset_decimals: [6u8, 6u8],
@@ -797,6 +926,75 @@ fn fail_to_create_existing_pair() {
     }
 }
 
+#[test]
+fn create_pairs_with_different_pair_type_or_fee_tier() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        AssetInfo::Token {
+            contract_addr: "asset0001".to_string(),
+        },
+    ];
+    let raw_infos = [
+        asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
+        asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
+    ];
+
+    // inject a 1% constant product pair into PAIRS
+    PAIRS
+        .save(
+            &mut deps.storage,
+            &pair_key(
+                &raw_infos,
+                &PairType::ConstantProduct,
+                Decimal::percent(1u64),
+            ),
+            &PairInfoRaw {
+                liquidity_token: AssetInfoRaw::Token {
+                    contract_addr: deps.api.addr_canonicalize("lp_token").unwrap(),
+                },
+                contract_addr: deps.api.addr_canonicalize("pair_contract").unwrap(),
+                asset_infos: raw_infos.clone(),
+                asset_decimals: [6u8, 6u8],
+                pair_type: PairType::ConstantProduct,
+            },
+        )
+        .unwrap();
+
+    for (pair_type, swap_fee) in [
+        (PairType::ConstantProduct, Decimal::permille(3u64)),
+        (PairType::StableSwap { amp: 100 }, Decimal::percent(1u64)),
+    ] {
+        let msg = ExecuteMsg::CreatePair {
+            asset_infos: asset_infos.clone(),
+            pool_fees: PoolFee {
+                protocol_fee: Fee {
+                    share: Decimal::zero(),
+                },
+                swap_fee: Fee { share: swap_fee },
+                burn_fee: Fee {
+                    share: Decimal::zero(),
+                },
+            },
+            pair_type: pair_type.clone(),
+            lp_token_type: None,
+            lp_token_info: None,
+        };
+
+        execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+        assert_eq!(
+            TMP_PAIR_INFO.load(&deps.storage).unwrap().pair_key,
+            pair_key(&raw_infos, &pair_type, swap_fee)
+        );
+    }
+}
+
 #[test]
 fn fail_to_create_pair_with_inactive_denoms() {
     let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
@@ -825,6 +1023,8 @@ fn fail_to_create_pair_with_inactive_denoms() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -868,6 +1068,8 @@ fn fail_to_create_pair_with_invalid_denom() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -903,6 +1105,8 @@ fn fail_to_create_pair_with_invalid_denom() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -955,6 +1159,8 @@ fn fail_to_create_pair_with_unknown_token() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -1007,6 +1213,8 @@ fn fail_to_create_pair_with_unknown_ibc_token() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -1046,7 +1254,11 @@ fn reply_test() {
         asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
     ];
 
-    let pair_key = pair_key(&raw_infos);
+    let pair_key = pair_key(
+        &raw_infos,
+        &PairType::ConstantProduct,
+        Decimal::percent(1u64),
+    );
     TMP_PAIR_INFO
         .save(
             &mut deps.storage,
@@ -1081,7 +1293,9 @@ fn reply_test() {
                     },
                 ],
                 contract_addr: "0000".to_string(),
-                liquidity_token: "liquidity0000".to_string(),
+                liquidity_token: AssetInfo::Token {
+                    contract_addr: "liquidity0000".to_string(),
+                },
                 asset_decimals: [8u8, 8u8],
                 pair_type: PairType::ConstantProduct,
             },
@@ -1096,6 +1310,8 @@ fn reply_test() {
         mock_env(),
         QueryMsg::Pair {
             asset_infos: asset_infos.clone(),
+            pair_type: None,
+            fee_tier: None,
         },
     )
     .unwrap();
@@ -1104,7 +1320,9 @@ fn reply_test() {
     assert_eq!(
         pair_res,
         PairInfo {
-            liquidity_token: "liquidity0000".to_string(),
+            liquidity_token: AssetInfo::Token {
+                contract_addr: "liquidity0000".to_string()
+            },
             contract_addr: "0000".to_string(),
             asset_infos,
             asset_decimals: [8u8, 8u8],
@@ -1231,6 +1449,127 @@ fn append_add_allow_native_token_with_already_exist_token() {
     assert_eq!(7u8, res.decimals)
 }
 
+#[test]
+fn add_native_token_decimals_batch() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+
+    let info = mock_info("addr0000", &[]);
+
+    let msg = ExecuteMsg::AddNativeTokenDecimalsBatch {
+        native_tokens: vec![
+            NativeTokenDecimals {
+                denom: "uluna".to_string(),
+                decimals: 6u8,
+            },
+            NativeTokenDecimals {
+                denom: "uwhale".to_string(),
+                decimals: 6u8,
+            },
+        ],
+    };
+
+    // the factory holds no uwhale
+    let res = execute(deps.as_mut(), mock_env(), info.clone(), msg);
+    match res {
+        Ok(_) => panic!("should return ContractError::InvalidVerificationBalance"),
+        Err(ContractError::InvalidVerificationBalance {}) => (),
+        _ => panic!("should return ContractError::InvalidVerificationBalance"),
+    }
+
+    deps.querier.with_balance(&[(
+        &MOCK_CONTRACT_ADDR.to_string(),
+        vec![coin(1u128, "uluna"), coin(1u128, "uwhale")],
+    )]);
+
+    let msg = ExecuteMsg::AddNativeTokenDecimalsBatch {
+        native_tokens: vec![
+            NativeTokenDecimals {
+                denom: "uluna".to_string(),
+                decimals: 6u8,
+            },
+            NativeTokenDecimals {
+                denom: "uwhale".to_string(),
+                decimals: 8u8,
+            },
+        ],
+    };
+
+    assert_eq!(
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap(),
+        Response::new().add_attributes(vec![
+            ("action", "add_allow_native_tokens"),
+            ("denom", "uluna"),
+            ("decimals", "6"),
+            ("denom", "uwhale"),
+            ("decimals", "8"),
+        ])
+    );
+
+    for (denom, decimals) in [("uluna", 6u8), ("uwhale", 8u8)] {
+        let res: NativeTokenDecimalsResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::NativeTokenDecimals {
+                    denom: denom.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(decimals, res.decimals);
+    }
+}
+
+#[test]
+fn remove_native_token_decimals() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+
+    let info = mock_info("addr0000", &[]);
+
+    let msg = ExecuteMsg::RemoveNativeTokenDecimals {
+        denom: "uluna".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg.clone()).unwrap_err();
+
+    let add_msg = ExecuteMsg::AddNativeTokenDecimals {
+        denom: "uluna".to_string(),
+        decimals: 6u8,
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), add_msg).unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("noadmin", &[]),
+        msg.clone(),
+    );
+    match res {
+        Ok(_) => panic!("should return ContractError::Unauthorized"),
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    assert_eq!(
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap(),
+        Response::new().add_attributes(vec![
+            ("action", "remove_allow_native_token"),
+            ("denom", "uluna"),
+        ])
+    );
+
+    query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::NativeTokenDecimals {
+            denom: "uluna".to_string(),
+        },
+    )
+    .unwrap_err();
+}
+
 #[test]
 fn execute_transactions_unauthorized() {
     let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
@@ -1264,6 +1603,8 @@ fn execute_transactions_unauthorized() {
             },
         },
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
     let res = execute(deps.as_mut(), env.clone(), info.clone(), msg);
 
@@ -1368,91 +1709,916 @@ fn failed_migrate_pair_with_no_admin() {
     }
 }
 
-#[test]
-fn delete_pair() {
-    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
-    deps = init(deps);
-    deps.querier
-        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
-    let asset_infos = [
-        AssetInfo::NativeToken {
-            denom: "uusd".to_string(),
-        },
-        AssetInfo::Token {
-            contract_addr: "asset0001".to_string(),
-        },
-    ];
-
-    let raw_infos = [
-        asset_infos[0].to_raw(&deps.api).unwrap(),
-        asset_infos[1].to_raw(&deps.api).unwrap(),
-    ];
-
-    let pair_key_vec = pair_key(&raw_infos);
+fn store_pairs(deps: &mut OwnedDeps<MockStorage, MockApi, WasmMockQuerier>, denoms: &[&str]) {
+    for denom in denoms {
+        let raw_infos = [
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            }
+            .to_raw(deps.as_ref().api)
+            .unwrap(),
+            AssetInfo::NativeToken {
+                denom: denom.to_string(),
+            }
+            .to_raw(deps.as_ref().api)
+            .unwrap(),
+        ];
 
-    PAIRS
-        .save(
+        save_pair(
             &mut deps.storage,
-            &pair_key_vec,
+            &pair_key(
+                &raw_infos,
+                &PairType::ConstantProduct,
+                Decimal::percent(1u64),
+            ),
             &PairInfoRaw {
-                liquidity_token: CanonicalAddr(cosmwasm_std::Binary(vec![])),
-                contract_addr: deps.api.addr_canonicalize("pair0000").unwrap(),
+                liquidity_token: AssetInfoRaw::Token {
+                    contract_addr: deps
+                        .api
+                        .addr_canonicalize(&format!("lp_{}", denom))
+                        .unwrap(),
+                },
+                contract_addr: deps
+                    .api
+                    .addr_canonicalize(&format!("pair_{}", denom))
+                    .unwrap(),
                 asset_infos: raw_infos,
                 asset_decimals: [6, 6],
                 pair_type: PairType::ConstantProduct,
             },
         )
         .unwrap();
+    }
+}
 
-    let pair = PAIRS.load(&deps.storage, &pair_key_vec);
-
-    assert!(pair.is_ok(), "pair key should exist");
+#[test]
+fn migrate_pairs_in_batches() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+    store_pairs(&mut deps, &["uatom", "ujuno", "uwhale"]);
 
-    let msg = ExecuteMsg::RemovePair { asset_infos };
-    let env = mock_env();
-    let info = mock_info("addr0000", &[]);
-    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+    let msg = ExecuteMsg::MigratePairs {
+        code_id: Some(123u64),
+        start_after: None,
+        limit: Some(2),
+    };
 
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
     assert_eq!(
-        res.attributes,
+        res.messages,
         vec![
-            attr("action", "remove_pair"),
-            attr("pair_contract_addr", "pair0000",),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Migrate {
+                contract_addr: "pair_uatom".to_string(),
+                new_code_id: 123u64,
+                msg: to_binary(&PairMigrateMsg {}).unwrap(),
+            })),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Migrate {
+                contract_addr: "pair_ujuno".to_string(),
+                new_code_id: 123u64,
+                msg: to_binary(&PairMigrateMsg {}).unwrap(),
+            })),
         ]
     );
+    assert!(res
+        .attributes
+        .contains(&attr("migration_complete", "false")));
+
+    let status: MigrationStatusResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::MigrationStatus {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(status.code_id, Some(123u64));
+    assert_eq!(
+        status.last_migrated,
+        Some([
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+            AssetInfo::NativeToken {
+                denom: "ujuno".to_string(),
+            },
+        ])
+    );
 
-    let pair = PAIRS.load(&deps.storage, &pair_key_vec);
+    // resume the migration where it stopped
+    let msg = ExecuteMsg::MigratePairs {
+        code_id: Some(123u64),
+        start_after: None,
+        limit: Some(2),
+    };
 
-    assert!(pair.is_err(), "pair key should not exist");
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: "pair_uwhale".to_string(),
+            new_code_id: 123u64,
+            msg: to_binary(&PairMigrateMsg {}).unwrap(),
+        }))]
+    );
+    assert!(res.attributes.contains(&attr("migration_complete", "true")));
+
+    let status: MigrationStatusResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::MigrationStatus {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        status,
+        MigrationStatusResponse {
+            code_id: None,
+            last_migrated: None,
+            code_ids: vec![CodeIdPairs {
+                code_id: 0u64,
+                pairs: vec![
+                    "pair_uatom".to_string(),
+                    "pair_ujuno".to_string(),
+                    "pair_uwhale".to_string(),
+                ],
+            }],
+        }
+    );
+
+    // the code ids are paginated
+    let status: MigrationStatusResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::MigrationStatus {
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        status.code_ids,
+        vec![CodeIdPairs {
+            code_id: 0u64,
+            pairs: vec!["pair_uatom".to_string()],
+        }]
+    );
 }
 
 #[test]
-fn delete_pair_failed_if_not_found() {
-    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+fn query_children_versions() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
     deps = init(deps);
-    deps.querier
-        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
-    let asset_infos = [
-        AssetInfo::NativeToken {
-            denom: "uusd".to_string(),
-        },
-        AssetInfo::Token {
-            contract_addr: "asset0001".to_string(),
-        },
-    ];
-
-    let msg = ExecuteMsg::RemovePair { asset_infos };
-    let env = mock_env();
-    let info = mock_info("addr0000", &[]);
-    let res = execute(deps.as_mut(), env, info, msg);
+    store_pairs(&mut deps, &["uatom", "uwhale"]);
+
+    let res: ChildrenVersionsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ChildrenVersions {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
 
-    match res {
-        Ok(_) => panic!("should return ContractError::UnExistingPair"),
+    assert_eq!(
+        res.children
+            .into_iter()
+            .map(|child| (child.contract_addr, child.code_id, child.version))
+            .collect::<Vec<_>>(),
+        vec![
+            ("pair_uatom".to_string(), 0u64, "1.0.0".to_string()),
+            ("pair_uwhale".to_string(), 0u64, "1.0.0".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn query_pairs_detailed() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+    store_pairs(&mut deps, &["uatom", "uwhale"]);
+
+    let res: PairsDetailedResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PairsDetailed {
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(res.pairs.len(), 1);
+    let pair = &res.pairs[0];
+    assert_eq!(pair.pair_info.contract_addr, "pair_uatom".to_string());
+    assert_eq!(pair.error, None);
+    let details = pair.details.as_ref().unwrap();
+    assert_eq!(
+        details
+            .assets
+            .iter()
+            .map(|asset| asset.amount)
+            .collect::<Vec<_>>(),
+        vec![Uint128::new(1_000_000u128), Uint128::new(1_000_000u128)]
+    );
+    assert_eq!(details.total_share, Uint128::new(1_000_000u128));
+    assert_eq!(details.pool_fees.swap_fee.share, Decimal::permille(3u64));
+    assert!(details.feature_toggle.swaps_enabled);
+    assert_eq!(details.protocol_fees[0].amount, Uint128::new(100u128));
+
+    // query the next page
+    let res: PairsDetailedResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PairsDetailed {
+                start_after: Some(pair.pair_info.asset_infos.clone()),
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        res.pairs
+            .into_iter()
+            .map(|pair| pair.pair_info.contract_addr)
+            .collect::<Vec<_>>(),
+        vec!["pair_uwhale".to_string()]
+    );
+}
+
+#[test]
+fn query_pairs_detailed_with_unavailable_pair() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+    store_pairs(&mut deps, &["uatom", "uwhale"]);
+    deps.querier.with_unavailable_contracts(&["pair_uatom"]);
+
+    let res: PairsDetailedResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PairsDetailed {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    // the unavailable pair is returned with the error, without failing the other pairs
+    assert_eq!(res.pairs.len(), 2);
+    assert_eq!(
+        res.pairs[0].pair_info.contract_addr,
+        "pair_uatom".to_string()
+    );
+    assert_eq!(res.pairs[0].details, None);
+    assert!(res.pairs[0].error.as_ref().unwrap().contains("pair_uatom"));
+    assert_eq!(
+        res.pairs[1].pair_info.contract_addr,
+        "pair_uwhale".to_string()
+    );
+    assert!(res.pairs[1].details.is_some());
+    assert_eq!(res.pairs[1].error, None);
+}
+
+#[test]
+fn query_pairs_of_same_assets() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+    store_pairs(&mut deps, &["uatom", "uwhale"]);
+
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        },
+        AssetInfo::NativeToken {
+            denom: "uatom".to_string(),
+        },
+    ];
+    let raw_infos = [
+        asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
+        asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
+    ];
+
+    // add a 0.3% constant product pair and a 0.05% stableswap pair for uluna-uatom
+    for (contract_addr, pair_type, fee_tier) in [
+        (
+            "pair_uatom_cp",
+            PairType::ConstantProduct,
+            Decimal::permille(3u64),
+        ),
+        (
+            "pair_uatom_ss",
+            PairType::StableSwap { amp: 100 },
+            Decimal::from_ratio(5u128, 10_000u128),
+        ),
+    ] {
+        PAIRS
+            .save(
+                &mut deps.storage,
+                &pair_key(&raw_infos, &pair_type, fee_tier),
+                &PairInfoRaw {
+                    liquidity_token: AssetInfoRaw::Token {
+                        contract_addr: deps
+                            .api
+                            .addr_canonicalize(&format!("lp_{}", contract_addr))
+                            .unwrap(),
+                    },
+                    contract_addr: deps.api.addr_canonicalize(contract_addr).unwrap(),
+                    asset_infos: raw_infos.clone(),
+                    asset_decimals: [6, 6],
+                    pair_type,
+                },
+            )
+            .unwrap();
+    }
+
+    let query_pair = |pair_type: Option<PairType>, fee_tier: Option<Decimal>| {
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pair {
+                asset_infos: asset_infos.clone(),
+                pair_type,
+                fee_tier,
+            },
+        )
+        .map(|res| from_binary::<PairInfo>(&res).unwrap().contract_addr)
+    };
+
+    // the pair type and fee tier are required when multiple pairs match
+    assert_eq!(
+        query_pair(None, None).unwrap_err(),
+        StdError::generic_err(ContractError::AmbiguousPair {}.to_string())
+    );
+    assert_eq!(
+        query_pair(None, Some(Decimal::permille(3u64))).unwrap(),
+        "pair_uatom_cp"
+    );
+    assert_eq!(
+        query_pair(None, Some(Decimal::percent(1u64))).unwrap(),
+        "pair_uatom"
+    );
+    assert_eq!(
+        query_pair(Some(PairType::StableSwap { amp: 100 }), None).unwrap(),
+        "pair_uatom_ss"
+    );
+    assert!(query_pair(
+        Some(PairType::StableSwap { amp: 100 }),
+        Some(Decimal::percent(1u64))
+    )
+    .is_err());
+
+    let res: AssetPairsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::AssetPairs {
+                asset_infos: asset_infos.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.pairs
+            .into_iter()
+            .map(|pair| (pair.pair_info.contract_addr, pair.fee_tier))
+            .collect::<Vec<_>>(),
+        vec![
+            ("pair_uatom_cp".to_string(), Decimal::permille(3u64)),
+            ("pair_uatom".to_string(), Decimal::percent(1u64)),
+            (
+                "pair_uatom_ss".to_string(),
+                Decimal::from_ratio(5u128, 10_000u128)
+            ),
+        ]
+    );
+
+    // the pairs of the same assets are not split across pages
+    let res: PairsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.pairs.len(), 3);
+
+    let res: PairsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: Some(asset_infos),
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.pairs
+            .into_iter()
+            .map(|pair| pair.contract_addr)
+            .collect::<Vec<_>>(),
+        vec!["pair_uwhale".to_string()]
+    );
+}
+
+#[test]
+fn query_pairs_by_asset() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+    store_pairs(&mut deps, &["uatom", "ujuno", "uwhale"]);
+
+    // add a uwhale-ujuno pair
+    let raw_infos = [
+        AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        }
+        .to_raw(deps.as_ref().api)
+        .unwrap(),
+        AssetInfo::NativeToken {
+            denom: "ujuno".to_string(),
+        }
+        .to_raw(deps.as_ref().api)
+        .unwrap(),
+    ];
+    save_pair(
+        &mut deps.storage,
+        &pair_key(
+            &raw_infos,
+            &PairType::ConstantProduct,
+            Decimal::percent(1u64),
+        ),
+        &PairInfoRaw {
+            liquidity_token: AssetInfoRaw::Token {
+                contract_addr: deps.api.addr_canonicalize("lp_uwhale_ujuno").unwrap(),
+            },
+            contract_addr: deps.api.addr_canonicalize("pair_uwhale_ujuno").unwrap(),
+            asset_infos: raw_infos,
+            asset_decimals: [6, 6],
+            pair_type: PairType::ConstantProduct,
+        },
+    )
+    .unwrap();
+
+    let query_pairs_by_asset = |denom: &str, start_after: Option<[AssetInfo; 2]>| {
+        let res: PairsResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PairsByAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: denom.to_string(),
+                    },
+                    start_after,
+                    limit: Some(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        res.pairs
+            .into_iter()
+            .map(|pair| pair.contract_addr)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        query_pairs_by_asset("uwhale", None),
+        vec!["pair_uwhale_ujuno".to_string()]
+    );
+    assert_eq!(
+        query_pairs_by_asset(
+            "uwhale",
+            Some([
+                AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: "ujuno".to_string(),
+                },
+            ])
+        ),
+        vec!["pair_uwhale".to_string()]
+    );
+    assert!(query_pairs_by_asset(
+        "uwhale",
+        Some([
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+        ])
+    )
+    .is_empty());
+    assert!(query_pairs_by_asset("uusd", None).is_empty());
+}
+
+#[test]
+fn delete_pair() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        AssetInfo::Token {
+            contract_addr: "asset0001".to_string(),
+        },
+    ];
+
+    let raw_infos = [
+        asset_infos[0].to_raw(&deps.api).unwrap(),
+        asset_infos[1].to_raw(&deps.api).unwrap(),
+    ];
+
+    let pair_key_vec = pair_key(
+        &raw_infos,
+        &PairType::ConstantProduct,
+        Decimal::percent(1u64),
+    );
+
+    save_pair(
+        &mut deps.storage,
+        &pair_key_vec,
+        &PairInfoRaw {
+            liquidity_token: AssetInfoRaw::Token {
+                contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+            },
+            contract_addr: deps.api.addr_canonicalize("pair0000").unwrap(),
+            asset_infos: raw_infos.clone(),
+            asset_decimals: [6, 6],
+            pair_type: PairType::ConstantProduct,
+        },
+    )
+    .unwrap();
+
+    let pair = PAIRS.load(&deps.storage, &pair_key_vec);
+
+    assert!(pair.is_ok(), "pair key should exist");
+
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos,
+        pair_type: None,
+        fee_tier: None,
+    };
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "remove_pair"),
+            attr("pair_contract_addr", "pair0000",),
+        ]
+    );
+
+    let pair = PAIRS.load(&deps.storage, &pair_key_vec);
+
+    assert!(pair.is_err(), "pair key should not exist");
+    assert!(
+        !ASSET_PAIRS.has(
+            &deps.storage,
+            (raw_infos[0].as_bytes(), pair_key_vec.as_slice())
+        ),
+        "pair should not be indexed by its assets"
+    );
+}
+
+#[test]
+fn delete_pair_failed_if_not_found() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        AssetInfo::Token {
+            contract_addr: "asset0001".to_string(),
+        },
+    ];
+
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos,
+        pair_type: None,
+        fee_tier: None,
+    };
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    let res = execute(deps.as_mut(), env, info, msg);
+
+    match res {
+        Ok(_) => panic!("should return ContractError::UnExistingPair"),
         Err(ContractError::UnExistingPair {}) => (),
         _ => panic!("should return ContractError::UnExistingPair"),
     }
 }
 
+#[test]
+fn delete_pair_failed_if_ambiguous() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+    store_pairs(&mut deps, &["uatom"]);
+
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        },
+        AssetInfo::NativeToken {
+            denom: "uatom".to_string(),
+        },
+    ];
+    let raw_infos = [
+        asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
+        asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
+    ];
+
+    // add a 0.3% pair of the same assets and pair type
+    save_pair(
+        &mut deps.storage,
+        &pair_key(
+            &raw_infos,
+            &PairType::ConstantProduct,
+            Decimal::permille(3u64),
+        ),
+        &PairInfoRaw {
+            liquidity_token: AssetInfoRaw::Token {
+                contract_addr: deps.api.addr_canonicalize("lp_uatom_3").unwrap(),
+            },
+            contract_addr: deps.api.addr_canonicalize("pair_uatom_3").unwrap(),
+            asset_infos: raw_infos,
+            asset_decimals: [6, 6],
+            pair_type: PairType::ConstantProduct,
+        },
+    )
+    .unwrap();
+
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos: asset_infos.clone(),
+        pair_type: Some(PairType::ConstantProduct),
+        fee_tier: None,
+    };
+    match execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg) {
+        Err(ContractError::AmbiguousPair {}) => (),
+        _ => panic!("should return ContractError::AmbiguousPair"),
+    }
+
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos,
+        pair_type: Some(PairType::ConstantProduct),
+        fee_tier: Some(Decimal::permille(3u64)),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert!(res
+        .attributes
+        .contains(&attr("pair_contract_addr", "pair_uatom_3")));
+}
+
+#[test]
+fn migrate_pair_keys_in_batches() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+
+    // pairs stored under the key of their assets, prior to v1.5.0
+    for denom in ["uatom", "ujuno", "uwhale"] {
+        let raw_infos = [
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            }
+            .to_raw(deps.as_ref().api)
+            .unwrap(),
+            AssetInfo::NativeToken {
+                denom: denom.to_string(),
+            }
+            .to_raw(deps.as_ref().api)
+            .unwrap(),
+        ];
+
+        PAIRS
+            .save(
+                &mut deps.storage,
+                &asset_pair_key(&raw_infos),
+                &PairInfoRaw {
+                    liquidity_token: AssetInfoRaw::Token {
+                        contract_addr: deps
+                            .api
+                            .addr_canonicalize(&format!("lp_{}", denom))
+                            .unwrap(),
+                    },
+                    contract_addr: deps
+                        .api
+                        .addr_canonicalize(&format!("pair_{}", denom))
+                        .unwrap(),
+                    asset_infos: raw_infos,
+                    asset_decimals: [6, 6],
+                    pair_type: PairType::ConstantProduct,
+                },
+            )
+            .unwrap();
+    }
+    PAIR_KEYS_MIGRATION_CURSOR
+        .save(&mut deps.storage, &vec![])
+        .unwrap();
+
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        },
+        AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+    ];
+    let query_pair = |deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>| {
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pair {
+                asset_infos: asset_infos.clone(),
+                pair_type: None,
+                fee_tier: Some(Decimal::permille(3u64)),
+            },
+        )
+        .map(|res| from_binary::<PairInfo>(&res).unwrap().contract_addr)
+    };
+
+    // the pairs not moved yet are found on the fee tier of their current swap fee
+    assert_eq!(query_pair(&deps).unwrap(), "pair_uwhale");
+
+    let msg = ExecuteMsg::MigratePairKeys { limit: Some(2) };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "migrate_pair_keys"),
+            attr("pair_contract_addr", "pair_uatom"),
+            attr("pair_contract_addr", "pair_ujuno"),
+            attr("migration_complete", "false"),
+        ]
+    );
+
+    let msg = ExecuteMsg::MigratePairKeys { limit: Some(2) };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert!(res
+        .attributes
+        .contains(&attr("pair_contract_addr", "pair_uwhale")));
+
+    // run until complete, the moved pairs are visited again but left untouched
+    let msg = ExecuteMsg::MigratePairKeys { limit: None };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "migrate_pair_keys"),
+            attr("migration_complete", "true"),
+        ]
+    );
+    assert!(PAIR_KEYS_MIGRATION_CURSOR
+        .may_load(&deps.storage)
+        .unwrap()
+        .is_none());
+
+    // all pairs are on the key of the 0.3% swap fee of the mocked pair config
+    let keys = PAIRS
+        .keys(&deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()
+        .unwrap();
+    assert_eq!(keys.len(), 3);
+    assert!(keys
+        .iter()
+        .all(|key| fee_tier_from_key(key).unwrap() == Decimal::permille(3u64)));
+    assert_eq!(query_pair(&deps).unwrap(), "pair_uwhale");
+}
+
+#[test]
+fn update_pair_config_moves_pair_to_its_fee_tier() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uluna".to_string())]);
+    deps = init(deps);
+
+    // the mocked pair0000 is a uluna-uluna pair
+    let raw_infos = [
+        AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        }
+        .to_raw(deps.as_ref().api)
+        .unwrap(),
+        AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        }
+        .to_raw(deps.as_ref().api)
+        .unwrap(),
+    ];
+    let pair = PairInfoRaw {
+        liquidity_token: AssetInfoRaw::Token {
+            contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+        },
+        contract_addr: deps.api.addr_canonicalize("pair0000").unwrap(),
+        asset_infos: raw_infos.clone(),
+        asset_decimals: [6, 6],
+        pair_type: PairType::ConstantProduct,
+    };
+    let old_key = pair_key(
+        &raw_infos,
+        &PairType::ConstantProduct,
+        Decimal::percent(1u64),
+    );
+    save_pair(&mut deps.storage, &old_key, &pair).unwrap();
+
+    let update_swap_fee = |swap_fee: Decimal| ExecuteMsg::UpdatePairConfig {
+        pair_addr: "pair0000".to_string(),
+        owner: None,
+        fee_collector_addr: None,
+        pool_fees: Some(PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::zero(),
+            },
+            swap_fee: Fee { share: swap_fee },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        }),
+        feature_toggle: None,
+        burn_fee_target: None,
+        epoch_config: None,
+    };
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        update_swap_fee(Decimal::percent(5u64)),
+    )
+    .unwrap();
+
+    let new_key = pair_key(
+        &raw_infos,
+        &PairType::ConstantProduct,
+        Decimal::percent(5u64),
+    );
+    assert!(!PAIRS.has(&deps.storage, &old_key));
+    assert_eq!(PAIRS.load(&deps.storage, &new_key).unwrap(), pair);
+
+    // can't move the pair to the fee tier of another pair
+    save_pair(
+        &mut deps.storage,
+        &old_key,
+        &PairInfoRaw {
+            liquidity_token: AssetInfoRaw::Token {
+                contract_addr: deps.api.addr_canonicalize("liquidity0001").unwrap(),
+            },
+            contract_addr: deps.api.addr_canonicalize("pair0001").unwrap(),
+            ..pair
+        },
+    )
+    .unwrap();
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        update_swap_fee(Decimal::percent(1u64)),
+    ) {
+        Err(ContractError::ExistingPair {}) => (),
+        _ => panic!("should return ContractError::ExistingPair"),
+    }
+}
+
 #[test]
 fn update_pair_config() {
     let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
@@ -1474,6 +2640,13 @@ fn update_pair_config() {
             },
         }),
         feature_toggle: None,
+        burn_fee_target: Some(BurnFeeTarget::Send {
+            address: "treasury".to_string(),
+        }),
+        epoch_config: Some(EpochConfig {
+            epoch_length: 86_400u64,
+            genesis_time: Timestamp::from_seconds(1_000u64),
+        }),
     };
 
     let env = mock_env();
@@ -1502,6 +2675,58 @@ fn update_pair_config() {
                         },
                     }),
                     feature_toggle: None,
+                    burn_fee_target: Some(BurnFeeTarget::Send {
+                        address: "treasury".to_string(),
+                    }),
+                    epoch_config: Some(EpochConfig {
+                        epoch_length: 86_400u64,
+                        genesis_time: Timestamp::from_seconds(1_000u64),
+                    }),
+                })
+                .unwrap()
+            })
+    );
+}
+
+#[test]
+fn update_pair_lp_token_info() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+
+    let lp_token_info = LpTokenInfo {
+        name: Some("uusd-mAAPL LP".to_string()),
+        project: Some("Mirror".to_string()),
+        ..LpTokenInfo::default()
+    };
+
+    let msg = ExecuteMsg::UpdatePairLpTokenInfo {
+        pair_addr: "pair_addr".to_string(),
+        lp_token_info: lp_token_info.clone(),
+    };
+
+    // only the owner can update the LP token of a pair
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0001", &[]),
+        msg.clone(),
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("Must return ContractError::Unauthorized error"),
+    }
+
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attributes(vec![attr("action", "update_pair_lp_token_info")])
+            .add_message(WasmMsg::Execute {
+                contract_addr: "pair_addr".to_string(),
+                funds: vec![],
+                msg: to_binary(&pool_network::pair::ExecuteMsg::UpdateLpTokenInfo {
+                    lp_token_info
                 })
                 .unwrap()
             })
@@ -1,20 +1,24 @@
 use cosmwasm_std::{
-    from_binary, to_binary, Addr, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, OverflowError,
-    Response, StdError, StdResult, Uint128, WasmMsg,
+    from_binary, to_binary, Addr, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Reply, Response,
+    StdError, StdResult, Uint128, WasmMsg,
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
-use pool_network::asset::{Asset, AssetInfo, PairInfoRaw, MINIMUM_LIQUIDITY_AMOUNT};
+use pool_network::asset::{Asset, AssetInfo, AssetInfoRaw, PairInfoRaw, MINIMUM_LIQUIDITY_AMOUNT};
+use pool_network::burn_fee::{get_bought_back_amount, BurnFeeTarget};
+use pool_network::epoch::EpochConfig;
+use pool_network::lp_token::{burn_lp_token_msg, mint_lp_token_msgs, query_lp_total_supply};
 use pool_network::pair::{Config, Cw20HookMsg, FeatureToggle, PoolFee};
-use pool_network::querier::query_token_info;
+use pool_network::token::LpTokenInfo;
 use pool_network::U256;
 
+use crate::contract::BUY_BACK_REPLY_ID;
 use crate::error::ContractError;
 use crate::helpers;
-use crate::helpers::get_protocol_fee_for_asset;
 use crate::state::{
-    store_fee, ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES,
-    CONFIG, PAIR_INFO,
+    add_fee, get_pools, store_epoch_fee, store_fee, ALL_TIME_BURNED_FEES,
+    ALL_TIME_COLLECTED_PROTOCOL_FEES, BURNED_FEES_BY_EPOCH, BUY_BACK_FEES, COLLECTED_PROTOCOL_FEES,
+    CONFIG, EPOCH_CONFIG, PAIR_INFO, PROTOCOL_FEES_BY_EPOCH, RESERVES,
 };
 
 /// Receives cw20 tokens. Used to swap and withdraw from the pool.
@@ -41,8 +45,7 @@ pub fn receive_cw20(
             // only asset contract can execute this message
             let mut authorized: bool = false;
             let config: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
-            let pools: [Asset; 2] =
-                config.query_pools(&deps.querier, deps.api, env.contract.address.clone())?;
+            let pools: [Asset; 2] = get_pools(deps.storage, deps.api, &config)?;
             for pool in pools.iter() {
                 if let AssetInfo::Token { contract_addr, .. } = &pool.info {
                     if contract_addr == &info.sender {
@@ -86,7 +89,12 @@ pub fn receive_cw20(
             }
 
             let config: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
-            if deps.api.addr_canonicalize(info.sender.as_str())? != config.liquidity_token {
+            let sender = deps.api.addr_canonicalize(info.sender.as_str())?;
+            if config.liquidity_token
+                != (AssetInfoRaw::Token {
+                    contract_addr: sender,
+                })
+            {
                 return Err(ContractError::Unauthorized {});
             }
 
@@ -97,6 +105,39 @@ pub fn receive_cw20(
     }
 }
 
+/// Withdraws liquidity with the token factory LP tokens sent along the message
+pub fn withdraw_native_liquidity(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // check if the withdrawal feature is enabled
+    let feature_toggle: FeatureToggle = CONFIG.load(deps.storage)?.feature_toggle;
+    if !feature_toggle.withdrawals_enabled {
+        return Err(ContractError::OperationDisabled(
+            "withdraw_liquidity".to_string(),
+        ));
+    }
+
+    // only pools issuing token factory LP tokens can be withdrawn from with native funds
+    let config: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
+    let lp_denom = match config.liquidity_token {
+        AssetInfoRaw::NativeToken { denom } => denom,
+        AssetInfoRaw::Token { .. } => return Err(ContractError::Unauthorized {}),
+    };
+
+    let amount = match info.funds.as_slice() {
+        [coin] if coin.denom == lp_denom => coin.amount,
+        _ => return Err(ContractError::InvalidLiquidityTokenFunds(lp_denom)),
+    };
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let sender_addr = info.sender.clone();
+    withdraw_liquidity(deps, env, info, sender_addr, amount)
+}
+
 /// Provides liquidity. The user must IncreaseAllowance on the token when providing cw20 tokens
 pub fn provide_liquidity(
     deps: DepsMut,
@@ -119,8 +160,7 @@ pub fn provide_liquidity(
     }
 
     let pair_info: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
-    let mut pools: [Asset; 2] =
-        pair_info.query_pools(&deps.querier, deps.api, env.contract.address.clone())?;
+    let pools: [Asset; 2] = get_pools(deps.storage, deps.api, &pair_info)?;
     let deposits: [Uint128; 2] = [
         assets
             .iter()
@@ -139,7 +179,7 @@ pub fn provide_liquidity(
     }
 
     let mut messages: Vec<CosmosMsg> = vec![];
-    for (i, pool) in pools.iter_mut().enumerate() {
+    for (i, pool) in pools.iter().enumerate() {
         // If the pool is token contract, then we need to execute TransferFrom msg to receive funds
         if let AssetInfo::Token { contract_addr, .. } = &pool.info {
             messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
@@ -151,26 +191,14 @@ pub fn provide_liquidity(
                 })?,
                 funds: vec![],
             }));
-        } else {
-            // If the asset is native token, balance is already increased
-            // To calculate it properly we should subtract user deposit from the pool
-            pool.amount = pool.amount.checked_sub(deposits[i])?;
         }
     }
 
-    // deduct protocol fee from pools
-    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
-    for pool in pools.iter_mut() {
-        let protocol_fee =
-            get_protocol_fee_for_asset(collected_protocol_fees.clone(), pool.clone().get_id());
-        pool.amount = pool.amount.checked_sub(protocol_fee)?;
-    }
-
     // assert slippage tolerance
     helpers::assert_slippage_tolerance(&slippage_tolerance, &deposits, &pools)?;
 
-    let liquidity_token = deps.api.addr_humanize(&pair_info.liquidity_token)?;
-    let total_share = query_token_info(&deps.querier, liquidity_token)?.total_supply;
+    let liquidity_token = pair_info.liquidity_token.to_normal(deps.api)?;
+    let total_share = query_lp_total_supply(&deps.querier, &liquidity_token)?;
     let share = if total_share == Uint128::zero() {
         // Make sure at least MINIMUM_LIQUIDITY_AMOUNT is deposited to mitigate the risk of the first
         // depositor preventing small liquidity providers from joining the pool
@@ -184,10 +212,9 @@ pub fn provide_liquidity(
         .checked_sub(MINIMUM_LIQUIDITY_AMOUNT)
         .map_err(|_| ContractError::InvalidInitialLiquidityAmount(MINIMUM_LIQUIDITY_AMOUNT))?;
 
-        messages.push(mint_lp_token_msg(
-            deps.api
-                .addr_humanize(&pair_info.liquidity_token)?
-                .to_string(),
+        messages.extend(mint_lp_token_msgs(
+            &liquidity_token,
+            &env.contract.address,
             env.contract.address.to_string(),
             MINIMUM_LIQUIDITY_AMOUNT,
         )?);
@@ -212,12 +239,20 @@ pub fn provide_liquidity(
         )
     };
 
+    // add the deposits to the reserves
+    RESERVES.save(
+        deps.storage,
+        &[
+            pools[0].amount.checked_add(deposits[0])?,
+            pools[1].amount.checked_add(deposits[1])?,
+        ],
+    )?;
+
     // mint LP token to sender
     let receiver = receiver.unwrap_or_else(|| info.sender.to_string());
-    messages.push(mint_lp_token_msg(
-        deps.api
-            .addr_humanize(&pair_info.liquidity_token)?
-            .to_string(),
+    messages.extend(mint_lp_token_msgs(
+        &liquidity_token,
+        &env.contract.address,
         receiver.clone(),
         share,
     )?);
@@ -241,34 +276,29 @@ pub fn withdraw_liquidity(
     amount: Uint128,
 ) -> Result<Response, ContractError> {
     let pair_info: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
-    let liquidity_addr: Addr = deps.api.addr_humanize(&pair_info.liquidity_token)?;
-
-    let pool_assets: [Asset; 2] =
-        pair_info.query_pools(&deps.querier, deps.api, env.contract.address)?;
-    let total_share: Uint128 = query_token_info(&deps.querier, liquidity_addr)?.total_supply;
+    let liquidity_token = pair_info.liquidity_token.to_normal(deps.api)?;
 
-    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
+    let pool_assets: [Asset; 2] = get_pools(deps.storage, deps.api, &pair_info)?;
+    let total_share: Uint128 = query_lp_total_supply(&deps.querier, &liquidity_token)?;
 
     let share_ratio: Decimal = Decimal::from_ratio(amount, total_share);
 
-    let refund_assets: Result<Vec<Asset>, OverflowError> = pool_assets
+    let refund_assets: Vec<Asset> = pool_assets
         .iter()
-        .map(|pool_asset| {
-            let protocol_fee = get_protocol_fee_for_asset(
-                collected_protocol_fees.clone(),
-                pool_asset.clone().get_id(),
-            );
-
-            // subtract the protocol_fee from the amount of the pool_asset
-            let refund_amount = pool_asset.amount.checked_sub(protocol_fee)?;
-            Ok(Asset {
-                info: pool_asset.info.clone(),
-                amount: refund_amount * share_ratio,
-            })
+        .map(|pool_asset| Asset {
+            info: pool_asset.info.clone(),
+            amount: pool_asset.amount * share_ratio,
         })
         .collect();
 
-    let refund_assets = refund_assets?;
+    // remove the refunds from the reserves
+    RESERVES.save(
+        deps.storage,
+        &[
+            pool_assets[0].amount.checked_sub(refund_assets[0].amount)?,
+            pool_assets[1].amount.checked_sub(refund_assets[1].amount)?,
+        ],
+    )?;
 
     // update pool info
     Ok(Response::new()
@@ -276,14 +306,7 @@ pub fn withdraw_liquidity(
             refund_assets[0].clone().into_msg(sender.clone())?,
             refund_assets[1].clone().into_msg(sender.clone())?,
             // burn liquidity token
-            CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: deps
-                    .api
-                    .addr_humanize(&pair_info.liquidity_token)?
-                    .to_string(),
-                msg: to_binary(&Cw20ExecuteMsg::Burn { amount })?,
-                funds: vec![],
-            }),
+            burn_lp_token_msg(&liquidity_token, &env.contract.address, amount)?,
         ])
         .add_attributes(vec![
             ("action", "withdraw_liquidity"),
@@ -315,28 +338,11 @@ pub fn swap(
     // determine what's the offer and ask pool based on the offer_asset
     let offer_pool: Asset;
     let ask_pool: Asset;
-    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
 
     let offer_decimal: u8;
     let ask_decimal: u8;
 
-    // To calculate pool amounts properly we should subtract user deposit and the protocol fees from the pool
-    let pools = pair_info
-        .query_pools(&deps.querier, deps.api, env.contract.address)?
-        .into_iter()
-        .map(|mut pool| {
-            // subtract the protocol fee from the pool
-            let protocol_fee =
-                get_protocol_fee_for_asset(collected_protocol_fees.clone(), pool.clone().get_id());
-            pool.amount = pool.amount.checked_sub(protocol_fee)?;
-
-            if pool.info.equal(&offer_asset.info) {
-                pool.amount = pool.amount.checked_sub(offer_asset.amount)?
-            }
-
-            Ok(pool)
-        })
-        .collect::<StdResult<Vec<_>>>()?;
+    let pools = get_pools(deps.storage, deps.api, &pair_info)?;
 
     if offer_asset.info.equal(&pools[0].info) {
         offer_pool = pools[0].clone();
@@ -355,7 +361,8 @@ pub fn swap(
     }
 
     let offer_amount = offer_asset.amount;
-    let pool_fees = CONFIG.load(deps.storage)?.pool_fees;
+    let config = CONFIG.load(deps.storage)?;
+    let pool_fees = config.pool_fees;
 
     let swap_computation = helpers::compute_swap(
         offer_pool.amount,
@@ -383,6 +390,19 @@ pub fn swap(
         ask_decimal,
     )?;
 
+    // update the reserves. The protocol and burn fees leave the ask pool along with the return amount
+    let offer_reserve = offer_pool.amount.checked_add(offer_amount)?;
+    let ask_reserve = ask_pool
+        .amount
+        .checked_sub(swap_computation.return_amount)?
+        .checked_sub(swap_computation.protocol_fee_amount)?
+        .checked_sub(swap_computation.burn_fee_amount)?;
+    if offer_pool.info.equal(&pools[0].info) {
+        RESERVES.save(deps.storage, &[offer_reserve, ask_reserve])?;
+    } else {
+        RESERVES.save(deps.storage, &[ask_reserve, offer_reserve])?;
+    }
+
     let receiver = to.unwrap_or_else(|| sender.clone());
 
     let mut messages: Vec<CosmosMsg> = vec![];
@@ -390,21 +410,36 @@ pub fn swap(
         messages.push(return_asset.into_msg(receiver.clone())?);
     }
 
-    // burn ask_asset from the pool
-    if !swap_computation.burn_fee_amount.is_zero() {
-        let burn_asset = Asset {
-            info: ask_pool.info.clone(),
-            amount: swap_computation.burn_fee_amount,
-        };
-
-        store_fee(
-            deps.storage,
-            burn_asset.amount,
-            burn_asset.clone().get_id(),
-            ALL_TIME_BURNED_FEES,
-        )?;
+    // dispose of the burn fee, taken on the ask_asset, as set by the burn fee target. The burn fees
+    // to buy back with are accrued, and bought back by the keeper with [ExecuteMsg::BuyBack]
+    let epoch = EPOCH_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .epoch(env.block.time);
+    let burn_asset = Asset {
+        info: ask_pool.info.clone(),
+        amount: swap_computation.burn_fee_amount,
+    };
+    if config.burn_fee_target.buys_back(&burn_asset.info) {
+        add_fee(deps.storage, burn_asset, BUY_BACK_FEES)?;
+    } else {
+        // burn fees sent away are not burned, hence not accounted as such
+        if config.burn_fee_target.burns_directly(&burn_asset.info) {
+            store_fee(
+                deps.storage,
+                burn_asset.amount,
+                burn_asset.clone().get_id(),
+                ALL_TIME_BURNED_FEES,
+            )?;
+            store_epoch_fee(
+                deps.storage,
+                epoch,
+                burn_asset.clone(),
+                BURNED_FEES_BY_EPOCH,
+            )?;
+        }
 
-        messages.push(burn_asset.into_burn_msg()?);
+        messages.extend(config.burn_fee_target.into_msgs(burn_asset)?);
     }
 
     // Store the protocol fees generated by this swap. The protocol fees are collected on the ask
@@ -421,9 +456,19 @@ pub fn swap(
         ask_pool.clone().get_id(),
         ALL_TIME_COLLECTED_PROTOCOL_FEES,
     )?;
+    store_epoch_fee(
+        deps.storage,
+        epoch,
+        Asset {
+            info: ask_pool.info.clone(),
+            amount: swap_computation.protocol_fee_amount,
+        },
+        PROTOCOL_FEES_BY_EPOCH,
+    )?;
 
     // 1. send collateral token from the contract to a user
-    // 2. stores the protocol fees
+    // 2. dispose of the burn fee
+    // 3. stores the protocol fees
     Ok(Response::new().add_messages(messages).add_attributes(vec![
         ("action", "swap"),
         ("sender", sender.as_str()),
@@ -449,14 +494,19 @@ pub fn swap(
     ]))
 }
 
-/// Updates the [Config] of the contract. Only the owner of the contract can do this.
+/// Updates the [Config] of the contract. Only the owner of the contract can do this. The
+/// [EpochConfig] can only be set once, as the fees already bucketed by epoch would be misaligned.
+#[allow(clippy::too_many_arguments)]
 pub fn update_config(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     owner: Option<String>,
     fee_collector_addr: Option<String>,
     pool_fees: Option<PoolFee>,
     feature_toggle: Option<FeatureToggle>,
+    burn_fee_target: Option<BurnFeeTarget>,
+    epoch_config: Option<EpochConfig>,
 ) -> Result<Response, ContractError> {
     let mut config: Config = CONFIG.load(deps.storage)?;
     if deps.api.addr_validate(info.sender.as_str())? != config.owner {
@@ -482,12 +532,115 @@ pub fn update_config(
         config.fee_collector_addr = deps.api.addr_validate(fee_collector_addr.as_str())?;
     }
 
+    if let Some(epoch_config) = epoch_config {
+        if EPOCH_CONFIG.may_load(deps.storage)?.is_some() {
+            return Err(ContractError::EpochConfigAlreadySet {});
+        }
+        epoch_config.validate()?;
+        EPOCH_CONFIG.save(deps.storage, &epoch_config)?;
+    }
+
+    // the burn fees accrued to buy back with are disposed of by the new target, unless it buys back
+    // with them as well
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if let Some(burn_fee_target) = burn_fee_target {
+        let pair_info = PAIR_INFO.load(deps.storage)?.to_normal(deps.api)?;
+        burn_fee_target.validate(deps.as_ref(), &pair_info.asset_infos)?;
+
+        let epoch = EPOCH_CONFIG
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .epoch(env.block.time);
+        let (kept_fees, disposed_fees): (Vec<Asset>, Vec<Asset>) = BUY_BACK_FEES
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .into_iter()
+            .partition(|fee| burn_fee_target.buys_back(&fee.info));
+        BUY_BACK_FEES.save(deps.storage, &kept_fees)?;
+
+        for fee in disposed_fees {
+            if burn_fee_target.burns_directly(&fee.info) {
+                add_fee(deps.storage, fee.clone(), ALL_TIME_BURNED_FEES)?;
+                store_epoch_fee(deps.storage, epoch, fee.clone(), BURNED_FEES_BY_EPOCH)?;
+            }
+            messages.extend(burn_fee_target.into_msgs(fee)?);
+        }
+
+        config.burn_fee_target = burn_fee_target;
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
-    Ok(Response::new().add_attribute("action", "update_config"))
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "update_config"))
+}
+
+/// Buys back with the burn fees accrued in the given asset, burning the tokens bought in the reply.
+/// Only the keeper of the [BurnFeeTarget::BuyBackAndBurn] target can do this, setting the minimum
+/// amount to buy back.
+pub fn buy_back(
+    deps: DepsMut,
+    info: MessageInfo,
+    asset_info: AssetInfo,
+    minimum_receive: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !config.burn_fee_target.is_keeper(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut buy_back_fees = BUY_BACK_FEES.may_load(deps.storage)?.unwrap_or_default();
+    let Some(index) = buy_back_fees.iter().position(|fee| fee.info == asset_info) else {
+        return Err(ContractError::NoBuyBackFees(asset_info.to_string()));
+    };
+    let burn_fees = buy_back_fees.remove(index);
+    if burn_fees.amount.is_zero() {
+        return Err(ContractError::NoBuyBackFees(asset_info.to_string()));
+    }
+    BUY_BACK_FEES.save(deps.storage, &buy_back_fees)?;
+
+    Ok(Response::new()
+        .add_submessage(config.burn_fee_target.buy_back_msg(
+            &deps.querier,
+            burn_fees.clone(),
+            minimum_receive,
+            BUY_BACK_REPLY_ID,
+        )?)
+        .add_attributes(vec![
+            ("action", "buy_back".to_string()),
+            ("burn_fees", burn_fees.to_string()),
+            ("minimum_receive", minimum_receive.to_string()),
+        ]))
 }
 
-/// Collects all protocol fees accrued by the pool
+/// Updates the name, symbol and/or marketing info of the cw20 LP token of the pool
+pub fn update_lp_token_info(
+    deps: DepsMut,
+    info: MessageInfo,
+    lp_token_info: LpTokenInfo,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // token factory LP tokens have no cw20 metadata to update
+    let lp_token = match PAIR_INFO.load(deps.storage)?.liquidity_token {
+        AssetInfoRaw::Token { contract_addr } => deps.api.addr_humanize(&contract_addr)?,
+        AssetInfoRaw::NativeToken { .. } => return Err(ContractError::NativeLpTokenInfo {}),
+    };
+
+    Ok(Response::new()
+        .add_messages(lp_token_info.update_msgs(lp_token.as_str())?)
+        .add_attributes(vec![
+            ("action", "update_lp_token_info"),
+            ("lp_token", lp_token.as_str()),
+        ]))
+}
+
+/// Collects all protocol fees accrued by the pool. The protocol fees are not part of the reserves,
+/// which are left untouched.
 pub fn collect_protocol_fees(deps: DepsMut) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -521,15 +674,92 @@ pub fn collect_protocol_fees(deps: DepsMut) -> Result<Response, ContractError> {
         .add_messages(messages))
 }
 
-/// Creates the Mint LP message
-fn mint_lp_token_msg(
-    lp_token_addr: String,
-    recipient: String,
-    amount: Uint128,
-) -> Result<CosmosMsg, ContractError> {
-    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: lp_token_addr,
-        msg: to_binary(&Cw20ExecuteMsg::Mint { recipient, amount })?,
-        funds: vec![],
-    }))
+/// Adds the tokens sent to the pool outside of its messages, e.g. donations, to the reserves
+pub fn sync(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let pair_info = PAIR_INFO.load(deps.storage)?;
+    let pools = helpers::query_balance_pools(deps.as_ref(), &pair_info, env.contract.address)?;
+
+    RESERVES.save(deps.storage, &[pools[0].amount, pools[1].amount])?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "sync"),
+        ("reserves", &format!("{}, {}", pools[0], pools[1])),
+    ]))
+}
+
+/// Sends the tokens sent to the pool outside of its messages, e.g. donations, to the given address
+pub fn skim(deps: DepsMut, env: Env, to: String) -> Result<Response, ContractError> {
+    let receiver = deps.api.addr_validate(&to)?;
+
+    let pair_info = PAIR_INFO.load(deps.storage)?;
+    let reserves = RESERVES.load(deps.storage)?;
+    let pools = helpers::query_balance_pools(deps.as_ref(), &pair_info, env.contract.address)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut skimmed_assets: Vec<String> = vec![];
+    for (pool, reserve) in pools.into_iter().zip(reserves) {
+        let skimmed_asset = Asset {
+            info: pool.info,
+            amount: pool.amount.saturating_sub(reserve),
+        };
+
+        // prevents trying to send 0 coins, which errors
+        if !skimmed_asset.amount.is_zero() {
+            skimmed_assets.push(skimmed_asset.to_string());
+            messages.push(skimmed_asset.into_msg(receiver.clone())?);
+        }
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "skim"),
+        ("receiver", receiver.as_str()),
+        ("skimmed_assets", &skimmed_assets.join(", ")),
+    ]))
+}
+
+/// Burns the tokens bought back with the burn fees of a swap, when the burn fee target is
+/// [BurnFeeTarget::BuyBackAndBurn]
+pub fn burn_bought_back(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
+    let asset_info = match CONFIG.load(deps.storage)?.burn_fee_target {
+        BurnFeeTarget::BuyBackAndBurn { asset_info, .. } => asset_info,
+        _ => return Err(StdError::generic_err("The pool is not buying back")),
+    };
+
+    let events = msg
+        .result
+        .into_result()
+        .map_err(StdError::generic_err)?
+        .events;
+    let bought_back_amount =
+        get_bought_back_amount(&events, env.contract.address.as_str(), &asset_info)?;
+
+    let mut response = Response::new().add_attributes(vec![
+        ("action", "burn_bought_back".to_string()),
+        ("burned_asset", asset_info.to_string()),
+        ("burned_amount", bought_back_amount.to_string()),
+    ]);
+
+    // the tokens bought back are the ones burned, hence accounted as such
+    if !bought_back_amount.is_zero() {
+        let burned_asset = Asset {
+            info: asset_info,
+            amount: bought_back_amount,
+        };
+
+        let epoch = EPOCH_CONFIG
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .epoch(env.block.time);
+        add_fee(deps.storage, burned_asset.clone(), ALL_TIME_BURNED_FEES)?;
+        store_epoch_fee(
+            deps.storage,
+            epoch,
+            burned_asset.clone(),
+            BURNED_FEES_BY_EPOCH,
+        )?;
+
+        response = response.add_message(burned_asset.into_burn_msg()?);
+    }
+
+    Ok(response)
 }
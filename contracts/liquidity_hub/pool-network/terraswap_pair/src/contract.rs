@@ -2,23 +2,25 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     to_binary, Binary, CanonicalAddr, Deps, DepsMut, Env, MessageInfo, Reply, ReplyOn, Response,
-    StdError, StdResult, SubMsg, WasmMsg,
+    StdError, StdResult, SubMsg, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::MinterResponse;
 use protobuf::Message;
 use semver::Version;
 
-use pool_network::asset::PairInfoRaw;
+use pool_network::asset::{AssetInfoRaw, PairInfoRaw};
+use pool_network::burn_fee::BurnFeeTarget;
+use pool_network::lp_token::{create_lp_denom_msg, get_lp_denom, LpTokenType};
 use pool_network::pair::{Config, ExecuteMsg, FeatureToggle, InstantiateMsg, MigrateMsg, QueryMsg};
-use pool_network::token::InstantiateMsg as TokenInstantiateMsg;
+use pool_network::token::{get_lp_token_symbol, InstantiateMsg as TokenInstantiateMsg};
 
 use crate::error::ContractError;
 use crate::error::ContractError::MigrateInvalidVersion;
 use crate::response::MsgInstantiateContractResponse;
 use crate::state::{
     ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES, CONFIG,
-    PAIR_INFO,
+    PAIR_INFO, RESERVES,
 };
 use crate::{commands, helpers, queries};
 
@@ -27,6 +29,7 @@ const CONTRACT_NAME: &str = "nico_teen-pool";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 const INSTANTIATE_REPLY_ID: u64 = 1;
+pub(crate) const BUY_BACK_REPLY_ID: u64 = 2;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -37,9 +40,20 @@ pub fn instantiate(
 ) -> StdResult<Response> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    let lp_token_type = msg.lp_token_type.unwrap_or(LpTokenType::Cw20);
+    let liquidity_token = match lp_token_type {
+        // the address of the cw20 LP token is patched in the reply
+        LpTokenType::Cw20 => AssetInfoRaw::Token {
+            contract_addr: CanonicalAddr::from(vec![]),
+        },
+        LpTokenType::TokenFactory => AssetInfoRaw::NativeToken {
+            denom: get_lp_denom(env.contract.address.as_str()),
+        },
+    };
+
     let pair_info: &PairInfoRaw = &PairInfoRaw {
         contract_addr: deps.api.addr_canonicalize(env.contract.address.as_str())?,
-        liquidity_token: CanonicalAddr::from(vec![]),
+        liquidity_token,
         asset_infos: [
             msg.asset_infos[0].to_raw(deps.api)?,
             msg.asset_infos[1].to_raw(deps.api)?,
@@ -49,13 +63,22 @@ pub fn instantiate(
     };
 
     PAIR_INFO.save(deps.storage, pair_info)?;
+    RESERVES.save(deps.storage, &[Uint128::zero(), Uint128::zero()])?;
 
     let asset_info_0 = pair_info.asset_infos[0].to_normal(deps.api)?;
     let asset_info_1 = pair_info.asset_infos[1].to_normal(deps.api)?;
 
     let asset0_label = asset_info_0.clone().get_label(&deps.as_ref())?;
     let asset1_label = asset_info_1.clone().get_label(&deps.as_ref())?;
-    let lp_token_name = format!("{}-{}-LP", asset0_label, asset1_label);
+    let lp_token_info = msg.lp_token_info.unwrap_or_default();
+    let lp_token_name = lp_token_info
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("{}-{}-LP", asset0_label, asset1_label));
+    let lp_token_symbol = lp_token_info
+        .symbol
+        .clone()
+        .unwrap_or_else(|| get_lp_token_symbol(&[asset0_label, asset1_label]));
 
     // check the fees are valid
     msg.pool_fees.is_valid()?;
@@ -70,6 +93,7 @@ pub fn instantiate(
             deposits_enabled: true,
             swaps_enabled: true,
         },
+        burn_fee_target: BurnFeeTarget::Burn,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -93,6 +117,13 @@ pub fn instantiate(
         ALL_TIME_BURNED_FEES,
     )?;
 
+    if let AssetInfoRaw::NativeToken { denom } = &pair_info.liquidity_token {
+        // Create the LP token with the token factory
+        return Ok(Response::new()
+            .add_message(create_lp_denom_msg(&env.contract.address)?)
+            .add_attribute("liquidity_token_denom", denom));
+    }
+
     Ok(Response::new().add_submessage(SubMsg {
         // Create LP token
         msg: WasmMsg::Instantiate {
@@ -100,13 +131,14 @@ pub fn instantiate(
             code_id: msg.token_code_id,
             msg: to_binary(&TokenInstantiateMsg {
                 name: lp_token_name.clone(),
-                symbol: "uLP".to_string(),
+                symbol: lp_token_symbol,
                 decimals: 6,
                 initial_balances: vec![],
                 mint: Some(MinterResponse {
                     minter: env.contract.address.to_string(),
                     cap: None,
                 }),
+                marketing: Some(lp_token_info.marketing_info(env.contract.address.as_str())),
             })?,
             funds: vec![],
             label: lp_token_name,
@@ -170,21 +202,43 @@ pub fn execute(
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            burn_fee_target,
+            epoch_config,
         } => commands::update_config(
             deps,
+            env,
             info,
             owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            burn_fee_target,
+            epoch_config,
         ),
         ExecuteMsg::CollectProtocolFees {} => commands::collect_protocol_fees(deps),
+        ExecuteMsg::UpdateLpTokenInfo { lp_token_info } => {
+            commands::update_lp_token_info(deps, info, lp_token_info)
+        }
+        ExecuteMsg::WithdrawLiquidity {} => commands::withdraw_native_liquidity(deps, env, info),
+        ExecuteMsg::Sync {} => commands::sync(deps, env),
+        ExecuteMsg::Skim { to } => commands::skim(deps, env, to),
+        ExecuteMsg::BuyBack {
+            asset_info,
+            minimum_receive,
+        } => commands::buy_back(deps, info, asset_info, minimum_receive),
     }
 }
 
-/// This just stores the result for future query
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
+    match msg.id {
+        BUY_BACK_REPLY_ID => commands::burn_bought_back(deps, env, msg),
+        _ => store_liquidity_token(deps, msg),
+    }
+}
+
+/// This just stores the result for future query
+fn store_liquidity_token(deps: DepsMut, msg: Reply) -> StdResult<Response> {
     let data = msg.result.unwrap().data.unwrap();
     let res: MsgInstantiateContractResponse =
         Message::parse_from_bytes(data.as_slice()).map_err(|_| {
@@ -194,7 +248,9 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
 
     let api = deps.api;
     PAIR_INFO.update(deps.storage, |mut meta| -> StdResult<_> {
-        meta.liquidity_token = api.addr_canonicalize(&liquidity_token)?;
+        meta.liquidity_token = AssetInfoRaw::Token {
+            contract_addr: api.addr_canonicalize(&liquidity_token)?,
+        };
         Ok(meta)
     })?;
 
@@ -202,7 +258,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::Pair {} => Ok(to_binary(&queries::query_pair_info(deps)?)?),
         QueryMsg::Pool {} => Ok(to_binary(&queries::query_pool(deps)?)?),
@@ -227,12 +283,22 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
             ALL_TIME_BURNED_FEES,
             None,
         )?)?),
+        QueryMsg::BuyBackFees {} => Ok(to_binary(&queries::query_buy_back_fees(deps)?)?),
+        QueryMsg::ProtocolFeesByEpoch {
+            start_epoch,
+            end_epoch,
+        } => Ok(to_binary(&queries::query_fees_by_epoch(
+            deps,
+            env,
+            start_epoch,
+            end_epoch,
+        )?)?),
     }
 }
 
 #[cfg(not(tarpaulin_include))]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     use crate::migrations;
 
     let version: Version = CONTRACT_VERSION.parse()?;
@@ -253,6 +319,15 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
     if storage_version == Version::parse("1.2.0")? {
         migrations::migrate_to_v130(deps.branch())?;
     }
+    if storage_version < Version::parse("1.4.0")? {
+        migrations::migrate_to_v140(deps.branch())?;
+    }
+    if storage_version < Version::parse("1.6.0")? {
+        migrations::migrate_to_v160(deps.branch())?;
+    }
+    if storage_version < Version::parse("1.8.0")? {
+        migrations::migrate_to_v180(deps.branch(), env)?;
+    }
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
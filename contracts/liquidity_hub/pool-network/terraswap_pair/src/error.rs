@@ -34,6 +34,9 @@ pub enum ContractError {
     #[error("Invalid zero amount")]
     InvalidZeroAmount {},
 
+    #[error("The {0} LP tokens must be the only funds sent to withdraw liquidity")]
+    InvalidLiquidityTokenFunds(String),
+
     #[error("Spread limit exceeded")]
     MaxSpreadAssertion {},
 
@@ -69,6 +72,15 @@ pub enum ContractError {
 
     #[error("An overflow occurred when attempting to construct a decimal")]
     DecimalOverflow {},
+
+    #[error("No burn fees accrued in {0} to buy back with")]
+    NoBuyBackFees(String),
+
+    #[error("Token factory LP tokens have no cw20 name, symbol or marketing info to update")]
+    NativeLpTokenInfo {},
+
+    #[error("The epoch config is already set and can't be changed")]
+    EpochConfigAlreadySet {},
 }
 
 impl From<semver::Error> for ContractError {
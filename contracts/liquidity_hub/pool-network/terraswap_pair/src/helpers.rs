@@ -2,14 +2,17 @@ use std::cmp::Ordering;
 use std::ops::Mul;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Decimal, Decimal256, StdError, StdResult, Storage, Uint128, Uint256};
+use cosmwasm_std::{
+    Addr, Decimal, Decimal256, Deps, StdError, StdResult, Storage, Uint128, Uint256,
+};
 use cw_storage_plus::Item;
 
-use pool_network::asset::{Asset, AssetInfo, PairType};
+use pool_network::asset::{Asset, AssetInfo, PairInfoRaw, PairType};
 use pool_network::pair::PoolFee;
 
 use crate::error::ContractError;
 use crate::math::Decimal256Helper;
+use crate::state::{BUY_BACK_FEES, COLLECTED_PROTOCOL_FEES};
 
 /// The amount of iterations to perform when calculating the Newton-Raphson approximation.
 const NEWTON_ITERATIONS: u64 = 32;
@@ -17,7 +20,8 @@ const NEWTON_ITERATIONS: u64 = 32;
 // the number of pools in the pair
 const N_COINS: Uint256 = Uint256::from_u128(2);
 
-fn calculate_stableswap_d(
+/// Calculates the stableswap invariant D of the given pools.
+pub fn calculate_stableswap_d(
     offer_pool: Decimal256,
     ask_pool: Decimal256,
     amp: &u64,
@@ -202,9 +206,12 @@ pub fn compute_swap(
                 StableSwapDirection::Simulate,
             )?;
 
+            // the approximation of the curve is rounded down by one unit in favor of the pool
             let return_amount = ask_pool
                 .to_uint256_with_precision(u32::from(ask_precision))?
-                .checked_sub(Uint256::from_uint128(new_pool))?;
+                .checked_sub(Uint256::from_uint128(new_pool))?
+                .checked_sub(Uint256::one())
+                .unwrap_or_default();
 
             // the spread is the loss from 1:1 conversion
             // thus is it the offer_amount - return_amount
@@ -265,18 +272,29 @@ pub fn compute_offer_amount(
 
     // ask => offer
     // offer_amount = cp / (ask_pool - ask_amount / (1 - fees)) - offer_pool
+    // both divisions are rounded up, so swapping the offer amount returns at least the ask amount
     let fees = pool_fees.swap_fee.to_decimal_256()
         + pool_fees.protocol_fee.to_decimal_256()
         + pool_fees.burn_fee.to_decimal_256();
-    let one_minus_commission = Decimal256::one() - fees;
-    let inv_one_minus_commission = Decimal256::one() / one_minus_commission;
-
-    let cp: Uint256 = offer_pool * ask_pool;
-    let offer_amount: Uint256 = Uint256::one()
-        .multiply_ratio(cp, ask_pool - ask_amount * inv_one_minus_commission)
-        - offer_pool;
+    let one_minus_commission = Decimal256::one().checked_sub(fees)?;
+
+    // asking for the whole ask pool or more, fees included, errors rather than panics
+    let cp: Uint256 = offer_pool.checked_mul(ask_pool)?;
+    let mut before_commission_deduction: Uint256 = checked_div_ceil(
+        ask_amount.checked_mul(Decimal256::one().atomics())?,
+        one_minus_commission.atomics(),
+    )?;
+    // each fee is rounded down on swaps, so the smallest return amount covering the ask amount
+    // once the fees are deducted can be a few units below the ceiling
+    while before_commission_deduction > ask_amount
+        && deduct_pool_fees(&pool_fees, before_commission_deduction - Uint256::one())? >= ask_amount
+    {
+        before_commission_deduction -= Uint256::one();
+    }
+    let offer_amount: Uint256 =
+        checked_div_ceil(cp, ask_pool.checked_sub(before_commission_deduction)?)?
+            .checked_sub(offer_pool)?;
 
-    let before_commission_deduction: Uint256 = ask_amount * inv_one_minus_commission;
     let before_spread_deduction: Uint256 =
         offer_amount * Decimal256::from_ratio(ask_pool, offer_pool);
 
@@ -299,6 +317,100 @@ pub fn compute_offer_amount(
     })
 }
 
+/// Deducts the pool fees from the given return amount, the way swaps do
+fn deduct_pool_fees(pool_fees: &PoolFee, return_amount: Uint256) -> StdResult<Uint256> {
+    Ok(return_amount
+        .checked_sub(pool_fees.swap_fee.compute(return_amount))?
+        .checked_sub(pool_fees.protocol_fee.compute(return_amount))?
+        .checked_sub(pool_fees.burn_fee.compute(return_amount))?)
+}
+
+/// Divides `numerator` by `denominator`, rounding up
+pub fn checked_div_ceil(numerator: Uint256, denominator: Uint256) -> StdResult<Uint256> {
+    let quotient = numerator.checked_div(denominator)?;
+    if quotient.checked_mul(denominator)? == numerator {
+        Ok(quotient)
+    } else {
+        Ok(quotient.checked_add(Uint256::one())?)
+    }
+}
+
+/// Computes the offer amount needed to get the ask amount out of a stableswap pool, rounding in
+/// favor of the pool.
+pub fn compute_stableswap_offer_amount(
+    offer_pool: Uint128,
+    ask_pool: Uint128,
+    ask_amount: Uint128,
+    pool_fees: PoolFee,
+    amp: &u64,
+    offer_precision: u8,
+    ask_precision: u8,
+) -> Result<OfferAmountComputation, ContractError> {
+    let offer_pool = Decimal256::decimal_with_precision(offer_pool, offer_precision)?;
+    let ask_pool = Decimal256::decimal_with_precision(ask_pool, ask_precision)?;
+
+    // the ask amount before fees, rounded up so the offer amount covers the fees
+    let one_minus_fees = Decimal256::one()
+        .checked_sub(pool_fees.protocol_fee.to_decimal_256())?
+        .checked_sub(pool_fees.swap_fee.to_decimal_256())?
+        .checked_sub(pool_fees.burn_fee.to_decimal_256())?;
+    let before_fees = Decimal256::decimal_with_precision(
+        checked_div_ceil(
+            Uint256::from(ask_amount).checked_mul(Decimal256::one().atomics())?,
+            one_minus_fees.atomics(),
+        )?,
+        ask_precision,
+    )?;
+
+    let before_fees_offer = before_fees.to_uint256_with_precision(offer_precision.into())?;
+    let before_fees_ask = before_fees.to_uint256_with_precision(ask_precision.into())?;
+
+    let max_precision = offer_precision.max(ask_precision);
+
+    let new_offer_pool_amount = calculate_stableswap_y(
+        offer_pool,
+        ask_pool,
+        before_fees,
+        amp,
+        max_precision,
+        StableSwapDirection::ReverseSimulate,
+    )?;
+
+    // round the new offer pool up by one unit, so the approximation of the curve favors the pool
+    let offer_amount = new_offer_pool_amount
+        .checked_add(Uint128::one())?
+        .checked_sub(Uint128::try_from(
+            offer_pool.to_uint256_with_precision(u32::from(max_precision))?,
+        )?)?;
+
+    // convert into the original offer precision, rounding up
+    let offer_amount = match max_precision.cmp(&offer_precision) {
+        Ordering::Equal => offer_amount,
+        // note that Less should never happen (as max_precision = max(offer_precision, ask_precision))
+        Ordering::Less => offer_amount.checked_mul(Uint128::new(
+            10u128.pow((offer_precision - max_precision).into()),
+        ))?,
+        Ordering::Greater => checked_div_ceil(
+            offer_amount.into(),
+            Uint256::from(10u128.pow((max_precision - offer_precision).into())),
+        )?
+        .try_into()?,
+    };
+
+    let spread_amount = offer_amount.saturating_sub(Uint128::try_from(before_fees_offer)?);
+    let swap_fee_amount = pool_fees.swap_fee.compute(before_fees_ask);
+    let protocol_fee_amount = pool_fees.protocol_fee.compute(before_fees_ask);
+    let burn_fee_amount = pool_fees.burn_fee.compute(before_fees_ask);
+
+    Ok(OfferAmountComputation {
+        offer_amount,
+        spread_amount,
+        swap_fee_amount: swap_fee_amount.try_into()?,
+        protocol_fee_amount: protocol_fee_amount.try_into()?,
+        burn_fee_amount: burn_fee_amount.try_into()?,
+    })
+}
+
 /// Represents the offer amount computation values
 #[cw_serde]
 pub struct OfferAmountComputation {
@@ -309,6 +421,116 @@ pub struct OfferAmountComputation {
     pub burn_fee_amount: Uint128,
 }
 
+/// Computes the spot price of the offer asset in terms of the ask asset, i.e. the marginal amount of
+/// ask tokens an offer token is worth, adjusted by the decimals of the assets.
+pub fn compute_spot_price(
+    offer_pool: Uint128,
+    ask_pool: Uint128,
+    pair_type: &PairType,
+    offer_precision: u8,
+    ask_precision: u8,
+) -> Result<Decimal256, ContractError> {
+    let offer_pool = Decimal256::decimal_with_precision(offer_pool, offer_precision)?;
+    let ask_pool = Decimal256::decimal_with_precision(ask_pool, ask_precision)?;
+
+    if offer_pool.is_zero() || ask_pool.is_zero() {
+        // there is no price without liquidity
+        return Ok(Decimal256::zero());
+    }
+
+    match pair_type {
+        PairType::ConstantProduct => Ok(ask_pool.checked_div(offer_pool)?),
+        PairType::StableSwap { amp } => {
+            let d = calculate_stableswap_d(
+                offer_pool,
+                ask_pool,
+                amp,
+                offer_precision.max(ask_precision),
+            )?;
+            let ann = Decimal256::from_ratio(
+                Uint256::from_u128((*amp).into()).checked_mul(N_COINS)?,
+                1u8,
+            );
+
+            // the spot price is the slope of the invariant, -dy/dx = (y + k) / (x + k),
+            // with k = 4 * ann * x^2 * y^2 / d^3
+            let k = offer_pool
+                .checked_multiply_ratio(offer_pool, d)?
+                .checked_multiply_ratio(ask_pool, d)?
+                .checked_multiply_ratio(ask_pool, d)?
+                .checked_mul(ann)?
+                .checked_mul(Decimal256::from_ratio(4u8, 1u8))?;
+
+            Ok(ask_pool
+                .checked_add(k)?
+                .checked_div(offer_pool.checked_add(k)?)?)
+        }
+    }
+}
+
+/// Computes the spot prices of the pools before and after a swap, and the price impact of the swap,
+/// i.e. how much worse its execution price before fees is than the pre-trade spot price.
+///
+/// The `ask_amount` is the amount leaving the ask pool, that is the return amount plus the protocol
+/// and burn fees, as the swap fee stays in the pool.
+#[allow(clippy::too_many_arguments)]
+pub fn compute_price_impact(
+    offer_pool: Uint128,
+    ask_pool: Uint128,
+    offer_amount: Uint128,
+    ask_amount: Uint128,
+    swap_fee_amount: Uint128,
+    pair_type: &PairType,
+    offer_precision: u8,
+    ask_precision: u8,
+) -> Result<PriceImpactComputation, ContractError> {
+    let pre_trade_spot_price = compute_spot_price(
+        offer_pool,
+        ask_pool,
+        pair_type,
+        offer_precision,
+        ask_precision,
+    )?;
+    let post_trade_spot_price = compute_spot_price(
+        offer_pool.checked_add(offer_amount)?,
+        ask_pool.checked_sub(ask_amount)?,
+        pair_type,
+        offer_precision,
+        ask_precision,
+    )?;
+
+    let offer_amount = Decimal256::decimal_with_precision(offer_amount, offer_precision)?;
+    let price_impact = if offer_amount.is_zero() || pre_trade_spot_price.is_zero() {
+        Decimal256::zero()
+    } else {
+        let execution_price = Decimal256::decimal_with_precision(
+            ask_amount.checked_add(swap_fee_amount)?,
+            ask_precision,
+        )?
+        .checked_div(offer_amount)?;
+
+        if execution_price >= pre_trade_spot_price {
+            Decimal256::zero()
+        } else {
+            Decimal256::one().checked_sub(execution_price.checked_div(pre_trade_spot_price)?)?
+        }
+    };
+
+    Ok(PriceImpactComputation {
+        pre_trade_spot_price,
+        post_trade_spot_price,
+        price_impact,
+    })
+}
+
+/// Represents the price impact computation values
+#[cw_serde]
+pub struct PriceImpactComputation {
+    pub pre_trade_spot_price: Decimal256,
+    pub post_trade_spot_price: Decimal256,
+    pub price_impact: Decimal256,
+}
+
 /// If `belief_price` and `max_spread` both are given,
 /// we compute new spread else we just use pool network
 /// spread to check `max_spread`
@@ -424,6 +646,32 @@ pub fn get_protocol_fee_for_asset(
     }
 }
 
+/// Queries the pools of the pair out of its balances, minus the protocol fees not collected yet and
+/// the burn fees waiting to be bought back. These only differ from the reserves when tokens are sent
+/// to the pool outside of its messages.
+pub fn query_balance_pools(
+    deps: Deps,
+    pair_info: &PairInfoRaw,
+    contract_addr: Addr,
+) -> StdResult<[Asset; 2]> {
+    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
+    let buy_back_fees = BUY_BACK_FEES.may_load(deps.storage)?.unwrap_or_default();
+
+    let mut pools = pair_info.query_pools(&deps.querier, deps.api, contract_addr)?;
+    for pool in pools.iter_mut() {
+        let protocol_fee =
+            get_protocol_fee_for_asset(collected_protocol_fees.clone(), pool.clone().get_id());
+        // the burn fees waiting to be bought back are held by the pool too
+        let buy_back_fee = get_protocol_fee_for_asset(buy_back_fees.clone(), pool.clone().get_id());
+        pool.amount = pool
+            .amount
+            .checked_sub(protocol_fee)?
+            .checked_sub(buy_back_fee)?;
+    }
+
+    Ok(pools)
+}
+
 /// Instantiates fees for a given fee_storage_item
 pub fn instantiate_fees(
     storage: &mut dyn Storage,
@@ -1,16 +1,17 @@
 #![cfg(not(tarpaulin_include))]
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, CanonicalAddr, Decimal, DepsMut, StdError, Uint128};
+use cosmwasm_std::{Addr, CanonicalAddr, Decimal, DepsMut, Env, StdError, Uint128};
 use cw_storage_plus::Item;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::helpers::instantiate_fees;
-use pool_network::asset::{AssetInfo, AssetInfoRaw, PairType};
-use pool_network::pair::{Config, FeatureToggle};
+use crate::helpers::{instantiate_fees, query_balance_pools};
+use pool_network::asset::{AssetInfo, AssetInfoRaw, PairInfoRaw, PairType};
+use pool_network::burn_fee::BurnFeeTarget;
+use pool_network::pair::{Config, FeatureToggle, PoolFee};
 use nico_teen::fee::Fee;
 
-use crate::state::{ALL_TIME_BURNED_FEES, CONFIG, PAIR_INFO};
+use crate::state::{ALL_TIME_BURNED_FEES, CONFIG, PAIR_INFO, RESERVES};
 
 /// Migrate state of the factory from PascalCase to snake_case for the following items:
 /// [`PairInfoRaw`], [`PairInfo`]
@@ -81,11 +82,20 @@ pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
         pub swap_fee: Fee,
     }
 
+    #[cw_serde]
+    struct ConfigV120 {
+        pub owner: Addr,
+        pub fee_collector_addr: Addr,
+        pub pool_fees: PoolFee,
+        pub feature_toggle: FeatureToggle,
+    }
+
     const CONFIG_V110: Item<ConfigV110> = Item::new("config");
+    const CONFIG_V120: Item<ConfigV120> = Item::new("config");
     let config_v110 = CONFIG_V110.load(deps.storage)?;
 
     // Add burn fee to config. Zero fee is used as default.
-    let config = Config {
+    let config = ConfigV120 {
         owner: config_v110.owner,
         fee_collector_addr: config_v110.fee_collector_addr,
         pool_fees: pool_network::pair::PoolFee {
@@ -98,10 +108,19 @@ pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
         feature_toggle: config_v110.feature_toggle,
     };
 
-    CONFIG.save(deps.storage, &config)?;
+    CONFIG_V120.save(deps.storage, &config)?;
 
     // Instantiates the ALL_TIME_BURNED_FEES
-    let pair_info = PAIR_INFO.load(deps.storage)?;
+    #[cw_serde]
+    struct PairInfoRawV110 {
+        pub asset_infos: [AssetInfoRaw; 2],
+        pub contract_addr: CanonicalAddr,
+        pub liquidity_token: CanonicalAddr,
+        pub asset_decimals: [u8; 2],
+    }
+
+    const PAIR_INFO_V110: Item<PairInfoRawV110> = Item::new("pair_info");
+    let pair_info = PAIR_INFO_V110.load(deps.storage)?;
     let asset_info_0 = pair_info.asset_infos[0].to_normal(deps.api)?;
     let asset_info_1 = pair_info.asset_infos[1].to_normal(deps.api)?;
 
@@ -153,3 +172,71 @@ pub fn migrate_to_v130(deps: DepsMut) -> Result<(), StdError> {
 
     Ok(())
 }
+
+/// Migrate to the token factory LP tokens, storing the liquidity token as a cw20 [AssetInfoRaw]
+pub fn migrate_to_v140(deps: DepsMut) -> Result<(), StdError> {
+    #[cw_serde]
+    pub struct PairInfoRawV130 {
+        pub asset_infos: [AssetInfoRaw; 2],
+        pub contract_addr: CanonicalAddr,
+        pub liquidity_token: CanonicalAddr,
+        pub asset_decimals: [u8; 2],
+        pub pair_type: PairType,
+    }
+
+    pub const PAIR_INFO_V130: Item<PairInfoRawV130> = Item::new("pair_info");
+
+    let pair_info = PAIR_INFO_V130.load(deps.storage)?;
+    PAIR_INFO.save(
+        deps.storage,
+        &PairInfoRaw {
+            asset_infos: pair_info.asset_infos,
+            contract_addr: pair_info.contract_addr,
+            liquidity_token: AssetInfoRaw::Token {
+                contract_addr: pair_info.liquidity_token,
+            },
+            asset_decimals: pair_info.asset_decimals,
+            pair_type: pair_info.pair_type,
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Migrate to the burn fee targets, burning the burn fees as before
+pub fn migrate_to_v160(deps: DepsMut) -> Result<(), StdError> {
+    #[cw_serde]
+    struct ConfigV150 {
+        pub owner: Addr,
+        pub fee_collector_addr: Addr,
+        pub pool_fees: PoolFee,
+        pub feature_toggle: FeatureToggle,
+    }
+
+    const CONFIG_V150: Item<ConfigV150> = Item::new("config");
+    let config_v150 = CONFIG_V150.load(deps.storage)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            owner: config_v150.owner,
+            fee_collector_addr: config_v150.fee_collector_addr,
+            pool_fees: config_v150.pool_fees,
+            feature_toggle: config_v150.feature_toggle,
+            burn_fee_target: BurnFeeTarget::Burn,
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Migrate to the reserves tracked by the pool, initialized from the balances of the pool minus
+/// the protocol fees not collected yet, which is how the pools were derived before.
+pub fn migrate_to_v180(deps: DepsMut, env: Env) -> Result<(), StdError> {
+    let pair_info = PAIR_INFO.load(deps.storage)?;
+    let pools = query_balance_pools(deps.as_ref(), &pair_info, env.contract.address)?;
+
+    RESERVES.save(deps.storage, &[pools[0].amount, pools[1].amount])?;
+
+    Ok(())
+}
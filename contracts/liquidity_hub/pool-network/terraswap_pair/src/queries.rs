@@ -1,21 +1,20 @@
-use std::cmp::Ordering;
-
-use cosmwasm_std::{Decimal256, Deps, Fraction, StdResult, Uint128};
-use cw_storage_plus::Item;
+use cosmwasm_std::{Deps, Env, Order, StdResult, Uint128};
+use cw_storage_plus::{Bound, Item};
 
 use pool_network::asset::{Asset, PairInfo, PairInfoRaw, PairType};
+use pool_network::epoch::{validate_epoch_range, EpochFees, ProtocolFeesByEpochResponse};
+use pool_network::lp_token::query_lp_total_supply;
 use pool_network::pair::{
     ConfigResponse, PoolResponse, ProtocolFeesResponse, ReverseSimulationResponse,
     SimulationResponse,
 };
-use pool_network::querier::query_token_info;
 
 use crate::error::ContractError;
-use crate::helpers::{
-    self, calculate_stableswap_y, get_protocol_fee_for_asset, StableSwapDirection,
+use crate::helpers;
+use crate::state::{
+    get_fees_for_asset, get_pools, BURNED_FEES_BY_EPOCH, BUY_BACK_FEES, CONFIG, EPOCH_CONFIG,
+    PAIR_INFO, PROTOCOL_FEES_BY_EPOCH,
 };
-use crate::math::Decimal256Helper;
-use crate::state::{get_fees_for_asset, COLLECTED_PROTOCOL_FEES, CONFIG, PAIR_INFO};
 
 /// Queries the [PairInfo] of the pool
 pub fn query_pair_info(deps: Deps) -> Result<PairInfo, ContractError> {
@@ -28,29 +27,12 @@ pub fn query_pair_info(deps: Deps) -> Result<PairInfo, ContractError> {
 /// Queries the Pool info, i.e. Assets and total share
 pub fn query_pool(deps: Deps) -> Result<PoolResponse, ContractError> {
     let pair_info: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
-    let contract_addr = deps.api.addr_humanize(&pair_info.contract_addr)?;
-
-    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
-    let assets = pair_info
-        .query_pools(&deps.querier, deps.api, contract_addr)?
-        .iter()
-        .map(|asset| {
-            // deduct protocol fee for that asset
-            let protocol_fee =
-                get_protocol_fee_for_asset(collected_protocol_fees.clone(), asset.clone().get_id());
-
-            Asset {
-                info: asset.info.clone(),
-                amount: asset.amount - protocol_fee,
-            }
-        })
-        .collect();
+    let assets = get_pools(deps.storage, deps.api, &pair_info)?.to_vec();
 
-    let total_share: Uint128 = query_token_info(
+    let total_share: Uint128 = query_lp_total_supply(
         &deps.querier,
-        deps.api.addr_humanize(&pair_info.liquidity_token)?,
-    )?
-    .total_supply;
+        &pair_info.liquidity_token.to_normal(deps.api)?,
+    )?;
 
     let resp = PoolResponse {
         assets,
@@ -67,29 +49,13 @@ pub fn query_simulation(
 ) -> Result<SimulationResponse, ContractError> {
     let pair_info = PAIR_INFO.load(deps.storage)?;
 
-    let contract_addr = deps.api.addr_humanize(&pair_info.contract_addr)?;
-
     let offer_pool: Asset;
     let offer_decimal;
 
     let ask_pool: Asset;
     let ask_decimal;
 
-    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
-
-    // To calculate pool amounts properly we should subtract the protocol fees from the pool
-    let pools = pair_info
-        .query_pools(&deps.querier, deps.api, contract_addr)?
-        .into_iter()
-        .map(|mut pool| {
-            // subtract the protocol fee from the pool
-            let protocol_fee =
-                get_protocol_fee_for_asset(collected_protocol_fees.clone(), pool.clone().get_id());
-            pool.amount = pool.amount.checked_sub(protocol_fee)?;
-
-            Ok(pool)
-        })
-        .collect::<StdResult<Vec<_>>>()?;
+    let pools = get_pools(deps.storage, deps.api, &pair_info)?;
 
     if offer_asset.info.equal(&pools[0].info) {
         offer_pool = pools[0].clone();
@@ -119,12 +85,29 @@ pub fn query_simulation(
         ask_decimal,
     )?;
 
+    let price_impact_computation = helpers::compute_price_impact(
+        offer_pool.amount,
+        ask_pool.amount,
+        offer_asset.amount,
+        swap_computation
+            .return_amount
+            .checked_add(swap_computation.protocol_fee_amount)?
+            .checked_add(swap_computation.burn_fee_amount)?,
+        swap_computation.swap_fee_amount,
+        &pair_info.pair_type,
+        offer_decimal,
+        ask_decimal,
+    )?;
+
     Ok(SimulationResponse {
         return_amount: swap_computation.return_amount,
         spread_amount: swap_computation.spread_amount,
         swap_fee_amount: swap_computation.swap_fee_amount,
         protocol_fee_amount: swap_computation.protocol_fee_amount,
         burn_fee_amount: swap_computation.burn_fee_amount,
+        pre_trade_spot_price: Some(price_impact_computation.pre_trade_spot_price),
+        post_trade_spot_price: Some(price_impact_computation.post_trade_spot_price),
+        price_impact: Some(price_impact_computation.price_impact),
     })
 }
 
@@ -136,23 +119,7 @@ pub fn query_reverse_simulation(
 ) -> Result<ReverseSimulationResponse, ContractError> {
     let pair_info: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
 
-    let contract_addr = deps.api.addr_humanize(&pair_info.contract_addr)?;
-
-    // To calculate pool amounts properly we should subtract the protocol fees from the pool
-    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
-
-    let pools = pair_info
-        .query_pools(&deps.querier, deps.api, contract_addr)?
-        .into_iter()
-        .map(|mut pool| {
-            // subtract the protocol fee from the pool
-            let protocol_fee =
-                get_protocol_fee_for_asset(collected_protocol_fees.clone(), pool.clone().get_id());
-            pool.amount = pool.amount.checked_sub(protocol_fee)?;
-
-            Ok(pool)
-        })
-        .collect::<StdResult<Vec<_>>>()?;
+    let pools = get_pools(deps.storage, deps.api, &pair_info)?;
 
     let offer_pool: Asset;
     let ask_pool: Asset;
@@ -177,82 +144,48 @@ pub fn query_reverse_simulation(
 
     let pool_fees = CONFIG.load(deps.storage)?.pool_fees;
 
-    match pair_info.pair_type {
-        PairType::ConstantProduct => {
-            let offer_amount_computation = helpers::compute_offer_amount(
-                offer_pool.amount,
-                ask_pool.amount,
-                ask_asset.amount,
-                pool_fees,
-            )?;
-
-            Ok(ReverseSimulationResponse {
-                offer_amount: offer_amount_computation.offer_amount,
-                spread_amount: offer_amount_computation.spread_amount,
-                swap_fee_amount: offer_amount_computation.swap_fee_amount,
-                protocol_fee_amount: offer_amount_computation.protocol_fee_amount,
-                burn_fee_amount: offer_amount_computation.burn_fee_amount,
-            })
-        }
-        PairType::StableSwap { amp } => {
-            let offer_pool = Decimal256::decimal_with_precision(offer_pool.amount, offer_decimal)?;
-            let ask_pool = Decimal256::decimal_with_precision(ask_pool.amount, ask_decimal)?;
-
-            let before_fees = (Decimal256::one()
-                .checked_sub(pool_fees.protocol_fee.to_decimal_256())?
-                .checked_sub(pool_fees.swap_fee.to_decimal_256())?
-                .checked_sub(pool_fees.burn_fee.to_decimal_256())?)
-            .inv()
-            .unwrap_or_else(Decimal256::one)
-            .checked_mul(Decimal256::decimal_with_precision(
-                ask_asset.amount,
-                ask_decimal,
-            )?)?;
-
-            let before_fees_offer = before_fees.to_uint256_with_precision(offer_decimal.into())?;
-            let before_fees_ask = before_fees.to_uint256_with_precision(ask_decimal.into())?;
-
-            let max_precision = offer_decimal.max(ask_decimal);
-
-            let new_offer_pool_amount = calculate_stableswap_y(
-                offer_pool,
-                ask_pool,
-                before_fees,
-                &amp,
-                max_precision,
-                StableSwapDirection::ReverseSimulate,
-            )?;
-
-            let offer_amount = new_offer_pool_amount.checked_sub(Uint128::try_from(
-                offer_pool.to_uint256_with_precision(u32::from(max_precision))?,
-            )?)?;
-
-            // convert into the original offer precision
-            let offer_amount = match max_precision.cmp(&offer_decimal) {
-                Ordering::Equal => offer_amount,
-                // note that Less should never happen (as max_precision = max(offer_decimal, ask_decimal))
-                Ordering::Less => offer_amount.checked_mul(Uint128::new(
-                    10u128.pow((offer_decimal - max_precision).into()),
-                ))?,
-                Ordering::Greater => offer_amount.checked_div(Uint128::new(
-                    10u128.pow((max_precision - offer_decimal).into()),
-                ))?,
-            };
-
-            let spread_amount = offer_amount.saturating_sub(Uint128::try_from(before_fees_offer)?);
-            let swap_fee_amount = pool_fees.swap_fee.compute(before_fees_ask);
-            let protocol_fee_amount = pool_fees.protocol_fee.compute(before_fees_ask);
-            let burn_fee_amount = pool_fees.burn_fee.compute(before_fees_ask);
-
-            Ok(ReverseSimulationResponse {
-                offer_amount,
-                spread_amount,
-                swap_fee_amount: swap_fee_amount.try_into()?,
-                protocol_fee_amount: protocol_fee_amount.try_into()?,
-                burn_fee_amount: burn_fee_amount.try_into()?,
-            })
-        }
-    }
+    let offer_amount_computation = match pair_info.pair_type {
+        PairType::ConstantProduct => helpers::compute_offer_amount(
+            offer_pool.amount,
+            ask_pool.amount,
+            ask_asset.amount,
+            pool_fees,
+        )?,
+        PairType::StableSwap { amp } => helpers::compute_stableswap_offer_amount(
+            offer_pool.amount,
+            ask_pool.amount,
+            ask_asset.amount,
+            pool_fees,
+            &amp,
+            offer_decimal,
+            ask_decimal,
+        )?,
+    };
+
+    let price_impact_computation = helpers::compute_price_impact(
+        offer_pool.amount,
+        ask_pool.amount,
+        offer_amount_computation.offer_amount,
+        ask_asset
+            .amount
+            .checked_add(offer_amount_computation.protocol_fee_amount)?
+            .checked_add(offer_amount_computation.burn_fee_amount)?,
+        offer_amount_computation.swap_fee_amount,
+        &pair_info.pair_type,
+        offer_decimal,
+        ask_decimal,
+    )?;
+
+    Ok(ReverseSimulationResponse {
+        offer_amount: offer_amount_computation.offer_amount,
+        spread_amount: offer_amount_computation.spread_amount,
+        swap_fee_amount: offer_amount_computation.swap_fee_amount,
+        protocol_fee_amount: offer_amount_computation.protocol_fee_amount,
+        burn_fee_amount: offer_amount_computation.burn_fee_amount,
+        pre_trade_spot_price: Some(price_impact_computation.pre_trade_spot_price),
+        post_trade_spot_price: Some(price_impact_computation.post_trade_spot_price),
+        price_impact: Some(price_impact_computation.price_impact),
+    })
 }
 
 /// Queries the [Config], which contains the owner, pool_fees and feature_toggle
@@ -286,3 +219,67 @@ pub fn query_fees(
     let fees = fees_storage_item.load(deps.storage)?;
     Ok(ProtocolFeesResponse { fees })
 }
+
+/// Queries the burn fees accrued by the pool, waiting to be bought back
+pub fn query_buy_back_fees(deps: Deps) -> Result<ProtocolFeesResponse, ContractError> {
+    let fees = BUY_BACK_FEES.may_load(deps.storage)?.unwrap_or_default();
+    Ok(ProtocolFeesResponse { fees })
+}
+
+/// Queries the protocol and burned fees accrued by the pool in each epoch from `start_epoch` to
+/// `end_epoch`, both included
+pub fn query_fees_by_epoch(
+    deps: Deps,
+    env: Env,
+    start_epoch: u64,
+    end_epoch: u64,
+) -> Result<ProtocolFeesByEpochResponse, ContractError> {
+    validate_epoch_range(start_epoch, end_epoch)?;
+
+    let epoch_config = EPOCH_CONFIG.may_load(deps.storage)?.unwrap_or_default();
+
+    let mut epochs: Vec<EpochFees> = PROTOCOL_FEES_BY_EPOCH
+        .range(
+            deps.storage,
+            Some(Bound::inclusive(start_epoch)),
+            Some(Bound::inclusive(end_epoch)),
+            Order::Ascending,
+        )
+        .map(|item| {
+            let (epoch, protocol_fees) = item?;
+            Ok(EpochFees {
+                epoch,
+                protocol_fees,
+                burned_fees: vec![],
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    // burn fees may be accrued in epochs without protocol fees, i.e. with a zero protocol fee
+    for item in BURNED_FEES_BY_EPOCH.range(
+        deps.storage,
+        Some(Bound::inclusive(start_epoch)),
+        Some(Bound::inclusive(end_epoch)),
+        Order::Ascending,
+    ) {
+        let (epoch, burned_fees) = item?;
+        match epochs
+            .iter_mut()
+            .find(|epoch_fees| epoch_fees.epoch == epoch)
+        {
+            Some(epoch_fees) => epoch_fees.burned_fees = burned_fees,
+            None => epochs.push(EpochFees {
+                epoch,
+                protocol_fees: vec![],
+                burned_fees,
+            }),
+        }
+    }
+    epochs.sort_by_key(|epoch_fees| epoch_fees.epoch);
+
+    Ok(ProtocolFeesByEpochResponse {
+        current_epoch: epoch_config.epoch(env.block.time),
+        epoch_config,
+        epochs,
+    })
+}
@@ -1,12 +1,17 @@
-use cosmwasm_std::{StdError, StdResult, Storage, Uint128};
-use cw_storage_plus::Item;
+use cosmwasm_std::{Api, StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
 
 use pool_network::asset::{Asset, PairInfoRaw};
+use pool_network::epoch::{add_epoch_fee, EpochConfig};
 use pool_network::pair::Config;
 
 pub const PAIR_INFO: Item<PairInfoRaw> = Item::new("pair_info");
 pub const CONFIG: Item<Config> = Item::new("config");
 
+// The reserves of the pool, in the order of the pair assets. The protocol fees not collected yet
+// and the tokens sent to the pool outside of its messages are not part of the reserves.
+pub const RESERVES: Item<[Uint128; 2]> = Item::new("reserves");
+
 // Fees that have been accrued by the pool, still unclaimed by the fee collector
 pub const COLLECTED_PROTOCOL_FEES: Item<Vec<Asset>> = Item::new("collected_protocol_fees");
 // Fees that have been accrued by the pool since the pool's inception
@@ -14,6 +19,16 @@ pub const ALL_TIME_COLLECTED_PROTOCOL_FEES: Item<Vec<Asset>> =
     Item::new("all_time_collected_protocol_fees");
 // Fees that have been burned by the pool since the pool's inception
 pub const ALL_TIME_BURNED_FEES: Item<Vec<Asset>> = Item::new("all_time_burned_fees");
+// Burn fees accrued by the pool, waiting to be bought back by the keeper of the buy-back burn fee
+// target. They are not part of the reserves.
+pub const BUY_BACK_FEES: Item<Vec<Asset>> = Item::new("buy_back_fees");
+
+// The epochs the fees are bucketed by. The default epoch config is used if not set
+pub const EPOCH_CONFIG: Item<EpochConfig> = Item::new("epoch_config");
+// Protocol fees accrued by the pool, by epoch
+pub const PROTOCOL_FEES_BY_EPOCH: Map<u64, Vec<Asset>> = Map::new("protocol_fees_by_epoch");
+// Fees burned by the pool, by epoch
+pub const BURNED_FEES_BY_EPOCH: Map<u64, Vec<Asset>> = Map::new("burned_fees_by_epoch");
 
 /// Stores the fee for an asset in the given fees_storage_item
 pub fn store_fee(
@@ -40,6 +55,23 @@ pub fn store_fee(
     fees_storage_item.save(storage, &fees)
 }
 
+/// Adds the fee to the given fees_storage_item, which may not hold fees of that asset yet, e.g. the
+/// fees burned after being bought back
+pub fn add_fee(
+    storage: &mut dyn Storage,
+    fee: Asset,
+    fees_storage_item: Item<Vec<Asset>>,
+) -> StdResult<()> {
+    if fee.amount.is_zero() {
+        return Ok(());
+    }
+
+    let mut fees = fees_storage_item.may_load(storage)?.unwrap_or_default();
+    add_epoch_fee(&mut fees, fee)?;
+
+    fees_storage_item.save(storage, &fees)
+}
+
 /// Gets the fees for an asset from the given fees_storage_item
 pub fn get_fees_for_asset(
     storage: &dyn Storage,
@@ -61,3 +93,43 @@ pub fn get_fees_for_asset(
         )))
     }
 }
+
+/// Stores the fee for an asset in the given epoch of the fees_by_epoch map
+pub fn store_epoch_fee(
+    storage: &mut dyn Storage,
+    epoch: u64,
+    fee: Asset,
+    fees_by_epoch: Map<u64, Vec<Asset>>,
+) -> StdResult<()> {
+    if fee.amount.is_zero() {
+        return Ok(());
+    }
+
+    fees_by_epoch.update(storage, epoch, |fees| -> StdResult<_> {
+        let mut fees = fees.unwrap_or_default();
+        add_epoch_fee(&mut fees, fee)?;
+        Ok(fees)
+    })?;
+
+    Ok(())
+}
+
+/// Gets the pools of the pair, i.e. the pair assets along with their reserves
+pub fn get_pools(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    pair_info: &PairInfoRaw,
+) -> StdResult<[Asset; 2]> {
+    let reserves = RESERVES.load(storage)?;
+
+    Ok([
+        Asset {
+            info: pair_info.asset_infos[0].to_normal(api)?,
+            amount: reserves[0],
+        },
+        Asset {
+            info: pair_info.asset_infos[1].to_normal(api)?,
+            amount: reserves[1],
+        },
+    ])
+}
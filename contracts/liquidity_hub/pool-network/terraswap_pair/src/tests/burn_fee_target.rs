@@ -0,0 +1,382 @@
+use crate::contract::{execute, instantiate, reply, BUY_BACK_REPLY_ID};
+use crate::error::ContractError;
+use crate::queries::{query_buy_back_fees, query_fees};
+use crate::state::{ALL_TIME_BURNED_FEES, RESERVES};
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockStorage, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    coins, to_binary, BankMsg, Coin, CosmosMsg, Decimal, Event, OwnedDeps, Reply, Response, SubMsg,
+    SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use pool_network::asset::{Asset, AssetInfo, PairType};
+use pool_network::burn_fee::BurnFeeTarget;
+use pool_network::mock_querier::{mock_dependencies, WasmMockQuerier};
+use pool_network::pair::ExecuteMsg::UpdateConfig;
+use pool_network::pair::{ExecuteMsg, InstantiateMsg, PoolFee};
+use pool_network::router::{Cw20HookMsg as RouterCw20HookMsg, SwapOperation};
+use nico_teen::fee::Fee;
+
+/// Instantiates a uusd-asset0000 pair with a burn fee, using the given burn fee target
+fn instantiate_pair(
+    burn_fee_target: BurnFeeTarget,
+) -> OwnedDeps<MockStorage, MockApi, WasmMockQuerier> {
+    let mut deps = mock_dependencies(&[Coin {
+        denom: "uusd".to_string(),
+        amount: Uint128::from(30000000000u128 + 1500000000u128),
+    }]);
+
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+        ),
+        (
+            &"asset0000".to_string(),
+            &[(
+                &MOCK_CONTRACT_ADDR.to_string(),
+                &Uint128::from(20000000000u128),
+            )],
+        ),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::from_ratio(1u128, 1000u128),
+            },
+            swap_fee: Fee {
+                share: Decimal::from_ratio(3u128, 1000u128),
+            },
+            burn_fee: Fee {
+                share: Decimal::from_ratio(1u128, 1000u128),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    // store liquidity token
+    let reply_msg = Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(
+                vec![
+                    10, 13, 108, 105, 113, 117, 105, 100, 105, 116, 121, 48, 48, 48, 48,
+                ]
+                .into(),
+            ),
+        }),
+    };
+    reply(deps.as_mut(), env.clone(), reply_msg).unwrap();
+
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[
+                Uint128::from(30000000000u128),
+                Uint128::from(20000000000u128),
+            ],
+        )
+        .unwrap();
+
+    let update_config_message = UpdateConfig {
+        owner: None,
+        fee_collector_addr: None,
+        pool_fees: None,
+        feature_toggle: None,
+        burn_fee_target: Some(burn_fee_target),
+        epoch_config: None,
+    };
+    execute(deps.as_mut(), env, info, update_config_message).unwrap();
+
+    deps
+}
+
+/// Swaps uusd for asset0000, returning the response and the burn fee taken on asset0000
+fn swap_uusd(deps: &mut OwnedDeps<MockStorage, MockApi, WasmMockQuerier>) -> (Response, Uint128) {
+    let offer_amount = Uint128::from(1500000000u128);
+    let msg = ExecuteMsg::Swap {
+        offer_asset: Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            amount: offer_amount,
+        },
+        belief_price: None,
+        max_spread: None,
+        to: None,
+    };
+    let info = mock_info("addr0000", &coins(offer_amount.u128(), "uusd"));
+    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let burn_fee_amount = res
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == "burn_fee_amount")
+        .unwrap()
+        .value
+        .parse::<Uint128>()
+        .unwrap();
+    assert!(!burn_fee_amount.is_zero());
+
+    (res, burn_fee_amount)
+}
+
+fn query_burned_fees(deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>) -> Uint128 {
+    query_fees(
+        deps.as_ref(),
+        Some("asset0000".to_string()),
+        None,
+        ALL_TIME_BURNED_FEES,
+        None,
+    )
+    .unwrap()
+    .fees
+    .first()
+    .unwrap()
+    .amount
+}
+
+#[test]
+fn swap_sends_burn_fees_to_target_address() {
+    let mut deps = instantiate_pair(BurnFeeTarget::Send {
+        address: "treasury".to_string(),
+    });
+
+    let (res, burn_fee_amount) = swap_uusd(&mut deps);
+    assert_eq!(
+        res.messages.last().unwrap(),
+        &SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "asset0000".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "treasury".to_string(),
+                amount: burn_fee_amount,
+            })
+            .unwrap(),
+            funds: vec![],
+        }))
+    );
+
+    // the burn fees sent away are not accounted as burned
+    assert_eq!(query_burned_fees(&deps), Uint128::zero());
+}
+
+#[test]
+fn swap_accrues_burn_fees_to_buy_back() {
+    let mut deps = instantiate_pair(BurnFeeTarget::BuyBackAndBurn {
+        router_addr: "router".to_string(),
+        asset_info: AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        keeper: "keeper".to_string(),
+    });
+
+    // the burn fees are accrued rather than swapped along with the swap
+    let (res, burn_fee_amount) = swap_uusd(&mut deps);
+    assert!(res.messages.iter().all(|msg| msg.id != BUY_BACK_REPLY_ID));
+    assert_eq!(query_burned_fees(&deps), Uint128::zero());
+    assert_eq!(
+        query_buy_back_fees(deps.as_ref()).unwrap().fees,
+        vec![Asset {
+            info: AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+            amount: burn_fee_amount,
+        }]
+    );
+
+    // the accrued burn fees are not part of the pool
+    let protocol_fee_amount = res
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == "protocol_fee_amount")
+        .unwrap()
+        .value
+        .parse::<Uint128>()
+        .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::Sync {},
+    )
+    .unwrap();
+    assert_eq!(
+        RESERVES.load(&deps.storage).unwrap()[1],
+        Uint128::from(20000000000u128) - protocol_fee_amount - burn_fee_amount
+    );
+}
+
+#[test]
+fn keeper_buys_back_and_burns_burn_fees() {
+    let mut deps = instantiate_pair(BurnFeeTarget::BuyBackAndBurn {
+        router_addr: "router".to_string(),
+        asset_info: AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        keeper: "keeper".to_string(),
+    });
+    let (_, burn_fee_amount) = swap_uusd(&mut deps);
+
+    let buy_back = ExecuteMsg::BuyBack {
+        asset_info: AssetInfo::Token {
+            contract_addr: "asset0000".to_string(),
+        },
+        minimum_receive: Uint128::new(900u128),
+    };
+
+    // only the keeper can buy back
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        buy_back.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("keeper", &[]),
+        buy_back.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::reply_on_success(
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "asset0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: "router".to_string(),
+                    amount: burn_fee_amount,
+                    msg: to_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+                        operations: vec![SwapOperation::TerraSwap {
+                            offer_asset_info: AssetInfo::Token {
+                                contract_addr: "asset0000".to_string(),
+                            },
+                            ask_asset_info: AssetInfo::NativeToken {
+                                denom: "uusd".to_string(),
+                            },
+                            pair_type: None,
+                            fee_tier: None,
+                        }],
+                        minimum_receive: Some(Uint128::new(900u128)),
+                        to: None,
+                    })
+                    .unwrap(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            BUY_BACK_REPLY_ID,
+        )]
+    );
+    assert!(query_buy_back_fees(deps.as_ref()).unwrap().fees.is_empty());
+
+    // nothing left to buy back with
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("keeper", &[]),
+        buy_back,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoBuyBackFees("asset0000".to_string()));
+
+    // the tokens bought back are burned in the reply, and accounted as burned
+    let reply_msg = Reply {
+        id: BUY_BACK_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![Event::new("wasm").add_attributes(vec![
+                ("action", "swap"),
+                ("receiver", MOCK_CONTRACT_ADDR),
+                ("ask_asset", "uusd"),
+                ("return_amount", "1000"),
+            ])],
+            data: None,
+        }),
+    };
+    let res = reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Burn {
+            amount: coins(1000u128, "uusd"),
+        }))]
+    );
+    assert_eq!(
+        query_fees(
+            deps.as_ref(),
+            Some("uusd".to_string()),
+            None,
+            ALL_TIME_BURNED_FEES,
+            None,
+        )
+        .unwrap()
+        .fees,
+        vec![Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            amount: Uint128::new(1000u128),
+        }]
+    );
+    assert_eq!(query_burned_fees(&deps), Uint128::zero());
+}
+
+#[test]
+fn burn_fees_to_buy_back_are_disposed_of_by_a_new_target() {
+    let mut deps = instantiate_pair(BurnFeeTarget::BuyBackAndBurn {
+        router_addr: "router".to_string(),
+        asset_info: AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        keeper: "keeper".to_string(),
+    });
+    let (_, burn_fee_amount) = swap_uusd(&mut deps);
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        UpdateConfig {
+            owner: None,
+            fee_collector_addr: None,
+            pool_fees: None,
+            feature_toggle: None,
+            burn_fee_target: Some(BurnFeeTarget::Burn),
+            epoch_config: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "asset0000".to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Burn {
+                amount: burn_fee_amount,
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+    assert_eq!(query_burned_fees(&deps), burn_fee_amount);
+    assert!(query_buy_back_fees(deps.as_ref()).unwrap().fees.is_empty());
+}
@@ -48,6 +48,8 @@ fn test_feature_toggle_swap_disabled() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -65,6 +67,8 @@ fn test_feature_toggle_swap_disabled() {
             deposits_enabled: true,
             swaps_enabled: false,
         }),
+        burn_fee_target: None,
+        epoch_config: None,
     };
     execute(deps.as_mut(), env.clone(), info, update_config_message).unwrap();
 
@@ -157,6 +161,8 @@ fn test_feature_toggle_withdrawals_disabled() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -173,6 +179,8 @@ fn test_feature_toggle_withdrawals_disabled() {
             deposits_enabled: true,
             swaps_enabled: true,
         }),
+        burn_fee_target: None,
+        epoch_config: None,
     };
     execute(deps.as_mut(), env, info, update_config_message).unwrap();
 
@@ -232,6 +240,8 @@ fn test_feature_toggle_deposits_disabled() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -248,6 +258,8 @@ fn test_feature_toggle_deposits_disabled() {
             deposits_enabled: false,
             swaps_enabled: true,
         }),
+        burn_fee_target: None,
+        epoch_config: None,
     };
     execute(deps.as_mut(), env, info, update_config_message).unwrap();
 
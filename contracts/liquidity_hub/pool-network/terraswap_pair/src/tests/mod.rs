@@ -1,8 +1,13 @@
+mod burn_fee_target;
 mod feature_toggle;
 mod protocol_fees;
 mod provide_liquidity;
 mod queries;
+mod reserves;
 mod stableswap;
 mod swap;
+mod swap_math;
 mod testing;
+#[cfg(feature = "token_factory")]
+mod token_factory;
 mod withdrawals;
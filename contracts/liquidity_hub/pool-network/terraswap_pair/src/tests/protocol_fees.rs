@@ -1,15 +1,17 @@
-use crate::contract::{execute, instantiate, reply};
+use crate::contract::{execute, instantiate, query, reply};
+use crate::error::ContractError;
 use crate::queries::query_fees;
-use crate::state::{ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES};
+use crate::state::{ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES, RESERVES};
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    to_binary, BankMsg, Coin, CosmosMsg, Decimal, Reply, StdError, SubMsg, SubMsgResponse,
-    SubMsgResult, Uint128, WasmMsg,
+    from_binary, to_binary, BankMsg, Coin, CosmosMsg, Decimal, Reply, StdError, SubMsg,
+    SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 use pool_network::asset::{Asset, AssetInfo, PairType};
+use pool_network::epoch::{EpochConfig, EpochFees, ProtocolFeesByEpochResponse};
 use pool_network::mock_querier::mock_dependencies;
-use pool_network::pair::{Cw20HookMsg, ExecuteMsg, InstantiateMsg, PoolFee};
+use pool_network::pair::{Cw20HookMsg, ExecuteMsg, InstantiateMsg, PoolFee, QueryMsg};
 use nico_teen::fee::Fee;
 
 #[test]
@@ -60,6 +62,8 @@ fn test_protocol_fees() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -83,6 +87,14 @@ fn test_protocol_fees() {
 
     let _res = reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
 
+    // the reserves of the pool, the offer is not part of them
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[collateral_pool_amount, asset_pool_amount],
+        )
+        .unwrap();
+
     // first swap
     let msg = ExecuteMsg::Swap {
         offer_asset: Asset {
@@ -160,9 +172,10 @@ fn test_protocol_fees() {
     );
     execute(deps.as_mut(), env, info, msg).unwrap();
 
-    // ask_amount = ((ask_pool - accrued protocol fees) * offer_amount / (offer_pool - accrued protocol fees + offer_amount))
-    // 952.335600 = (20000 - 0.952380 ) * 1500 / (30000 - 0 + 1500) - swap_fee - protocol_fee
-    let expected_ret_amount = Uint128::from(952_335_600u128);
+    // ask_amount = (ask_pool * offer_amount / (offer_pool + offer_amount)), the reserves having been
+    // updated by the first swap
+    // 865.930735 = 19050.476190 * 1500 / (31500 + 1500) - swap_fee - protocol_fee
+    let expected_ret_amount = Uint128::from(865_930_735u128);
     let new_expected_protocol_fee_amount = expected_ret_amount.multiply_ratio(1u128, 1000u128); // 0.1%
 
     // the new protocol fees should have increased from the previous time
@@ -243,6 +256,8 @@ fn test_collect_protocol_fees_successful() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -266,6 +281,14 @@ fn test_collect_protocol_fees_successful() {
 
     let _res = reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
 
+    // the reserves of the pool, the offer is not part of them
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[collateral_pool_amount, asset_pool_amount],
+        )
+        .unwrap();
+
     // first swap, native -> token
     let msg = ExecuteMsg::Swap {
         offer_asset: Asset {
@@ -307,9 +330,10 @@ fn test_collect_protocol_fees_successful() {
     let info = mock_info("asset0000", &[]);
     execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
-    // ask_amount = ((ask_pool - accrued protocol fees) * offer_amount / (offer_pool - accrued protocol fees + offer_amount))
-    // 2362.612505 = (31500 - 0) * 1500 / (18500 - 0.952380 + 1500) - swap_fee - protocol_fee
-    let expected_ret_amount = Uint128::from(2_362_612_505u128);
+    // ask_amount = (ask_pool * offer_amount / (offer_pool + offer_amount)), the reserves having been
+    // updated by the first swap
+    // 2299.216794 = 31500 * 1500 / (19050.476190 + 1500) - swap_fee - protocol_fee
+    let expected_ret_amount = Uint128::from(2_299_216_794u128);
     let expected_protocol_fee_native_amount = expected_ret_amount.multiply_ratio(1u128, 1000u128); // 0.001%
 
     // as we swapped both native and token, we should have collected fees in both of them
@@ -470,6 +494,8 @@ fn test_collect_protocol_fees_successful_1_fee_only() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -493,6 +519,14 @@ fn test_collect_protocol_fees_successful_1_fee_only() {
 
     let _res = reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
 
+    // the reserves of the pool, the offer is not part of them
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[collateral_pool_amount, asset_pool_amount],
+        )
+        .unwrap();
+
     // swap native -> token
     let msg = ExecuteMsg::Swap {
         offer_asset: Asset {
@@ -679,3 +713,217 @@ fn protocol_fees() {
     };
     assert_eq!(protocol_fee.is_valid(), Ok(()));
 }
+
+#[test]
+fn protocol_fees_by_epoch() {
+    let mut deps = mock_dependencies(&[Coin {
+        denom: "uusd".to_string(),
+        amount: Uint128::from(30_000_000_000u128 + 1_500_000_000u128),
+    }]);
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+        ),
+        (
+            &"asset0000".to_string(),
+            &[(
+                &MOCK_CONTRACT_ADDR.to_string(),
+                &Uint128::from(20_000_000_000u128),
+            )],
+        ),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::from_ratio(1u128, 1000u128),
+            },
+            swap_fee: Fee {
+                share: Decimal::from_ratio(3u128, 1000u128),
+            },
+            burn_fee: Fee {
+                share: Decimal::from_ratio(1u128, 1000u128),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
+    };
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[
+                Uint128::from(30_000_000_000u128),
+                Uint128::from(20_000_000_000u128),
+            ],
+        )
+        .unwrap();
+
+    // daily epochs, starting now
+    let epoch_config = EpochConfig {
+        epoch_length: 86_400u64,
+        genesis_time: env.block.time,
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::UpdateConfig {
+            owner: None,
+            fee_collector_addr: None,
+            pool_fees: None,
+            feature_toggle: None,
+            burn_fee_target: None,
+            epoch_config: Some(epoch_config.clone()),
+        },
+    )
+    .unwrap();
+
+    // the epoch config can't be changed once set
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateConfig {
+            owner: None,
+            fee_collector_addr: None,
+            pool_fees: None,
+            feature_toggle: None,
+            burn_fee_target: None,
+            epoch_config: Some(EpochConfig {
+                epoch_length: 3_600u64,
+                genesis_time: env.block.time,
+            }),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::EpochConfigAlreadySet {});
+
+    // swap in the first epoch and in the third one
+    let mut fees = vec![];
+    for days in [0u64, 2u64] {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(days * 86_400u64);
+
+        let offer_amount = Uint128::from(1_500_000_000u128);
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info(
+                "addr0000",
+                &[Coin {
+                    denom: "uusd".to_string(),
+                    amount: offer_amount,
+                }],
+            ),
+            ExecuteMsg::Swap {
+                offer_asset: Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "uusd".to_string(),
+                    },
+                    amount: offer_amount,
+                },
+                belief_price: None,
+                max_spread: None,
+                to: None,
+            },
+        )
+        .unwrap();
+
+        let get_amount = |key: &str| {
+            res.attributes
+                .iter()
+                .find(|attribute| attribute.key == key)
+                .unwrap()
+                .value
+                .parse::<Uint128>()
+                .unwrap()
+        };
+        fees.push((
+            get_amount("protocol_fee_amount"),
+            get_amount("burn_fee_amount"),
+        ));
+    }
+
+    let asset_fee = |amount: Uint128| {
+        vec![Asset {
+            info: AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+            amount,
+        }]
+    };
+
+    let res: ProtocolFeesByEpochResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ProtocolFeesByEpoch {
+                start_epoch: 0u64,
+                end_epoch: 2u64,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        ProtocolFeesByEpochResponse {
+            current_epoch: 0u64,
+            epoch_config,
+            epochs: vec![
+                EpochFees {
+                    epoch: 0u64,
+                    protocol_fees: asset_fee(fees[0].0),
+                    burned_fees: asset_fee(fees[0].1),
+                },
+                EpochFees {
+                    epoch: 2u64,
+                    protocol_fees: asset_fee(fees[1].0),
+                    burned_fees: asset_fee(fees[1].1),
+                },
+            ],
+        }
+    );
+
+    // no fees were accrued in the second epoch
+    let res: ProtocolFeesByEpochResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ProtocolFeesByEpoch {
+                start_epoch: 1u64,
+                end_epoch: 1u64,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(res.epochs.is_empty());
+
+    // invalid range
+    query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ProtocolFeesByEpoch {
+            start_epoch: 2u64,
+            end_epoch: 1u64,
+        },
+    )
+    .unwrap_err();
+}
@@ -1,5 +1,6 @@
 use crate::contract::{execute, instantiate, reply};
 use crate::error::ContractError;
+use crate::state::RESERVES;
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
     to_binary, Coin, CosmosMsg, Decimal, Reply, Response, StdError, SubMsg, SubMsgResponse,
@@ -50,6 +51,8 @@ fn provide_liquidity() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -182,16 +185,6 @@ fn provide_liquidity() {
 
     // provide more liquidity 1:2, which is not proportional to 1:1,
     // then it must accept 1:1 and treat left amount as donation
-    deps.querier.with_balance(&[(
-        &MOCK_CONTRACT_ADDR.to_string(),
-        vec![Coin {
-            denom: "uusd".to_string(),
-            amount: Uint128::from(
-                200u128 + 200u128, /* user deposit must be pre-applied */
-            ),
-        }],
-    )]);
-
     deps.querier.with_token_balances(&[
         (
             &"liquidity0000".to_string(),
@@ -203,6 +196,13 @@ fn provide_liquidity() {
         ),
     ]);
 
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[Uint128::from(200u128), Uint128::from(200u128)],
+        )
+        .unwrap();
+
     let msg = ExecuteMsg::ProvideLiquidity {
         assets: [
             Asset {
@@ -300,17 +300,7 @@ fn provide_liquidity() {
         _ => panic!("Must return generic error"),
     }
 
-    // initialize token balance to 1:1
-    deps.querier.with_balance(&[(
-        &MOCK_CONTRACT_ADDR.to_string(),
-        vec![Coin {
-            denom: "uusd".to_string(),
-            amount: Uint128::from(
-                100u128 + 100u128, /* user deposit must be pre-applied */
-            ),
-        }],
-    )]);
-
+    // initialize reserves to 1:1
     deps.querier.with_token_balances(&[
         (
             &"liquidity0000".to_string(),
@@ -322,6 +312,13 @@ fn provide_liquidity() {
         ),
     ]);
 
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[Uint128::from(100u128), Uint128::from(100u128)],
+        )
+        .unwrap();
+
     // failed because the price is under slippage_tolerance
     let msg = ExecuteMsg::ProvideLiquidity {
         assets: [
@@ -356,14 +353,13 @@ fn provide_liquidity() {
         _ => panic!("DO NOT ENTER HERE"),
     }
 
-    // initialize token balance to 1:1
-    deps.querier.with_balance(&[(
-        &MOCK_CONTRACT_ADDR.to_string(),
-        vec![Coin {
-            denom: "uusd".to_string(),
-            amount: Uint128::from(100u128 + 98u128 /* user deposit must be pre-applied */),
-        }],
-    )]);
+    // initialize reserves to 1:1
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[Uint128::from(100u128), Uint128::from(100u128)],
+        )
+        .unwrap();
 
     // failed because the price is under slippage_tolerance
     let msg = ExecuteMsg::ProvideLiquidity {
@@ -399,16 +395,13 @@ fn provide_liquidity() {
         _ => panic!("DO NOT ENTER HERE"),
     }
 
-    // initialize token balance to 1:1
-    deps.querier.with_balance(&[(
-        &MOCK_CONTRACT_ADDR.to_string(),
-        vec![Coin {
-            denom: "uusd".to_string(),
-            amount: Uint128::from(
-                100u128 + 100u128, /* user deposit must be pre-applied */
-            ),
-        }],
-    )]);
+    // initialize reserves to 1:1
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[Uint128::from(100u128), Uint128::from(100u128)],
+        )
+        .unwrap();
 
     // successfully provides
     let msg = ExecuteMsg::ProvideLiquidity {
@@ -440,14 +433,13 @@ fn provide_liquidity() {
     );
     let _res = execute(deps.as_mut(), env, info, msg).unwrap();
 
-    // initialize token balance to 1:1
-    deps.querier.with_balance(&[(
-        &MOCK_CONTRACT_ADDR.to_string(),
-        vec![Coin {
-            denom: "uusd".to_string(),
-            amount: Uint128::from(100u128 + 99u128 /* user deposit must be pre-applied */),
-        }],
-    )]);
+    // initialize reserves to 1:1
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[Uint128::from(100u128), Uint128::from(100u128)],
+        )
+        .unwrap();
 
     // successfully provides
     let msg = ExecuteMsg::ProvideLiquidity {
@@ -519,6 +511,8 @@ fn provide_liquidity_zero_amount() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -618,6 +612,8 @@ fn provide_liquidity_invalid_minimum_lp_amount() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
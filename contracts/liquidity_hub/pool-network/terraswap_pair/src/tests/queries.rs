@@ -1,6 +1,7 @@
 use crate::contract::{instantiate, query, reply};
 use crate::error::ContractError;
 use crate::queries::query_pool;
+use crate::state::RESERVES;
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{Coin, Decimal, Reply, SubMsgResponse, SubMsgResult, Uint128};
 use pool_network::asset::{Asset, AssetInfo, PairType};
@@ -47,6 +48,8 @@ fn test_simulations_asset_missmatch() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -133,6 +136,8 @@ fn test_query_pool() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -155,6 +160,9 @@ fn test_query_pool() {
     };
 
     let _res = reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+    RESERVES
+        .save(&mut deps.storage, &[asset_0_amount, asset_1_amount])
+        .unwrap();
 
     let res: PoolResponse = query_pool(deps.as_ref()).unwrap();
 
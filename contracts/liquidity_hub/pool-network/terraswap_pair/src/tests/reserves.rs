@@ -0,0 +1,188 @@
+use crate::contract::{execute, instantiate, reply};
+use crate::queries::query_pool;
+use crate::state::{store_fee, COLLECTED_PROTOCOL_FEES, RESERVES};
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockStorage, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    attr, to_binary, BankMsg, Coin, CosmosMsg, Decimal, OwnedDeps, Reply, SubMsg, SubMsgResponse,
+    SubMsgResult, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use pool_network::asset::{Asset, AssetInfo, PairType};
+use pool_network::mock_querier::{mock_dependencies, WasmMockQuerier};
+use pool_network::pair::{ExecuteMsg, InstantiateMsg, PoolFee};
+use nico_teen::fee::Fee;
+
+/// Instantiates a uusd-asset0000 pair with 1000:1000 reserves, to which 100uusd and 1000asset0000
+/// were donated, i.e. sent outside of the pair's messages
+fn instantiate_pair_with_donations() -> OwnedDeps<MockStorage, MockApi, WasmMockQuerier> {
+    let mut deps = mock_dependencies(&[Coin {
+        denom: "uusd".to_string(),
+        amount: Uint128::from(1_100u128),
+    }]);
+
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&"addr0000".to_string(), &Uint128::from(1_000u128))],
+        ),
+        (
+            &"asset0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(2_000u128))],
+        ),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 6u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::zero(),
+            },
+            swap_fee: Fee {
+                share: Decimal::zero(),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env, info, msg).unwrap();
+
+    // store liquidity token
+    let reply_msg = Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(
+                vec![
+                    10, 13, 108, 105, 113, 117, 105, 100, 105, 116, 121, 48, 48, 48, 48,
+                ]
+                .into(),
+            ),
+        }),
+    };
+    reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[Uint128::from(1_000u128), Uint128::from(1_000u128)],
+        )
+        .unwrap();
+
+    deps
+}
+
+#[test]
+fn donations_do_not_change_the_pool() {
+    let deps = instantiate_pair_with_donations();
+
+    let pool = query_pool(deps.as_ref()).unwrap();
+    assert_eq!(
+        pool.assets,
+        vec![
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uusd".to_string(),
+                },
+                amount: Uint128::from(1_000u128),
+            },
+            Asset {
+                info: AssetInfo::Token {
+                    contract_addr: "asset0000".to_string(),
+                },
+                amount: Uint128::from(1_000u128),
+            },
+        ]
+    );
+}
+
+#[test]
+fn sync_absorbs_donations_into_the_reserves() {
+    let mut deps = instantiate_pair_with_donations();
+
+    // the uncollected protocol fees are kept out of the reserves
+    store_fee(
+        deps.as_mut().storage,
+        Uint128::from(10u128),
+        "uusd".to_string(),
+        COLLECTED_PROTOCOL_FEES,
+    )
+    .unwrap();
+
+    // anyone can sync the reserves
+    let info = mock_info("addr0001", &[]);
+    let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Sync {}).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "sync"),
+            attr("reserves", "1090uusd, 2000asset0000"),
+        ]
+    );
+    assert_eq!(
+        RESERVES.load(&deps.storage).unwrap(),
+        [Uint128::from(1_090u128), Uint128::from(2_000u128)]
+    );
+}
+
+#[test]
+fn skim_sends_donations_away() {
+    let mut deps = instantiate_pair_with_donations();
+
+    let info = mock_info("addr0001", &[]);
+    let msg = ExecuteMsg::Skim {
+        to: "addr0002".to_string(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "addr0002".to_string(),
+                amount: vec![Coin {
+                    denom: "uusd".to_string(),
+                    amount: Uint128::from(100u128),
+                }],
+            })),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "asset0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "addr0002".to_string(),
+                    amount: Uint128::from(1_000u128),
+                })
+                .unwrap(),
+                funds: vec![],
+            })),
+        ]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "skim"),
+            attr("receiver", "addr0002"),
+            attr("skimmed_assets", "100uusd, 1000asset0000"),
+        ]
+    );
+
+    // the reserves are left untouched
+    assert_eq!(
+        RESERVES.load(&deps.storage).unwrap(),
+        [Uint128::from(1_000u128), Uint128::from(1_000u128)]
+    );
+}
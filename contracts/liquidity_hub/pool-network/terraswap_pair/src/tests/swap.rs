@@ -3,12 +3,12 @@ use crate::error::ContractError;
 use crate::helpers::compute_swap;
 use crate::queries::query_fees;
 use crate::state::{
-    ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES,
+    ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES, RESERVES,
 };
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    attr, coins, from_binary, to_binary, BankMsg, Coin, CosmosMsg, Decimal, Reply, ReplyOn, SubMsg,
-    SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
+    attr, coins, from_binary, to_binary, BankMsg, Coin, CosmosMsg, Decimal, Decimal256, Reply,
+    ReplyOn, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 use pool_network::asset::{Asset, AssetInfo, PairType};
@@ -18,6 +18,7 @@ use pool_network::pair::{
     SimulationResponse,
 };
 use nico_teen::fee::Fee;
+use std::str::FromStr;
 
 #[test]
 fn test_compute_swap_with_huge_pool_variance() {
@@ -100,6 +101,8 @@ fn try_native_to_token() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -123,6 +126,14 @@ fn try_native_to_token() {
 
     let _res = reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
 
+    // the reserves of the pool, the offer is not part of them
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[collateral_pool_amount, asset_pool_amount],
+        )
+        .unwrap();
+
     // normal swap
     let msg = ExecuteMsg::Swap {
         offer_asset: Asset {
@@ -237,14 +248,12 @@ fn try_native_to_token() {
     );
 
     // check simulation res, reset values pre-swap to check simulation
-    deps.querier.with_balance(&[(
-        &MOCK_CONTRACT_ADDR.to_string(),
-        vec![Coin {
-            denom: "uusd".to_string(),
-            amount: collateral_pool_amount,
-            /* user deposit must be pre-applied */
-        }],
-    )]);
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[collateral_pool_amount, asset_pool_amount],
+        )
+        .unwrap();
 
     // reset protocol fees so the simulation returns same values as the actual swap
     COLLECTED_PROTOCOL_FEES
@@ -292,6 +301,20 @@ fn try_native_to_token() {
         expected_protocol_fee_amount,
         simulation_res.protocol_fee_amount
     );
+    // 20000 asset with 8 decimals for 30000 uusd with 6 decimals
+    assert_eq!(
+        simulation_res.pre_trade_spot_price,
+        Some(Decimal256::from_str("0.006666666666666666").unwrap())
+    );
+    assert_eq!(
+        simulation_res.post_trade_spot_price,
+        Some(Decimal256::from_str("0.006047770219047619").unwrap())
+    );
+    // the offer is 1500 / 31500 of the offer pool after the swap
+    assert_eq!(
+        simulation_res.price_impact,
+        Some(Decimal256::from_str("0.047619048000000005").unwrap())
+    );
 
     // reset protocol fees so the simulation returns same values as the actual swap
     COLLECTED_PROTOCOL_FEES
@@ -443,6 +466,8 @@ fn try_swap_invalid_token() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -545,6 +570,8 @@ fn try_token_to_native() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -568,6 +595,14 @@ fn try_token_to_native() {
 
     let _res = reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
 
+    // the reserves of the pool, the offer is not part of them
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[collateral_pool_amount, asset_pool_amount],
+        )
+        .unwrap();
+
     // unauthorized access; can not execute swap directly for token swap
     let msg = ExecuteMsg::Swap {
         offer_asset: Asset {
@@ -665,16 +700,12 @@ fn try_token_to_native() {
     );
 
     // check simulation res, reset values pre-swap to check simulation
-    deps.querier.with_token_balances(&[
-        (
-            &"liquidity0000".to_string(),
-            &[(&MOCK_CONTRACT_ADDR.to_string(), &total_share)],
-        ),
-        (
-            &"asset0000".to_string(),
-            &[(&MOCK_CONTRACT_ADDR.to_string(), &asset_pool_amount)],
-        ),
-    ]);
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[collateral_pool_amount, asset_pool_amount],
+        )
+        .unwrap();
 
     // reset protocol fees so the simulation returns same values as the actual swap
     COLLECTED_PROTOCOL_FEES
@@ -891,6 +922,8 @@ fn test_swap_to_third_party() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -912,6 +945,12 @@ fn test_swap_to_third_party() {
     };
 
     reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[collateral_pool_amount, asset_pool_amount],
+        )
+        .unwrap();
 
     // first swap
     let msg = ExecuteMsg::Swap {
@@ -1038,9 +1077,17 @@ fn stableswap_reverse_simulation() {
             },
             fee_collector_addr: "collector".to_string(),
             pair_type: PairType::StableSwap { amp: 100 },
+            lp_token_type: None,
+            lp_token_info: None,
         },
     )
     .unwrap();
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[ask_pool_amount, collateral_pool_amount],
+        )
+        .unwrap();
 
     // check reverse simulation res
     let reverse_simulation_res: ReverseSimulationResponse = from_binary(
@@ -1063,11 +1110,15 @@ fn stableswap_reverse_simulation() {
     assert_eq!(
         reverse_simulation_res,
         ReverseSimulationResponse {
-            offer_amount: Uint128::new(10_050),
+            // the curve approximation is rounded up by one unit in favor of the pool
+            offer_amount: Uint128::new(10_051),
             protocol_fee_amount: Uint128::new(25),
             spread_amount: Uint128::new(0),
             swap_fee_amount: Uint128::new(25),
             burn_fee_amount: Uint128::zero(),
+            pre_trade_spot_price: Some(Decimal256::from_str("1.000197577963317089").unwrap()),
+            post_trade_spot_price: Some(Decimal256::from_str("0.999998752523420032").unwrap()),
+            price_impact: Some(Decimal256::from_str("0.000297011868119314").unwrap()),
         }
     )
 }
@@ -1078,7 +1129,8 @@ fn stableswap_with_different_precisions() {
     let asset_pool = Uint128::from(10_000_000_000u128); // 100_000.00000
     let uusd_pool = Uint128::from(1_000_000_000u128); // 100_000.0000
     let uusd_offer_amount = Uint128::from(10_0000u128); // // 10.0000
-    let asset_return_amount = Uint128::from(10_00000u128); // 10.00000
+                                                        // the curve approximation is rounded down by one unit in favor of the pool
+    let asset_return_amount = Uint128::from(9_99999u128); // 9.99999
 
     let mut deps = mock_dependencies(&[Coin {
         denom: "uusd".to_string(),
@@ -1121,6 +1173,8 @@ fn stableswap_with_different_precisions() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::StableSwap { amp: 100 },
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -1142,6 +1196,11 @@ fn stableswap_with_different_precisions() {
     };
     reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
 
+    // the reserves of the pool, the offer is not part of them
+    RESERVES
+        .save(&mut deps.storage, &[uusd_pool, asset_pool])
+        .unwrap();
+
     // normal swap
     let msg = ExecuteMsg::Swap {
         offer_asset: Asset {
@@ -1165,7 +1224,7 @@ fn stableswap_with_different_precisions() {
     let res = execute(deps.as_mut(), env, info, msg).unwrap();
     let msg_transfer = res.messages.get(0).expect("no message");
 
-    let expected_spread_amount = Uint128::new(0);
+    let expected_spread_amount = Uint128::new(1);
     let expected_swap_fee_amount = asset_return_amount.multiply_ratio(1u128, 400u128); // 0.25%
     let expected_protocol_fee_amount = asset_return_amount.multiply_ratio(1u128, 400u128); // 0.25%
     let expected_burn_fee_amount = asset_return_amount.multiply_ratio(1u128, 500u128); // 0.2%
@@ -1206,14 +1265,9 @@ fn stableswap_with_different_precisions() {
     );
 
     // check simulation res, reset values pre-swap to check simulation
-    deps.querier.with_balance(&[(
-        &MOCK_CONTRACT_ADDR.to_string(),
-        vec![Coin {
-            denom: "uusd".to_string(),
-            amount: uusd_pool,
-            /* user deposit must be pre-applied */
-        }],
-    )]);
+    RESERVES
+        .save(&mut deps.storage, &[uusd_pool, asset_pool])
+        .unwrap();
 
     // reset protocol fees so the simulation returns same values as the actual swap
     COLLECTED_PROTOCOL_FEES
@@ -1324,11 +1378,15 @@ fn stableswap_with_different_precisions() {
     assert_eq!(
         reverse_simulation_res,
         ReverseSimulationResponse {
-            offer_amount: Uint128::new(100_000),
+            // the offer amount and the ask amount before fees are rounded up in favor of the pool
+            offer_amount: Uint128::new(100_001),
             spread_amount: Uint128::new(1),
-            protocol_fee_amount: Uint128::new(2499),
-            swap_fee_amount: Uint128::new(2499),
-            burn_fee_amount: Uint128::new(1999),
+            protocol_fee_amount: Uint128::new(2500),
+            swap_fee_amount: Uint128::new(2500),
+            burn_fee_amount: Uint128::new(2000),
+            pre_trade_spot_price: Some(Decimal256::one()),
+            post_trade_spot_price: Some(Decimal256::from_str("0.999998022267511481").unwrap()),
+            price_impact: Some(Decimal256::from_str("0.0000079999200008").unwrap()),
         }
     );
 
@@ -0,0 +1,562 @@
+use cosmwasm_std::{Decimal, Decimal256, Uint128, Uint256};
+use proptest::prelude::*;
+use std::str::FromStr;
+
+use pool_network::asset::PairType;
+use pool_network::pair::PoolFee;
+use nico_teen::fee::Fee;
+
+use crate::helpers::{
+    calculate_stableswap_d, calculate_stableswap_y, compute_offer_amount, compute_price_impact,
+    compute_spot_price, compute_stableswap_offer_amount, compute_swap, StableSwapDirection,
+    SwapComputation,
+};
+use crate::math::Decimal256Helper;
+
+/// The largest pool used in the properties, 1B tokens with 18 decimals
+const MAX_POOL: u128 = 1_000_000_000_000_000_000_000_000_000u128;
+
+fn pool_fees(swap_fee: Decimal, protocol_fee: Decimal, burn_fee: Decimal) -> PoolFee {
+    PoolFee {
+        protocol_fee: Fee {
+            share: protocol_fee,
+        },
+        swap_fee: Fee { share: swap_fee },
+        burn_fee: Fee { share: burn_fee },
+    }
+}
+
+fn no_fees() -> PoolFee {
+    pool_fees(Decimal::zero(), Decimal::zero(), Decimal::zero())
+}
+
+prop_compose! {
+    /// Pool fees of up to 10% each
+    fn arb_pool_fees()(
+        swap_fee in 0u64..=100u64,
+        protocol_fee in 0u64..=100u64,
+        burn_fee in 0u64..=100u64,
+    ) -> PoolFee {
+        pool_fees(
+            Decimal::permille(swap_fee),
+            Decimal::permille(protocol_fee),
+            Decimal::permille(burn_fee),
+        )
+    }
+}
+
+fn constant_product_swap(
+    offer_pool: u128,
+    ask_pool: u128,
+    offer_amount: u128,
+    pool_fees: PoolFee,
+) -> SwapComputation {
+    compute_swap(
+        Uint128::new(offer_pool),
+        Uint128::new(ask_pool),
+        Uint128::new(offer_amount),
+        pool_fees,
+        &PairType::ConstantProduct,
+        6u8,
+        6u8,
+    )
+    .unwrap()
+}
+
+/// Returns the pools after the swap. The swap fee stays in the ask pool, while the return amount,
+/// protocol and burn fees leave it.
+fn pools_after_swap(
+    offer_pool: u128,
+    ask_pool: u128,
+    offer_amount: u128,
+    swap_computation: &SwapComputation,
+) -> (u128, u128) {
+    (
+        offer_pool + offer_amount,
+        ask_pool
+            - swap_computation.return_amount.u128()
+            - swap_computation.protocol_fee_amount.u128()
+            - swap_computation.burn_fee_amount.u128(),
+    )
+}
+
+proptest! {
+    #[test]
+    fn constant_product_k_never_decreases(
+        offer_pool in 1u128..=MAX_POOL,
+        ask_pool in 1u128..=MAX_POOL,
+        offer_amount in 0u128..=MAX_POOL,
+        pool_fees in arb_pool_fees(),
+    ) {
+        let swap_computation = constant_product_swap(offer_pool, ask_pool, offer_amount, pool_fees);
+        let (new_offer_pool, new_ask_pool) =
+            pools_after_swap(offer_pool, ask_pool, offer_amount, &swap_computation);
+
+        prop_assert!(
+            Uint256::from(new_offer_pool) * Uint256::from(new_ask_pool)
+                >= Uint256::from(offer_pool) * Uint256::from(ask_pool)
+        );
+    }
+
+    #[test]
+    fn constant_product_reverse_simulation_covers_the_ask_amount(
+        offer_pool in 1u128..=MAX_POOL,
+        ask_pool in 1u128..=MAX_POOL,
+        ask_share in 0u64..1_000u64,
+        pool_fees in arb_pool_fees(),
+    ) {
+        let ask_amount = Uint128::new(ask_pool).multiply_ratio(ask_share, 1_000u128);
+
+        // asking for more than the pool can give after fees errors out
+        if let Ok(offer_amount_computation) = compute_offer_amount(
+            Uint128::new(offer_pool),
+            Uint128::new(ask_pool),
+            ask_amount,
+            pool_fees.clone(),
+        ) {
+            let swap_computation = constant_product_swap(
+                offer_pool,
+                ask_pool,
+                offer_amount_computation.offer_amount.u128(),
+                pool_fees,
+            );
+            prop_assert!(swap_computation.return_amount >= ask_amount);
+        }
+    }
+
+    #[test]
+    fn constant_product_simulations_round_trip(
+        offer_pool in 1u128..=MAX_POOL,
+        ask_pool in 1u128..=MAX_POOL,
+        offer_amount in 1u128..=MAX_POOL,
+    ) {
+        let return_amount =
+            constant_product_swap(offer_pool, ask_pool, offer_amount, no_fees()).return_amount;
+
+        // the reverse simulation returns the smallest offer amount returning the ask amount,
+        // which can't be more than the amount offered in the first place
+        let reverse_offer_amount = compute_offer_amount(
+            Uint128::new(offer_pool),
+            Uint128::new(ask_pool),
+            return_amount,
+            no_fees(),
+        )
+        .unwrap()
+        .offer_amount
+        .u128();
+        prop_assert!(reverse_offer_amount <= offer_amount);
+        prop_assert_eq!(
+            constant_product_swap(offer_pool, ask_pool, reverse_offer_amount, no_fees())
+                .return_amount,
+            return_amount
+        );
+        if reverse_offer_amount > 0 {
+            prop_assert!(
+                constant_product_swap(offer_pool, ask_pool, reverse_offer_amount - 1, no_fees())
+                    .return_amount
+                    < return_amount
+            );
+        }
+    }
+
+    #[test]
+    fn stableswap_d_never_decreases(
+        precision in 0u8..=18u8,
+        amp in 1u64..=1_000u64,
+        offer_pool in 1u128..=MAX_POOL,
+        ask_pool in 1u128..=MAX_POOL,
+        offer_share in 0u64..=1_000u64,
+        pool_fees in arb_pool_fees(),
+    ) {
+        let offer_amount = Uint128::new(offer_pool).multiply_ratio(offer_share, 1_000u128).u128();
+
+        // the swap can fail to converge on heavily imbalanced pools, which is fine
+        if let Ok(swap_computation) = compute_swap(
+            Uint128::new(offer_pool),
+            Uint128::new(ask_pool),
+            Uint128::new(offer_amount),
+            pool_fees,
+            &PairType::StableSwap { amp },
+            precision,
+            precision,
+        ) {
+            let (new_offer_pool, new_ask_pool) =
+                pools_after_swap(offer_pool, ask_pool, offer_amount, &swap_computation);
+
+            let d = |offer_pool: u128, ask_pool: u128| {
+                calculate_stableswap_d(
+                    Decimal256::decimal_with_precision(offer_pool, precision).unwrap(),
+                    Decimal256::decimal_with_precision(ask_pool, precision).unwrap(),
+                    &amp,
+                    precision,
+                )
+            };
+
+            if let (Ok(d_before), Ok(d_after)) =
+                (d(offer_pool, ask_pool), d(new_offer_pool, new_ask_pool))
+            {
+                // D is approximated to the smallest unit of the precision, on each side
+                let tolerance = Decimal256::decimal_with_precision(2u8, precision).unwrap();
+                prop_assert!(d_after + tolerance >= d_before);
+            }
+        }
+    }
+
+    #[test]
+    fn stableswap_reverse_simulation_covers_the_ask_amount(
+        precision in 0u8..=18u8,
+        amp in 1u64..=1_000u64,
+        offer_pool in 1u128..=MAX_POOL,
+        ask_pool in 1u128..=MAX_POOL,
+        ask_share in 0u64..1_000u64,
+        pool_fees in arb_pool_fees(),
+    ) {
+        let ask_amount = Uint128::new(ask_pool).multiply_ratio(ask_share, 1_000u128);
+
+        if let Ok(offer_amount_computation) = compute_stableswap_offer_amount(
+            Uint128::new(offer_pool),
+            Uint128::new(ask_pool),
+            ask_amount,
+            pool_fees.clone(),
+            &amp,
+            precision,
+            precision,
+        ) {
+            if let Ok(swap_computation) = compute_swap(
+                Uint128::new(offer_pool),
+                Uint128::new(ask_pool),
+                offer_amount_computation.offer_amount,
+                pool_fees,
+                &PairType::StableSwap { amp },
+                precision,
+                precision,
+            ) {
+                prop_assert!(swap_computation.return_amount >= ask_amount);
+            }
+        }
+    }
+
+    #[test]
+    fn swaps_move_the_spot_price_against_the_trader(
+        precision in 0u8..=18u8,
+        amp in 1u64..=1_000u64,
+        offer_pool in 1u128..=MAX_POOL,
+        ask_pool in 1u128..=MAX_POOL,
+        offer_share in 0u64..=1_000u64,
+        pool_fees in arb_pool_fees(),
+    ) {
+        let offer_amount = Uint128::new(offer_pool).multiply_ratio(offer_share, 1_000u128).u128();
+
+        for pair_type in [PairType::ConstantProduct, PairType::StableSwap { amp }] {
+            if let Ok(swap_computation) = compute_swap(
+                Uint128::new(offer_pool),
+                Uint128::new(ask_pool),
+                Uint128::new(offer_amount),
+                pool_fees.clone(),
+                &pair_type,
+                precision,
+                precision,
+            ) {
+                let (_, new_ask_pool) =
+                    pools_after_swap(offer_pool, ask_pool, offer_amount, &swap_computation);
+
+                if let Ok(price_impact_computation) = compute_price_impact(
+                    Uint128::new(offer_pool),
+                    Uint128::new(ask_pool),
+                    Uint128::new(offer_amount),
+                    Uint128::new(ask_pool - new_ask_pool),
+                    swap_computation.swap_fee_amount,
+                    &pair_type,
+                    precision,
+                    precision,
+                ) {
+                    prop_assert!(
+                        price_impact_computation.post_trade_spot_price
+                            <= price_impact_computation.pre_trade_spot_price
+                    );
+                    prop_assert!(price_impact_computation.price_impact <= Decimal256::one());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn swap_math_does_not_panic_across_precisions(
+        offer_precision in 0u8..=18u8,
+        ask_precision in 0u8..=18u8,
+        amp in 1u64..=1_000u64,
+        offer_pool in 1u128..=MAX_POOL,
+        ask_pool in 1u128..=MAX_POOL,
+        amount in 0u128..=MAX_POOL,
+        pool_fees in arb_pool_fees(),
+    ) {
+        for pair_type in [PairType::ConstantProduct, PairType::StableSwap { amp }] {
+            let _ = compute_swap(
+                Uint128::new(offer_pool),
+                Uint128::new(ask_pool),
+                Uint128::new(amount),
+                pool_fees.clone(),
+                &pair_type,
+                offer_precision,
+                ask_precision,
+            );
+        }
+
+        let _ = compute_offer_amount(
+            Uint128::new(offer_pool),
+            Uint128::new(ask_pool),
+            Uint128::new(amount),
+            pool_fees.clone(),
+        );
+        let _ = compute_stableswap_offer_amount(
+            Uint128::new(offer_pool),
+            Uint128::new(ask_pool),
+            Uint128::new(amount),
+            pool_fees,
+            &amp,
+            offer_precision,
+            ask_precision,
+        );
+
+        let offer_pool = Decimal256::decimal_with_precision(offer_pool, offer_precision).unwrap();
+        let ask_pool = Decimal256::decimal_with_precision(ask_pool, ask_precision).unwrap();
+        let amount = Decimal256::decimal_with_precision(amount, offer_precision).unwrap();
+        for direction in [StableSwapDirection::Simulate, StableSwapDirection::ReverseSimulate] {
+            let _ = calculate_stableswap_y(
+                offer_pool,
+                ask_pool,
+                amount,
+                &amp,
+                ask_precision,
+                direction,
+            );
+        }
+    }
+
+    #[test]
+    fn decimal_with_precision_round_trips(
+        value in 0u128..=MAX_POOL,
+        precision in 0u8..=18u8,
+    ) {
+        let decimal = Decimal256::decimal_with_precision(value, precision).unwrap();
+        prop_assert_eq!(
+            decimal.to_uint256_with_precision(u32::from(precision)).unwrap(),
+            Uint256::from(value)
+        );
+    }
+
+    #[test]
+    fn checked_multiply_ratio_does_not_panic(
+        value in 0u128..=MAX_POOL,
+        numerator in 0u128..=MAX_POOL,
+        denominator in 0u128..=MAX_POOL,
+        precision in 0u8..=18u8,
+    ) {
+        let value = Decimal256::decimal_with_precision(value, precision).unwrap();
+        let _ = value.checked_multiply_ratio(
+            Decimal256::decimal_with_precision(numerator, precision).unwrap(),
+            Decimal256::decimal_with_precision(denominator, precision).unwrap(),
+        );
+    }
+}
+
+/// Regression for the reverse simulation rounding the offer amount down, which made swapping the
+/// simulated offer amount return one unit less than asked
+#[test]
+fn reverse_simulation_rounds_the_offer_amount_up() {
+    let offer_amount_computation = compute_offer_amount(
+        Uint128::new(1_000_000_000u128),
+        Uint128::new(1_000_000_000u128),
+        Uint128::new(1_000_000u128),
+        no_fees(),
+    )
+    .unwrap();
+    assert_eq!(
+        offer_amount_computation.offer_amount,
+        Uint128::new(1_001_002u128)
+    );
+
+    let swap_computation = constant_product_swap(
+        1_000_000_000u128,
+        1_000_000_000u128,
+        offer_amount_computation.offer_amount.u128(),
+        no_fees(),
+    );
+    assert_eq!(swap_computation.return_amount, Uint128::new(1_000_000u128));
+}
+
+/// Regressions for the reverse simulation rounding down the division by one minus the fees and
+/// the division of the constant product, which made swapping the simulated offer amount return
+/// less than asked. The offer amount is now the smallest one returning the ask amount.
+#[test]
+fn reverse_simulation_with_fees_rounds_the_offer_amount_up() {
+    // (offer pool, ask pool, ask amount, pool fees, offer amount)
+    let cases = [
+        (
+            1_000u128,
+            1_000u128,
+            37u128,
+            pool_fees(
+                Decimal::permille(3u64),
+                Decimal::permille(1u64),
+                Decimal::zero(),
+            ),
+            39u128,
+        ),
+        (
+            123_456_789u128,
+            987_654_321u128,
+            111u128,
+            pool_fees(
+                Decimal::permille(3u64),
+                Decimal::permille(1u64),
+                Decimal::zero(),
+            ),
+            14u128,
+        ),
+        (
+            999_999u128,
+            1_000_003u128,
+            111u128,
+            pool_fees(Decimal::percent(3u64), Decimal::zero(), Decimal::zero()),
+            115u128,
+        ),
+        (
+            1_337u128,
+            7_919u128,
+            148u128,
+            pool_fees(
+                Decimal::permille(7u64),
+                Decimal::permille(9u64),
+                Decimal::permille(5u64),
+            ),
+            26u128,
+        ),
+    ];
+
+    for (offer_pool, ask_pool, ask_amount, pool_fees, expected_offer_amount) in cases {
+        let offer_amount = compute_offer_amount(
+            Uint128::new(offer_pool),
+            Uint128::new(ask_pool),
+            Uint128::new(ask_amount),
+            pool_fees.clone(),
+        )
+        .unwrap()
+        .offer_amount
+        .u128();
+        assert_eq!(offer_amount, expected_offer_amount);
+
+        assert!(
+            constant_product_swap(offer_pool, ask_pool, offer_amount, pool_fees.clone())
+                .return_amount
+                .u128()
+                >= ask_amount
+        );
+        assert!(
+            constant_product_swap(offer_pool, ask_pool, offer_amount - 1, pool_fees)
+                .return_amount
+                .u128()
+                < ask_amount
+        );
+    }
+}
+
+/// Regression for the reverse simulation panicking when asking for the whole ask pool
+#[test]
+fn reverse_simulation_of_the_whole_pool_errors() {
+    compute_offer_amount(
+        Uint128::new(1_000_000u128),
+        Uint128::new(1_000_000u128),
+        Uint128::new(1_000_000u128),
+        no_fees(),
+    )
+    .unwrap_err();
+
+    compute_offer_amount(
+        Uint128::new(1_000_000u128),
+        Uint128::new(1_000_000u128),
+        Uint128::new(999_000u128),
+        pool_fees(Decimal::percent(1u64), Decimal::zero(), Decimal::zero()),
+    )
+    .unwrap_err();
+}
+
+/// Regression for the stableswap returning one unit when offering nothing to heavily imbalanced
+/// pools, decreasing D
+#[test]
+fn stableswap_without_offer_returns_nothing() {
+    let swap_computation = compute_swap(
+        Uint128::new(332_877_128_727_123_815_192_320_455u128),
+        Uint128::new(711_293_836_412_930_958_350u128),
+        Uint128::zero(),
+        no_fees(),
+        &PairType::StableSwap { amp: 1 },
+        0u8,
+        0u8,
+    )
+    .unwrap();
+    assert_eq!(swap_computation.return_amount, Uint128::zero());
+}
+
+/// The spot price of balanced stableswap pools is one, whatever the amplification
+#[test]
+fn balanced_stableswap_spot_price_is_one() {
+    for amp in [1u64, 10u64, 100u64, 1_000u64] {
+        assert_eq!(
+            compute_spot_price(
+                Uint128::new(1_000_000_000u128),
+                Uint128::new(1_000_000_000u128),
+                &PairType::StableSwap { amp },
+                6u8,
+                6u8,
+            )
+            .unwrap(),
+            Decimal256::one()
+        );
+    }
+
+    // the pools are normalized by the decimals of the assets
+    assert_eq!(
+        compute_spot_price(
+            Uint128::new(1_000_000_000u128),
+            Uint128::new(100_000_000_000_000_000u128),
+            &PairType::StableSwap { amp: 100 },
+            6u8,
+            14u8,
+        )
+        .unwrap(),
+        Decimal256::one()
+    );
+}
+
+/// The stableswap spot price is the marginal price of the curve, i.e. the price of a small swap
+#[test]
+fn stableswap_spot_price_matches_a_small_swap() {
+    let spot_price = compute_spot_price(
+        Uint128::new(300_000_000_000_000_000_000_000u128),
+        Uint128::new(1_700_000_000_000_000_000_000_000u128),
+        &PairType::StableSwap { amp: 10 },
+        18u8,
+        18u8,
+    )
+    .unwrap();
+    assert_eq!(
+        spot_price,
+        Decimal256::from_str("1.433383699669071285").unwrap()
+    );
+
+    let swap_computation = compute_swap(
+        Uint128::new(300_000_000_000_000_000_000_000u128),
+        Uint128::new(1_700_000_000_000_000_000_000_000u128),
+        Uint128::new(1_000_000_000_000u128),
+        no_fees(),
+        &PairType::StableSwap { amp: 10 },
+        18u8,
+        18u8,
+    )
+    .unwrap();
+    assert_eq!(
+        swap_computation.return_amount,
+        Uint128::new(1_433_383_699_668u128)
+    );
+}
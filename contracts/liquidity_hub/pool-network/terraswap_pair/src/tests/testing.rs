@@ -3,13 +3,16 @@ use cosmwasm_std::{
     from_binary, to_binary, Addr, Decimal, Reply, ReplyOn, StdError, SubMsg, SubMsgResponse,
     SubMsgResult, Uint128, WasmMsg,
 };
-use cw20::MinterResponse;
+use cw20::{Cw20ExecuteMsg, Logo, MinterResponse};
 
 use pool_network::asset::{Asset, AssetInfo, PairInfo, PairType};
 use pool_network::mock_querier::mock_dependencies;
 use pool_network::pair::ExecuteMsg::UpdateConfig;
-use pool_network::pair::{Config, InstantiateMsg, MigrateMsg, PoolFee, QueryMsg};
-use pool_network::token::InstantiateMsg as TokenInstantiateMsg;
+use pool_network::pair::{Config, ExecuteMsg, InstantiateMsg, MigrateMsg, PoolFee, QueryMsg};
+use pool_network::token::{
+    ExecuteMsg as TokenExecuteMsg, InstantiateMarketingInfo, InstantiateMsg as TokenInstantiateMsg,
+    LpTokenInfo,
+};
 use nico_teen::fee::Fee;
 
 use crate::contract::{execute, instantiate, migrate, query, reply};
@@ -50,6 +53,8 @@ fn proper_initialization() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     // we can just call .unwrap() to assert this was a success
@@ -63,13 +68,19 @@ fn proper_initialization() {
                 code_id: 10u64,
                 msg: to_binary(&TokenInstantiateMsg {
                     name: "uusd-mAAPL-LP".to_string(),
-                    symbol: "uLP".to_string(),
+                    symbol: "USD-MAAP-LP".to_string(),
                     decimals: 6,
                     initial_balances: vec![],
                     mint: Some(MinterResponse {
                         minter: MOCK_CONTRACT_ADDR.to_string(),
                         cap: None,
                     }),
+                    marketing: Some(InstantiateMarketingInfo {
+                        project: None,
+                        description: None,
+                        marketing: Some(MOCK_CONTRACT_ADDR.to_string()),
+                        logo: None,
+                    }),
                 })
                 .unwrap(),
                 funds: vec![],
@@ -101,7 +112,12 @@ fn proper_initialization() {
 
     // it worked, let's query the state
     let pair_info: PairInfo = query_pair_info(deps.as_ref()).unwrap();
-    assert_eq!("liquidity0000", pair_info.liquidity_token.as_str());
+    assert_eq!(
+        pair_info.liquidity_token,
+        AssetInfo::Token {
+            contract_addr: "liquidity0000".to_string(),
+        }
+    );
     assert_eq!(
         pair_info.asset_infos,
         [
@@ -148,6 +164,8 @@ fn test_initialization_invalid_fees() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     // we can just call .unwrap() to assert this was a success
@@ -193,6 +211,8 @@ fn can_migrate_contract() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -417,6 +437,8 @@ fn test_update_config_unsuccessful() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -440,6 +462,8 @@ fn test_update_config_unsuccessful() {
             },
         }),
         feature_toggle: None,
+        burn_fee_target: None,
+        epoch_config: None,
     };
 
     let res = execute(deps.as_mut(), env.clone(), info, update_config_message);
@@ -456,6 +480,8 @@ fn test_update_config_unsuccessful() {
         fee_collector_addr: None,
         pool_fees: None,
         feature_toggle: None,
+        burn_fee_target: None,
+        epoch_config: None,
     };
 
     let res = execute(deps.as_mut(), env, info, update_config_message);
@@ -502,6 +528,8 @@ fn test_update_config_successful() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -532,6 +560,8 @@ fn test_update_config_successful() {
             },
         }),
         feature_toggle: None,
+        burn_fee_target: None,
+        epoch_config: None,
     };
 
     execute(deps.as_mut(), env, info, update_config_message).unwrap();
@@ -545,6 +575,101 @@ fn test_update_config_successful() {
     assert_eq!(config.pool_fees.swap_fee.share, Decimal::percent(3u64));
 }
 
+#[test]
+fn test_update_lp_token_info() {
+    let mut deps = mock_dependencies(&[]);
+
+    deps.querier
+        .with_token_balances(&[(&"asset0000".to_string(), &[])]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::zero(),
+            },
+            swap_fee: Fee {
+                share: Decimal::zero(),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
+    };
+
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    // store liquidity token
+    let reply_msg = Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(
+                vec![
+                    10, 13, 108, 105, 113, 117, 105, 100, 105, 116, 121, 48, 48, 48, 48,
+                ]
+                .into(),
+            ),
+        }),
+    };
+    reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+
+    let update_msg = ExecuteMsg::UpdateLpTokenInfo {
+        lp_token_info: LpTokenInfo {
+            symbol: Some("USD-AAPL-LP".to_string()),
+            logo_url: Some("https://mirror.finance/aapl.png".to_string()),
+            ..LpTokenInfo::default()
+        },
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("unauthorized", &[]),
+        update_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let res = execute(deps.as_mut(), mock_env(), info, update_msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "liquidity0000".to_string(),
+                msg: to_binary(&TokenExecuteMsg::UpdateTokenInfo {
+                    name: None,
+                    symbol: Some("USD-AAPL-LP".to_string()),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "liquidity0000".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::UploadLogo(Logo::Url(
+                    "https://mirror.finance/aapl.png".to_string()
+                )))
+                .unwrap(),
+                funds: vec![],
+            }),
+        ]
+    );
+}
+
 #[test]
 fn test_assert_slippage_tolerance_invalid_ratio() {
     let res = assert_slippage_tolerance(
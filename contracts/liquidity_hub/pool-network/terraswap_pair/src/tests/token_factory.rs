@@ -0,0 +1,218 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{attr, from_binary, Addr, Coin, Decimal, SubMsg, Uint128};
+
+use pool_network::asset::{Asset, AssetInfo, PairInfo, PairType};
+use pool_network::lp_token::{
+    burn_lp_token_msg, create_lp_denom_msg, get_lp_denom, mint_lp_token_msgs, LpTokenType,
+};
+use pool_network::mock_querier::mock_dependencies;
+use pool_network::pair::{ExecuteMsg, InstantiateMsg, PoolFee, PoolResponse, QueryMsg};
+use pool_network::token::LpTokenInfo;
+use nico_teen::fee::Fee;
+
+use crate::contract::{execute, instantiate, query};
+use crate::error::ContractError;
+use crate::queries::query_pair_info;
+use crate::state::RESERVES;
+
+fn mock_instantiate_msg() -> InstantiateMsg {
+    InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 6u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::zero(),
+            },
+            swap_fee: Fee {
+                share: Decimal::zero(),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        lp_token_type: Some(LpTokenType::TokenFactory),
+        lp_token_info: None,
+    }
+}
+
+#[test]
+fn instantiate_with_token_factory_lp_token() {
+    let mut deps = mock_dependencies(&[]);
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    let res = instantiate(deps.as_mut(), env, info, mock_instantiate_msg()).unwrap();
+
+    let lp_denom = get_lp_denom(MOCK_CONTRACT_ADDR);
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(
+            create_lp_denom_msg(&Addr::unchecked(MOCK_CONTRACT_ADDR)).unwrap()
+        )]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![attr("liquidity_token_denom", lp_denom.clone())]
+    );
+
+    let pair_info: PairInfo = query_pair_info(deps.as_ref()).unwrap();
+    assert_eq!(
+        pair_info.liquidity_token,
+        AssetInfo::NativeToken { denom: lp_denom }
+    );
+}
+
+#[test]
+fn provide_liquidity_mints_token_factory_lp_tokens() {
+    let mut deps =
+        mock_dependencies(&[Coin::new(2_000u128, "uusd"), Coin::new(2_000u128, "uluna")]);
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env, info, mock_instantiate_msg()).unwrap();
+
+    let msg = ExecuteMsg::ProvideLiquidity {
+        assets: [
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uusd".to_string(),
+                },
+                amount: Uint128::new(2_000u128),
+            },
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                amount: Uint128::new(2_000u128),
+            },
+        ],
+        slippage_tolerance: None,
+        receiver: None,
+    };
+    let env = mock_env();
+    let info = mock_info(
+        "addr0000",
+        &[Coin::new(2_000u128, "uusd"), Coin::new(2_000u128, "uluna")],
+    );
+    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+
+    // the minimum liquidity is minted to the pool itself, the rest to the provider
+    let liquidity_token = AssetInfo::NativeToken {
+        denom: get_lp_denom(MOCK_CONTRACT_ADDR),
+    };
+    let contract_addr = Addr::unchecked(MOCK_CONTRACT_ADDR);
+    let expected_messages: Vec<SubMsg> = [
+        mint_lp_token_msgs(
+            &liquidity_token,
+            &contract_addr,
+            MOCK_CONTRACT_ADDR.to_string(),
+            Uint128::new(1_000u128),
+        )
+        .unwrap(),
+        mint_lp_token_msgs(
+            &liquidity_token,
+            &contract_addr,
+            "addr0000".to_string(),
+            Uint128::new(1_000u128),
+        )
+        .unwrap(),
+    ]
+    .concat()
+    .into_iter()
+    .map(SubMsg::new)
+    .collect();
+
+    assert_eq!(res.messages, expected_messages);
+}
+
+#[test]
+fn withdraw_token_factory_liquidity() {
+    let lp_denom = get_lp_denom(MOCK_CONTRACT_ADDR);
+    let mut deps = mock_dependencies(&[
+        Coin::new(100u128, "uusd"),
+        Coin::new(200u128, "uluna"),
+        // the LP tokens sent along the withdrawal
+        Coin::new(100u128, lp_denom.clone()),
+    ]);
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env, info, mock_instantiate_msg()).unwrap();
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[Uint128::new(100u128), Uint128::new(200u128)],
+        )
+        .unwrap();
+
+    // the total share is the supply of the LP denom
+    let pool: PoolResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Pool {}).unwrap()).unwrap();
+    assert_eq!(pool.total_share, Uint128::new(100u128));
+
+    // only the LP tokens can be sent along
+    let env = mock_env();
+    let info = mock_info("addr0000", &[Coin::new(100u128, "uusd")]);
+    let err = execute(deps.as_mut(), env, info, ExecuteMsg::WithdrawLiquidity {}).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidLiquidityTokenFunds(lp_denom.clone())
+    );
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[Coin::new(50u128, lp_denom.clone())]);
+    let res = execute(deps.as_mut(), env, info, ExecuteMsg::WithdrawLiquidity {}).unwrap();
+
+    assert_eq!(
+        res.messages.last().unwrap(),
+        &SubMsg::new(
+            burn_lp_token_msg(
+                &AssetInfo::NativeToken { denom: lp_denom },
+                &Addr::unchecked(MOCK_CONTRACT_ADDR),
+                Uint128::new(50u128),
+            )
+            .unwrap()
+        )
+    );
+    assert!(res
+        .attributes
+        .contains(&attr("refund_assets", "50uusd, 100uluna")));
+}
+
+#[test]
+fn cannot_update_token_factory_lp_token_info() {
+    let mut deps = mock_dependencies(&[]);
+
+    let info = mock_info("addr0000", &[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        mock_instantiate_msg(),
+    )
+    .unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::UpdateLpTokenInfo {
+            lp_token_info: LpTokenInfo {
+                symbol: Some("USD-LUNA-LP".to_string()),
+                ..LpTokenInfo::default()
+            },
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NativeLpTokenInfo {});
+}
@@ -1,6 +1,6 @@
 use crate::contract::{execute, instantiate, reply};
 use crate::error::ContractError;
-use crate::state::{get_fees_for_asset, store_fee, COLLECTED_PROTOCOL_FEES};
+use crate::state::{get_fees_for_asset, store_fee, COLLECTED_PROTOCOL_FEES, RESERVES};
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
     attr, to_binary, BankMsg, Coin, CosmosMsg, Decimal, Reply, Response, SubMsg, SubMsgResponse,
@@ -54,6 +54,8 @@ fn withdraw_xyk_liquidity() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -93,6 +95,14 @@ fn withdraw_xyk_liquidity() {
     )
     .unwrap();
 
+    // the reserves exclude the protocol fees
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[Uint128::from(90u128), Uint128::from(80u128)],
+        )
+        .unwrap();
+
     // withdraw liquidity
     let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
         sender: "addr0000".to_string(),
@@ -215,6 +225,8 @@ fn withdraw_stableswap_liquidity() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::StableSwap { amp: 100 },
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -252,6 +264,14 @@ fn withdraw_stableswap_liquidity() {
     )
     .unwrap();
 
+    // the reserves exclude the protocol fees
+    RESERVES
+        .save(
+            &mut deps.storage,
+            &[Uint128::from(90u128), Uint128::from(80u128)],
+        )
+        .unwrap();
+
     // withdraw liquidity
     let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
         sender: "addr0000".to_string(),
@@ -363,6 +383,8 @@ fn test_withdrawal_unauthorized() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -428,6 +450,8 @@ fn test_withdrawal_wrong_message() {
         },
         fee_collector_addr: "collector".to_string(),
         pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
     };
 
     let env = mock_env();
@@ -450,3 +474,50 @@ fn test_withdrawal_wrong_message() {
         _ => panic!("should return ContractError::Std"),
     }
 }
+
+#[test]
+fn cannot_withdraw_cw20_liquidity_with_native_funds() {
+    let mut deps = mock_dependencies(&[]);
+    deps.querier.with_token_balances(&[(
+        &"asset0000".to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+    )]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::zero(),
+            },
+            swap_fee: Fee {
+                share: Decimal::zero(),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        lp_token_type: None,
+        lp_token_info: None,
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env, info, msg).unwrap();
+
+    // the pool issues cw20 LP tokens, which are withdrawn through the cw20 hook
+    let env = mock_env();
+    let info = mock_info("addr0000", &[Coin::new(100u128, "liquidity0000")]);
+    let res = execute(deps.as_mut(), env, info, ExecuteMsg::WithdrawLiquidity {});
+    assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+}
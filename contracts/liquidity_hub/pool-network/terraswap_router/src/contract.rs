@@ -3,19 +3,19 @@ use std::collections::HashMap;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, from_binary, to_binary, Addr, Api, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Response, StdError, StdResult, Uint128, WasmMsg,
+    attr, from_binary, to_binary, Addr, Api, Binary, CosmosMsg, Decimal256, Deps, DepsMut, Env,
+    MessageInfo, Response, StdError, StdResult, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::Cw20ReceiveMsg;
 use semver::Version;
 
 use pool_network::asset::{Asset, AssetInfo, PairInfo};
-use pool_network::pair::SimulationResponse;
+use pool_network::pair::{ReverseSimulationResponse, SimulationResponse};
 use pool_network::querier::{query_pair_info, reverse_simulate, simulate};
 use pool_network::router::{
     ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
-    SimulateSwapOperationsResponse, SwapOperation, SwapRoute,
+    SimulateSwapOperationsResponse, SwapOperation, SwapOperationSimulation, SwapRoute,
 };
 
 use crate::error::ContractError;
@@ -320,34 +320,71 @@ fn simulate_swap_operations(
     }
 
     let mut offer_amount = offer_amount;
+    let mut pre_trade_spot_price = Some(Decimal256::one());
+    let mut post_trade_spot_price = Some(Decimal256::one());
+    // the share of the value kept by the swaps along the route, i.e. the product of 1 - price_impact
+    let mut kept_value_share = Some(Decimal256::one());
+    let mut operation_simulations = Vec::with_capacity(operations_len);
     for operation in operations.into_iter() {
         match operation {
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+                pair_type,
+                fee_tier,
             } => {
                 let pair_info: PairInfo = query_pair_info(
                     &deps.querier,
                     terraswap_factory.clone(),
                     &[offer_asset_info.clone(), ask_asset_info.clone()],
+                    pair_type,
+                    fee_tier,
                 )?;
 
+                let offer_asset = Asset {
+                    info: offer_asset_info,
+                    amount: offer_amount,
+                };
                 let res: SimulationResponse = simulate(
                     &deps.querier,
-                    Addr::unchecked(pair_info.contract_addr),
-                    &Asset {
-                        info: offer_asset_info,
-                        amount: offer_amount,
-                    },
+                    Addr::unchecked(pair_info.contract_addr.clone()),
+                    &offer_asset,
                 )?;
 
                 offer_amount = res.return_amount;
+                pre_trade_spot_price = compound(pre_trade_spot_price, res.pre_trade_spot_price)?;
+                post_trade_spot_price = compound(post_trade_spot_price, res.post_trade_spot_price)?;
+                kept_value_share = compound(
+                    kept_value_share,
+                    res.price_impact
+                        .map(|price_impact| Decimal256::one().checked_sub(price_impact))
+                        .transpose()?,
+                )?;
+
+                operation_simulations.push(SwapOperationSimulation {
+                    pair_address: pair_info.contract_addr,
+                    offer_asset,
+                    ask_asset: Asset {
+                        info: ask_asset_info,
+                        amount: res.return_amount,
+                    },
+                    spread_amount: res.spread_amount,
+                    swap_fee_amount: res.swap_fee_amount,
+                    protocol_fee_amount: res.protocol_fee_amount,
+                    burn_fee_amount: res.burn_fee_amount,
+                });
             }
         }
     }
 
     Ok(SimulateSwapOperationsResponse {
         amount: offer_amount,
+        pre_trade_spot_price,
+        post_trade_spot_price,
+        price_impact: kept_value_share
+            .map(|kept_value_share| Decimal256::one().checked_sub(kept_value_share))
+            .transpose()?,
+        operations: operation_simulations,
     })
 }
 
@@ -357,6 +394,7 @@ fn reverse_simulate_swap_operations(
     operations: Vec<SwapOperation>,
 ) -> Result<SimulateSwapOperationsResponse, ContractError> {
     let config: Config = CONFIG.load(deps.storage)?;
+    let terraswap_factory = deps.api.addr_humanize(&config.terraswap_factory)?;
 
     let operations_len = operations.len();
     if operations_len == 0 {
@@ -364,51 +402,87 @@ fn reverse_simulate_swap_operations(
     }
 
     let mut ask_amount = ask_amount;
+    let mut pre_trade_spot_price = Some(Decimal256::one());
+    let mut post_trade_spot_price = Some(Decimal256::one());
+    // the share of the value kept by the swaps along the route, i.e. the product of 1 - price_impact
+    let mut kept_value_share = Some(Decimal256::one());
+    let mut operation_simulations = Vec::with_capacity(operations_len);
     for operation in operations.into_iter().rev() {
-        ask_amount = match operation {
+        match operation {
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+                pair_type,
+                fee_tier,
             } => {
-                let terraswap_factory = deps.api.addr_humanize(&config.terraswap_factory)?;
-
-                reverse_simulate_return_amount(
-                    deps,
-                    terraswap_factory,
-                    ask_amount,
-                    offer_asset_info,
-                    ask_asset_info,
-                )?
+                let pair_info: PairInfo = query_pair_info(
+                    &deps.querier,
+                    terraswap_factory.clone(),
+                    &[offer_asset_info.clone(), ask_asset_info.clone()],
+                    pair_type,
+                    fee_tier,
+                )?;
+
+                let ask_asset = Asset {
+                    info: ask_asset_info,
+                    amount: ask_amount,
+                };
+                let res: ReverseSimulationResponse = reverse_simulate(
+                    &deps.querier,
+                    Addr::unchecked(pair_info.contract_addr.clone()),
+                    &ask_asset,
+                )?;
+
+                ask_amount = res.offer_amount;
+                pre_trade_spot_price = compound(pre_trade_spot_price, res.pre_trade_spot_price)?;
+                post_trade_spot_price = compound(post_trade_spot_price, res.post_trade_spot_price)?;
+                kept_value_share = compound(
+                    kept_value_share,
+                    res.price_impact
+                        .map(|price_impact| Decimal256::one().checked_sub(price_impact))
+                        .transpose()?,
+                )?;
+
+                operation_simulations.push(SwapOperationSimulation {
+                    pair_address: pair_info.contract_addr,
+                    offer_asset: Asset {
+                        info: offer_asset_info,
+                        amount: res.offer_amount,
+                    },
+                    ask_asset,
+                    spread_amount: res.spread_amount,
+                    swap_fee_amount: res.swap_fee_amount,
+                    protocol_fee_amount: res.protocol_fee_amount,
+                    burn_fee_amount: res.burn_fee_amount,
+                });
             }
         }
     }
 
-    Ok(SimulateSwapOperationsResponse { amount: ask_amount })
-}
-
-fn reverse_simulate_return_amount(
-    deps: Deps,
-    factory: Addr,
-    ask_amount: Uint128,
-    offer_asset_info: AssetInfo,
-    ask_asset_info: AssetInfo,
-) -> StdResult<Uint128> {
-    let pair_info: PairInfo = query_pair_info(
-        &deps.querier,
-        factory,
-        &[offer_asset_info, ask_asset_info.clone()],
-    )?;
+    // the operations were simulated from the last one
+    operation_simulations.reverse();
 
-    let res = reverse_simulate(
-        &deps.querier,
-        Addr::unchecked(pair_info.contract_addr),
-        &Asset {
-            amount: ask_amount,
-            info: ask_asset_info,
-        },
-    )?;
+    Ok(SimulateSwapOperationsResponse {
+        amount: ask_amount,
+        pre_trade_spot_price,
+        post_trade_spot_price,
+        price_impact: kept_value_share
+            .map(|kept_value_share| Decimal256::one().checked_sub(kept_value_share))
+            .transpose()?,
+        operations: operation_simulations,
+    })
+}
 
-    Ok(res.offer_amount)
+/// Compounds the price of a route with the price of the next pair along it. The price of the route
+/// is unknown if the pair doesn't return its price, i.e. is on a version predating it.
+fn compound(
+    route_price: Option<Decimal256>,
+    pair_price: Option<Decimal256>,
+) -> StdResult<Option<Decimal256>> {
+    match (route_price, pair_price) {
+        (Some(route_price), Some(pair_price)) => Ok(Some(route_price.checked_mul(pair_price)?)),
+        _ => Ok(None),
+    }
 }
 
 fn get_swap_route(
@@ -436,6 +510,7 @@ fn assert_operations(operations: &[SwapOperation]) -> Result<(), ContractError>
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+                ..
             } => (offer_asset_info.clone(), ask_asset_info.clone()),
         };
 
@@ -464,6 +539,8 @@ fn test_invalid_operations() {
             ask_asset_info: AssetInfo::Token {
                 contract_addr: "asset0001".to_string(),
             },
+            pair_type: None,
+            fee_tier: None,
         },
         SwapOperation::TerraSwap {
             offer_asset_info: AssetInfo::Token {
@@ -472,6 +549,8 @@ fn test_invalid_operations() {
             ask_asset_info: AssetInfo::NativeToken {
                 denom: "uluna".to_string(),
             },
+            pair_type: None,
+            fee_tier: None,
         }
     ])
     .is_ok());
@@ -485,6 +564,8 @@ fn test_invalid_operations() {
             ask_asset_info: AssetInfo::Token {
                 contract_addr: "asset0001".to_string(),
             },
+            pair_type: None,
+            fee_tier: None,
         },
         SwapOperation::TerraSwap {
             offer_asset_info: AssetInfo::Token {
@@ -493,6 +574,8 @@ fn test_invalid_operations() {
             ask_asset_info: AssetInfo::NativeToken {
                 denom: "uluna".to_string(),
             },
+            pair_type: None,
+            fee_tier: None,
         },
         SwapOperation::TerraSwap {
             offer_asset_info: AssetInfo::NativeToken {
@@ -501,6 +584,8 @@ fn test_invalid_operations() {
             ask_asset_info: AssetInfo::Token {
                 contract_addr: "asset0002".to_string(),
             },
+            pair_type: None,
+            fee_tier: None,
         },
     ])
     .is_ok());
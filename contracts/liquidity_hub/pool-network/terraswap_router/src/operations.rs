@@ -1,5 +1,6 @@
 use cosmwasm_std::{
-    to_binary, Addr, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Response, WasmMsg,
+    attr, to_binary, Addr, Attribute, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Response, WasmMsg,
 };
 
 use crate::state::{Config, CONFIG};
@@ -24,17 +25,21 @@ pub fn execute_swap_operation(
         return Err(ContractError::Unauthorized {});
     }
 
-    let messages: Vec<CosmosMsg> = match operation {
+    let (messages, attributes): (Vec<CosmosMsg>, Vec<Attribute>) = match operation {
         SwapOperation::TerraSwap {
             offer_asset_info,
             ask_asset_info,
+            pair_type,
+            fee_tier,
         } => {
             let config: Config = CONFIG.load(deps.as_ref().storage)?;
             let terraswap_factory = deps.api.addr_humanize(&config.terraswap_factory)?;
             let pair_info: PairInfo = query_pair_info(
                 &deps.querier,
                 terraswap_factory,
-                &[offer_asset_info.clone(), ask_asset_info],
+                &[offer_asset_info.clone(), ask_asset_info.clone()],
+                pair_type,
+                fee_tier,
             )?;
 
             let amount = match offer_asset_info.clone() {
@@ -52,17 +57,32 @@ pub fn execute_swap_operation(
                 amount,
             };
 
-            vec![asset_into_swap_msg(
-                deps.as_ref(),
-                Addr::unchecked(pair_info.contract_addr),
-                offer_asset,
-                None,
-                to,
-            )?]
+            // the return amount and the fees of the swap are emitted by the pair in its swap event
+            let pair_contract = Addr::unchecked(pair_info.contract_addr);
+            let attributes = vec![
+                attr("pair_address", pair_contract.as_str()),
+                attr("offer_asset", offer_asset.info.to_string()),
+                attr("ask_asset", ask_asset_info.to_string()),
+                attr("offer_amount", offer_asset.amount.to_string()),
+            ];
+
+            (
+                vec![asset_into_swap_msg(
+                    deps.as_ref(),
+                    pair_contract,
+                    offer_asset,
+                    None,
+                    to,
+                )?],
+                attributes,
+            )
         }
     };
 
-    Ok(Response::new().add_messages(messages))
+    Ok(Response::new()
+        .add_attribute("action", "execute_swap_operation")
+        .add_attributes(attributes)
+        .add_messages(messages))
 }
 
 pub fn asset_into_swap_msg(
@@ -1,1332 +1,928 @@
-use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-use cosmwasm_std::{
-    attr, coin, from_binary, to_binary, Addr, Coin, CosmosMsg, StdError, SubMsg, Uint128, WasmMsg,
-};
-use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
-
-use pool_network::asset::{Asset, AssetInfo, PairInfo, PairType};
-use pool_network::mock_querier::mock_dependencies;
-use pool_network::pair::ExecuteMsg as PairExecuteMsg;
+use cosmwasm_std::{coin, coins, to_binary, Addr, Decimal256, Empty, Event, StdError, Uint128};
+use cw20::Cw20ExecuteMsg;
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+
+use liquidity_hub_testing::{native, PairBuilder, TestSuite};
+use pool_network::asset::{Asset, AssetInfo, PairInfo};
+use pool_network::factory::QueryMsg as FactoryQueryMsg;
+use pool_network::pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse};
 use pool_network::router::{
-    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
-    SimulateSwapOperationsResponse, SwapOperation, SwapRoute,
+    ConfigResponse, Cw20HookMsg, ExecuteMsg, MigrateMsg, QueryMsg, SimulateSwapOperationsResponse,
+    SwapOperation, SwapOperationSimulation, SwapRoute,
 };
 
 use crate::contract::{execute, instantiate, migrate, query};
 use crate::error::ContractError;
-use crate::operations::asset_into_swap_msg;
-use crate::state::SWAP_ROUTES;
-
-#[test]
-fn proper_initialization() {
-    let mut deps = mock_dependencies(&[]);
-
-    let msg = InstantiateMsg {
-        terraswap_factory: "terraswapfactory".to_string(),
-    };
-
-    let info = mock_info("addr0000", &[]);
 
-    // we can just call .unwrap() to assert this was a success
-    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+/// The amount of each asset provided to the pairs
+const POOL_LIQUIDITY: u128 = 1_000_000_000u128;
 
-    // it worked, let's query the state
-    let config: ConfigResponse =
-        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
-    assert_eq!("terraswapfactory", config.terraswap_factory.as_str());
+/// The router built along the tests, so its errors can be downcast
+fn router_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(execute, instantiate, query).with_migrate(migrate))
 }
 
-#[test]
-fn execute_swap_operations() {
-    let mut deps = mock_dependencies(&[]);
-    deps.querier.with_token_balances(&[(
-        &"asset0002".to_string(),
-        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(1000000u128))],
-    )]);
-
-    let msg = InstantiateMsg {
-        terraswap_factory: "terraswapfactory".to_string(),
-    };
-
-    let info = mock_info("addr0000", &[]);
-
-    // we can just call .unwrap() to assert this was a success
-    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+/// Creates the ukrw-asset, asset-uluna and uluna-uwhale pairs, funding addr0000 with the assets
+fn mock_suite() -> (TestSuite, AssetInfo) {
+    let mut suite = TestSuite::builder()
+        .with_funded_user(
+            "addr0000",
+            vec![
+                coin(10 * POOL_LIQUIDITY, "ukrw"),
+                coin(10 * POOL_LIQUIDITY, "uluna"),
+                coin(10 * POOL_LIQUIDITY, "uwhale"),
+            ],
+        )
+        .with_pool_router_contract(router_contract())
+        .build();
+
+    let asset = suite.create_token("asset", &[("addr0000", 10 * POOL_LIQUIDITY)]);
+
+    for asset_infos in [
+        [native("ukrw"), asset.clone()],
+        [asset.clone(), native("uluna")],
+        [native("uluna"), native("uwhale")],
+    ] {
+        suite.create_pair(
+            PairBuilder::new(asset_infos)
+                .with_liquidity("addr0000", [Uint128::new(POOL_LIQUIDITY); 2]),
+        );
+    }
 
-    let msg = ExecuteMsg::ExecuteSwapOperations {
-        operations: vec![],
-        minimum_receive: None,
-        to: None,
-    };
+    (suite, asset)
+}
 
-    let info = mock_info("addr0000", &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg);
-    match res {
-        Err(ContractError::Std(error)) => assert_eq!(
-            error,
-            StdError::generic_err("Must provide swap operations to execute")
-        ),
-        _ => panic!("DO NOT ENTER HERE"),
+fn swap_operation(offer_asset_info: &AssetInfo, ask_asset_info: &AssetInfo) -> SwapOperation {
+    SwapOperation::TerraSwap {
+        offer_asset_info: offer_asset_info.clone(),
+        ask_asset_info: ask_asset_info.clone(),
+        pair_type: None,
+        fee_tier: None,
     }
+}
 
-    let msg = ExecuteMsg::ExecuteSwapOperations {
-        operations: vec![
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::NativeToken {
-                    denom: "ukrw".to_string(),
-                },
-                ask_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0001".to_string(),
-                },
-            },
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0001".to_string(),
-                },
-                ask_asset_info: AssetInfo::NativeToken {
-                    denom: "uluna".to_string(),
-                },
+fn query_pair_addr(suite: &TestSuite, asset_infos: [AssetInfo; 2]) -> Addr {
+    let pair_info: PairInfo = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.pool_factory_addr,
+            &FactoryQueryMsg::Pair {
+                asset_infos,
+                pair_type: None,
+                fee_tier: None,
             },
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::NativeToken {
-                    denom: "uluna".to_string(),
-                },
-                ask_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0002".to_string(),
-                },
+        )
+        .unwrap();
+
+    Addr::unchecked(pair_info.contract_addr)
+}
+
+fn simulate_swap_operations(
+    suite: &TestSuite,
+    offer_amount: Uint128,
+    operations: Vec<SwapOperation>,
+) -> Uint128 {
+    let res: SimulateSwapOperationsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.pool_router_addr,
+            &QueryMsg::SimulateSwapOperations {
+                offer_amount,
+                operations,
             },
-        ],
-        minimum_receive: Some(Uint128::from(1000000u128)),
-        to: None,
-    };
+        )
+        .unwrap();
 
-    let info = mock_info("addr0000", &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
-    assert_eq!(
-        res.messages,
-        vec![
-            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
-                funds: vec![],
-                msg: to_binary(&ExecuteMsg::ExecuteSwapOperation {
-                    operation: SwapOperation::TerraSwap {
-                        offer_asset_info: AssetInfo::NativeToken {
-                            denom: "ukrw".to_string(),
-                        },
-                        ask_asset_info: AssetInfo::Token {
-                            contract_addr: "asset0001".to_string(),
-                        },
-                    },
-                    to: None,
-                })
-                .unwrap(),
-            })),
-            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
-                funds: vec![],
-                msg: to_binary(&ExecuteMsg::ExecuteSwapOperation {
-                    operation: SwapOperation::TerraSwap {
-                        offer_asset_info: AssetInfo::Token {
-                            contract_addr: "asset0001".to_string(),
-                        },
-                        ask_asset_info: AssetInfo::NativeToken {
-                            denom: "uluna".to_string(),
-                        },
-                    },
-                    to: None,
-                })
-                .unwrap(),
-            })),
-            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
-                funds: vec![],
-                msg: to_binary(&ExecuteMsg::ExecuteSwapOperation {
-                    operation: SwapOperation::TerraSwap {
-                        offer_asset_info: AssetInfo::NativeToken {
-                            denom: "uluna".to_string(),
-                        },
-                        ask_asset_info: AssetInfo::Token {
-                            contract_addr: "asset0002".to_string(),
-                        },
-                    },
-                    to: Some("addr0000".to_string()),
-                })
-                .unwrap(),
-            })),
-            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
-                funds: vec![],
-                msg: to_binary(&ExecuteMsg::AssertMinimumReceive {
-                    asset_info: AssetInfo::Token {
-                        contract_addr: "asset0002".to_string(),
-                    },
-                    prev_balance: Uint128::zero(),
-                    minimum_receive: Uint128::from(1000000u128),
-                    receiver: "addr0000".to_string(),
-                })
-                .unwrap(),
-            })),
-        ]
-    );
+    res.amount
+}
 
-    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
-        sender: "addr0000".to_string(),
-        amount: Uint128::from(1000000u128),
-        msg: to_binary(&Cw20HookMsg::ExecuteSwapOperations {
-            operations: vec![
-                SwapOperation::TerraSwap {
-                    offer_asset_info: AssetInfo::NativeToken {
-                        denom: "ukrw".to_string(),
-                    },
-                    ask_asset_info: AssetInfo::Token {
-                        contract_addr: "asset0001".to_string(),
-                    },
-                },
-                SwapOperation::TerraSwap {
-                    offer_asset_info: AssetInfo::Token {
-                        contract_addr: "asset0001".to_string(),
-                    },
-                    ask_asset_info: AssetInfo::NativeToken {
-                        denom: "uluna".to_string(),
-                    },
-                },
-                SwapOperation::TerraSwap {
-                    offer_asset_info: AssetInfo::NativeToken {
-                        denom: "uluna".to_string(),
-                    },
-                    ask_asset_info: AssetInfo::Token {
-                        contract_addr: "asset0002".to_string(),
-                    },
-                },
-            ],
-            minimum_receive: None,
-            to: Some("addr0002".to_string()),
-        })
-        .unwrap(),
-    });
-
-    let info = mock_info("asset0000", &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+#[test]
+fn proper_initialization() {
+    let (suite, _) = mock_suite();
+
+    let config: ConfigResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.pool_router_addr, &QueryMsg::Config {})
+        .unwrap();
     assert_eq!(
-        res.messages,
-        vec![
-            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
-                funds: vec![],
-                msg: to_binary(&ExecuteMsg::ExecuteSwapOperation {
-                    operation: SwapOperation::TerraSwap {
-                        offer_asset_info: AssetInfo::NativeToken {
-                            denom: "ukrw".to_string(),
-                        },
-                        ask_asset_info: AssetInfo::Token {
-                            contract_addr: "asset0001".to_string(),
-                        },
-                    },
-                    to: None,
-                })
-                .unwrap(),
-            })),
-            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
-                funds: vec![],
-                msg: to_binary(&ExecuteMsg::ExecuteSwapOperation {
-                    operation: SwapOperation::TerraSwap {
-                        offer_asset_info: AssetInfo::Token {
-                            contract_addr: "asset0001".to_string(),
-                        },
-                        ask_asset_info: AssetInfo::NativeToken {
-                            denom: "uluna".to_string(),
-                        },
-                    },
-                    to: None,
-                })
-                .unwrap(),
-            })),
-            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
-                funds: vec![],
-                msg: to_binary(&ExecuteMsg::ExecuteSwapOperation {
-                    operation: SwapOperation::TerraSwap {
-                        offer_asset_info: AssetInfo::NativeToken {
-                            denom: "uluna".to_string(),
-                        },
-                        ask_asset_info: AssetInfo::Token {
-                            contract_addr: "asset0002".to_string(),
-                        },
-                    },
-                    to: Some("addr0002".to_string()),
-                })
-                .unwrap(),
-            })),
-        ]
+        config.terraswap_factory,
+        suite.pool_factory_addr.to_string()
     );
 }
 
 #[test]
-fn execute_swap_operation() {
-    let mut deps = mock_dependencies(&[]);
-    let msg = InstantiateMsg {
-        terraswap_factory: "terraswapfactory".to_string(),
-    };
-
-    let info = mock_info("addr0000", &[]);
-
-    // we can just call .unwrap() to assert this was a success
-    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
-
-    deps.querier.with_pool_factory(
-        &[(
-            &"uusdasset0000".to_string(),
-            &PairInfo {
-                asset_infos: [
-                    AssetInfo::NativeToken {
-                        denom: "uusd".to_string(),
-                    },
-                    AssetInfo::Token {
-                        contract_addr: "asset0000".to_string(),
-                    },
-                ],
-                contract_addr: "pair0000".to_string(),
-                liquidity_token: "liquidity0000".to_string(),
-                asset_decimals: [6u8, 6u8],
-                pair_type: PairType::ConstantProduct,
-            },
-        )],
-        &[("uusd".to_string(), 6u8)],
-    );
-    deps.querier.with_balance(&[(
-        &MOCK_CONTRACT_ADDR.to_string(),
-        [Coin {
-            amount: Uint128::from(1000000u128),
-            denom: "uusd".to_string(),
-        }]
-        .to_vec(),
-    )]);
-
-    let msg = ExecuteMsg::ExecuteSwapOperation {
-        operation: SwapOperation::TerraSwap {
-            offer_asset_info: AssetInfo::NativeToken {
-                denom: "uusd".to_string(),
-            },
-            ask_asset_info: AssetInfo::Token {
-                contract_addr: "asset0000".to_string(),
+fn execute_swap_operations() {
+    let (mut suite, asset) = mock_suite();
+    let sender = Addr::unchecked("addr0000");
+
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            sender.clone(),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::ExecuteSwapOperations {
+                operations: vec![],
+                minimum_receive: None,
+                to: None,
             },
-        },
-        to: None,
-    };
-    let info = mock_info("addr0000", &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg.clone());
-    match res {
-        Err(ContractError::Unauthorized {}) => (),
-        _ => panic!("DO NOT ENTER HERE"),
-    }
-
-    let info = mock_info(MOCK_CONTRACT_ADDR, &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
     assert_eq!(
-        res.messages,
-        vec![SubMsg::new(
-            asset_into_swap_msg(
-                deps.as_ref(),
-                Addr::unchecked("pair0000"),
-                Asset {
-                    info: AssetInfo::NativeToken {
-                        denom: "uusd".to_string(),
-                    },
-                    amount: Uint128::from(1000000u128),
-                },
-                None,
-                None,
-            )
-            .unwrap()
-        )],
+        err.to_string(),
+        ContractError::Std(StdError::generic_err(
+            "Must provide swap operations to execute"
+        ))
+        .to_string()
     );
 
-    // optional to address
-    // swap_send
-    let msg = ExecuteMsg::ExecuteSwapOperation {
-        operation: SwapOperation::TerraSwap {
-            offer_asset_info: AssetInfo::NativeToken {
-                denom: "uusd".to_string(),
-            },
-            ask_asset_info: AssetInfo::Token {
-                contract_addr: "asset0000".to_string(),
+    // ukrw -> asset -> uluna -> uwhale
+    let operations = vec![
+        swap_operation(&native("ukrw"), &asset),
+        swap_operation(&asset, &native("uluna")),
+        swap_operation(&native("uluna"), &native("uwhale")),
+    ];
+    let offer_amount = Uint128::new(1_000_000u128);
+    let expected_return = simulate_swap_operations(&suite, offer_amount, operations.clone());
+
+    let uwhale_balance = suite.query_balance("addr0000", &native("uwhale"));
+    suite
+        .app
+        .execute_contract(
+            sender.clone(),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::ExecuteSwapOperations {
+                operations,
+                minimum_receive: Some(expected_return),
+                to: None,
             },
-        },
-        to: Some("addr0000".to_string()),
-    };
-    let info = mock_info(MOCK_CONTRACT_ADDR, &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+            &coins(offer_amount.u128(), "ukrw"),
+        )
+        .unwrap();
     assert_eq!(
-        res.messages,
-        vec![SubMsg::new(
-            asset_into_swap_msg(
-                deps.as_ref(),
-                Addr::unchecked("pair0000"),
-                Asset {
-                    info: AssetInfo::NativeToken {
-                        denom: "uusd".to_string(),
-                    },
-                    amount: Uint128::from(1000000u128),
-                },
-                None,
-                Some("addr0000".to_string()),
-            )
-            .unwrap()
-        )],
+        suite.query_balance("addr0000", &native("uwhale")),
+        uwhale_balance + expected_return
     );
-    deps.querier.with_pool_factory(
-        &[(
-            &"assetuusd".to_string(),
-            &PairInfo {
-                asset_infos: [
-                    AssetInfo::Token {
-                        contract_addr: "asset".to_string(),
-                    },
-                    AssetInfo::NativeToken {
-                        denom: "uusd".to_string(),
-                    },
-                ],
-                contract_addr: "pair0000".to_string(),
-                liquidity_token: "liquidity0000".to_string(),
-                asset_decimals: [6u8, 6u8],
-                pair_type: PairType::ConstantProduct,
-            },
-        )],
-        &[("uusd".to_string(), 6u8)],
-    );
-    deps.querier.with_token_balances(&[(
-        &"asset".to_string(),
-        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(1000000u128))],
-    )]);
-
-    let msg = ExecuteMsg::ExecuteSwapOperation {
-        operation: SwapOperation::TerraSwap {
-            offer_asset_info: AssetInfo::Token {
-                contract_addr: "asset".to_string(),
-            },
-            ask_asset_info: AssetInfo::NativeToken {
-                denom: "uusd".to_string(),
-            },
-        },
-        to: Some("addr0000".to_string()),
-    };
 
-    let info = mock_info(MOCK_CONTRACT_ADDR, &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
-    assert_eq!(
-        res.messages,
-        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: "asset".to_string(),
-            funds: vec![],
-            msg: to_binary(&Cw20ExecuteMsg::Send {
-                contract: "pair0000".to_string(),
-                amount: Uint128::from(1000000u128),
-                msg: to_binary(&pool_network::pair::Cw20HookMsg::Swap {
-                    belief_price: None,
-                    max_spread: None,
-                    to: Some("addr0000".to_string()),
+    // asset -> uluna -> uwhale, through the cw20 hook and to another address
+    let operations = vec![
+        swap_operation(&asset, &native("uluna")),
+        swap_operation(&native("uluna"), &native("uwhale")),
+    ];
+    let expected_return = simulate_swap_operations(&suite, offer_amount, operations.clone());
+
+    let AssetInfo::Token { contract_addr } = &asset else {
+        unreachable!()
+    };
+    suite
+        .app
+        .execute_contract(
+            sender,
+            Addr::unchecked(contract_addr),
+            &Cw20ExecuteMsg::Send {
+                contract: suite.pool_router_addr.to_string(),
+                amount: offer_amount,
+                msg: to_binary(&Cw20HookMsg::ExecuteSwapOperations {
+                    operations,
+                    minimum_receive: None,
+                    to: Some("addr0002".to_string()),
                 })
                 .unwrap(),
-            })
-            .unwrap(),
-        }))]
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        suite.query_balance("addr0002", &native("uwhale")),
+        expected_return
     );
+
+    // the router doesn't keep any of the assets swapped through
+    for asset_info in [native("ukrw"), asset, native("uluna"), native("uwhale")] {
+        assert_eq!(
+            suite.query_balance(suite.pool_router_addr.as_str(), &asset_info),
+            Uint128::zero()
+        );
+    }
 }
 
 #[test]
-fn query_buy_with_routes() {
-    let mut deps = mock_dependencies(&[]);
-
-    let msg = InstantiateMsg {
-        terraswap_factory: "terraswapfactory".to_string(),
-    };
-
-    let info = mock_info("addr0000", &[]);
-
-    // we can just call .unwrap() to assert this was a success
-    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
-
-    // try simulating with empty operations
-    let empty_operations_msg = QueryMsg::SimulateSwapOperations {
-        offer_amount: Uint128::from(1000000u128),
-        operations: vec![],
-    };
-
-    let error = query(deps.as_ref(), mock_env(), empty_operations_msg).unwrap_err();
-
-    match error {
-        ContractError::NoSwapOperationsProvided {} => (),
-        _ => panic!("should return ContractError::NoSwapOperationsProvided"),
-    }
+fn execute_swap_operations_below_minimum_receive() {
+    let (mut suite, asset) = mock_suite();
 
-    let msg = QueryMsg::SimulateSwapOperations {
-        offer_amount: Uint128::from(1000000u128),
-        operations: vec![
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::NativeToken {
-                    denom: "ukrw".to_string(),
-                },
-                ask_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-            },
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-                ask_asset_info: AssetInfo::NativeToken {
-                    denom: "uluna".to_string(),
-                },
+    let operations = vec![
+        swap_operation(&native("ukrw"), &asset),
+        swap_operation(&asset, &native("uluna")),
+    ];
+    let offer_amount = Uint128::new(1_000_000u128);
+    let expected_return = simulate_swap_operations(&suite, offer_amount, operations.clone());
+
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked("addr0000"),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::ExecuteSwapOperations {
+                operations,
+                minimum_receive: Some(expected_return + Uint128::one()),
+                to: None,
             },
-        ],
-    };
-
-    deps.querier.with_pool_factory(
-        &[
-            (
-                &"ukrwasset0000".to_string(),
-                &PairInfo {
-                    asset_infos: [
-                        AssetInfo::NativeToken {
-                            denom: "ukrw".to_string(),
-                        },
-                        AssetInfo::Token {
-                            contract_addr: "asset0000".to_string(),
-                        },
-                    ],
-                    contract_addr: "pair0000".to_string(),
-                    liquidity_token: "liquidity0000".to_string(),
-                    asset_decimals: [6u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
-                },
-            ),
-            (
-                &"asset0000uluna".to_string(),
-                &PairInfo {
-                    asset_infos: [
-                        AssetInfo::Token {
-                            contract_addr: "asset0000".to_string(),
-                        },
-                        AssetInfo::NativeToken {
-                            denom: "uluna".to_string(),
-                        },
-                    ],
-                    contract_addr: "pair0001".to_string(),
-                    liquidity_token: "liquidity0001".to_string(),
-                    asset_decimals: [6u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
-                },
-            ),
-        ],
-        &[("ukrw".to_string(), 6u8), ("uluna".to_string(), 6u8)],
-    );
-
-    let res: SimulateSwapOperationsResponse =
-        from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+            &coins(offer_amount.u128(), "ukrw"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
     assert_eq!(
-        res,
-        SimulateSwapOperationsResponse {
-            amount: Uint128::from(1000000u128)
+        err.to_string(),
+        ContractError::MinimumReceiveAssertion {
+            minimum_receive: expected_return + Uint128::one(),
+            swap_amount: expected_return,
         }
+        .to_string()
     );
 }
 
 #[test]
-fn query_reverse_routes_with_from_native() {
-    let mut deps = mock_dependencies(&[]);
-
-    let msg = InstantiateMsg {
-        terraswap_factory: "terraswapfactory".to_string(),
-    };
-
-    let target_amount = 1000000u128;
+fn execute_swap_operations_emits_the_breakdown_of_each_swap() {
+    let (mut suite, asset) = mock_suite();
 
-    let info = mock_info("addr0000", &[coin(10000000, "ukrw")]);
-
-    // we can just call .unwrap() to assert this was a success
-    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+    let operations = vec![
+        swap_operation(&native("ukrw"), &asset),
+        swap_operation(&asset, &native("uluna")),
+    ];
+    let offer_amount = Uint128::new(1_000_000u128);
+    let simulation: SimulateSwapOperationsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.pool_router_addr,
+            &QueryMsg::SimulateSwapOperations {
+                offer_amount,
+                operations: operations.clone(),
+            },
+        )
+        .unwrap();
+
+    let res = suite
+        .app
+        .execute_contract(
+            Addr::unchecked("addr0000"),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::ExecuteSwapOperations {
+                operations,
+                minimum_receive: None,
+                to: None,
+            },
+            &coins(offer_amount.u128(), "ukrw"),
+        )
+        .unwrap();
+
+    // the router identifies each swap, the pair swapping emits its return amount and fees
+    for operation in simulation.operations {
+        assert!(res.has_event(&Event::new("wasm").add_attributes(vec![
+            ("action", "execute_swap_operation".to_string()),
+            ("pair_address", operation.pair_address),
+            ("offer_asset", operation.offer_asset.info.to_string()),
+            ("ask_asset", operation.ask_asset.info.to_string()),
+            ("offer_amount", operation.offer_asset.amount.to_string()),
+        ])));
+        assert!(res.has_event(&Event::new("wasm").add_attributes(vec![
+            ("action", "swap".to_string()),
+            ("offer_asset", operation.offer_asset.info.to_string()),
+            ("ask_asset", operation.ask_asset.info.to_string()),
+            ("offer_amount", operation.offer_asset.amount.to_string()),
+            ("return_amount", operation.ask_asset.amount.to_string()),
+            ("spread_amount", operation.spread_amount.to_string()),
+            ("swap_fee_amount", operation.swap_fee_amount.to_string()),
+            (
+                "protocol_fee_amount",
+                operation.protocol_fee_amount.to_string()
+            ),
+            ("burn_fee_amount", operation.burn_fee_amount.to_string()),
+        ])));
+    }
+}
 
-    deps.querier.with_balance(&[(
-        &MOCK_CONTRACT_ADDR.to_string(),
-        [Coin {
-            amount: Uint128::from(1000000u128),
-            denom: "ukrw".to_string(),
-        }]
-        .to_vec(),
-    )]);
+#[test]
+fn execute_swap_operation_unauthorized() {
+    let (mut suite, asset) = mock_suite();
+
+    // only the router can execute single swap operations
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked("addr0000"),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::ExecuteSwapOperation {
+                operation: swap_operation(&native("ukrw"), &asset),
+                to: None,
+            },
+            &coins(1_000_000u128, "ukrw"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err.to_string(), ContractError::Unauthorized {}.to_string());
+}
 
-    deps.querier.with_token_balances(&[(
-        &"asset0001".to_string(),
-        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(1000000u128))],
-    )]);
+#[test]
+fn query_buy_with_routes() {
+    let (suite, asset) = mock_suite();
 
     // try simulating with empty operations
-    let empty_operations_msg = QueryMsg::ReverseSimulateSwapOperations {
-        ask_amount: Uint128::from(target_amount),
-        operations: vec![],
-    };
-
-    let error = query(deps.as_ref(), mock_env(), empty_operations_msg).unwrap_err();
-
-    match error {
-        ContractError::NoSwapOperationsProvided {} => (),
-        _ => panic!("should return ContractError::NoSwapOperationsProvided"),
-    }
-
-    let msg = QueryMsg::ReverseSimulateSwapOperations {
-        ask_amount: Uint128::from(target_amount),
-        operations: vec![SwapOperation::TerraSwap {
-            offer_asset_info: AssetInfo::NativeToken {
-                denom: "ukrw".to_string(),
+    let err = suite
+        .app
+        .wrap()
+        .query_wasm_smart::<SimulateSwapOperationsResponse>(
+            &suite.pool_router_addr,
+            &QueryMsg::SimulateSwapOperations {
+                offer_amount: Uint128::new(1_000_000u128),
+                operations: vec![],
             },
-            ask_asset_info: AssetInfo::Token {
-                contract_addr: "asset0000".to_string(),
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NoSwapOperationsProvided {}.to_string()));
+
+    let offer_amount = Uint128::new(1_000_000u128);
+    let res: SimulateSwapOperationsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.pool_router_addr,
+            &QueryMsg::SimulateSwapOperations {
+                offer_amount,
+                operations: vec![
+                    swap_operation(&native("ukrw"), &asset),
+                    swap_operation(&asset, &native("uluna")),
+                ],
             },
-        }],
-    };
-
-    deps.querier.with_pool_factory(
-        &[
-            (
-                &"ukrwasset0000".to_string(),
-                &PairInfo {
-                    contract_addr: "pair0000".to_string(),
-                    liquidity_token: "liquidity0000".to_string(),
-                    asset_infos: [
-                        AssetInfo::Token {
-                            contract_addr: "asset0000".to_string(),
-                        },
-                        AssetInfo::NativeToken {
-                            denom: "ukrw".to_string(),
-                        },
-                    ],
-                    asset_decimals: [8u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
+        )
+        .unwrap();
+
+    // the simulation chains the simulations of the pairs swapped through
+    let first_hop: SimulationResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            query_pair_addr(&suite, [native("ukrw"), asset.clone()]),
+            &PairQueryMsg::Simulation {
+                offer_asset: Asset {
+                    info: native("ukrw"),
+                    amount: offer_amount,
                 },
-            ),
-            (
-                &"asset0000uluna".to_string(),
-                &PairInfo {
-                    contract_addr: "pair0001".to_string(),
-                    liquidity_token: "liquidity0001".to_string(),
-                    asset_infos: [
-                        AssetInfo::Token {
-                            contract_addr: "asset0000".to_string(),
-                        },
-                        AssetInfo::NativeToken {
-                            denom: "uluna".to_string(),
-                        },
-                    ],
-                    asset_decimals: [8u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
+            },
+        )
+        .unwrap();
+    let second_hop: SimulationResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            query_pair_addr(&suite, [asset.clone(), native("uluna")]),
+            &PairQueryMsg::Simulation {
+                offer_asset: Asset {
+                    info: asset.clone(),
+                    amount: first_hop.return_amount,
                 },
-            ),
-        ],
-        &[("ukrw".to_string(), 6u8), ("uluna".to_string(), 6u8)],
-    );
-
-    let res: SimulateSwapOperationsResponse =
-        from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+            },
+        )
+        .unwrap();
+    assert_eq!(res.amount, second_hop.return_amount);
 
+    // and compounds their prices
     assert_eq!(
-        res,
-        SimulateSwapOperationsResponse {
-            amount: Uint128::from(1000000u128),
-        }
+        res.pre_trade_spot_price.unwrap(),
+        first_hop.pre_trade_spot_price.unwrap() * second_hop.pre_trade_spot_price.unwrap()
     );
+    assert_eq!(
+        res.post_trade_spot_price.unwrap(),
+        first_hop.post_trade_spot_price.unwrap() * second_hop.post_trade_spot_price.unwrap()
+    );
+    let price_impact = res.price_impact.unwrap();
+    assert_eq!(
+        price_impact,
+        Decimal256::one()
+            - (Decimal256::one() - first_hop.price_impact.unwrap())
+                * (Decimal256::one() - second_hop.price_impact.unwrap())
+    );
+    assert!(price_impact > first_hop.price_impact.unwrap());
+    assert!(price_impact > second_hop.price_impact.unwrap());
 
-    let offer_amount = res.amount;
-
-    let msg = ExecuteMsg::ExecuteSwapOperation {
-        operation: SwapOperation::TerraSwap {
-            offer_asset_info: AssetInfo::NativeToken {
-                denom: "ukrw".to_string(),
-            },
-            ask_asset_info: AssetInfo::Token {
-                contract_addr: "asset0000".to_string(),
-            },
-        },
-        to: None,
-    };
-    let info = mock_info("addr0", &[coin(offer_amount.u128(), "ukrw")]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg.clone());
-    match res {
-        Err(ContractError::Unauthorized {}) => (),
-        _ => panic!("DO NOT ENTER HERE"),
-    }
-
-    let info = mock_info(MOCK_CONTRACT_ADDR, &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
-
+    // with the breakdown of each swap
     assert_eq!(
-        res.messages,
-        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: "pair0000".to_string(),
-            funds: vec![coin(target_amount, "ukrw")],
-            msg: to_binary(&PairExecuteMsg::Swap {
+        res.operations,
+        vec![
+            SwapOperationSimulation {
+                pair_address: query_pair_addr(&suite, [native("ukrw"), asset.clone()]).to_string(),
                 offer_asset: Asset {
-                    info: AssetInfo::NativeToken {
-                        denom: "ukrw".to_string(),
-                    },
-                    amount: Uint128::from(target_amount),
+                    info: native("ukrw"),
+                    amount: offer_amount,
                 },
-                belief_price: None,
-                max_spread: None,
-                to: None,
-            })
-            .unwrap(),
-        })),],
+                ask_asset: Asset {
+                    info: asset.clone(),
+                    amount: first_hop.return_amount,
+                },
+                spread_amount: first_hop.spread_amount,
+                swap_fee_amount: first_hop.swap_fee_amount,
+                protocol_fee_amount: first_hop.protocol_fee_amount,
+                burn_fee_amount: first_hop.burn_fee_amount,
+            },
+            SwapOperationSimulation {
+                pair_address: query_pair_addr(&suite, [asset.clone(), native("uluna")]).to_string(),
+                offer_asset: Asset {
+                    info: asset,
+                    amount: first_hop.return_amount,
+                },
+                ask_asset: Asset {
+                    info: native("uluna"),
+                    amount: second_hop.return_amount,
+                },
+                spread_amount: second_hop.spread_amount,
+                swap_fee_amount: second_hop.swap_fee_amount,
+                protocol_fee_amount: second_hop.protocol_fee_amount,
+                burn_fee_amount: second_hop.burn_fee_amount,
+            },
+        ]
     );
 }
 
 #[test]
-fn query_reverse_routes_with_to_native() {
-    let mut deps = mock_dependencies(&[]);
-
-    let msg = InstantiateMsg {
-        terraswap_factory: "terraswapfactory".to_string(),
-    };
-
-    let target_amount = 1000000u128;
-
-    let info = mock_info("addr0000", &[]);
-
-    // we can just call .unwrap() to assert this was a success
-    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
-
-    deps.querier.with_token_balances(&[
-        (
-            &"asset0000".to_string(),
-            &[(&"pair0000".to_string(), &Uint128::from(1000000u128))],
-        ),
-        (
-            &"asset0000".to_string(),
-            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::from(1000000u128))],
-        ),
-    ]);
-
-    let msg = QueryMsg::ReverseSimulateSwapOperations {
-        ask_amount: Uint128::from(target_amount),
-        operations: vec![SwapOperation::TerraSwap {
-            offer_asset_info: AssetInfo::Token {
-                contract_addr: "asset0000".to_string(),
-            },
-            ask_asset_info: AssetInfo::NativeToken {
-                denom: "ukrw".to_string(),
+fn query_reverse_routes_breakdown() {
+    let (suite, asset) = mock_suite();
+    let ask_amount = Uint128::new(1_000_000u128);
+
+    // ukrw -> asset -> uluna
+    let res: SimulateSwapOperationsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.pool_router_addr,
+            &QueryMsg::ReverseSimulateSwapOperations {
+                ask_amount,
+                operations: vec![
+                    swap_operation(&native("ukrw"), &asset),
+                    swap_operation(&asset, &native("uluna")),
+                ],
             },
-        }],
-    };
+        )
+        .unwrap();
 
-    deps.querier.with_pool_factory(
-        &[
-            (
-                &"ukrwasset0000".to_string(),
-                &PairInfo {
-                    contract_addr: "pair0000".to_string(),
-                    liquidity_token: "liquidity0000".to_string(),
-                    asset_infos: [
-                        AssetInfo::Token {
-                            contract_addr: "asset0000".to_string(),
-                        },
-                        AssetInfo::NativeToken {
-                            denom: "ukrw".to_string(),
-                        },
-                    ],
-                    asset_decimals: [8u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
-                },
-            ),
-            (
-                &"asset0000uluna".to_string(),
-                &PairInfo {
-                    contract_addr: "pair0001".to_string(),
-                    liquidity_token: "liquidity0001".to_string(),
-                    asset_infos: [
-                        AssetInfo::Token {
-                            contract_addr: "asset0000".to_string(),
-                        },
-                        AssetInfo::NativeToken {
-                            denom: "uluna".to_string(),
-                        },
-                    ],
-                    asset_decimals: [8u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
-                },
-            ),
-        ],
-        &[("ukrw".to_string(), 6u8), ("uluna".to_string(), 6u8)],
+    // the breakdown follows the order of the route, each swap asking for the offer of the next one
+    let [first_hop, second_hop] = res.operations.as_slice() else {
+        panic!("expected two swap operations, got {:?}", res.operations)
+    };
+    assert_eq!(
+        first_hop.pair_address,
+        query_pair_addr(&suite, [native("ukrw"), asset.clone()]).to_string()
     );
-
-    let res: SimulateSwapOperationsResponse =
-        from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
-
     assert_eq!(
-        res,
-        SimulateSwapOperationsResponse {
-            amount: Uint128::from(target_amount),
+        first_hop.offer_asset,
+        Asset {
+            info: native("ukrw"),
+            amount: res.amount,
+        }
+    );
+    assert_eq!(first_hop.ask_asset, second_hop.offer_asset);
+    assert_eq!(
+        second_hop.pair_address,
+        query_pair_addr(&suite, [asset, native("uluna")]).to_string()
+    );
+    assert_eq!(
+        second_hop.ask_asset,
+        Asset {
+            info: native("uluna"),
+            amount: ask_amount,
         }
     );
 
-    let offer_amount = res.amount;
+    // the fees match the reverse simulation of the last pair
+    let pair_res: ReverseSimulationResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &second_hop.pair_address,
+            &PairQueryMsg::ReverseSimulation {
+                ask_asset: second_hop.ask_asset.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(second_hop.offer_asset.amount, pair_res.offer_amount);
+    assert_eq!(second_hop.spread_amount, pair_res.spread_amount);
+    assert_eq!(second_hop.swap_fee_amount, pair_res.swap_fee_amount);
+    assert_eq!(second_hop.protocol_fee_amount, pair_res.protocol_fee_amount);
+    assert_eq!(second_hop.burn_fee_amount, pair_res.burn_fee_amount);
+}
 
-    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
-        sender: "addr0".to_string(),
-        amount: offer_amount,
-        msg: to_binary(&Cw20HookMsg::ExecuteSwapOperations {
-            operations: vec![SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-                ask_asset_info: AssetInfo::NativeToken {
-                    denom: "ukrw".to_string(),
-                },
-            }],
-            minimum_receive: None,
-            to: None,
-        })
-        .unwrap(),
-    });
-    let info = mock_info("addr0", &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+#[test]
+fn query_reverse_routes_with_from_native() {
+    let (mut suite, asset) = mock_suite();
+    let ask_amount = Uint128::new(1_000_000u128);
 
-    assert_eq!(
-        res.messages,
-        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: MOCK_CONTRACT_ADDR.to_string(),
-            funds: vec![],
-            msg: to_binary(&ExecuteMsg::ExecuteSwapOperation {
-                operation: SwapOperation::TerraSwap {
-                    offer_asset_info: AssetInfo::Token {
-                        contract_addr: "asset0000".to_string(),
-                    },
-                    ask_asset_info: AssetInfo::NativeToken {
-                        denom: "ukrw".to_string(),
-                    },
+    // try simulating with empty operations
+    let err = suite
+        .app
+        .wrap()
+        .query_wasm_smart::<SimulateSwapOperationsResponse>(
+            &suite.pool_router_addr,
+            &QueryMsg::ReverseSimulateSwapOperations {
+                ask_amount,
+                operations: vec![],
+            },
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NoSwapOperationsProvided {}.to_string()));
+
+    let operations = vec![swap_operation(&native("ukrw"), &asset)];
+    let res: SimulateSwapOperationsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.pool_router_addr,
+            &QueryMsg::ReverseSimulateSwapOperations {
+                ask_amount,
+                operations: operations.clone(),
+            },
+        )
+        .unwrap();
+
+    let pair_res: ReverseSimulationResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            query_pair_addr(&suite, [native("ukrw"), asset.clone()]),
+            &PairQueryMsg::ReverseSimulation {
+                ask_asset: Asset {
+                    info: asset.clone(),
+                    amount: ask_amount,
                 },
-                to: Some("addr0".to_string()),
-            })
-            .unwrap(),
-        })),],
-    );
-
-    let msg = ExecuteMsg::ExecuteSwapOperation {
-        operation: SwapOperation::TerraSwap {
-            offer_asset_info: AssetInfo::Token {
-                contract_addr: "asset0000".to_string(),
             },
-            ask_asset_info: AssetInfo::NativeToken {
-                denom: "ukrw".to_string(),
+        )
+        .unwrap();
+    assert_eq!(res.amount, pair_res.offer_amount);
+
+    // swapping the offer amount simulated returns at least the amount asked
+    suite.fund("addr0001", coins(res.amount.u128(), "ukrw"));
+    suite
+        .app
+        .execute_contract(
+            Addr::unchecked("addr0001"),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::ExecuteSwapOperations {
+                operations,
+                minimum_receive: Some(ask_amount),
+                to: None,
             },
-        },
-        to: None,
-    };
-
-    let info = mock_info(MOCK_CONTRACT_ADDR, &[]);
-    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+            &coins(res.amount.u128(), "ukrw"),
+        )
+        .unwrap();
+    assert!(suite.query_balance("addr0001", &asset) >= ask_amount);
+}
 
-    assert_eq!(
-        res.messages,
-        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: "asset0000".to_string(),
-            funds: vec![],
-            msg: to_binary(&Cw20ExecuteMsg::Send {
-                contract: "pair0000".to_string(),
-                amount: Uint128::from(target_amount),
-                msg: to_binary(&pool_network::pair::Cw20HookMsg::Swap {
-                    belief_price: None,
-                    max_spread: None,
-                    to: None,
+#[test]
+fn query_reverse_routes_with_to_native() {
+    let (mut suite, asset) = mock_suite();
+    let ask_amount = Uint128::new(1_000_000u128);
+
+    let operations = vec![swap_operation(&asset, &native("ukrw"))];
+    let res: SimulateSwapOperationsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.pool_router_addr,
+            &QueryMsg::ReverseSimulateSwapOperations {
+                ask_amount,
+                operations: operations.clone(),
+            },
+        )
+        .unwrap();
+
+    let pair_res: ReverseSimulationResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            query_pair_addr(&suite, [native("ukrw"), asset.clone()]),
+            &PairQueryMsg::ReverseSimulation {
+                ask_asset: Asset {
+                    info: native("ukrw"),
+                    amount: ask_amount,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(res.amount, pair_res.offer_amount);
+
+    // swapping the offer amount simulated returns at least the amount asked
+    let AssetInfo::Token { contract_addr } = &asset else {
+        unreachable!()
+    };
+    suite
+        .app
+        .execute_contract(
+            Addr::unchecked("addr0000"),
+            Addr::unchecked(contract_addr),
+            &Cw20ExecuteMsg::Send {
+                contract: suite.pool_router_addr.to_string(),
+                amount: res.amount,
+                msg: to_binary(&Cw20HookMsg::ExecuteSwapOperations {
+                    operations,
+                    minimum_receive: None,
+                    to: Some("addr0001".to_string()),
                 })
                 .unwrap(),
-            })
-            .unwrap(),
-        }))],
-    );
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(suite.query_balance("addr0001", &native("ukrw")) >= ask_amount);
 }
 
 #[test]
 fn assert_minimum_receive_native_token() {
-    let mut deps = mock_dependencies(&[]);
-    deps.querier.with_balance(&[(
-        &"addr0000".to_string(),
-        [Coin {
-            denom: "uusd".to_string(),
-            amount: Uint128::from(1000000u128),
-        }]
-        .to_vec(),
-    )]);
-
-    let info = mock_info("addr0000", &[]);
+    let mut suite = TestSuite::builder()
+        .with_funded_user("addr0000", coins(1_000_000u128, "uusd"))
+        .with_pool_router_contract(router_contract())
+        .build();
+
     // success
-    let msg = ExecuteMsg::AssertMinimumReceive {
-        asset_info: AssetInfo::NativeToken {
-            denom: "uusd".to_string(),
-        },
-        prev_balance: Uint128::zero(),
-        minimum_receive: Uint128::from(1000000u128),
-        receiver: "addr0000".to_string(),
-    };
-    let _res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    suite
+        .app
+        .execute_contract(
+            Addr::unchecked("addr0000"),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::AssertMinimumReceive {
+                asset_info: native("uusd"),
+                prev_balance: Uint128::zero(),
+                minimum_receive: Uint128::new(1_000_000u128),
+                receiver: "addr0000".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
 
     // assertion failed; native token
-    let msg = ExecuteMsg::AssertMinimumReceive {
-        asset_info: AssetInfo::NativeToken {
-            denom: "uusd".to_string(),
-        },
-        prev_balance: Uint128::zero(),
-        minimum_receive: Uint128::from(1000001u128),
-        receiver: "addr0000".to_string(),
-    };
-    let res = execute(deps.as_mut(), mock_env(), info, msg);
-    match res {
-        Err(ContractError::MinimumReceiveAssertion {
-            minimum_receive,
-            swap_amount,
-        }) => {
-            assert_eq!(minimum_receive, Uint128::new(1000001));
-            assert_eq!(swap_amount, Uint128::new(1000000));
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked("addr0000"),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::AssertMinimumReceive {
+                asset_info: native("uusd"),
+                prev_balance: Uint128::zero(),
+                minimum_receive: Uint128::new(1_000_001u128),
+                receiver: "addr0000".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        ContractError::MinimumReceiveAssertion {
+            minimum_receive: Uint128::new(1_000_001u128),
+            swap_amount: Uint128::new(1_000_000u128),
         }
-        _ => panic!("DO NOT ENTER HERE"),
-    }
+        .to_string()
+    );
 }
 
 #[test]
 fn assert_minimum_receive_token() {
-    let mut deps = mock_dependencies(&[]);
-    deps.querier.with_token_balances(&[(
-        &"token0000".to_string(),
-        &[(&"addr0000".to_string(), &Uint128::from(1000000u128))],
-    )]);
+    let mut suite = TestSuite::builder()
+        .with_pool_router_contract(router_contract())
+        .build();
+    let token = suite.create_token("token", &[("addr0000", 1_000_000u128)]);
 
-    let info = mock_info("addr0000", &[]);
     // success
-    let msg = ExecuteMsg::AssertMinimumReceive {
-        asset_info: AssetInfo::Token {
-            contract_addr: "token0000".to_string(),
-        },
-        prev_balance: Uint128::zero(),
-        minimum_receive: Uint128::from(1000000u128),
-        receiver: "addr0000".to_string(),
-    };
-    let _res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
-
-    // assertion failed; native token
-    let msg = ExecuteMsg::AssertMinimumReceive {
-        asset_info: AssetInfo::Token {
-            contract_addr: "token0000".to_string(),
-        },
-        prev_balance: Uint128::zero(),
-        minimum_receive: Uint128::from(1000001u128),
-        receiver: "addr0000".to_string(),
-    };
-    let res = execute(deps.as_mut(), mock_env(), info, msg);
-    match res {
-        Err(ContractError::MinimumReceiveAssertion {
-            minimum_receive,
-            swap_amount,
-        }) => {
-            assert_eq!(minimum_receive, Uint128::new(1000001));
-            assert_eq!(swap_amount, Uint128::new(1000000));
+    suite
+        .app
+        .execute_contract(
+            Addr::unchecked("addr0000"),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::AssertMinimumReceive {
+                asset_info: token.clone(),
+                prev_balance: Uint128::zero(),
+                minimum_receive: Uint128::new(1_000_000u128),
+                receiver: "addr0000".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // assertion failed; token
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked("addr0000"),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::AssertMinimumReceive {
+                asset_info: token,
+                prev_balance: Uint128::zero(),
+                minimum_receive: Uint128::new(1_000_001u128),
+                receiver: "addr0000".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        ContractError::MinimumReceiveAssertion {
+            minimum_receive: Uint128::new(1_000_001u128),
+            swap_amount: Uint128::new(1_000_000u128),
         }
-        _ => panic!("DO NOT ENTER HERE"),
-    }
+        .to_string()
+    );
 }
 
 #[test]
 fn can_migrate_contract() {
-    let mut deps = mock_dependencies(&[]);
-    let msg = InstantiateMsg {
-        terraswap_factory: "terraswap_factory".to_string(),
-    };
-
-    let info = mock_info("addr0000", &[]);
-    instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
-
-    let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {});
-
-    // should not be able to migrate as the version is lower
-    match res {
-        Err(ContractError::MigrateInvalidVersion { .. }) => (),
+    let (mut suite, _) = mock_suite();
+
+    let code_id = suite
+        .app
+        .wrap()
+        .query_wasm_contract_info(&suite.pool_router_addr)
+        .unwrap()
+        .code_id;
+
+    // should not be able to migrate as the version is not higher
+    let err: ContractError = suite
+        .app
+        .migrate_contract(
+            suite.creator.clone(),
+            suite.pool_router_addr.clone(),
+            &MigrateMsg {},
+            code_id,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    match err {
+        ContractError::MigrateInvalidVersion { .. } => (),
         _ => panic!("should return ContractError::MigrateInvalidVersion"),
     }
 }
 
 #[test]
 fn add_swap_routes() {
-    let mut deps = mock_dependencies(&[]);
-
-    let msg = InstantiateMsg {
-        terraswap_factory: "terraswapfactory".to_string(),
-    };
-
-    let info = mock_info("creator", &[]);
-
-    // we can just call .unwrap() to assert this was a success
-    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
-
-    deps.querier.with_pool_factory(
-        &[
-            (
-                &"ukrwasset0000".to_string(),
-                &PairInfo {
-                    asset_infos: [
-                        AssetInfo::NativeToken {
-                            denom: "ukrw".to_string(),
-                        },
-                        AssetInfo::Token {
-                            contract_addr: "asset0000".to_string(),
-                        },
-                    ],
-                    contract_addr: "pair0000".to_string(),
-                    liquidity_token: "liquidity0000".to_string(),
-                    asset_decimals: [6u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
-                },
-            ),
-            (
-                &"asset0000uluna".to_string(),
-                &PairInfo {
-                    asset_infos: [
-                        AssetInfo::Token {
-                            contract_addr: "asset0000".to_string(),
-                        },
-                        AssetInfo::NativeToken {
-                            denom: "uluna".to_string(),
-                        },
-                    ],
-                    contract_addr: "pair0001".to_string(),
-                    liquidity_token: "liquidity0001".to_string(),
-                    asset_decimals: [6u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
-                },
-            ),
-            (
-                &"ulunauwhale".to_string(),
-                &PairInfo {
-                    asset_infos: [
-                        AssetInfo::NativeToken {
-                            denom: "uluna".to_string(),
-                        },
-                        AssetInfo::NativeToken {
-                            denom: "uwhale".to_string(),
-                        },
-                    ],
-                    contract_addr: "pair0002".to_string(),
-                    liquidity_token: "liquidity0002".to_string(),
-                    asset_decimals: [6u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
-                },
-            ),
-        ],
-        &[
-            ("ukrw".to_string(), 6u8),
-            ("uluna".to_string(), 6u8),
-            ("uwhale".to_string(), 6u8),
-        ],
-    );
+    let (mut suite, asset) = mock_suite();
 
     let swap_route_1 = SwapRoute {
-        offer_asset_info: AssetInfo::NativeToken {
-            denom: "ukrw".to_string(),
-        },
-        ask_asset_info: AssetInfo::NativeToken {
-            denom: "uluna".to_string(),
-        },
+        offer_asset_info: native("ukrw"),
+        ask_asset_info: native("uluna"),
         swap_operations: vec![
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::NativeToken {
-                    denom: "ukrw".to_string(),
-                },
-                ask_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-            },
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-                ask_asset_info: AssetInfo::NativeToken {
-                    denom: "uluna".to_string(),
-                },
-            },
+            swap_operation(&native("ukrw"), &asset),
+            swap_operation(&asset, &native("uluna")),
         ],
     };
-
     let swap_route_2 = SwapRoute {
-        offer_asset_info: AssetInfo::NativeToken {
-            denom: "ukrw".to_string(),
-        },
-        ask_asset_info: AssetInfo::NativeToken {
-            denom: "uwhale".to_string(),
-        },
+        offer_asset_info: native("ukrw"),
+        ask_asset_info: native("uwhale"),
         swap_operations: vec![
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::NativeToken {
-                    denom: "ukrw".to_string(),
-                },
-                ask_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-            },
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-                ask_asset_info: AssetInfo::NativeToken {
-                    denom: "uluna".to_string(),
-                },
-            },
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::NativeToken {
-                    denom: "uluna".to_string(),
-                },
-                ask_asset_info: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
-            },
+            swap_operation(&native("ukrw"), &asset),
+            swap_operation(&asset, &native("uluna")),
+            swap_operation(&native("uluna"), &native("uwhale")),
         ],
     };
 
-    let swap_route_1_key = SWAP_ROUTES.key((
-        swap_route_1
-            .clone()
-            .offer_asset_info
-            .get_label(&deps.as_ref())
-            .unwrap()
-            .as_str(),
-        swap_route_1
-            .clone()
-            .ask_asset_info
-            .get_label(&deps.as_ref())
-            .unwrap()
-            .as_str(),
-    ));
-
-    let swap_route_2_key = SWAP_ROUTES.key((
-        swap_route_2
-            .clone()
-            .offer_asset_info
-            .get_label(&deps.as_ref())
-            .unwrap()
-            .as_str(),
-        swap_route_2
-            .clone()
-            .ask_asset_info
-            .get_label(&deps.as_ref())
-            .unwrap()
-            .as_str(),
-    ));
-
-    // verify the keys are not there
-    assert_eq!(None, swap_route_1_key.may_load(&deps.storage).unwrap());
-    assert_eq!(None, swap_route_2_key.may_load(&deps.storage).unwrap());
-
-    // add swap route
-    let msg = ExecuteMsg::AddSwapRoutes {
-        swap_routes: vec![swap_route_1.clone(), swap_route_2.clone()],
-    };
-
-    let res = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
-    let expected_attributes = vec![
-        attr("action", "add_swap_routes"),
-        attr("swap_route", swap_route_1.to_string()),
-        attr("swap_route", swap_route_2.to_string()),
-    ];
-
-    assert_eq!(res.messages.len(), 0usize);
-    assert_eq!(res.attributes, expected_attributes);
-
-    // query swap route
-    let msg = QueryMsg::SwapRoute {
-        offer_asset_info: swap_route_1.offer_asset_info.clone(),
-        ask_asset_info: swap_route_1.ask_asset_info.clone(),
-    };
+    // verify the routes are not there
+    for swap_route in [&swap_route_1, &swap_route_2] {
+        suite
+            .app
+            .wrap()
+            .query_wasm_smart::<Vec<SwapOperation>>(
+                &suite.pool_router_addr,
+                &QueryMsg::SwapRoute {
+                    offer_asset_info: swap_route.offer_asset_info.clone(),
+                    ask_asset_info: swap_route.ask_asset_info.clone(),
+                },
+            )
+            .unwrap_err();
+    }
 
-    let res: Vec<SwapOperation> =
-        from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
-    assert_eq!(res, swap_route_1.swap_operations);
+    // add swap routes
+    let res = suite
+        .app
+        .execute_contract(
+            suite.creator.clone(),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::AddSwapRoutes {
+                swap_routes: vec![swap_route_1.clone(), swap_route_2.clone()],
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(&Event::new("wasm").add_attributes(vec![
+        ("action", "add_swap_routes".to_string()),
+        ("swap_route", swap_route_1.to_string()),
+        ("swap_route", swap_route_2.to_string()),
+    ])));
+
+    // query swap routes
+    for swap_route in [swap_route_1, swap_route_2] {
+        let res: Vec<SwapOperation> = suite
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &suite.pool_router_addr,
+                &QueryMsg::SwapRoute {
+                    offer_asset_info: swap_route.offer_asset_info,
+                    ask_asset_info: swap_route.ask_asset_info,
+                },
+            )
+            .unwrap();
+        assert_eq!(res, swap_route.swap_operations);
+    }
 }
 
 #[test]
 fn add_swap_routes_invalid_route() {
-    let mut deps = mock_dependencies(&[]);
-
-    let msg = InstantiateMsg {
-        terraswap_factory: "terraswapfactory".to_string(),
-    };
-
-    let info = mock_info("creator", &[]);
-
-    // we can just call .unwrap() to assert this was a success
-    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
-
-    deps.querier.with_pool_factory(
-        &[
-            (
-                &"ukrwasset0000".to_string(),
-                &PairInfo {
-                    asset_infos: [
-                        AssetInfo::NativeToken {
-                            denom: "ukrw".to_string(),
-                        },
-                        AssetInfo::Token {
-                            contract_addr: "asset0000".to_string(),
-                        },
-                    ],
-                    contract_addr: "pair0000".to_string(),
-                    liquidity_token: "liquidity0000".to_string(),
-                    asset_decimals: [6u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
-                },
-            ),
-            (
-                &"ulunauwhale".to_string(),
-                &PairInfo {
-                    asset_infos: [
-                        AssetInfo::NativeToken {
-                            denom: "uluna".to_string(),
-                        },
-                        AssetInfo::NativeToken {
-                            denom: "uwhale".to_string(),
-                        },
-                    ],
-                    contract_addr: "pair0002".to_string(),
-                    liquidity_token: "liquidity0002".to_string(),
-                    asset_decimals: [6u8, 6u8],
-                    pair_type: PairType::ConstantProduct,
-                },
-            ),
-        ],
-        &[
-            ("ukrw".to_string(), 6u8),
-            ("uluna".to_string(), 6u8),
-            ("uwhale".to_string(), 6u8),
-        ],
-    );
-
-    let offer_asset_info = AssetInfo::NativeToken {
-        denom: "ukrw".to_string(),
-    };
-
-    let ask_asset_info = AssetInfo::NativeToken {
-        denom: "uluna".to_string(),
-    };
+    let (mut suite, asset) = mock_suite();
 
-    let swap_route_1 = SwapRoute {
-        offer_asset_info: offer_asset_info.clone(),
-        ask_asset_info: ask_asset_info.clone(),
+    // there's no asset-uwhale pair
+    let swap_route = SwapRoute {
+        offer_asset_info: native("ukrw"),
+        ask_asset_info: native("uwhale"),
         swap_operations: vec![
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::NativeToken {
-                    denom: "ukrw".to_string(),
-                },
-                ask_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-            },
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-                ask_asset_info: AssetInfo::NativeToken {
-                    denom: "uluna".to_string(),
-                },
-            },
+            swap_operation(&native("ukrw"), &asset),
+            swap_operation(&asset, &native("uwhale")),
         ],
     };
 
-    // add swap route
-    let msg = ExecuteMsg::AddSwapRoutes {
-        swap_routes: vec![swap_route_1.clone()],
-    };
-
-    let error = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap_err();
-
-    match error {
-        ContractError::InvalidSwapRoute(swap_route) => assert_eq!(swap_route, swap_route_1),
-        _ => panic!("should return ContractError::InvalidSwapRoute"),
-    }
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            suite.creator.clone(),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::AddSwapRoutes {
+                swap_routes: vec![swap_route.clone()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        ContractError::InvalidSwapRoute(swap_route.clone()).to_string()
+    );
 
     // query swap route
-    let msg = QueryMsg::SwapRoute {
-        offer_asset_info: offer_asset_info.clone(),
-        ask_asset_info: ask_asset_info.clone(),
-    };
-
-    let error = query(deps.as_ref(), mock_env(), msg).unwrap_err();
-
-    match error {
-        ContractError::NoSwapRouteForAssets {
-            offer_asset,
-            ask_asset,
-        } => {
-            assert_eq!(offer_asset_info.to_string(), offer_asset);
-            assert_eq!(ask_asset_info.to_string(), ask_asset);
+    let err = suite
+        .app
+        .wrap()
+        .query_wasm_smart::<Vec<SwapOperation>>(
+            &suite.pool_router_addr,
+            &QueryMsg::SwapRoute {
+                offer_asset_info: swap_route.offer_asset_info.clone(),
+                ask_asset_info: swap_route.ask_asset_info.clone(),
+            },
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains(
+        &ContractError::NoSwapRouteForAssets {
+            offer_asset: swap_route.offer_asset_info.to_string(),
+            ask_asset: swap_route.ask_asset_info.to_string(),
         }
-        _ => panic!("should return ContractError::NoSwapRouteForAssets"),
-    }
+        .to_string()
+    ));
 }
 
 #[test]
 fn add_swap_routes_unauthorized() {
-    let mut deps = mock_dependencies(&[]);
-
-    let msg = InstantiateMsg {
-        terraswap_factory: "terraswapfactory".to_string(),
-    };
+    let (mut suite, asset) = mock_suite();
 
-    let info = mock_info("creator", &[]);
-
-    // we can just call .unwrap() to assert this was a success
-    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
-
-    let offer_asset_info = AssetInfo::NativeToken {
-        denom: "ukrw".to_string(),
-    };
-
-    let ask_asset_info = AssetInfo::NativeToken {
-        denom: "uluna".to_string(),
-    };
-
-    let swap_route_1 = SwapRoute {
-        offer_asset_info: offer_asset_info.clone(),
-        ask_asset_info: ask_asset_info.clone(),
+    let swap_route = SwapRoute {
+        offer_asset_info: native("ukrw"),
+        ask_asset_info: native("uluna"),
         swap_operations: vec![
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::NativeToken {
-                    denom: "ukrw".to_string(),
-                },
-                ask_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-            },
-            SwapOperation::TerraSwap {
-                offer_asset_info: AssetInfo::Token {
-                    contract_addr: "asset0000".to_string(),
-                },
-                ask_asset_info: AssetInfo::NativeToken {
-                    denom: "uluna".to_string(),
-                },
-            },
+            swap_operation(&native("ukrw"), &asset),
+            swap_operation(&asset, &native("uluna")),
         ],
     };
 
-    // add swap route
-    let msg = ExecuteMsg::AddSwapRoutes {
-        swap_routes: vec![swap_route_1.clone()],
-    };
-
-    let error = execute(
-        deps.as_mut(),
-        mock_env(),
-        mock_info("unauthorized", &[]),
-        msg,
-    )
-    .unwrap_err();
-
-    match error {
-        ContractError::Unauthorized {} => (),
-        _ => panic!("should return ContractError::Unauthorized"),
-    }
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked("unauthorized"),
+            suite.pool_router_addr.clone(),
+            &ExecuteMsg::AddSwapRoutes {
+                swap_routes: vec![swap_route],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err.to_string(), ContractError::Unauthorized {}.to_string());
 }
@@ -1,6 +1,7 @@
 use cosmwasm_schema::write_api;
 
-use cw20_base::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use cw20_base::msg::{InstantiateMsg, MigrateMsg, QueryMsg};
+use terraswap_token::msg::ExecuteMsg;
 
 fn main() {
     write_api! {
@@ -2,12 +2,17 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
 use cw2::set_contract_version;
+use cw20::{Logo, LogoInfo, MarketingInfoResponse};
 use cw20_base::contract::{create_accounts, execute as cw20_execute, query as cw20_query};
-use cw20_base::msg::{ExecuteMsg, QueryMsg};
-use cw20_base::state::{MinterData, TokenInfo, TOKEN_INFO};
+use cw20_base::msg::QueryMsg;
+use cw20_base::state::{MinterData, TokenInfo, LOGO, MARKETING_INFO, TOKEN_INFO};
 use cw20_base::ContractError;
 
-use pool_network::token::InstantiateMsg;
+use pool_network::token::{
+    is_valid_name, is_valid_symbol, ExecuteMsg as TokenExecuteMsg, InstantiateMsg,
+};
+
+use crate::msg::ExecuteMsg;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "nico_teen-cw20_token";
@@ -53,6 +58,36 @@ pub fn instantiate(
     };
 
     TOKEN_INFO.save(deps.storage, &data)?;
+
+    if let Some(marketing) = msg.marketing {
+        let logo = match marketing.logo {
+            Some(Logo::Url(url)) => {
+                LOGO.save(deps.storage, &Logo::Url(url.clone()))?;
+                Some(LogoInfo::Url(url))
+            }
+            // embedded logos can be uploaded once the token is instantiated
+            Some(Logo::Embedded(_)) => {
+                return Err(ContractError::Std(StdError::generic_err(
+                    "Only logo URLs are supported when instantiating the token",
+                )))
+            }
+            None => None,
+        };
+
+        MARKETING_INFO.save(
+            deps.storage,
+            &MarketingInfoResponse {
+                project: marketing.project,
+                description: marketing.description,
+                marketing: marketing
+                    .marketing
+                    .map(|addr| deps.api.addr_validate(&addr))
+                    .transpose()?,
+                logo,
+            },
+        )?;
+    }
+
     Ok(Response::default())
 }
 
@@ -63,7 +98,53 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    cw20_execute(deps, env, info, msg)
+    match msg {
+        ExecuteMsg::Cw20(msg) => cw20_execute(deps, env, info, msg),
+        ExecuteMsg::Token(TokenExecuteMsg::UpdateTokenInfo { name, symbol }) => {
+            update_token_info(deps, info, name, symbol)
+        }
+    }
+}
+
+/// Updates the name and/or the symbol of the token. Only the minter can update them.
+fn update_token_info(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: Option<String>,
+    symbol: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut token_info = TOKEN_INFO.load(deps.storage)?;
+
+    match &token_info.mint {
+        Some(mint) if mint.minter == info.sender => {}
+        _ => return Err(ContractError::Unauthorized {}),
+    }
+
+    if let Some(name) = name {
+        if !is_valid_name(&name) {
+            return Err(ContractError::Std(StdError::generic_err(
+                "Name is not in the expected format (3-50 UTF-8 bytes)",
+            )));
+        }
+        token_info.name = name;
+    }
+
+    if let Some(symbol) = symbol {
+        if !is_valid_symbol(&symbol) {
+            return Err(ContractError::Std(StdError::generic_err(
+                "Ticker symbol is not in expected format [a-zA-Z\\-]{3,12}",
+            )));
+        }
+        token_info.symbol = symbol;
+    }
+
+    TOKEN_INFO.save(deps.storage, &token_info)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_token_info"),
+        ("name", &token_info.name),
+        ("symbol", &token_info.symbol),
+    ]))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -74,8 +155,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::Uint128;
-    use cw20::{Cw20Coin, MinterResponse};
+    use cosmwasm_std::{from_binary, Addr, Uint128};
+    use cw20::{Cw20Coin, MinterResponse, TokenInfoResponse};
+    use pool_network::token::InstantiateMarketingInfo;
 
     use super::*;
 
@@ -95,6 +177,7 @@ mod tests {
                 minter: "minter_addr".to_string(),
                 cap: Some(Uint128::new(99)),
             }),
+            marketing: None,
         };
         let info = mock_info("creator", &[]);
 
@@ -121,9 +204,111 @@ mod tests {
                 amount: Uint128::new(100),
             }],
             mint: None,
+            marketing: None,
         };
         let info = mock_info("creator", &[]);
 
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
     }
+
+    #[test]
+    fn instantiate_with_marketing_info() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "uluna-uwhale-LP".to_string(),
+            symbol: "LUNA-WHAL-LP".to_string(),
+            decimals: 6,
+            initial_balances: vec![],
+            mint: Some(MinterResponse {
+                minter: "pair".to_string(),
+                cap: None,
+            }),
+            marketing: Some(InstantiateMarketingInfo {
+                project: Some("White Whale".to_string()),
+                description: Some("LP token of the LUNA-WHALE pool".to_string()),
+                marketing: Some("pair".to_string()),
+                logo: Some(Logo::Url("https://whitewhale.money/logo.png".to_string())),
+            }),
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("pair", &[]), msg).unwrap();
+
+        let marketing_info: MarketingInfoResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::MarketingInfo {}).unwrap())
+                .unwrap();
+        assert_eq!(
+            marketing_info,
+            MarketingInfoResponse {
+                project: Some("White Whale".to_string()),
+                description: Some("LP token of the LUNA-WHALE pool".to_string()),
+                marketing: Some(Addr::unchecked("pair")),
+                logo: Some(LogoInfo::Url(
+                    "https://whitewhale.money/logo.png".to_string()
+                )),
+            }
+        );
+    }
+
+    #[test]
+    fn minter_can_update_token_info() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "test".to_string(),
+            symbol: "uLP".to_string(),
+            decimals: 6,
+            initial_balances: vec![],
+            mint: Some(MinterResponse {
+                minter: "pair".to_string(),
+                cap: None,
+            }),
+            marketing: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("pair", &[]), msg).unwrap();
+
+        let update_msg = ExecuteMsg::Token(TokenExecuteMsg::UpdateTokenInfo {
+            name: None,
+            symbol: Some("LUNA-WHAL-LP".to_string()),
+        });
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("someone", &[]),
+            update_msg.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("pair", &[]),
+            ExecuteMsg::Token(TokenExecuteMsg::UpdateTokenInfo {
+                name: None,
+                symbol: Some("LUNA-WHALE-LP".to_string()),
+            }),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::generic_err(
+                "Ticker symbol is not in expected format [a-zA-Z\\-]{3,12}"
+            ))
+        );
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("pair", &[]),
+            update_msg,
+        )
+        .unwrap();
+
+        let token_info: TokenInfoResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::TokenInfo {}).unwrap())
+                .unwrap();
+        assert_eq!(token_info.name, "test");
+        assert_eq!(token_info.symbol, "LUNA-WHAL-LP");
+    }
 }
@@ -0,0 +1,13 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use pool_network::token::ExecuteMsg as TokenExecuteMsg;
+
+/// The messages handled by the token, i.e. the cw20-base messages plus the ones the token
+/// defines on top of them
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum ExecuteMsg {
+    Cw20(cw20_base::msg::ExecuteMsg),
+    Token(TokenExecuteMsg),
+}
@@ -0,0 +1,184 @@
+use cosmwasm_std::{to_binary, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdResult, WasmMsg};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, TimelockAction};
+use crate::state::{next_action_id, Config, QueuedAction, CONFIG, QUEUED_ACTIONS};
+
+/// Queues an action, executable once the delay is over. Only the owner can queue actions.
+pub fn queue_action(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: TimelockAction,
+    delay: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let delay = delay.unwrap_or(config.min_delay);
+    if delay < config.min_delay {
+        return Err(ContractError::DelayTooShort {
+            delay,
+            min_delay: config.min_delay,
+        });
+    }
+
+    let action_id = next_action_id(deps.storage)?;
+    let eta = env.block.time.plus_seconds(delay);
+    let queued_action = QueuedAction {
+        action_id,
+        action,
+        queued_at: env.block.time,
+        eta,
+        expires_at: eta.plus_seconds(config.grace_period),
+    };
+    QUEUED_ACTIONS.save(deps.storage, action_id, &queued_action)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "queue_action".to_string()),
+        ("action_id", action_id.to_string()),
+        ("eta", queued_action.eta.to_string()),
+        ("expires_at", queued_action.expires_at.to_string()),
+    ]))
+}
+
+/// Executes a queued action whose delay is over, removing it from the queue. Stale actions, whose
+/// grace period is over, can't be executed.
+pub fn execute_action(deps: DepsMut, env: Env, action_id: u64) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let queued_action = QUEUED_ACTIONS.load(deps.storage, action_id)?;
+
+    if env.block.time < queued_action.eta {
+        return Err(ContractError::ActionNotReady {
+            action_id,
+            eta: queued_action.eta,
+        });
+    }
+
+    if env.block.time >= queued_action.expires_at {
+        return Err(ContractError::ActionExpired {
+            action_id,
+            expires_at: queued_action.expires_at,
+        });
+    }
+
+    QUEUED_ACTIONS.remove(deps.storage, action_id);
+
+    Ok(Response::new()
+        .add_message(action_msg(&config, &env, queued_action.action)?)
+        .add_attributes(vec![
+            ("action", "execute_action".to_string()),
+            ("action_id", action_id.to_string()),
+        ]))
+}
+
+/// Creates the message executing a [TimelockAction]
+fn action_msg(config: &Config, env: &Env, action: TimelockAction) -> StdResult<CosmosMsg> {
+    let (contract_addr, msg) = match action {
+        TimelockAction::PoolFactory(msg) => {
+            (config.pool_factory_addr.to_string(), to_binary(&msg)?)
+        }
+        TimelockAction::VaultFactory(msg) => {
+            (config.vault_factory_addr.to_string(), to_binary(&msg)?)
+        }
+        TimelockAction::PoolRouter(msg) => (config.pool_router_addr.to_string(), to_binary(&msg)?),
+        TimelockAction::UpdateConfig {
+            owner,
+            guardian,
+            min_delay,
+            grace_period,
+        } => (
+            env.contract.address.to_string(),
+            to_binary(&ExecuteMsg::UpdateConfig {
+                owner,
+                guardian,
+                min_delay,
+                grace_period,
+            })?,
+        ),
+    };
+
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr,
+        msg,
+        funds: vec![],
+    }))
+}
+
+/// Cancels a queued action. Only the guardian or the owner can cancel actions.
+pub fn cancel_action(
+    deps: DepsMut,
+    info: MessageInfo,
+    action_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.guardian && info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // make sure the action is queued
+    QUEUED_ACTIONS.load(deps.storage, action_id)?;
+    QUEUED_ACTIONS.remove(deps.storage, action_id);
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "cancel_action".to_string()),
+        ("action_id", action_id.to_string()),
+        ("sender", info.sender.to_string()),
+    ]))
+}
+
+/// Updates the configuration of the contract. Only the contract itself can update it, through a
+/// queued action.
+pub fn update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: Option<String>,
+    guardian: Option<String>,
+    min_delay: Option<u64>,
+    grace_period: Option<u64>,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if let Some(owner) = owner {
+        config.owner = deps.api.addr_validate(&owner)?;
+    }
+
+    if let Some(guardian) = guardian {
+        config.guardian = deps.api.addr_validate(&guardian)?;
+    }
+
+    if let Some(min_delay) = min_delay {
+        if min_delay == 0 {
+            return Err(ContractError::InvalidMinDelay {});
+        }
+
+        config.min_delay = min_delay;
+    }
+
+    if let Some(grace_period) = grace_period {
+        if grace_period == 0 {
+            return Err(ContractError::InvalidGracePeriod {});
+        }
+
+        config.grace_period = grace_period;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_config".to_string()),
+        ("owner", config.owner.to_string()),
+        ("guardian", config.guardian.to_string()),
+        ("min_delay", config.min_delay.to_string()),
+        ("grace_period", config.grace_period.to_string()),
+    ]))
+}
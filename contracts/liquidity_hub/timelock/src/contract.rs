@@ -0,0 +1,105 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
+
+use crate::error::ContractError;
+use crate::error::ContractError::MigrateInvalidVersion;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{Config, ACTION_COUNT, CONFIG};
+use crate::{commands, queries};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "nico_teen-timelock";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.min_delay == 0 {
+        return Err(ContractError::InvalidMinDelay {});
+    }
+
+    if msg.grace_period == 0 {
+        return Err(ContractError::InvalidGracePeriod {});
+    }
+
+    let config = Config {
+        owner: deps.api.addr_validate(&msg.owner)?,
+        guardian: deps.api.addr_validate(&msg.guardian)?,
+        min_delay: msg.min_delay,
+        grace_period: msg.grace_period,
+        pool_factory_addr: deps.api.addr_validate(&msg.pool_factory_addr)?,
+        vault_factory_addr: deps.api.addr_validate(&msg.vault_factory_addr)?,
+        pool_router_addr: deps.api.addr_validate(&msg.pool_router_addr)?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    ACTION_COUNT.save(deps.storage, &0u64)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "instantiate".to_string()),
+        ("owner", config.owner.to_string()),
+        ("guardian", config.guardian.to_string()),
+        ("min_delay", config.min_delay.to_string()),
+        ("grace_period", config.grace_period.to_string()),
+    ]))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::QueueAction { action, delay } => {
+            commands::queue_action(deps, env, info, *action, delay)
+        }
+        ExecuteMsg::ExecuteAction { action_id } => commands::execute_action(deps, env, action_id),
+        ExecuteMsg::CancelAction { action_id } => commands::cancel_action(deps, info, action_id),
+        ExecuteMsg::UpdateConfig {
+            owner,
+            guardian,
+            min_delay,
+            grace_period,
+        } => commands::update_config(deps, env, info, owner, guardian, min_delay, grace_period),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    match msg {
+        QueryMsg::Config {} => Ok(to_binary(&queries::query_config(deps)?)?),
+        QueryMsg::Action { action_id } => Ok(to_binary(&queries::query_action(deps, action_id)?)?),
+        QueryMsg::Actions { start_after, limit } => Ok(to_binary(&queries::query_actions(
+            deps,
+            start_after,
+            limit,
+        )?)?),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let version: Version = CONTRACT_VERSION.parse()?;
+    let storage_version: Version = get_contract_version(deps.storage)?.version.parse()?;
+
+    if storage_version >= version {
+        return Err(MigrateInvalidVersion {
+            current_version: storage_version,
+            new_version: version,
+        });
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
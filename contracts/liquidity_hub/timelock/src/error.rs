@@ -0,0 +1,47 @@
+use cosmwasm_std::{StdError, Timestamp};
+use semver::Version;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+
+    #[error("Attempt to migrate to version {new_version}, but contract is on a higher version {current_version}")]
+    MigrateInvalidVersion {
+        new_version: Version,
+        current_version: Version,
+    },
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("The minimum delay can't be zero")]
+    InvalidMinDelay {},
+
+    #[error("The grace period can't be zero")]
+    InvalidGracePeriod {},
+
+    #[error(
+        "The delay of {delay} seconds is shorter than the minimum delay of {min_delay} seconds"
+    )]
+    DelayTooShort { delay: u64, min_delay: u64 },
+
+    #[error("The delay of action {action_id} is not over, it can be executed from {eta}")]
+    ActionNotReady { action_id: u64, eta: Timestamp },
+
+    #[error("The grace period of action {action_id} is over since {expires_at}, it has to be queued again")]
+    ActionExpired {
+        action_id: u64,
+        expires_at: Timestamp,
+    },
+}
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
+}
@@ -0,0 +1,88 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+
+use pool_network::factory::ExecuteMsg as PoolFactoryExecuteMsg;
+use pool_network::router::ExecuteMsg as PoolRouterExecuteMsg;
+use vault_network::vault_factory::ExecuteMsg as VaultFactoryExecuteMsg;
+
+use crate::state::{ConfigResponse, QueuedAction};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The address allowed to queue actions
+    pub owner: String,
+    /// The address allowed to cancel queued actions, along with the owner
+    pub guardian: String,
+    /// The minimum delay between queuing an action and executing it, in seconds
+    pub min_delay: u64,
+    /// The time during which an action can be executed once its delay is over, in seconds
+    pub grace_period: u64,
+    pub pool_factory_addr: String,
+    pub vault_factory_addr: String,
+    pub pool_router_addr: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Queues an action, executable once `delay` seconds have passed and until the grace period
+    /// is over. The minimum delay is used if no delay is provided. Only the owner can queue actions.
+    QueueAction {
+        action: Box<TimelockAction>,
+        delay: Option<u64>,
+    },
+    /// Executes a queued action whose delay is over, within its grace period. Anyone can execute a
+    /// ready action.
+    ExecuteAction { action_id: u64 },
+    /// Cancels a queued action. Only the guardian or the owner can cancel actions.
+    CancelAction { action_id: u64 },
+    /// Updates the configuration of the contract. This message can only be called by the contract
+    /// itself, i.e. through a queued [TimelockAction::UpdateConfig].
+    UpdateConfig {
+        owner: Option<String>,
+        guardian: Option<String>,
+        min_delay: Option<u64>,
+        grace_period: Option<u64>,
+    },
+}
+
+/// An action executed through the timelock
+#[cw_serde]
+pub enum TimelockAction {
+    /// Executes a message on the pool factory, e.g. `UpdatePairConfig` or `UpdateConfig`
+    PoolFactory(PoolFactoryExecuteMsg),
+    /// Executes a message on the vault factory, e.g. `UpdateVaultConfig` or `UpdateConfig`
+    VaultFactory(VaultFactoryExecuteMsg),
+    /// Executes a message on the pool router, e.g. `AddSwapRoutes`
+    PoolRouter(PoolRouterExecuteMsg),
+    /// Updates the configuration of the timelock
+    UpdateConfig {
+        owner: Option<String>,
+        guardian: Option<String>,
+        min_delay: Option<u64>,
+        grace_period: Option<u64>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Retrieves the configuration of the contract.
+    #[returns(ConfigResponse)]
+    Config {},
+    /// Retrieves a queued action.
+    #[returns(QueuedAction)]
+    Action { action_id: u64 },
+    /// Retrieves the queued actions, from the oldest to the newest.
+    #[returns(ActionsResponse)]
+    Actions {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct ActionsResponse {
+    pub actions: Vec<QueuedAction>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,26 @@
+use cosmwasm_std::Deps;
+
+use crate::error::ContractError;
+use crate::msg::ActionsResponse;
+use crate::state::{read_queued_actions, ConfigResponse, QueuedAction, CONFIG, QUEUED_ACTIONS};
+
+/// Queries the [Config] of the contract
+pub fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
+    Ok(CONFIG.load(deps.storage)?)
+}
+
+/// Queries a queued action
+pub fn query_action(deps: Deps, action_id: u64) -> Result<QueuedAction, ContractError> {
+    Ok(QUEUED_ACTIONS.load(deps.storage, action_id)?)
+}
+
+/// Queries the queued actions, from the oldest to the newest
+pub fn query_actions(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<ActionsResponse, ContractError> {
+    Ok(ActionsResponse {
+        actions: read_queued_actions(deps.storage, start_after, limit)?,
+    })
+}
@@ -0,0 +1,69 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Item, Map};
+
+use crate::msg::TimelockAction;
+
+#[cw_serde]
+pub struct Config {
+    /// The address allowed to queue actions
+    pub owner: Addr,
+    /// The address allowed to cancel queued actions, along with the owner
+    pub guardian: Addr,
+    /// The minimum delay between queuing an action and executing it, in seconds
+    pub min_delay: u64,
+    /// The time during which an action can be executed once its delay is over, in seconds
+    pub grace_period: u64,
+    pub pool_factory_addr: Addr,
+    pub vault_factory_addr: Addr,
+    pub pool_router_addr: Addr,
+}
+
+pub type ConfigResponse = Config;
+
+/// An action waiting for its delay to be over
+#[cw_serde]
+pub struct QueuedAction {
+    pub action_id: u64,
+    pub action: TimelockAction,
+    pub queued_at: Timestamp,
+    /// The time from which the action can be executed
+    pub eta: Timestamp,
+    /// The time from which the action is stale and can't be executed anymore, i.e. the end of the
+    /// grace period
+    pub expires_at: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+/// The queued actions. Actions are removed once executed or cancelled, stale actions have to be
+/// cancelled.
+pub const QUEUED_ACTIONS: Map<u64, QueuedAction> = Map::new("queued_actions");
+pub const ACTION_COUNT: Item<u64> = Item::new("action_count");
+
+/// Gets the id of the next action, incrementing the action count
+pub fn next_action_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let action_id = ACTION_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    ACTION_COUNT.save(storage, &action_id)?;
+
+    Ok(action_id)
+}
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+/// Reads the queued actions, from the oldest to the newest
+pub fn read_queued_actions(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<QueuedAction>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    QUEUED_ACTIONS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, action)| action))
+        .collect()
+}
@@ -0,0 +1,576 @@
+use cosmwasm_std::{coin, to_vec, Addr, Decimal, Empty, StdError, Uint128};
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use cw_storage_plus::Map;
+
+use liquidity_hub_testing::{native, PairBuilder, TestSuite};
+use pool_network::asset::PairInfo;
+use pool_network::factory::ExecuteMsg as PoolFactoryExecuteMsg;
+use pool_network::pair::{ConfigResponse as PairConfigResponse, QueryMsg as PairQueryMsg};
+use pool_network::router::{
+    ExecuteMsg as PoolRouterExecuteMsg, QueryMsg as PoolRouterQueryMsg, SwapOperation, SwapRoute,
+};
+use vault_network::vault_factory::{
+    Config as VaultFactoryConfig, ExecuteMsg as VaultFactoryExecuteMsg,
+    QueryMsg as VaultFactoryQueryMsg,
+};
+
+use crate::contract::{execute, instantiate, migrate, query};
+use crate::msg::{
+    ActionsResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, TimelockAction,
+};
+use crate::state::{ConfigResponse, QueuedAction};
+use crate::ContractError;
+
+/// The minimum delay of the timelock in the tests
+const DAY: u64 = 86_400u64;
+/// The grace period of the timelock in the tests
+const GRACE_PERIOD: u64 = 7 * DAY;
+
+fn timelock_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(execute, instantiate, query).with_migrate(migrate))
+}
+
+struct TimelockSuite {
+    suite: TestSuite,
+    code_id: u64,
+    timelock_addr: Addr,
+    /// The uluna-uwhale pair
+    pair_info: PairInfo,
+}
+
+fn instantiate_msg(suite: &TestSuite, min_delay: u64) -> InstantiateMsg {
+    InstantiateMsg {
+        owner: "owner".to_string(),
+        guardian: "guardian".to_string(),
+        min_delay,
+        grace_period: GRACE_PERIOD,
+        pool_factory_addr: suite.pool_factory_addr.to_string(),
+        vault_factory_addr: suite.vault_factory_addr.to_string(),
+        pool_router_addr: suite.pool_router_addr.to_string(),
+    }
+}
+
+/// Creates the uluna-uwhale pair and the timelock, owned by `owner` and guarded by `guardian`, and
+/// hands it the ownership of the factories and the admin of the pool router
+fn mock_suite() -> TimelockSuite {
+    let mut suite = TestSuite::builder()
+        .with_funded_user(
+            "alice",
+            vec![coin(1_000_000, "uluna"), coin(1_000_000, "uwhale")],
+        )
+        .build();
+
+    let pair_info = suite.create_pair(
+        PairBuilder::new([native("uluna"), native("uwhale")])
+            .with_liquidity("alice", [Uint128::new(1_000_000); 2]),
+    );
+
+    let code_id = suite.app.store_code(timelock_contract());
+    let timelock_addr = suite
+        .app
+        .instantiate_contract(
+            code_id,
+            suite.creator.clone(),
+            &instantiate_msg(&suite, DAY),
+            &[],
+            "timelock",
+            Some(suite.creator.to_string()),
+        )
+        .unwrap();
+
+    suite
+        .app
+        .execute_contract(
+            suite.creator.clone(),
+            suite.pool_factory_addr.clone(),
+            &PoolFactoryExecuteMsg::UpdateConfig {
+                owner: Some(timelock_addr.to_string()),
+                fee_collector_addr: None,
+                token_code_id: None,
+                pair_code_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+    suite
+        .app
+        .execute_contract(
+            suite.creator.clone(),
+            suite.vault_factory_addr.clone(),
+            &VaultFactoryExecuteMsg::UpdateConfig {
+                owner: Some(timelock_addr.to_string()),
+                fee_collector_addr: None,
+                vault_id: None,
+                token_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let pool_router_addr = suite.pool_router_addr.clone();
+    update_admin(&mut suite.app, &pool_router_addr, &timelock_addr);
+
+    TimelockSuite {
+        suite,
+        code_id,
+        timelock_addr,
+        pair_info,
+    }
+}
+
+/// Sets the admin of the given contract. multi-test doesn't support `WasmMsg::UpdateAdmin`, so the
+/// contract data is overwritten in the storage of the wasm module instead.
+fn update_admin(app: &mut App, contract_addr: &Addr, admin: &Addr) {
+    let mut contract_data = app.contract_data(contract_addr).unwrap();
+    contract_data.admin = Some(admin.clone());
+
+    app.init_modules(|_, _, storage| {
+        // the wasm module stores the contracts under the length-prefixed `wasm` namespace
+        let namespace = [&[0u8, 4u8][..], b"wasm"].concat();
+        let key = Map::<&Addr, Empty>::new("contracts").key(contract_addr);
+        storage.set(
+            &[namespace.as_slice(), &key].concat(),
+            &to_vec(&contract_data).unwrap(),
+        );
+    });
+}
+
+impl TimelockSuite {
+    fn execute(&mut self, sender: &str, msg: &ExecuteMsg) -> Result<(), ContractError> {
+        self.suite
+            .app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.timelock_addr.clone(),
+                msg,
+                &[],
+            )
+            .map(|_| ())
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    fn queue(&mut self, action: TimelockAction, delay: Option<u64>) -> Result<u64, ContractError> {
+        let res = self
+            .suite
+            .app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                self.timelock_addr.clone(),
+                &ExecuteMsg::QueueAction {
+                    action: Box::new(action),
+                    delay,
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast::<ContractError>().unwrap())?;
+
+        Ok(res
+            .events
+            .iter()
+            .flat_map(|event| event.attributes.iter())
+            .find(|attribute| attribute.key == "action_id")
+            .unwrap()
+            .value
+            .parse()
+            .unwrap())
+    }
+
+    fn execute_action(&mut self, action_id: u64) -> Result<(), ContractError> {
+        self.execute("alice", &ExecuteMsg::ExecuteAction { action_id })
+    }
+
+    fn query_actions(&self) -> Vec<QueuedAction> {
+        let res: ActionsResponse = self
+            .suite
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.timelock_addr,
+                &QueryMsg::Actions {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        res.actions
+    }
+
+    fn query_config(&self) -> ConfigResponse {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.timelock_addr, &QueryMsg::Config {})
+            .unwrap()
+    }
+
+    fn query_pair_config(&self) -> PairConfigResponse {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.pair_info.contract_addr, &PairQueryMsg::Config {})
+            .unwrap()
+    }
+
+    fn skip(&mut self, seconds: u64) {
+        self.suite.app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(seconds);
+        });
+    }
+}
+
+/// The action updating the swap fee of the uluna-uwhale pair to 0.5%
+fn update_swap_fee_action(suite: &TimelockSuite) -> TimelockAction {
+    let mut pool_fees = suite.query_pair_config().pool_fees;
+    pool_fees.swap_fee.share = Decimal::permille(5);
+
+    TimelockAction::PoolFactory(PoolFactoryExecuteMsg::UpdatePairConfig {
+        pair_addr: suite.pair_info.contract_addr.clone(),
+        owner: None,
+        fee_collector_addr: None,
+        pool_fees: Some(pool_fees),
+        feature_toggle: None,
+        burn_fee_target: None,
+        epoch_config: None,
+    })
+}
+
+#[test]
+fn proper_initialization() {
+    let mut suite = mock_suite();
+
+    let config = suite.query_config();
+    assert_eq!(config.owner, Addr::unchecked("owner"));
+    assert_eq!(config.guardian, Addr::unchecked("guardian"));
+    assert_eq!(config.min_delay, DAY);
+    assert_eq!(config.grace_period, GRACE_PERIOD);
+    assert_eq!(config.pool_factory_addr, suite.suite.pool_factory_addr);
+    assert_eq!(config.vault_factory_addr, suite.suite.vault_factory_addr);
+    assert_eq!(config.pool_router_addr, suite.suite.pool_router_addr);
+
+    let err: ContractError = suite
+        .suite
+        .app
+        .instantiate_contract(
+            suite.code_id,
+            suite.suite.creator.clone(),
+            &instantiate_msg(&suite.suite, 0),
+            &[],
+            "timelock",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidMinDelay {});
+
+    let err: ContractError = suite
+        .suite
+        .app
+        .instantiate_contract(
+            suite.code_id,
+            suite.suite.creator.clone(),
+            &InstantiateMsg {
+                grace_period: 0,
+                ..instantiate_msg(&suite.suite, DAY)
+            },
+            &[],
+            "timelock",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidGracePeriod {});
+}
+
+#[test]
+fn stale_action_cannot_be_executed() {
+    let mut suite = mock_suite();
+
+    let now = suite.suite.app.block_info().time;
+    let action_id = suite.queue(update_swap_fee_action(&suite), None).unwrap();
+
+    // the action can't be executed once its grace period is over
+    suite.skip(DAY + GRACE_PERIOD);
+    let err = suite.execute_action(action_id).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ActionExpired {
+            action_id,
+            expires_at: now.plus_seconds(DAY + GRACE_PERIOD),
+        }
+    );
+    assert_eq!(
+        suite.query_pair_config().pool_fees.swap_fee.share,
+        Decimal::zero()
+    );
+
+    // the stale action stays queued until it is cancelled
+    assert_eq!(suite.query_actions().len(), 1);
+    suite
+        .execute("guardian", &ExecuteMsg::CancelAction { action_id })
+        .unwrap();
+    assert_eq!(suite.query_actions(), vec![]);
+}
+
+#[test]
+fn queued_action_is_executed_after_delay() {
+    let mut suite = mock_suite();
+    let action = update_swap_fee_action(&suite);
+
+    // only the owner can queue actions
+    let err = suite
+        .execute(
+            "alice",
+            &ExecuteMsg::QueueAction {
+                action: Box::new(action.clone()),
+                delay: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err = suite.queue(action.clone(), Some(DAY - 1)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DelayTooShort {
+            delay: DAY - 1,
+            min_delay: DAY,
+        }
+    );
+
+    let now = suite.suite.app.block_info().time;
+    let action_id = suite.queue(action.clone(), None).unwrap();
+    assert_eq!(
+        suite.query_actions(),
+        vec![QueuedAction {
+            action_id,
+            action,
+            queued_at: now,
+            eta: now.plus_seconds(DAY),
+            expires_at: now.plus_seconds(DAY + GRACE_PERIOD),
+        }]
+    );
+
+    let err = suite.execute_action(action_id).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ActionNotReady {
+            action_id,
+            eta: now.plus_seconds(DAY),
+        }
+    );
+    assert_eq!(
+        suite.query_pair_config().pool_fees.swap_fee.share,
+        Decimal::zero()
+    );
+
+    // anyone can execute the action once the delay is over
+    suite.skip(DAY);
+    suite.execute_action(action_id).unwrap();
+
+    assert_eq!(
+        suite.query_pair_config().pool_fees.swap_fee.share,
+        Decimal::permille(5)
+    );
+    assert_eq!(suite.query_actions(), vec![]);
+    let err = suite.execute_action(action_id).unwrap_err();
+    assert!(matches!(err, ContractError::Std(StdError::NotFound { .. })));
+}
+
+#[test]
+fn guardian_can_cancel_actions() {
+    let mut suite = mock_suite();
+
+    let first_action_id = suite
+        .queue(
+            TimelockAction::VaultFactory(VaultFactoryExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: Some("new_fee_collector".to_string()),
+                vault_id: None,
+                token_id: None,
+            }),
+            Some(2 * DAY),
+        )
+        .unwrap();
+    let second_action_id = suite.queue(update_swap_fee_action(&suite), None).unwrap();
+
+    let err = suite
+        .execute(
+            "alice",
+            &ExecuteMsg::CancelAction {
+                action_id: first_action_id,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    suite
+        .execute(
+            "guardian",
+            &ExecuteMsg::CancelAction {
+                action_id: first_action_id,
+            },
+        )
+        .unwrap();
+    // the owner can cancel its actions too
+    suite
+        .execute(
+            "owner",
+            &ExecuteMsg::CancelAction {
+                action_id: second_action_id,
+            },
+        )
+        .unwrap();
+    assert_eq!(suite.query_actions(), vec![]);
+
+    suite.skip(2 * DAY);
+    let err = suite.execute_action(first_action_id).unwrap_err();
+    assert!(matches!(err, ContractError::Std(StdError::NotFound { .. })));
+
+    let vault_factory_config: VaultFactoryConfig = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.suite.vault_factory_addr,
+            &VaultFactoryQueryMsg::Config {},
+        )
+        .unwrap();
+    assert_ne!(
+        vault_factory_config.fee_collector_addr,
+        Addr::unchecked("new_fee_collector")
+    );
+    assert_eq!(
+        suite.query_pair_config().pool_fees.swap_fee.share,
+        Decimal::zero()
+    );
+}
+
+#[test]
+fn swap_routes_are_added_through_the_timelock() {
+    let mut suite = mock_suite();
+
+    let swap_operations = vec![SwapOperation::TerraSwap {
+        offer_asset_info: native("uluna"),
+        ask_asset_info: native("uwhale"),
+        pair_type: None,
+        fee_tier: None,
+    }];
+    let action_id = suite
+        .queue(
+            TimelockAction::PoolRouter(PoolRouterExecuteMsg::AddSwapRoutes {
+                swap_routes: vec![SwapRoute {
+                    offer_asset_info: native("uluna"),
+                    ask_asset_info: native("uwhale"),
+                    swap_operations: swap_operations.clone(),
+                }],
+            }),
+            None,
+        )
+        .unwrap();
+
+    suite.skip(DAY);
+    suite.execute_action(action_id).unwrap();
+
+    let swap_route: Vec<SwapOperation> = suite
+        .suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.suite.pool_router_addr,
+            &PoolRouterQueryMsg::SwapRoute {
+                offer_asset_info: native("uluna"),
+                ask_asset_info: native("uwhale"),
+            },
+        )
+        .unwrap();
+    assert_eq!(swap_route, swap_operations);
+}
+
+#[test]
+fn config_is_updated_through_the_timelock() {
+    let mut suite = mock_suite();
+
+    let err = suite
+        .execute(
+            "owner",
+            &ExecuteMsg::UpdateConfig {
+                owner: None,
+                guardian: Some("new_guardian".to_string()),
+                min_delay: None,
+                grace_period: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let action_id = suite
+        .queue(
+            TimelockAction::UpdateConfig {
+                owner: Some("new_owner".to_string()),
+                guardian: Some("new_guardian".to_string()),
+                min_delay: Some(2 * DAY),
+                grace_period: Some(2 * GRACE_PERIOD),
+            },
+            None,
+        )
+        .unwrap();
+    suite.skip(DAY);
+    suite.execute_action(action_id).unwrap();
+
+    let config = suite.query_config();
+    assert_eq!(config.owner, Addr::unchecked("new_owner"));
+    assert_eq!(config.guardian, Addr::unchecked("new_guardian"));
+    assert_eq!(config.min_delay, 2 * DAY);
+    assert_eq!(config.grace_period, 2 * GRACE_PERIOD);
+
+    // the previous owner can't queue actions anymore
+    let err = suite
+        .queue(update_swap_fee_action(&suite), None)
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // the minimum delay can't be removed
+    suite
+        .execute(
+            "new_owner",
+            &ExecuteMsg::QueueAction {
+                action: Box::new(TimelockAction::UpdateConfig {
+                    owner: None,
+                    guardian: None,
+                    min_delay: Some(0),
+                    grace_period: None,
+                }),
+                delay: None,
+            },
+        )
+        .unwrap();
+    suite.skip(2 * DAY);
+    assert!(suite.execute_action(action_id + 1).is_err());
+    assert_eq!(suite.query_config().min_delay, 2 * DAY);
+}
+
+#[test]
+fn can_migrate_contract() {
+    let mut suite = mock_suite();
+
+    // should not be able to migrate as the version is not higher
+    let err: ContractError = suite
+        .suite
+        .app
+        .migrate_contract(
+            suite.suite.creator.clone(),
+            suite.timelock_addr.clone(),
+            &MigrateMsg {},
+            suite.code_id,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    match err {
+        ContractError::MigrateInvalidVersion { .. } => (),
+        _ => panic!("should return ContractError::MigrateInvalidVersion"),
+    }
+}
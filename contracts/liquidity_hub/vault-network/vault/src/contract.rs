@@ -1,14 +1,15 @@
 use cosmwasm_std::{
-    attr, entry_point, to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response,
-    StdError, SubMsg, WasmMsg,
+    attr, entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+    SubMsg, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::MinterResponse;
 use semver::Version;
 
-use pool_network::asset::IBC_PREFIX;
-#[cfg(feature = "injective")]
-use pool_network::asset::PEGGY_PREFIX;
+use pool_network::asset::AssetInfo;
+use pool_network::burn_fee::BurnFeeTarget;
+use pool_network::lp_token::{create_lp_denom_msg, get_lp_denom, LpTokenType};
+use pool_network::token::get_lp_token_symbol;
 use vault_network::vault::{
     Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, INSTANTIATE_LP_TOKEN_REPLY_ID,
 };
@@ -16,9 +17,15 @@ use vault_network::vault::{
 use crate::state::{initialize_fee, ALL_TIME_BURNED_FEES};
 use crate::{
     error::VaultError,
-    execute::{callback, collect_protocol_fees, deposit, flash_loan, receive, update_config},
+    execute::{
+        buy_back, callback, collect_protocol_fees, deposit, flash_loan, rebalance, receive,
+        update_config, update_liquidity_strategy, update_lp_token_info, withdraw_native,
+    },
     migrations,
-    queries::{get_config, get_fees, get_payback_amount, get_share},
+    queries::{
+        get_available_liquidity, get_bought_back_fees, get_buy_back_fees, get_config, get_fees,
+        get_liquidity_strategy, get_payback_amount, get_protocol_fees_by_epoch, get_share,
+    },
     state::{ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES, CONFIG, LOAN_COUNTER},
 };
 
@@ -40,47 +47,37 @@ pub fn instantiate(
     let config = Config {
         owner: deps.api.addr_validate(&msg.owner)?,
         asset_info: msg.asset_info.clone(),
-        // we patch this in the INSTANTIATE_LP_TOKEN_REPLY
-        liquidity_token: Addr::unchecked(""),
+        liquidity_token: match msg.lp_token_type.unwrap_or(LpTokenType::Cw20) {
+            // we patch this in the INSTANTIATE_LP_TOKEN_REPLY
+            LpTokenType::Cw20 => AssetInfo::Token {
+                contract_addr: "".to_string(),
+            },
+            LpTokenType::TokenFactory => AssetInfo::NativeToken {
+                denom: get_lp_denom(env.contract.address.as_str()),
+            },
+        },
         fee_collector_addr: deps.api.addr_validate(&msg.fee_collector_addr)?,
         fees: msg.vault_fees,
 
         deposit_enabled: true,
         flash_loan_enabled: true,
         withdraw_enabled: true,
+        burn_fee_target: BurnFeeTarget::Burn,
     };
     CONFIG.save(deps.storage, &config)?;
 
     let asset_label: String = msg.asset_info.clone().get_label(&deps.as_ref())?;
-
-    // cw20 asset symbols are 3-12 characters,
-    // so we take the first 8 characters of the symbol and append "uLP-" to it
-    let mut lp_symbol = format!("uLP-{}", asset_label.chars().take(8).collect::<String>());
-
-    // in case it's an ibc token, strip away everything from the '/' in 'ibc/'. The resulting
-    // lp_symbol would be uLP-ibc in that case.
-    if asset_label.starts_with(IBC_PREFIX) {
-        lp_symbol = lp_symbol.splitn(2, '/').collect::<Vec<_>>()[0].to_string();
-    }
-
-    #[cfg(feature = "injective")]
-    {
-        // in case it is an Ethereum bridged (peggy) asset on Injective, strip away everything from
-        // the "0x" in 'peggy0x...'. The resulting lp_symbol would be uLP-peggy in that case.
-        if asset_label.starts_with(PEGGY_PREFIX) {
-            lp_symbol = lp_symbol.splitn(2, "0x").collect::<Vec<_>>()[0].to_string();
-        }
-    }
-
-    let lp_label = format!(
-        "WW Vault {} LP token",
-        msg.asset_info
-            .clone()
-            .get_label(&deps.as_ref())?
-            .chars()
-            .take(32)
-            .collect::<String>()
-    );
+    let lp_token_info = msg.lp_token_info.unwrap_or_default();
+    let lp_label = lp_token_info.name.clone().unwrap_or_else(|| {
+        format!(
+            "WW Vault {} LP token",
+            asset_label.chars().take(32).collect::<String>()
+        )
+    });
+    let lp_symbol = lp_token_info
+        .symbol
+        .clone()
+        .unwrap_or_else(|| get_lp_token_symbol(&[asset_label]));
 
     let lp_instantiate_msg = SubMsg {
         id: INSTANTIATE_LP_TOKEN_REPLY_ID,
@@ -98,6 +95,7 @@ pub fn instantiate(
                     minter: env.contract.address.to_string(),
                     cap: None,
                 }),
+                marketing: Some(lp_token_info.marketing_info(env.contract.address.as_str())),
             })?,
             funds: vec![],
             label: lp_label,
@@ -121,9 +119,14 @@ pub fn instantiate(
     // set loan counter to zero
     LOAN_COUNTER.save(deps.storage, &0)?;
 
-    Ok(Response::new()
-        .add_attributes(vec![attr("method", "instantiate")])
-        .add_submessage(lp_instantiate_msg))
+    let response = Response::new().add_attributes(vec![attr("method", "instantiate")]);
+    match config.liquidity_token {
+        // create the LP token with the token factory
+        AssetInfo::NativeToken { denom } => Ok(response
+            .add_message(create_lp_denom_msg(&env.contract.address)?)
+            .add_attribute("lp_denom", denom)),
+        AssetInfo::Token { .. } => Ok(response.add_submessage(lp_instantiate_msg)),
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -134,10 +137,19 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, VaultError> {
     match msg {
-        ExecuteMsg::UpdateConfig(params) => update_config(deps, info, params),
-        ExecuteMsg::Deposit { amount } => deposit(deps, env, info, amount),
+        ExecuteMsg::UpdateConfig(params) => update_config(deps, env, info, params),
+        ExecuteMsg::UpdateLpTokenInfo { lp_token_info } => {
+            update_lp_token_info(deps, info, lp_token_info)
+        }
+        ExecuteMsg::Deposit { amount, receiver } => deposit(deps, env, info, amount, receiver),
         ExecuteMsg::FlashLoan { amount, msg } => flash_loan(deps, env, info, amount, msg),
         ExecuteMsg::CollectProtocolFees {} => collect_protocol_fees(deps),
+        ExecuteMsg::UpdateLiquidityStrategy { strategy } => {
+            update_liquidity_strategy(deps, env, info, strategy)
+        }
+        ExecuteMsg::Rebalance {} => rebalance(deps, env, info),
+        ExecuteMsg::Withdraw {} => withdraw_native(deps, env, info),
+        ExecuteMsg::BuyBack { minimum_receive } => buy_back(deps, info, minimum_receive),
         ExecuteMsg::Receive(msg) => receive(deps, env, info, msg),
         ExecuteMsg::Callback(msg) => callback(deps, env, info, msg),
     }
@@ -171,6 +183,20 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
         migrations::migrate_to_v120(deps.branch())?;
     }
 
+    if storage_version
+        < Version::parse("1.5.0")
+            .map_err(|_| StdError::parse_err("Version", "Failed to parse version"))?
+    {
+        migrations::migrate_to_v150(deps.branch())?;
+    }
+
+    if storage_version
+        < Version::parse("1.7.0")
+            .map_err(|_| StdError::parse_err("Version", "Failed to parse version"))?
+    {
+        migrations::migrate_to_v170(deps.branch())?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     Ok(Response::default())
@@ -189,5 +215,13 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, VaultError>
         ),
         QueryMsg::GetPaybackAmount { amount } => get_payback_amount(deps, amount),
         QueryMsg::BurnedFees {} => get_fees(deps, true, ALL_TIME_BURNED_FEES, None),
+        QueryMsg::BuyBackFees {} => get_buy_back_fees(deps),
+        QueryMsg::BoughtBackFees {} => get_bought_back_fees(deps),
+        QueryMsg::LiquidityStrategy {} => get_liquidity_strategy(deps, env),
+        QueryMsg::AvailableLiquidity {} => get_available_liquidity(deps, env),
+        QueryMsg::ProtocolFeesByEpoch {
+            start_epoch,
+            end_epoch,
+        } => get_protocol_fees_by_epoch(deps, env, start_epoch, end_epoch),
     }
 }
@@ -1,4 +1,6 @@
-use cosmwasm_std::{ConversionOverflowError, DivideByZeroError, OverflowError, StdError, Uint128};
+use cosmwasm_std::{
+    ConversionOverflowError, Decimal, DivideByZeroError, OverflowError, StdError, Uint128,
+};
 use semver::Version;
 use thiserror::Error;
 
@@ -55,6 +57,54 @@ pub enum VaultError {
     #[error("Withdrawals are not enabled")]
     WithdrawsDisabled {},
 
+    #[error("The {denom} LP tokens must be the only funds sent to withdraw")]
+    InvalidLiquidityTokenFunds { denom: String },
+
     #[error("Cannot deposit while flash-loaning")]
     DepositDuringLoan {},
+
+    #[error("No liquidity strategy is set for the vault")]
+    NoLiquidityStrategy {},
+
+    #[error("The liquidity strategy pair must contain the vault asset")]
+    InvalidStrategyPair {},
+
+    #[error("The liquidity strategy pair must issue cw20 LP tokens")]
+    UnsupportedStrategyLpToken {},
+
+    #[error("The liquidity strategy pair must be a constant product pair")]
+    UnsupportedStrategyPairType {},
+
+    #[error("The liquidity strategy allocation must not exceed 100%")]
+    InvalidStrategyAllocation {},
+
+    #[error("Cannot change the liquidity strategy pair while liquidity is provided to it")]
+    StrategyPositionOpen {},
+
+    #[error("The liquidity strategy pair price {price} deviates too much from the reference price {reference_price}, the owner has to rebalance")]
+    StrategyPriceDeviation {
+        price: Decimal,
+        reference_price: Decimal,
+    },
+
+    #[error("Cannot flash-loan {requested}, only {available} is available in the vault")]
+    InsufficientLiquidity {
+        available: Uint128,
+        requested: Uint128,
+    },
+
+    #[error("Cannot rebalance while flash-loaning")]
+    RebalanceDuringLoan {},
+
+    #[error("Cannot buy back while flash-loaning")]
+    BuyBackDuringLoan {},
+
+    #[error("No burn fees accrued to buy back with")]
+    NoBuyBackFees {},
+
+    #[error("Token factory LP tokens have no cw20 name, symbol or marketing info to update")]
+    NativeLpTokenInfo {},
+
+    #[error("The epoch config is already set and can't be changed")]
+    EpochConfigAlreadySet {},
 }
@@ -0,0 +1,163 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
+
+use pool_network::asset::Asset;
+use vault_network::vault::BUY_BACK_REPLY_ID;
+
+use crate::{
+    error::VaultError,
+    state::{BUY_BACK_FEES, CONFIG, LOAN_COUNTER},
+};
+
+/// Buys back with the burn fees accrued by the vault, burning the tokens bought in the reply. Only
+/// the keeper of the [BurnFeeTarget::BuyBackAndBurn] target can do this, setting the minimum amount
+/// to buy back.
+///
+/// [BurnFeeTarget::BuyBackAndBurn]: pool_network::burn_fee::BurnFeeTarget::BuyBackAndBurn
+pub fn buy_back(
+    deps: DepsMut,
+    info: MessageInfo,
+    minimum_receive: Uint128,
+) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !config.burn_fee_target.is_keeper(&info.sender) {
+        return Err(VaultError::Unauthorized {});
+    }
+
+    // the burn fees leaving the vault would be accounted as a loss of the flash-loan
+    if LOAN_COUNTER.load(deps.storage)? != 0 {
+        return Err(VaultError::BuyBackDuringLoan {});
+    }
+
+    let buy_back_fees = BUY_BACK_FEES.may_load(deps.storage)?.unwrap_or_default();
+    if buy_back_fees.is_zero() {
+        return Err(VaultError::NoBuyBackFees {});
+    }
+    BUY_BACK_FEES.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new()
+        .add_submessage(config.burn_fee_target.buy_back_msg(
+            &deps.querier,
+            Asset {
+                info: config.asset_info,
+                amount: buy_back_fees,
+            },
+            minimum_receive,
+            BUY_BACK_REPLY_ID,
+        )?)
+        .add_attributes(vec![
+            ("method", "buy_back".to_string()),
+            ("burn_fees", buy_back_fees.to_string()),
+            ("minimum_receive", minimum_receive.to_string()),
+        ]))
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{
+        coins,
+        testing::{mock_env, mock_info},
+        to_binary, Addr, SubMsg, Uint128, WasmMsg,
+    };
+
+    use pool_network::asset::AssetInfo;
+    use pool_network::burn_fee::BurnFeeTarget;
+    use pool_network::mock_querier::mock_dependencies;
+    use pool_network::router::{ExecuteMsg as RouterExecuteMsg, SwapOperation};
+    use vault_network::vault::{Config, ExecuteMsg, BUY_BACK_REPLY_ID};
+
+    use crate::{
+        contract::execute,
+        error::VaultError,
+        state::{BUY_BACK_FEES, CONFIG, LOAN_COUNTER},
+        tests::{get_fees, mock_creator},
+    };
+
+    #[test]
+    fn keeper_buys_back_with_the_accrued_burn_fees() {
+        let mut deps = mock_dependencies(&[]);
+        CONFIG
+            .save(
+                &mut deps.storage,
+                &Config {
+                    owner: mock_creator().sender,
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                    flash_loan_enabled: true,
+                    deposit_enabled: true,
+                    withdraw_enabled: true,
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
+                    fee_collector_addr: Addr::unchecked("fee_collector"),
+                    fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::BuyBackAndBurn {
+                        router_addr: "router".to_string(),
+                        asset_info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        keeper: "keeper".to_string(),
+                    },
+                },
+            )
+            .unwrap();
+        LOAN_COUNTER.save(&mut deps.storage, &0).unwrap();
+
+        let buy_back = || ExecuteMsg::BuyBack {
+            minimum_receive: Uint128::new(90),
+        };
+
+        // nothing to buy back with
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("keeper", &[]),
+            buy_back(),
+        )
+        .unwrap_err();
+        assert_eq!(err, VaultError::NoBuyBackFees {});
+
+        BUY_BACK_FEES
+            .save(&mut deps.storage, &Uint128::new(100))
+            .unwrap();
+
+        // only the keeper can buy back
+        let err = execute(deps.as_mut(), mock_env(), mock_creator(), buy_back()).unwrap_err();
+        assert_eq!(err, VaultError::Unauthorized {});
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("keeper", &[]),
+            buy_back(),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::reply_on_success(
+                WasmMsg::Execute {
+                    contract_addr: "router".to_string(),
+                    msg: to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                        operations: vec![SwapOperation::TerraSwap {
+                            offer_asset_info: AssetInfo::NativeToken {
+                                denom: "uluna".to_string(),
+                            },
+                            ask_asset_info: AssetInfo::NativeToken {
+                                denom: "uwhale".to_string(),
+                            },
+                            pair_type: None,
+                            fee_tier: None,
+                        }],
+                        minimum_receive: Some(Uint128::new(90)),
+                        to: None,
+                    })
+                    .unwrap(),
+                    funds: coins(100, "uluna"),
+                },
+                BUY_BACK_REPLY_ID,
+            )]
+        );
+        assert_eq!(BUY_BACK_FEES.load(&deps.storage).unwrap(), Uint128::zero());
+    }
+}
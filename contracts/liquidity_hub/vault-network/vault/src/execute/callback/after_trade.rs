@@ -3,7 +3,10 @@ use cw20::{BalanceResponse, Cw20QueryMsg};
 
 use pool_network::asset::{Asset, AssetInfo};
 
-use crate::state::{store_fee, ALL_TIME_BURNED_FEES};
+use crate::state::{
+    store_epoch_fee, store_fee, ALL_TIME_BURNED_FEES, BURNED_FEES_BY_EPOCH, BUY_BACK_FEES,
+    EPOCH_CONFIG, PROTOCOL_FEES_BY_EPOCH,
+};
 use crate::{
     error::VaultError,
     state::{ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES, CONFIG, LOAN_COUNTER},
@@ -69,19 +72,31 @@ pub fn after_trade(
     store_fee(deps.storage, COLLECTED_PROTOCOL_FEES, protocol_fee)?;
     store_fee(deps.storage, ALL_TIME_COLLECTED_PROTOCOL_FEES, protocol_fee)?;
 
+    let epoch = EPOCH_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .epoch(env.block.time);
+    store_epoch_fee(deps.storage, epoch, protocol_fee, PROTOCOL_FEES_BY_EPOCH)?;
+
     // deduct loan counter
     LOAN_COUNTER.update::<_, StdError>(deps.storage, |c| Ok(c.saturating_sub(1)))?;
 
+    // the burn fees to buy back with are accrued, and bought back by the keeper later on
     let mut response = Response::new();
-    if !burn_fee.is_zero() {
-        let burn_asset = Asset {
+    if config.burn_fee_target.buys_back(&config.asset_info) {
+        let buy_back_fees = BUY_BACK_FEES.may_load(deps.storage)?.unwrap_or_default();
+        BUY_BACK_FEES.save(deps.storage, &buy_back_fees.checked_add(burn_fee)?)?;
+    } else if !burn_fee.is_zero() {
+        // burn fees sent away are not burned, hence not accounted as such
+        if config.burn_fee_target.burns_directly(&config.asset_info) {
+            store_fee(deps.storage, ALL_TIME_BURNED_FEES, burn_fee)?;
+            store_epoch_fee(deps.storage, epoch, burn_fee, BURNED_FEES_BY_EPOCH)?;
+        }
+
+        response = response.add_messages(config.burn_fee_target.into_msgs(Asset {
             info: config.asset_info,
             amount: burn_fee,
-        };
-
-        store_fee(deps.storage, ALL_TIME_BURNED_FEES, burn_fee)?;
-
-        response = response.add_message(burn_asset.into_burn_msg()?);
+        })?);
     }
 
     Ok(response.add_attributes(vec![
@@ -96,19 +111,23 @@ pub fn after_trade(
 #[cfg(test)]
 mod test {
     use cosmwasm_std::{
-        coins,
+        coins, from_binary,
         testing::{mock_env, mock_info},
-        to_binary, Addr, BankMsg, CosmosMsg, Decimal, ReplyOn, Response, SubMsg, Uint128, WasmMsg,
+        to_binary, Addr, BankMsg, CosmosMsg, Decimal, ReplyOn, Response, StdError, SubMsg, Uint128,
+        WasmMsg,
     };
     use cw20::Cw20ExecuteMsg;
 
     use pool_network::asset::{Asset, AssetInfo};
-    use vault_network::vault::Config;
+    use pool_network::burn_fee::BurnFeeTarget;
     use nico_teen::fee::{Fee, VaultFee};
+    use vault_network::vault::{Config, UpdateConfigParams};
 
-    use crate::state::ALL_TIME_BURNED_FEES;
+    use crate::state::{
+        ALL_TIME_BURNED_FEES, BURNED_FEES_BY_EPOCH, BUY_BACK_FEES, PROTOCOL_FEES_BY_EPOCH,
+    };
     use crate::{
-        contract::{execute, instantiate},
+        contract::{execute, instantiate, query},
         error::VaultError,
         state::{ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES, CONFIG, LOAN_COUNTER},
         tests::{get_fees, mock_creator, mock_dependencies_lp},
@@ -148,6 +167,8 @@ mod test {
                         share: Decimal::permille(1),
                     },
                 },
+                lp_token_type: None,
+                lp_token_info: None,
             },
         )
         .unwrap();
@@ -208,6 +229,17 @@ mod test {
                 },
             }
         );
+
+        // should have accounted the fees in the current epoch, weekly by default
+        let epoch = env.block.time.seconds() / 604_800;
+        assert_eq!(
+            PROTOCOL_FEES_BY_EPOCH.load(&deps.storage, epoch).unwrap(),
+            Uint128::new(5)
+        );
+        assert_eq!(
+            BURNED_FEES_BY_EPOCH.load(&deps.storage, epoch).unwrap(),
+            Uint128::new(1)
+        );
     }
 
     #[test]
@@ -228,7 +260,9 @@ mod test {
                 &mut deps.storage,
                 &Config {
                     owner: mock_creator().sender,
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::Token {
                         contract_addr: "vault_token".to_string(),
                     },
@@ -247,6 +281,7 @@ mod test {
                             share: Decimal::permille(1),
                         },
                     },
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -362,6 +397,177 @@ mod test {
         );
     }
 
+    #[test]
+    fn sends_burn_fee_to_burn_fee_target() {
+        let env = mock_env();
+        let mut deps = mock_dependencies_lp(
+            &[(
+                &env.clone().contract.address.into_string(),
+                &coins(7_500, "uluna"),
+            )],
+            &[],
+            vec![],
+        );
+
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            vault_network::vault::InstantiateMsg {
+                owner: mock_creator().sender.into_string(),
+                token_id: 5,
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fee_collector_addr: "fee_collector".to_string(),
+                vault_fees: VaultFee {
+                    flash_loan_fee: Fee {
+                        share: Decimal::permille(5),
+                    },
+                    protocol_fee: Fee {
+                        share: Decimal::permille(5),
+                    },
+                    burn_fee: Fee {
+                        share: Decimal::permille(1),
+                    },
+                },
+                lp_token_type: None,
+                lp_token_info: None,
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            vault_network::vault::ExecuteMsg::UpdateConfig(UpdateConfigParams {
+                flash_loan_enabled: None,
+                deposit_enabled: None,
+                withdraw_enabled: None,
+                new_owner: None,
+                new_vault_fees: None,
+                new_fee_collector_addr: None,
+                new_burn_fee_target: Some(BurnFeeTarget::Send {
+                    address: "treasury".to_string(),
+                }),
+                new_epoch_config: None,
+            }),
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(&env.contract.address.into_string(), &[]),
+            vault_network::vault::ExecuteMsg::Callback(
+                vault_network::vault::CallbackMsg::AfterTrade {
+                    old_balance: Uint128::new(5_000),
+                    loan_amount: Uint128::new(1_000),
+                },
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "treasury".to_string(),
+                amount: coins(1, "uluna"),
+            }))]
+        );
+
+        // the burn fee sent to the treasury is not accounted as burned
+        let burned_fees = ALL_TIME_BURNED_FEES.load(&deps.storage).unwrap();
+        assert_eq!(burned_fees.amount, Uint128::zero());
+    }
+
+    #[test]
+    fn accrues_burn_fee_to_buy_back() {
+        let env = mock_env();
+        let mut deps = mock_dependencies_lp(
+            &[(
+                &env.clone().contract.address.into_string(),
+                &coins(7_500, "uluna"),
+            )],
+            &[],
+            vec![],
+        );
+
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            vault_network::vault::InstantiateMsg {
+                owner: mock_creator().sender.into_string(),
+                token_id: 5,
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fee_collector_addr: "fee_collector".to_string(),
+                vault_fees: VaultFee {
+                    flash_loan_fee: Fee {
+                        share: Decimal::permille(5),
+                    },
+                    protocol_fee: Fee {
+                        share: Decimal::permille(5),
+                    },
+                    burn_fee: Fee {
+                        share: Decimal::permille(1),
+                    },
+                },
+                lp_token_type: None,
+                lp_token_info: None,
+            },
+        )
+        .unwrap();
+
+        // inject the buy-back target, as the router is not mocked
+        CONFIG
+            .update::<_, StdError>(&mut deps.storage, |mut config| {
+                config.burn_fee_target = BurnFeeTarget::BuyBackAndBurn {
+                    router_addr: "router".to_string(),
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    keeper: "keeper".to_string(),
+                };
+                Ok(config)
+            })
+            .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(&env.contract.address.clone().into_string(), &[]),
+            vault_network::vault::ExecuteMsg::Callback(
+                vault_network::vault::CallbackMsg::AfterTrade {
+                    old_balance: Uint128::new(5_000),
+                    loan_amount: Uint128::new(1_000),
+                },
+            ),
+        )
+        .unwrap();
+
+        // the burn fee is kept by the vault until the keeper buys back with it
+        assert!(res.messages.is_empty());
+        assert_eq!(BUY_BACK_FEES.load(&deps.storage).unwrap(), Uint128::new(1));
+        let burned_fees = ALL_TIME_BURNED_FEES.load(&deps.storage).unwrap();
+        assert_eq!(burned_fees.amount, Uint128::zero());
+
+        // and is not part of the liquidity of the vault, along with the protocol fee
+        let available_liquidity: Uint128 = from_binary(
+            &query(
+                deps.as_ref(),
+                env,
+                vault_network::vault::QueryMsg::AvailableLiquidity {},
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(available_liquidity, Uint128::new(7_500 - 5 - 1));
+    }
+
     #[test]
     fn does_fail_on_negative_profit_native() {
         let env = mock_env();
@@ -386,6 +592,8 @@ mod test {
                 },
                 fee_collector_addr: "fee_collector".to_string(),
                 vault_fees: get_fees(),
+                lp_token_type: None,
+                lp_token_info: None,
             },
         )
         .unwrap();
@@ -431,7 +639,9 @@ mod test {
                 &mut deps.storage,
                 &Config {
                     owner: mock_creator().sender,
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::Token {
                         contract_addr: "vault_token".to_string(),
                     },
@@ -440,6 +650,7 @@ mod test {
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -485,7 +696,9 @@ mod test {
                 &mut deps.storage,
                 &Config {
                     owner: mock_creator().sender,
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::Token {
                         contract_addr: "vault_token".to_string(),
                     },
@@ -494,6 +707,7 @@ mod test {
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
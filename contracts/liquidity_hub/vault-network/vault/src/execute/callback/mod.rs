@@ -1,11 +1,16 @@
 mod after_trade;
+mod provide_strategy_liquidity;
+mod swap_strategy_asset;
 
 pub use after_trade::after_trade;
+pub use provide_strategy_liquidity::provide_strategy_liquidity;
+pub use swap_strategy_asset::swap_strategy_asset;
 
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 use vault_network::vault::CallbackMsg;
 
 use crate::error::VaultError;
+use crate::execute::receive::withdraw_unwound;
 
 pub fn callback(
     deps: DepsMut,
@@ -23,6 +28,11 @@ pub fn callback(
             old_balance,
             loan_amount,
         } => after_trade(deps, env, old_balance, loan_amount),
+        CallbackMsg::SwapStrategyAsset {} => swap_strategy_asset(deps, env),
+        CallbackMsg::ProvideStrategyLiquidity { max_amount } => {
+            provide_strategy_liquidity(deps, env, max_amount)
+        }
+        CallbackMsg::Withdraw { sender, amount } => withdraw_unwound(deps, env, sender, amount),
     }
 }
 
@@ -80,6 +90,8 @@ mod test {
                     },
                     fee_collector_addr: "fee_collector".to_string(),
                     vault_fees: get_fees(),
+                    lp_token_type: None,
+                    lp_token_info: None,
                 },
                 &coins(1_000, "uluna"),
                 "vault",
@@ -0,0 +1,118 @@
+use cosmwasm_std::{to_binary, Coin, CosmosMsg, DepsMut, Env, Response, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+
+use pool_network::asset::{Asset, AssetInfo};
+use pool_network::pair::{ExecuteMsg as PairExecuteMsg, PoolResponse, QueryMsg as PairQueryMsg};
+
+use crate::{
+    error::VaultError,
+    state::{CONFIG, LIQUIDITY_STRATEGY},
+    strategy::query_position,
+};
+
+/// Provides the counter asset held by the vault as liquidity to the strategy pair, together with
+/// the matching amount of the vault asset, capped at `max_amount`.
+pub fn provide_strategy_liquidity(
+    deps: DepsMut,
+    env: Env,
+    max_amount: Uint128,
+) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+    let strategy = LIQUIDITY_STRATEGY
+        .may_load(deps.storage)?
+        .ok_or(VaultError::NoLiquidityStrategy {})?;
+
+    let (_, counter_asset_amount) = query_position(deps.as_ref(), &env, &strategy)?;
+
+    let pool: PoolResponse = deps
+        .querier
+        .query_wasm_smart(strategy.pair_addr.clone(), &PairQueryMsg::Pool {})?;
+    let (vault_asset_reserve, counter_asset_reserve) =
+        if pool.assets[0].info.equal(&config.asset_info) {
+            (pool.assets[0].amount, pool.assets[1].amount)
+        } else {
+            (pool.assets[1].amount, pool.assets[0].amount)
+        };
+
+    if vault_asset_reserve.is_zero() || counter_asset_reserve.is_zero() {
+        return Ok(Response::new().add_attributes(vec![
+            ("method", "provide_strategy_liquidity"),
+            ("amount", "0"),
+        ]));
+    }
+
+    // match the ratio of the pool reserves, without exceeding `max_amount` of the vault asset
+    let counter_asset_for_max_amount =
+        max_amount.multiply_ratio(counter_asset_reserve, vault_asset_reserve);
+    let (vault_asset_amount, counter_asset_amount) =
+        if counter_asset_amount <= counter_asset_for_max_amount {
+            (
+                counter_asset_amount.multiply_ratio(vault_asset_reserve, counter_asset_reserve),
+                counter_asset_amount,
+            )
+        } else {
+            (max_amount, counter_asset_for_max_amount)
+        };
+
+    if vault_asset_amount.is_zero() || counter_asset_amount.is_zero() {
+        return Ok(Response::new().add_attributes(vec![
+            ("method", "provide_strategy_liquidity"),
+            ("amount", "0"),
+        ]));
+    }
+
+    let assets = [
+        Asset {
+            info: config.asset_info,
+            amount: vault_asset_amount,
+        },
+        Asset {
+            info: strategy.counter_asset,
+            amount: counter_asset_amount,
+        },
+    ];
+
+    // cw20 tokens are pulled by the pair, native tokens are sent along with the message
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut funds: Vec<Coin> = vec![];
+    for asset in assets.iter() {
+        match &asset.info {
+            AssetInfo::Token { contract_addr } => messages.push(
+                WasmMsg::Execute {
+                    contract_addr: contract_addr.clone(),
+                    msg: to_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                        spender: strategy.pair_addr.to_string(),
+                        amount: asset.amount,
+                        expires: None,
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+            ),
+            AssetInfo::NativeToken { denom } => funds.push(Coin {
+                denom: denom.clone(),
+                amount: asset.amount,
+            }),
+        }
+    }
+    funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+    messages.push(
+        WasmMsg::Execute {
+            contract_addr: strategy.pair_addr.to_string(),
+            msg: to_binary(&PairExecuteMsg::ProvideLiquidity {
+                assets,
+                slippage_tolerance: strategy.max_spread,
+                receiver: None,
+            })?,
+            funds,
+        }
+        .into(),
+    );
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("method", "provide_strategy_liquidity".to_string()),
+        ("vault_asset_amount", vault_asset_amount.to_string()),
+        ("counter_asset_amount", counter_asset_amount.to_string()),
+    ]))
+}
@@ -0,0 +1,37 @@
+use cosmwasm_std::{DepsMut, Env, Response};
+
+use pool_network::asset::Asset;
+
+use crate::{
+    error::VaultError,
+    state::{CONFIG, LIQUIDITY_STRATEGY},
+    strategy::{query_position, swap_msg},
+};
+
+/// Swaps all the counter asset held by the vault back into the vault asset.
+pub fn swap_strategy_asset(deps: DepsMut, env: Env) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+    let strategy = LIQUIDITY_STRATEGY
+        .may_load(deps.storage)?
+        .ok_or(VaultError::NoLiquidityStrategy {})?;
+
+    let (_, counter_asset_amount) = query_position(deps.as_ref(), &env, &strategy)?;
+
+    let mut response = Response::new();
+    if !counter_asset_amount.is_zero() {
+        response = response.add_message(swap_msg(
+            deps.as_ref(),
+            &config.asset_info,
+            &strategy,
+            Asset {
+                info: strategy.counter_asset.clone(),
+                amount: counter_asset_amount,
+            },
+        )?);
+    }
+
+    Ok(response.add_attributes(vec![
+        ("method", "swap_strategy_asset".to_string()),
+        ("amount", counter_asset_amount.to_string()),
+    ]))
+}
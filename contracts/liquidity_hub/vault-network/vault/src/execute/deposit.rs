@@ -1,31 +1,29 @@
-use cosmwasm_std::{to_binary, CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128, WasmMsg};
+use cosmwasm_std::{
+    to_binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, Uint128, WasmMsg,
+};
 use cw20::{AllowanceResponse, Cw20ExecuteMsg};
 
-use pool_network::{asset::AssetInfo, querier::query_token_info};
+use pool_network::asset::AssetInfo;
+use pool_network::lp_token::{mint_lp_token_msgs, query_lp_total_supply};
+use vault_network::vault::Config;
 
 use crate::{
     error::VaultError,
-    state::{COLLECTED_PROTOCOL_FEES, CONFIG, LOAN_COUNTER},
+    state::{get_held_fees, CONFIG, LOAN_COUNTER},
+    strategy::{get_strategy_value, ValuationPrice},
 };
 
+/// Deposits the vault asset into the vault. For cw20 vaults, the user must `IncreaseAllowance` on
+/// the token first, as the tokens are pulled from the user with a `TransferFrom`.
 pub fn deposit(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     amount: Uint128,
+    receiver: Option<String>,
 ) -> Result<Response, VaultError> {
     let config = CONFIG.load(deps.storage)?;
-
-    // check that deposits are enabled
-    if !config.deposit_enabled {
-        return Err(VaultError::DepositsDisabled {});
-    }
-
-    // check that we are not currently in a flash-loan
-    if LOAN_COUNTER.load(deps.storage)? != 0 {
-        // more than 0 loans is being performed currently
-        return Err(VaultError::DepositDuringLoan {});
-    }
+    assert_can_deposit(deps.as_ref(), &config)?;
 
     // check that user sent assets they said they did
     let sent_funds = match config.asset_info.clone() {
@@ -71,50 +69,118 @@ pub fn deposit(
         )
     }
 
-    // mint LP token for the sender
-    let total_lp_share =
-        query_token_info(&deps.querier, config.liquidity_token.clone())?.total_supply;
+    // If the asset is native token, the balance has already increased in the vault
+    // To calculate it properly we should subtract user deposit from the vault.
+    // If the asset is a cw20 token, the balance has not changed yet so we don't need to subtract it
+    let deposited_amount = match config.asset_info {
+        AssetInfo::NativeToken { .. } => amount,
+        AssetInfo::Token { .. } => Uint128::zero(),
+    };
+
+    let receiver = match receiver {
+        Some(receiver) => deps.api.addr_validate(&receiver)?.into_string(),
+        None => info.sender.into_string(),
+    };
+
+    messages.extend(mint_lp_tokens(
+        deps.as_ref(),
+        env,
+        &config,
+        amount,
+        deposited_amount,
+        receiver,
+    )?);
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attributes(vec![("method", "deposit"), ("amount", &amount.to_string())]))
+}
+
+/// Deposits cw20 tokens sent to the vault via [`cw20::Cw20ExecuteMsg::Send`]. The tokens are
+/// already in the vault's balance at this point, so no allowance is needed.
+pub fn deposit_cw20(
+    deps: DepsMut,
+    env: Env,
+    sender: String,
+    amount: Uint128,
+    receiver: Option<String>,
+) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+    assert_can_deposit(deps.as_ref(), &config)?;
+
+    let receiver = deps
+        .api
+        .addr_validate(&receiver.unwrap_or(sender))?
+        .into_string();
+
+    let mint_msgs = mint_lp_tokens(deps.as_ref(), env, &config, amount, amount, receiver)?;
+
+    Ok(Response::new()
+        .add_messages(mint_msgs)
+        .add_attributes(vec![("method", "deposit"), ("amount", &amount.to_string())]))
+}
+
+/// Checks that deposits are enabled and that there is no flash-loan being performed.
+fn assert_can_deposit(deps: Deps, config: &Config) -> Result<(), VaultError> {
+    // check that deposits are enabled
+    if !config.deposit_enabled {
+        return Err(VaultError::DepositsDisabled {});
+    }
+
+    // check that we are not currently in a flash-loan
+    if LOAN_COUNTER.load(deps.storage)? != 0 {
+        // more than 0 loans is being performed currently
+        return Err(VaultError::DepositDuringLoan {});
+    }
+
+    Ok(())
+}
+
+/// Creates the messages minting the LP tokens for a deposit of `amount` to the `receiver`.
+///
+/// `deposited_amount` is the part of `amount` that is already reflected in the vault balance,
+/// which has to be excluded when computing the share of the pool.
+fn mint_lp_tokens(
+    deps: Deps,
+    env: Env,
+    config: &Config,
+    amount: Uint128,
+    deposited_amount: Uint128,
+    receiver: String,
+) -> Result<Vec<CosmosMsg>, VaultError> {
+    let total_lp_share = query_lp_total_supply(&deps.querier, &config.liquidity_token)?;
 
     let lp_amount = if total_lp_share.is_zero() {
         // first depositor to the vault, mint LP tokens 1:1
         amount
     } else {
-        // If the asset is native token, the balance has already increased in the vault
-        // To calculate it properly we should subtract user deposit from the vault.
-        // If the asset is a cw20 token, the balance has not changed yet so we don't need to subtract it
-        let deposit_amount = match config.asset_info {
-            AssetInfo::NativeToken { .. } => amount,
-            AssetInfo::Token { .. } => Uint128::zero(),
-        };
-
         // return based on a share of the total pool
-        let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
+        let held_fees = get_held_fees(deps.storage)?;
         let total_deposits = config
             .asset_info
-            .query_pool(&deps.querier, deps.api, env.contract.address)?
-            .checked_sub(collected_protocol_fees.amount)?
-            .checked_sub(deposit_amount)?;
+            .query_pool(&deps.querier, deps.api, env.contract.address.clone())?
+            .checked_sub(held_fees)?
+            .checked_sub(deposited_amount)?
+            // add the value of the liquidity strategy position, at the price most favorable to the
+            // vault so the deposit can't be priced below the value of the existing shares
+            .checked_add(get_strategy_value(
+                deps,
+                &env,
+                &config.asset_info,
+                ValuationPrice::Highest,
+            )?)?;
 
         amount
             .checked_mul(total_lp_share)?
             .checked_div(total_deposits)?
     };
 
-    messages.push(
-        WasmMsg::Execute {
-            contract_addr: config.liquidity_token.into_string(),
-            msg: to_binary(&Cw20ExecuteMsg::Mint {
-                recipient: info.sender.into_string(),
-                amount: lp_amount,
-            })?,
-            funds: vec![],
-        }
-        .into(),
-    );
-
-    Ok(Response::new()
-        .add_messages(messages)
-        .add_attributes(vec![("method", "deposit"), ("amount", &amount.to_string())]))
+    Ok(mint_lp_token_msgs(
+        &config.liquidity_token,
+        &env.contract.address,
+        receiver,
+        lp_amount,
+    )?)
 }
 
 #[cfg(test)]
@@ -128,10 +194,12 @@ mod test {
     use cw_multi_test::Executor;
 
     use pool_network::asset::AssetInfo;
+    use pool_network::burn_fee::BurnFeeTarget;
     use vault_network::vault::Config;
 
-    use crate::tests::mock_app::mock_app_with_balance;
+    use crate::tests::mock_app::{mock_app, mock_app_with_balance};
     use crate::tests::mock_instantiate::app_mock_instantiate;
+    use crate::tests::store_code::store_cw20_token_code;
     use crate::{
         contract::execute,
         error::VaultError,
@@ -158,7 +226,9 @@ mod test {
                 &mut deps.storage,
                 &Config {
                     owner: mock_creator().sender,
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::NativeToken {
                         denom: "uluna".to_string(),
                     },
@@ -167,6 +237,7 @@ mod test {
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -180,6 +251,7 @@ mod test {
             mock_info("creator", &coins(5_000, "uluna")),
             vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
             },
         );
 
@@ -221,7 +293,9 @@ mod test {
                 &mut deps.storage,
                 &Config {
                     owner: mock_creator().sender,
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::Token {
                         contract_addr: "vault_token".to_string(),
                     },
@@ -230,6 +304,7 @@ mod test {
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -243,6 +318,7 @@ mod test {
             mock_creator(),
             vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
             },
         );
 
@@ -283,6 +359,7 @@ mod test {
             },
             vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
             },
         );
 
@@ -309,12 +386,15 @@ mod test {
                     asset_info: AssetInfo::Token {
                         contract_addr: "vault_token".to_string(),
                     },
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     deposit_enabled: true,
                     flash_loan_enabled: true,
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -328,6 +408,7 @@ mod test {
             mock_creator(),
             vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
             },
         );
 
@@ -354,12 +435,15 @@ mod test {
                     asset_info: AssetInfo::NativeToken {
                         denom: "uluna".to_string(),
                     },
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     deposit_enabled: false,
                     flash_loan_enabled: true,
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector_addr"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -370,6 +454,7 @@ mod test {
             mock_creator(),
             vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
             },
         );
 
@@ -389,12 +474,15 @@ mod test {
                     asset_info: AssetInfo::NativeToken {
                         denom: "uluna".to_string(),
                     },
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     deposit_enabled: true,
                     flash_loan_enabled: true,
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector_addr"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -408,6 +496,7 @@ mod test {
             mock_creator(),
             vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
             },
         );
 
@@ -442,6 +531,7 @@ mod test {
             vault_addr.clone(),
             &vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(10_000),
+                receiver: None,
             },
             &coins(10_000, "uluna"),
         )
@@ -455,12 +545,13 @@ mod test {
                 &vault_network::vault::QueryMsg::Config {},
             )
             .unwrap();
+        let liquidity_token = Addr::unchecked(config.liquidity_token.to_string());
 
         // user should have 10,000 lp tokens
         let cw20_balance: cw20::BalanceResponse = app
             .wrap()
             .query_wasm_smart(
-                config.liquidity_token.clone(),
+                liquidity_token.clone(),
                 &cw20::Cw20QueryMsg::Balance {
                     address: mock_creator().sender.into_string(),
                 },
@@ -484,6 +575,7 @@ mod test {
             vault_addr.clone(),
             &vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(5_000),
+                receiver: None,
             },
             &coins(5_000, "uluna"),
         )
@@ -494,7 +586,7 @@ mod test {
         let cw20_balance: cw20::BalanceResponse = app
             .wrap()
             .query_wasm_smart(
-                config.liquidity_token.clone(),
+                liquidity_token.clone(),
                 &cw20::Cw20QueryMsg::Balance {
                     address: second_depositor.to_string(),
                 },
@@ -508,6 +600,7 @@ mod test {
             vault_addr,
             &vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(8_000),
+                receiver: None,
             },
             &coins(8_000, "uluna"),
         )
@@ -519,7 +612,7 @@ mod test {
         let cw20_balance: cw20::BalanceResponse = app
             .wrap()
             .query_wasm_smart(
-                config.liquidity_token,
+                liquidity_token,
                 &cw20::Cw20QueryMsg::Balance {
                     address: third_depositor.to_string(),
                 },
@@ -532,4 +625,171 @@ mod test {
         // depositor2 is entitled to 3,333 / 18,666 of the total LP supply or 5,000 tokens
         // depositor3 is entitled to 5,333 / 18,666 of the total LP supply or 8,000 tokens
     }
+
+    #[test]
+    fn can_deposit_native_to_receiver() {
+        let env = mock_env();
+        let mut deps = mock_dependencies_lp(&[], &[], vec![]);
+
+        // inject lp token address to config
+        CONFIG
+            .save(
+                &mut deps.storage,
+                &Config {
+                    owner: mock_creator().sender,
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                    deposit_enabled: true,
+                    flash_loan_enabled: true,
+                    withdraw_enabled: true,
+                    fee_collector_addr: Addr::unchecked("fee_collector"),
+                    fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
+                },
+            )
+            .unwrap();
+
+        // inject loan counter
+        LOAN_COUNTER.save(&mut deps.storage, &0).unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("creator", &coins(5_000, "uluna")),
+            vault_network::vault::ExecuteMsg::Deposit {
+                amount: Uint128::new(5_000),
+                receiver: Some("receiver".to_string()),
+            },
+        );
+
+        assert_eq!(
+            res.unwrap(),
+            Response::new()
+                .add_attributes(vec![("method", "deposit"), ("amount", "5000")])
+                .add_message(WasmMsg::Execute {
+                    contract_addr: "lp_token".to_string(),
+                    funds: vec![],
+                    msg: to_binary(&Cw20ExecuteMsg::Mint {
+                        recipient: "receiver".to_string(),
+                        amount: Uint128::new(5_000),
+                    })
+                    .unwrap(),
+                })
+        );
+    }
+
+    #[test]
+    fn can_deposit_token_via_send() {
+        let mut app = mock_app();
+        let receiver = Addr::unchecked("receiver");
+
+        // instantiate vault asset with creator having 15,000 of the asset
+        let vault_asset_token_id = store_cw20_token_code(&mut app);
+        let token_addr = app
+            .instantiate_contract(
+                vault_asset_token_id,
+                mock_creator().sender,
+                &cw20_base::msg::InstantiateMsg {
+                    decimals: 6,
+                    initial_balances: vec![cw20::Cw20Coin {
+                        address: mock_creator().sender.to_string(),
+                        amount: Uint128::new(15_000),
+                    }],
+                    marketing: None,
+                    mint: None,
+                    name: "CASH".to_string(),
+                    symbol: "CASH".to_string(),
+                },
+                &[],
+                "cw20_token",
+                None,
+            )
+            .unwrap();
+
+        let vault_addr = app_mock_instantiate(
+            &mut app,
+            AssetInfo::Token {
+                contract_addr: token_addr.clone().into_string(),
+            },
+        );
+
+        // get config for the liquidity token address
+        let config: Config = app
+            .wrap()
+            .query_wasm_smart(
+                vault_addr.clone(),
+                &vault_network::vault::QueryMsg::Config {},
+            )
+            .unwrap();
+        let liquidity_token = Addr::unchecked(config.liquidity_token.to_string());
+
+        // deposit 10,000 tokens in a single transaction, no allowance needed
+        app.execute_contract(
+            mock_creator().sender,
+            token_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: vault_addr.to_string(),
+                amount: Uint128::new(10_000),
+                msg: to_binary(&vault_network::vault::Cw20HookMsg::Deposit { receiver: None })
+                    .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // deposit 5,000 tokens on behalf of the receiver
+        app.execute_contract(
+            mock_creator().sender,
+            token_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: vault_addr.to_string(),
+                amount: Uint128::new(5_000),
+                msg: to_binary(&vault_network::vault::Cw20HookMsg::Deposit {
+                    receiver: Some(receiver.to_string()),
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // vault should hold all the deposited tokens
+        let balance: cw20::BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                token_addr,
+                &cw20::Cw20QueryMsg::Balance {
+                    address: vault_addr.into_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(15_000), balance.balance);
+
+        // creator should have 10,000 lp tokens, receiver 5,000
+        let cw20_balance: cw20::BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                liquidity_token.clone(),
+                &cw20::Cw20QueryMsg::Balance {
+                    address: mock_creator().sender.into_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(10_000), cw20_balance.balance);
+
+        let cw20_balance: cw20::BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                liquidity_token,
+                &cw20::Cw20QueryMsg::Balance {
+                    address: receiver.into_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(Uint128::new(5_000), cw20_balance.balance);
+    }
 }
@@ -8,7 +8,7 @@ use vault_network::vault::{CallbackMsg, ExecuteMsg};
 
 use crate::{
     error::VaultError,
-    state::{CONFIG, LOAN_COUNTER},
+    state::{get_held_fees, CONFIG, LOAN_COUNTER},
 };
 
 pub fn flash_loan(
@@ -48,6 +48,16 @@ pub fn flash_loan(
         }
     };
 
+    // only the idle assets can be loaned, without the fees held by the vault. The liquidity
+    // strategy position is not unwound for loans, as the loan can move the pair price.
+    let available_liquidity = old_balance.checked_sub(get_held_fees(deps.storage)?)?;
+    if amount > available_liquidity {
+        return Err(VaultError::InsufficientLiquidity {
+            available: available_liquidity,
+            requested: amount,
+        });
+    }
+
     let mut messages: Vec<CosmosMsg> = vec![];
 
     // create message to send funds to sender if cw20 token
@@ -106,13 +116,14 @@ mod test {
         testing::{mock_dependencies, mock_dependencies_with_balance, mock_env},
         to_binary, Addr, BankMsg, Response, Uint128, WasmMsg,
     };
-    use pool_network::asset::AssetInfo;
+    use pool_network::asset::{Asset, AssetInfo};
+    use pool_network::burn_fee::BurnFeeTarget;
     use vault_network::vault::Config;
 
     use crate::{
         contract::{execute, instantiate},
         error::VaultError,
-        state::{CONFIG, LOAN_COUNTER},
+        state::{COLLECTED_PROTOCOL_FEES, CONFIG, LOAN_COUNTER},
         tests::{get_fees, mock_creator, mock_dependencies_lp},
     };
 
@@ -126,7 +137,9 @@ mod test {
                 &mut deps.storage,
                 &Config {
                     owner: mock_creator().sender,
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::NativeToken {
                         denom: "uluna".to_string(),
                     },
@@ -135,6 +148,7 @@ mod test {
                     withdraw_enabled: true,
                     fees: get_fees(),
                     fee_collector_addr: Addr::unchecked("fee_collector"),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -171,6 +185,8 @@ mod test {
                 },
                 fee_collector_addr: "fee_collector".to_string(),
                 vault_fees: get_fees(),
+                lp_token_type: None,
+                lp_token_info: None,
             },
         )
         .unwrap();
@@ -193,6 +209,61 @@ mod test {
         assert_eq!(LOAN_COUNTER.load(&deps.storage).unwrap(), 1);
     }
 
+    #[test]
+    fn cannot_loan_more_than_available_liquidity() {
+        let mut deps = mock_dependencies_with_balance(&coins(10_000, "uluna"));
+        let env = mock_env();
+
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            vault_network::vault::InstantiateMsg {
+                owner: mock_creator().sender.into_string(),
+                token_id: 2,
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fee_collector_addr: "fee_collector".to_string(),
+                vault_fees: get_fees(),
+                lp_token_type: None,
+                lp_token_info: None,
+            },
+        )
+        .unwrap();
+
+        // the protocol fees held by the vault can't be loaned
+        COLLECTED_PROTOCOL_FEES
+            .save(
+                &mut deps.storage,
+                &Asset {
+                    amount: Uint128::new(1_000),
+                    info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                },
+            )
+            .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            vault_network::vault::ExecuteMsg::FlashLoan {
+                amount: Uint128::new(9_500),
+                msg: to_binary(&BankMsg::Burn { amount: vec![] }).unwrap(),
+            },
+        );
+
+        assert_eq!(
+            res.unwrap_err(),
+            VaultError::InsufficientLiquidity {
+                available: Uint128::new(9_000),
+                requested: Uint128::new(9_500),
+            }
+        );
+    }
+
     #[test]
     fn can_loan_native() {
         let mut deps = mock_dependencies_with_balance(&coins(10_000, "uluna"));
@@ -212,6 +283,8 @@ mod test {
                 },
                 fee_collector_addr: "fee_collector".to_string(),
                 vault_fees: get_fees(),
+                lp_token_type: None,
+                lp_token_info: None,
             },
         )
         .unwrap();
@@ -272,7 +345,9 @@ mod test {
                 &mut deps.storage,
                 &Config {
                     owner: mock_creator().sender,
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::Token {
                         contract_addr: "vault_token".to_string(),
                     },
@@ -281,12 +356,24 @@ mod test {
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
 
-        // inject loan counter
+        // inject loan counter and protocol fees
         LOAN_COUNTER.save(&mut deps.storage, &0).unwrap();
+        COLLECTED_PROTOCOL_FEES
+            .save(
+                &mut deps.storage,
+                &Asset {
+                    amount: Uint128::zero(),
+                    info: AssetInfo::Token {
+                        contract_addr: "vault_token".to_string(),
+                    },
+                },
+            )
+            .unwrap();
 
         let res = execute(
             deps.as_mut(),
@@ -1,13 +1,21 @@
+mod buy_back;
 mod callback;
 mod collect_protocol_fee;
 mod deposit;
 mod flash_loan;
+mod rebalance;
 mod receive;
 mod update_config;
+mod update_liquidity_strategy;
+mod update_lp_token_info;
 
+pub use buy_back::buy_back;
 pub use callback::callback;
 pub use collect_protocol_fee::collect_protocol_fees;
 pub use deposit::deposit;
 pub use flash_loan::flash_loan;
-pub use receive::receive;
+pub use rebalance::rebalance;
+pub use receive::{receive, withdraw_native};
 pub use update_config::update_config;
+pub use update_liquidity_strategy::update_liquidity_strategy;
+pub use update_lp_token_info::update_lp_token_info;
@@ -0,0 +1,151 @@
+use cosmwasm_std::{CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128};
+
+use pool_network::asset::Asset;
+use vault_network::vault::CallbackMsg;
+
+use crate::{
+    error::VaultError,
+    state::{get_held_fees, CONFIG, LIQUIDITY_STRATEGY, LOAN_COUNTER},
+    strategy::{
+        callback_msg, get_position_value, query_pair_price, swap_msg, unwind_position_msgs,
+        ValuationPrice,
+    },
+};
+
+/// Rebalances the liquidity strategy position towards the configured allocation of the vault
+/// assets. If the position is below the target, half of the missing amount is swapped into the
+/// counter asset and provided as liquidity to the pair together with the other half. If the
+/// position exceeds the target, it is withdrawn from the pair.
+///
+/// The position is valued at the current pair price, which becomes the reference price of the
+/// strategy. Only the owner can rebalance, as the pair price can be moved within a transaction.
+pub fn rebalance(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(VaultError::Unauthorized {});
+    }
+
+    // check that we are not currently in a flash-loan
+    if LOAN_COUNTER.load(deps.storage)? != 0 {
+        return Err(VaultError::RebalanceDuringLoan {});
+    }
+
+    let mut strategy = LIQUIDITY_STRATEGY
+        .may_load(deps.storage)?
+        .ok_or(VaultError::NoLiquidityStrategy {})?;
+    strategy.reference_price = query_pair_price(deps.as_ref(), &config.asset_info, &strategy)?;
+    LIQUIDITY_STRATEGY.save(deps.storage, &strategy)?;
+
+    let held_fees = get_held_fees(deps.storage)?;
+    let idle_amount = config
+        .asset_info
+        .query_pool(&deps.querier, deps.api, env.contract.address.clone())?
+        .checked_sub(held_fees)?;
+    let position_value = get_position_value(
+        deps.as_ref(),
+        &env,
+        &config.asset_info,
+        &strategy,
+        ValuationPrice::Lowest,
+    )?;
+
+    let target_value = strategy.allocation * idle_amount.checked_add(position_value)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if position_value > target_value {
+        messages.extend(unwind_position_msgs(deps.as_ref(), &env, &strategy, None)?);
+    } else {
+        let supply_amount = target_value.checked_sub(position_value)?;
+        let swap_amount = supply_amount.checked_div(Uint128::new(2))?;
+
+        if !swap_amount.is_zero() {
+            messages.push(swap_msg(
+                deps.as_ref(),
+                &config.asset_info,
+                &strategy,
+                Asset {
+                    info: config.asset_info.clone(),
+                    amount: swap_amount,
+                },
+            )?);
+            messages.push(callback_msg(
+                &env,
+                CallbackMsg::ProvideStrategyLiquidity {
+                    max_amount: supply_amount.checked_sub(swap_amount)?,
+                },
+            )?);
+        }
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("method", "rebalance".to_string()),
+        ("position_value", position_value.to_string()),
+        ("target_value", target_value.to_string()),
+        ("reference_price", strategy.reference_price.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::testing::mock_info;
+
+    use pool_network::asset::AssetInfo;
+    use vault_network::vault::ExecuteMsg;
+
+    use crate::{
+        contract::execute,
+        error::VaultError,
+        state::LOAN_COUNTER,
+        tests::{mock_creator, mock_instantiate::mock_instantiate},
+    };
+
+    #[test]
+    fn cannot_rebalance_without_strategy() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res = execute(deps.as_mut(), env, mock_creator(), ExecuteMsg::Rebalance {});
+
+        assert_eq!(res.unwrap_err(), VaultError::NoLiquidityStrategy {});
+    }
+
+    #[test]
+    fn cannot_rebalance_unauthorized() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("keeper", &[]),
+            ExecuteMsg::Rebalance {},
+        );
+
+        assert_eq!(res.unwrap_err(), VaultError::Unauthorized {});
+    }
+
+    #[test]
+    fn cannot_rebalance_during_loan() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        // inject loan state
+        LOAN_COUNTER.save(&mut deps.storage, &1).unwrap();
+
+        let res = execute(deps.as_mut(), env, mock_creator(), ExecuteMsg::Rebalance {});
+
+        assert_eq!(res.unwrap_err(), VaultError::RebalanceDuringLoan {});
+    }
+}
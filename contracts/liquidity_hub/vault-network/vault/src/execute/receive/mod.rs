@@ -1,11 +1,13 @@
 use cosmwasm_std::{from_binary, DepsMut, Env, MessageInfo, Response};
 use vault_network::vault::{Cw20HookMsg, Cw20ReceiveMsg};
 
-use crate::{error::VaultError, state::CONFIG};
+use pool_network::asset::AssetInfo;
+
+use crate::{error::VaultError, execute::deposit::deposit_cw20, state::CONFIG};
 
 mod withdraw;
 
-use withdraw::withdraw;
+pub use withdraw::{withdraw, withdraw_native, withdraw_unwound};
 
 /// Handles receiving CW20 messages
 pub fn receive(
@@ -14,25 +16,47 @@ pub fn receive(
     info: MessageInfo,
     msg: Cw20ReceiveMsg,
 ) -> Result<Response, VaultError> {
-    // callback can only be called by liquidity token
     let config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.liquidity_token {
-        return Err(VaultError::ExternalCallback {});
-    }
-
     match from_binary(&msg.msg)? {
-        Cw20HookMsg::Withdraw {} => withdraw(deps, env, msg.sender, msg.amount),
+        Cw20HookMsg::Withdraw {} => {
+            // withdrawals can only be triggered by the liquidity token
+            if config.liquidity_token
+                != (AssetInfo::Token {
+                    contract_addr: info.sender.to_string(),
+                })
+            {
+                return Err(VaultError::ExternalCallback {});
+            }
+
+            withdraw(deps, env, msg.sender, msg.amount)
+        }
+        Cw20HookMsg::Deposit { receiver } => {
+            // deposits can only be triggered by the token managed by the vault
+            match config.asset_info {
+                AssetInfo::Token { contract_addr } if contract_addr == info.sender => {
+                    deposit_cw20(deps, env, msg.sender, msg.amount, receiver)
+                }
+                _ => Err(VaultError::Unauthorized {}),
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use cosmwasm_std::{to_binary, Uint128};
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        to_binary, Addr, Uint128,
+    };
+    use pool_network::asset::AssetInfo;
+    use pool_network::burn_fee::BurnFeeTarget;
+    use vault_network::vault::Config;
 
     use crate::{
         error::VaultError,
-        tests::{mock_creator, mock_instantiate::mock_instantiate},
+        state::CONFIG,
+        tests::{get_fees, mock_creator, mock_instantiate::mock_instantiate},
     };
 
     use super::receive;
@@ -59,4 +83,69 @@ mod test {
 
         assert_eq!(res.unwrap_err(), VaultError::ExternalCallback {})
     }
+
+    #[test]
+    fn cannot_deposit_from_not_vault_token() {
+        let mut deps = mock_dependencies();
+
+        // inject config, as instantiating a cw20 vault queries the token info
+        CONFIG
+            .save(
+                &mut deps.storage,
+                &Config {
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
+                    asset_info: AssetInfo::Token {
+                        contract_addr: "vault_token".to_string(),
+                    },
+                    deposit_enabled: true,
+                    flash_loan_enabled: true,
+                    owner: mock_creator().sender,
+                    withdraw_enabled: true,
+                    fee_collector_addr: Addr::unchecked("fee_collector"),
+                    fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
+                },
+            )
+            .unwrap();
+
+        let res = receive(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_token", &[]),
+            vault_network::vault::Cw20ReceiveMsg {
+                sender: mock_creator().sender.into_string(),
+                amount: Uint128::new(5_000),
+                msg: to_binary(&vault_network::vault::Cw20HookMsg::Deposit { receiver: None })
+                    .unwrap(),
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), VaultError::Unauthorized {})
+    }
+
+    #[test]
+    fn cannot_deposit_via_send_into_native_vault() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            pool_network::asset::AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res = receive(
+            deps.as_mut(),
+            env,
+            mock_info("uluna", &[]),
+            vault_network::vault::Cw20ReceiveMsg {
+                sender: mock_creator().sender.into_string(),
+                amount: Uint128::new(5_000),
+                msg: to_binary(&vault_network::vault::Cw20HookMsg::Deposit { receiver: None })
+                    .unwrap(),
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), VaultError::Unauthorized {})
+    }
 }
@@ -1,11 +1,19 @@
 use cosmwasm_std::{
-    coins, to_binary, BankMsg, CosmosMsg, Decimal, DepsMut, Env, Response, Uint128, WasmMsg,
+    coins, to_binary, Addr, BankMsg, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
+    Uint128, WasmMsg,
 };
-use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, TokenInfoResponse};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
 
 use pool_network::asset::AssetInfo;
+use pool_network::lp_token::{burn_lp_token_msg, query_lp_total_supply};
 
-use crate::state::COLLECTED_PROTOCOL_FEES;
+use vault_network::vault::{CallbackMsg, Config};
+
+use crate::state::{get_held_fees, LIQUIDITY_STRATEGY};
+use crate::strategy::{
+    assert_price_deviation, callback_msg, get_strategy_value, query_position, unwind_position_msgs,
+    ValuationPrice,
+};
 use crate::{error::VaultError, state::CONFIG};
 
 pub fn withdraw(
@@ -24,32 +32,121 @@ pub fn withdraw(
     // parse sender
     let sender = deps.api.addr_validate(&sender)?;
 
+    let (idle_asset_amount, position_value, withdraw_amount) =
+        get_withdraw_amounts(deps.as_ref(), &env, &config, amount)?;
+
+    // if the vault does not hold enough funds, withdraw the missing part of the liquidity strategy
+    // position first and perform the withdrawal once the funds are back in the vault
+    if withdraw_amount > idle_asset_amount {
+        if let Some(strategy) = LIQUIDITY_STRATEGY.may_load(deps.storage)? {
+            assert_price_deviation(deps.as_ref(), &config.asset_info, &strategy)?;
+
+            let unwind_lp_amount = if position_value.is_zero() {
+                None
+            } else {
+                let (lp_amount, _) = query_position(deps.as_ref(), &env, &strategy)?;
+                Some(
+                    lp_amount
+                        .multiply_ratio(withdraw_amount - idle_asset_amount, position_value)
+                        .checked_add(Uint128::one())?,
+                )
+            };
+            let unwind_msgs =
+                unwind_position_msgs(deps.as_ref(), &env, &strategy, unwind_lp_amount)?;
+
+            if !unwind_msgs.is_empty() {
+                return Ok(Response::new()
+                    .add_messages(unwind_msgs)
+                    .add_message(callback_msg(
+                        &env,
+                        CallbackMsg::Withdraw {
+                            sender: sender.into_string(),
+                            amount,
+                        },
+                    )?)
+                    .add_attributes(vec![
+                        ("method", "withdraw"),
+                        ("action", "unwind_liquidity_strategy"),
+                    ]));
+            }
+        }
+    }
+
+    withdrawal_response(config, &env, sender, amount, withdraw_amount)
+}
+
+/// Performs a withdrawal once part of the liquidity strategy position has been unwound to cover it.
+/// The withdrawal bears the cost of unwinding the position, as it is capped to the idle assets of
+/// the vault.
+pub fn withdraw_unwound(
+    deps: DepsMut,
+    env: Env,
+    sender: String,
+    amount: Uint128,
+) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+    let sender = deps.api.addr_validate(&sender)?;
+
+    let (idle_asset_amount, _, withdraw_amount) =
+        get_withdraw_amounts(deps.as_ref(), &env, &config, amount)?;
+
+    withdrawal_response(
+        config,
+        &env,
+        sender,
+        amount,
+        withdraw_amount.min(idle_asset_amount),
+    )
+}
+
+/// Gets the idle assets of the vault, the value of its liquidity strategy position and the amount
+/// of assets to withdraw for `amount` of LP tokens.
+fn get_withdraw_amounts(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    amount: Uint128,
+) -> Result<(Uint128, Uint128, Uint128), VaultError> {
     // calculate the size of vault and the amount of assets to withdraw
-    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
-    let total_asset_amount = match &config.asset_info {
+    let held_fees = get_held_fees(deps.storage)?;
+    let idle_asset_amount = match &config.asset_info {
         AssetInfo::NativeToken { denom } => {
             deps.querier
-                .query_balance(env.contract.address, denom)?
+                .query_balance(env.contract.address.clone(), denom)?
                 .amount
         }
         AssetInfo::Token { contract_addr } => {
             let balance: BalanceResponse = deps.querier.query_wasm_smart(
                 contract_addr,
                 &Cw20QueryMsg::Balance {
-                    address: env.contract.address.into_string(),
+                    address: env.contract.address.clone().into_string(),
                 },
             )?;
             balance.balance
         }
-    } // deduct protocol fees
-    .checked_sub(collected_protocol_fees.amount)?;
+    } // deduct protocol fees and burn fees to buy back
+    .checked_sub(held_fees)?;
+
+    // add the value of the liquidity strategy position, at the price least favorable to the
+    // withdrawal
+    let position_value = get_strategy_value(deps, env, &config.asset_info, ValuationPrice::Lowest)?;
+    let total_asset_amount = idle_asset_amount.checked_add(position_value)?;
+
+    let total_share_amount = query_lp_total_supply(&deps.querier, &config.liquidity_token)?;
+    let withdraw_amount = Decimal::from_ratio(amount, total_share_amount) * total_asset_amount;
 
-    let total_share_amount: TokenInfoResponse = deps
-        .querier
-        .query_wasm_smart(config.liquidity_token.clone(), &Cw20QueryMsg::TokenInfo {})?;
-    let withdraw_amount =
-        Decimal::from_ratio(amount, total_share_amount.total_supply) * total_asset_amount;
+    Ok((idle_asset_amount, position_value, withdraw_amount))
+}
 
+/// Sends `withdraw_amount` of the vault asset to the `sender`, burning the `amount` of LP tokens
+/// withdrawn.
+fn withdrawal_response(
+    config: Config,
+    env: &Env,
+    sender: Addr,
+    amount: Uint128,
+    withdraw_amount: Uint128,
+) -> Result<Response, VaultError> {
     // create message to send back to user if cw20
     let messages: Vec<CosmosMsg> = vec![
         match config.asset_info {
@@ -68,12 +165,7 @@ pub fn withdraw(
             }
             .into(),
         },
-        WasmMsg::Execute {
-            contract_addr: config.liquidity_token.into_string(),
-            funds: vec![],
-            msg: to_binary(&Cw20ExecuteMsg::Burn { amount })?,
-        }
-        .into(),
+        burn_lp_token_msg(&config.liquidity_token, &env.contract.address, amount)?,
     ];
 
     Ok(Response::new().add_messages(messages).add_attributes(vec![
@@ -83,6 +175,24 @@ pub fn withdraw(
     ]))
 }
 
+/// Withdraws with the token factory LP tokens sent along the message
+pub fn withdraw_native(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // only vaults issuing token factory LP tokens can be withdrawn from with native funds
+    let lp_denom = match config.liquidity_token {
+        AssetInfo::NativeToken { denom } => denom,
+        AssetInfo::Token { .. } => return Err(VaultError::Unauthorized {}),
+    };
+
+    let amount = match info.funds.as_slice() {
+        [coin] if coin.denom == lp_denom && !coin.amount.is_zero() => coin.amount,
+        _ => return Err(VaultError::InvalidLiquidityTokenFunds { denom: lp_denom }),
+    };
+
+    withdraw(deps, env, info.sender.into_string(), amount)
+}
+
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::{
@@ -94,6 +204,7 @@ mod tests {
     use cw_multi_test::Executor;
 
     use pool_network::asset::{Asset, AssetInfo};
+    use pool_network::burn_fee::BurnFeeTarget;
     use vault_network::vault::{Config, UpdateConfigParams};
 
     use crate::state::COLLECTED_PROTOCOL_FEES;
@@ -126,6 +237,35 @@ mod tests {
         assert_eq!(res.unwrap_err(), VaultError::ExternalCallback {})
     }
 
+    #[test]
+    fn cannot_withdraw_native_funds_from_cw20_lp_vault() {
+        let (_, mut deps, ..) = mock_execute(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+            vault_network::vault::ExecuteMsg::UpdateConfig(UpdateConfigParams {
+                flash_loan_enabled: None,
+                deposit_enabled: None,
+                withdraw_enabled: None,
+                new_owner: None,
+                new_fee_collector_addr: None,
+                new_vault_fees: None,
+                new_burn_fee_target: None,
+                new_epoch_config: None,
+            }),
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &coins(5_000, "factory/cosmos2contract/uLP")),
+            vault_network::vault::ExecuteMsg::Withdraw {},
+        );
+
+        assert_eq!(res.unwrap_err(), VaultError::Unauthorized {});
+    }
+
     #[test]
     fn cannot_withdraw_when_disabled() {
         let (res, mut deps, ..) = mock_execute(
@@ -140,6 +280,8 @@ mod tests {
                 new_owner: None,
                 new_fee_collector_addr: None,
                 new_vault_fees: None,
+                new_burn_fee_target: None,
+                new_epoch_config: None,
             }),
         );
 
@@ -179,12 +321,14 @@ mod tests {
                 &vault_network::vault::QueryMsg::Config {},
             )
             .unwrap();
+        let liquidity_token = Addr::unchecked(config.liquidity_token.to_string());
 
         app.execute_contract(
             mock_creator().sender,
             vault_addr.clone(),
             &vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(10_000),
+                receiver: None,
             },
             &coins(10_000, "uluna"),
         )
@@ -193,7 +337,7 @@ mod tests {
         // withdraw 50% of funds
         app.execute_contract(
             mock_creator().sender,
-            config.liquidity_token.clone(),
+            liquidity_token.clone(),
             &Cw20ExecuteMsg::Send {
                 contract: vault_addr.to_string(),
                 amount: Uint128::new(5_000),
@@ -216,7 +360,7 @@ mod tests {
         let cw20_balance: cw20::BalanceResponse = app
             .wrap()
             .query_wasm_smart(
-                config.liquidity_token,
+                liquidity_token,
                 &cw20::Cw20QueryMsg::Balance {
                     address: mock_creator().sender.into_string(),
                 },
@@ -266,6 +410,7 @@ mod tests {
                 &vault_network::vault::QueryMsg::Config {},
             )
             .unwrap();
+        let liquidity_token = Addr::unchecked(config.liquidity_token.to_string());
 
         // increment allowance for deposit
         app.execute_contract(
@@ -286,6 +431,7 @@ mod tests {
             vault_addr.clone(),
             &vault_network::vault::ExecuteMsg::Deposit {
                 amount: Uint128::new(10_000),
+                receiver: None,
             },
             &[],
         )
@@ -294,7 +440,7 @@ mod tests {
         // withdraw 50% of funds
         app.execute_contract(
             mock_creator().sender,
-            config.liquidity_token.clone(),
+            liquidity_token.clone(),
             &Cw20ExecuteMsg::Send {
                 contract: vault_addr.to_string(),
                 amount: Uint128::new(5_000),
@@ -320,7 +466,7 @@ mod tests {
         let cw20_balance: cw20::BalanceResponse = app
             .wrap()
             .query_wasm_smart(
-                config.liquidity_token,
+                liquidity_token,
                 &cw20::Cw20QueryMsg::Balance {
                     address: mock_creator().sender.into_string(),
                 },
@@ -358,7 +504,9 @@ mod tests {
             .save(
                 &mut deps.storage,
                 &Config {
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::NativeToken {
                         denom: "uluna".to_string(),
                     },
@@ -368,6 +516,7 @@ mod tests {
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -463,7 +612,9 @@ mod tests {
             .save(
                 &mut deps.storage,
                 &Config {
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::Token {
                         contract_addr: "vault_token".to_string(),
                     },
@@ -473,6 +624,7 @@ mod tests {
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
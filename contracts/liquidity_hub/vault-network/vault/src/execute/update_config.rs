@@ -1,11 +1,19 @@
-use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use cosmwasm_std::{CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128};
 
+use pool_network::asset::Asset;
 use vault_network::vault::UpdateConfigParams;
 
-use crate::{error::VaultError, state::CONFIG};
+use crate::{
+    error::VaultError,
+    state::{
+        store_epoch_fee, store_fee, ALL_TIME_BURNED_FEES, BURNED_FEES_BY_EPOCH, BUY_BACK_FEES,
+        CONFIG, EPOCH_CONFIG,
+    },
+};
 
 pub fn update_config(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     params: UpdateConfigParams,
 ) -> Result<Response, VaultError> {
@@ -16,6 +24,8 @@ pub fn update_config(
         new_owner,
         new_fee_collector_addr,
         new_vault_fees,
+        new_burn_fee_target,
+        new_epoch_config,
     } = params;
 
     let mut config = CONFIG.load(deps.storage)?;
@@ -44,10 +54,46 @@ pub fn update_config(
         new_fees.is_valid()?;
         config.fees = new_fees;
     }
+    // the epoch config can only be set once, as the fees already bucketed by epoch would be
+    // misaligned
+    if let Some(new_epoch_config) = new_epoch_config {
+        if EPOCH_CONFIG.may_load(deps.storage)?.is_some() {
+            return Err(VaultError::EpochConfigAlreadySet {});
+        }
+        new_epoch_config.validate()?;
+        EPOCH_CONFIG.save(deps.storage, &new_epoch_config)?;
+    }
+
+    // the burn fees accrued to buy back with are disposed of by the new target, unless it buys back
+    // with them as well
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if let Some(new_burn_fee_target) = new_burn_fee_target {
+        new_burn_fee_target.validate(deps.as_ref(), &[config.asset_info.clone()])?;
+
+        let buy_back_fees = BUY_BACK_FEES.may_load(deps.storage)?.unwrap_or_default();
+        if !buy_back_fees.is_zero() && !new_burn_fee_target.buys_back(&config.asset_info) {
+            BUY_BACK_FEES.save(deps.storage, &Uint128::zero())?;
+
+            if new_burn_fee_target.burns_directly(&config.asset_info) {
+                let epoch = EPOCH_CONFIG
+                    .may_load(deps.storage)?
+                    .unwrap_or_default()
+                    .epoch(env.block.time);
+                store_fee(deps.storage, ALL_TIME_BURNED_FEES, buy_back_fees)?;
+                store_epoch_fee(deps.storage, epoch, buy_back_fees, BURNED_FEES_BY_EPOCH)?;
+            }
+            messages.extend(new_burn_fee_target.into_msgs(Asset {
+                info: config.asset_info.clone(),
+                amount: buy_back_fees,
+            })?);
+        }
+
+        config.burn_fee_target = new_burn_fee_target;
+    }
 
     CONFIG.save(deps.storage, &config)?;
 
-    Ok(Response::new().add_attributes(vec![
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
         ("method", "update_config"),
         ("flash_loan_enabled", &config.flash_loan_enabled.to_string()),
         ("withdraw_enabled", &config.withdraw_enabled.to_string()),
@@ -68,13 +114,15 @@ mod test {
     };
 
     use pool_network::asset::AssetInfo;
-    use vault_network::vault::{Config, UpdateConfigParams};
+    use pool_network::burn_fee::BurnFeeTarget;
+    use pool_network::epoch::EpochConfig;
     use nico_teen::fee::{Fee, VaultFee};
+    use vault_network::vault::{Config, UpdateConfigParams};
 
     use crate::{
         contract::execute,
         error::VaultError,
-        state::CONFIG,
+        state::{CONFIG, EPOCH_CONFIG},
         tests::{get_fees, mock_creator, mock_instantiate::mock_instantiate},
     };
 
@@ -98,12 +146,56 @@ mod test {
                 new_owner: None,
                 new_fee_collector_addr: None,
                 new_vault_fees: None,
+                new_burn_fee_target: None,
+                new_epoch_config: None,
             }),
         );
 
         assert_eq!(res.unwrap_err(), VaultError::Unauthorized {});
     }
 
+    #[test]
+    fn cannot_change_epoch_config_once_set() {
+        let (mut deps, env) = mock_instantiate(
+            2,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        for epoch_length in [86_400u64, 3_600u64] {
+            let res = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_creator(),
+                vault_network::vault::ExecuteMsg::UpdateConfig(UpdateConfigParams {
+                    flash_loan_enabled: None,
+                    deposit_enabled: None,
+                    withdraw_enabled: None,
+                    new_owner: None,
+                    new_fee_collector_addr: None,
+                    new_vault_fees: None,
+                    new_burn_fee_target: None,
+                    new_epoch_config: Some(EpochConfig {
+                        epoch_length,
+                        genesis_time: env.block.time,
+                    }),
+                }),
+            );
+
+            if epoch_length == 86_400u64 {
+                res.unwrap();
+            } else {
+                assert_eq!(res.unwrap_err(), VaultError::EpochConfigAlreadySet {});
+            }
+        }
+
+        assert_eq!(
+            EPOCH_CONFIG.load(&deps.storage).unwrap().epoch_length,
+            86_400u64
+        );
+    }
+
     #[test]
     fn does_not_change_if_none() {
         let mut deps = mock_dependencies();
@@ -114,12 +206,15 @@ mod test {
             asset_info: AssetInfo::NativeToken {
                 denom: "uluna".to_string(),
             },
-            liquidity_token: Addr::unchecked("lp_token"),
+            liquidity_token: AssetInfo::Token {
+                contract_addr: "lp_token".to_string(),
+            },
             deposit_enabled: false,
             flash_loan_enabled: false,
             withdraw_enabled: false,
             fee_collector_addr: Addr::unchecked("fee_collector"),
             fees: get_fees(),
+            burn_fee_target: BurnFeeTarget::Burn,
         };
 
         CONFIG.save(&mut deps.storage, &config).unwrap();
@@ -135,6 +230,8 @@ mod test {
                 new_owner: None,
                 new_fee_collector_addr: None,
                 new_vault_fees: None,
+                new_burn_fee_target: None,
+                new_epoch_config: None,
             }),
         )
         .unwrap();
@@ -166,12 +263,15 @@ mod test {
             asset_info: AssetInfo::NativeToken {
                 denom: "uluna".to_string(),
             },
-            liquidity_token: Addr::unchecked("lp_token"),
+            liquidity_token: AssetInfo::Token {
+                contract_addr: "lp_token".to_string(),
+            },
             deposit_enabled: false,
             flash_loan_enabled: false,
             withdraw_enabled: false,
             fee_collector_addr: Addr::unchecked("fee_collector"),
             fees: get_fees(),
+            burn_fee_target: BurnFeeTarget::Burn,
         };
 
         CONFIG.save(&mut deps.storage, &config).unwrap();
@@ -197,6 +297,8 @@ mod test {
                         share: Decimal::zero(),
                     },
                 }),
+                new_burn_fee_target: None,
+                new_epoch_config: None,
             }),
         )
         .unwrap_err();
@@ -217,12 +319,15 @@ mod test {
             asset_info: AssetInfo::NativeToken {
                 denom: "uluna".to_string(),
             },
-            liquidity_token: Addr::unchecked("lp_token"),
+            liquidity_token: AssetInfo::Token {
+                contract_addr: "lp_token".to_string(),
+            },
             deposit_enabled: false,
             flash_loan_enabled: false,
             withdraw_enabled: false,
             fee_collector_addr: Addr::unchecked("fee_collector"),
             fees: get_fees(),
+            burn_fee_target: BurnFeeTarget::Burn,
         };
 
         CONFIG.save(&mut deps.storage, &config).unwrap();
@@ -250,6 +355,8 @@ mod test {
                 new_owner: Some("new_owner".to_string()),
                 new_fee_collector_addr: Some("new_fee_collector".to_string()),
                 new_vault_fees: Some(new_fee.clone()),
+                new_burn_fee_target: None,
+                new_epoch_config: None,
             }),
         )
         .unwrap();
@@ -272,7 +379,9 @@ mod test {
             config_after,
             Config {
                 owner: Addr::unchecked("new_owner"),
-                liquidity_token: Addr::unchecked("lp_token"),
+                liquidity_token: AssetInfo::Token {
+                    contract_addr: "lp_token".to_string()
+                },
                 asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string()
                 },
@@ -281,6 +390,7 @@ mod test {
                 withdraw_enabled: true,
                 fee_collector_addr: Addr::unchecked("new_fee_collector"),
                 fees: new_fee,
+                burn_fee_target: BurnFeeTarget::Burn,
             }
         );
     }
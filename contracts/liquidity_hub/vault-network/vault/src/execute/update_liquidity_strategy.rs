@@ -0,0 +1,177 @@
+use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response};
+
+use pool_network::asset::{AssetInfo, PairType};
+use pool_network::querier::query_pair_info_from_pair;
+use vault_network::vault::{LiquidityStrategy, LiquidityStrategyParams};
+
+use crate::{
+    error::VaultError,
+    state::{CONFIG, LIQUIDITY_STRATEGY},
+    strategy::{get_counter_asset, query_pair_price, unwind_position_msgs},
+};
+
+/// Sets or removes the liquidity strategy of the vault. The reference price of the strategy is set
+/// to the current pair price.
+pub fn update_liquidity_strategy(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    strategy: Option<LiquidityStrategyParams>,
+) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if config.owner != info.sender {
+        return Err(VaultError::Unauthorized {});
+    }
+
+    let new_pair_addr = strategy
+        .as_ref()
+        .map(|params| deps.api.addr_validate(&params.pair_addr))
+        .transpose()?;
+
+    // the pair can only be changed when there is no position left in the current one
+    if let Some(current_strategy) = LIQUIDITY_STRATEGY.may_load(deps.storage)? {
+        if new_pair_addr.as_ref() != Some(&current_strategy.pair_addr)
+            && !unwind_position_msgs(deps.as_ref(), &env, &current_strategy, None)?.is_empty()
+        {
+            return Err(VaultError::StrategyPositionOpen {});
+        }
+    }
+
+    match (strategy, new_pair_addr) {
+        (Some(params), Some(pair_addr)) => {
+            if params.allocation > Decimal::one() {
+                return Err(VaultError::InvalidStrategyAllocation {});
+            }
+
+            let pair_info = query_pair_info_from_pair(&deps.querier, pair_addr.clone())?;
+            let counter_asset = get_counter_asset(&pair_info.asset_infos, &config.asset_info)
+                .ok_or(VaultError::InvalidStrategyPair {})?;
+
+            // the position is valued from the constant product of the pair
+            if pair_info.pair_type != PairType::ConstantProduct {
+                return Err(VaultError::UnsupportedStrategyPairType {});
+            }
+
+            let mut strategy = LiquidityStrategy {
+                pair_addr: pair_addr.clone(),
+                lp_token: match pair_info.liquidity_token {
+                    AssetInfo::Token { contract_addr } => deps.api.addr_validate(&contract_addr)?,
+                    AssetInfo::NativeToken { .. } => {
+                        return Err(VaultError::UnsupportedStrategyLpToken {})
+                    }
+                },
+                counter_asset,
+                allocation: params.allocation,
+                max_spread: params.max_spread,
+                max_price_deviation: params.max_price_deviation,
+                reference_price: Decimal::zero(),
+            };
+            strategy.reference_price =
+                query_pair_price(deps.as_ref(), &config.asset_info, &strategy)?;
+            LIQUIDITY_STRATEGY.save(deps.storage, &strategy)?;
+
+            Ok(Response::new().add_attributes(vec![
+                ("method", "update_liquidity_strategy"),
+                ("pair_addr", pair_addr.as_str()),
+                ("allocation", &params.allocation.to_string()),
+                ("reference_price", &strategy.reference_price.to_string()),
+            ]))
+        }
+        _ => {
+            LIQUIDITY_STRATEGY.remove(deps.storage);
+
+            Ok(Response::new().add_attributes(vec![
+                ("method", "update_liquidity_strategy"),
+                ("pair_addr", "none"),
+            ]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{testing::mock_info, Decimal, Response};
+
+    use pool_network::asset::{AssetInfo, PairType};
+    use vault_network::vault::{ExecuteMsg, LiquidityStrategyParams};
+
+    use crate::{
+        contract::execute,
+        error::VaultError,
+        state::LIQUIDITY_STRATEGY,
+        tests::{mock_creator, mock_instantiate::mock_instantiate},
+    };
+
+    #[test]
+    fn cannot_update_strategy_unauthorized() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("bad_actor", &[]),
+            ExecuteMsg::UpdateLiquidityStrategy { strategy: None },
+        );
+
+        assert_eq!(res.unwrap_err(), VaultError::Unauthorized {});
+    }
+
+    #[test]
+    fn cannot_set_strategy_with_invalid_allocation() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            ExecuteMsg::UpdateLiquidityStrategy {
+                strategy: Some(LiquidityStrategyParams {
+                    pair_addr: "pair".to_string(),
+                    allocation: Decimal::percent(101),
+                    max_spread: None,
+                    max_price_deviation: Decimal::percent(5),
+                }),
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), VaultError::InvalidStrategyAllocation {});
+    }
+
+    #[test]
+    fn can_remove_unset_strategy() {
+        let (mut deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            ExecuteMsg::UpdateLiquidityStrategy { strategy: None },
+        )
+        .unwrap();
+
+        assert_eq!(
+            res,
+            Response::new().add_attributes(vec![
+                ("method", "update_liquidity_strategy"),
+                ("pair_addr", "none"),
+            ])
+        );
+        assert_eq!(LIQUIDITY_STRATEGY.may_load(&deps.storage).unwrap(), None);
+    }
+}
@@ -0,0 +1,90 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+use pool_network::asset::AssetInfo;
+use pool_network::token::LpTokenInfo;
+
+use crate::{error::VaultError, state::CONFIG};
+
+/// Updates the name, symbol and/or marketing info of the cw20 LP token of the vault
+pub fn update_lp_token_info(
+    deps: DepsMut,
+    info: MessageInfo,
+    lp_token_info: LpTokenInfo,
+) -> Result<Response, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if config.owner != info.sender {
+        return Err(VaultError::Unauthorized {});
+    }
+
+    // token factory LP tokens have no cw20 metadata to update
+    let lp_token = match config.liquidity_token {
+        AssetInfo::Token { contract_addr } => contract_addr,
+        AssetInfo::NativeToken { .. } => return Err(VaultError::NativeLpTokenInfo {}),
+    };
+
+    Ok(Response::new()
+        .add_messages(lp_token_info.update_msgs(&lp_token)?)
+        .add_attributes(vec![
+            ("method", "update_lp_token_info"),
+            ("lp_token", &lp_token),
+        ]))
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Addr;
+
+    use pool_network::asset::AssetInfo;
+    use pool_network::burn_fee::BurnFeeTarget;
+    use pool_network::token::LpTokenInfo;
+    use vault_network::vault::{Config, ExecuteMsg};
+
+    use crate::{
+        contract::execute,
+        error::VaultError,
+        state::CONFIG,
+        tests::{get_fees, mock_creator},
+    };
+
+    #[test]
+    fn cannot_update_token_factory_lp_token_info() {
+        let mut deps = mock_dependencies();
+
+        CONFIG
+            .save(
+                &mut deps.storage,
+                &Config {
+                    owner: mock_creator().sender,
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                    liquidity_token: AssetInfo::NativeToken {
+                        denom: "factory/vault/uLP".to_string(),
+                    },
+                    deposit_enabled: true,
+                    flash_loan_enabled: true,
+                    withdraw_enabled: true,
+                    fee_collector_addr: Addr::unchecked("fee_collector"),
+                    fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
+                },
+            )
+            .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_creator(),
+            ExecuteMsg::UpdateLpTokenInfo {
+                lp_token_info: LpTokenInfo {
+                    symbol: Some("uLUNA-LP".to_string()),
+                    ..LpTokenInfo::default()
+                },
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), VaultError::NativeLpTokenInfo {});
+    }
+}
@@ -6,6 +6,7 @@ pub mod reply;
 
 pub mod response;
 pub mod state;
+mod strategy;
 
 pub mod error;
 
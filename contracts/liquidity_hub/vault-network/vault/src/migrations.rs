@@ -4,9 +4,40 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Decimal, DepsMut, StdError};
 use cw_storage_plus::Item;
 use pool_network::asset::AssetInfo;
+use pool_network::burn_fee::BurnFeeTarget;
 use vault_network::vault::Config;
 use nico_teen::fee::{Fee, VaultFee};
 
+/// The [Config] stored by the vault before the LP token became an [AssetInfo] in v1.5.0
+#[cw_serde]
+struct ConfigV140 {
+    pub owner: Addr,
+    pub asset_info: AssetInfo,
+    pub flash_loan_enabled: bool,
+    pub deposit_enabled: bool,
+    pub withdraw_enabled: bool,
+    pub liquidity_token: Addr,
+    pub fee_collector_addr: Addr,
+    pub fees: VaultFee,
+}
+
+const CONFIG_V140: Item<ConfigV140> = Item::new("config");
+
+/// The [Config] stored by the vault before the burn fee target was added in v1.7.0
+#[cw_serde]
+struct ConfigV160 {
+    pub owner: Addr,
+    pub asset_info: AssetInfo,
+    pub flash_loan_enabled: bool,
+    pub deposit_enabled: bool,
+    pub withdraw_enabled: bool,
+    pub liquidity_token: AssetInfo,
+    pub fee_collector_addr: Addr,
+    pub fees: VaultFee,
+}
+
+const CONFIG_V160: Item<ConfigV160> = Item::new("config");
+
 pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
     #[cw_serde]
     pub struct ConfigV113 {
@@ -38,7 +69,7 @@ pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
     let config_v113 = CONFIG_V113.load(deps.storage)?;
 
     // Add burn fee to config. Zero fee is used as default.
-    let config = Config {
+    let config = ConfigV140 {
         owner: config_v113.owner,
         asset_info: config_v113.asset_info,
         flash_loan_enabled: config_v113.flash_loan_enabled,
@@ -55,10 +86,55 @@ pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
         },
     };
 
-    CONFIG.save(deps.storage, &config)?;
+    CONFIG_V140.save(deps.storage, &config)?;
 
     // initialize the burned fee storage item
     initialize_fee(deps.storage, ALL_TIME_BURNED_FEES, config.asset_info)?;
 
     Ok(())
 }
+
+/// Migrate to the token factory LP tokens, storing the liquidity token as a cw20 [AssetInfo]
+pub fn migrate_to_v150(deps: DepsMut) -> Result<(), StdError> {
+    let config = CONFIG_V140.load(deps.storage)?;
+
+    CONFIG_V160.save(
+        deps.storage,
+        &ConfigV160 {
+            owner: config.owner,
+            asset_info: config.asset_info,
+            flash_loan_enabled: config.flash_loan_enabled,
+            deposit_enabled: config.deposit_enabled,
+            withdraw_enabled: config.withdraw_enabled,
+            liquidity_token: AssetInfo::Token {
+                contract_addr: config.liquidity_token.into_string(),
+            },
+            fee_collector_addr: config.fee_collector_addr,
+            fees: config.fees,
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Migrate to the burn fee targets, burning the burn fees as before
+pub fn migrate_to_v170(deps: DepsMut) -> Result<(), StdError> {
+    let config = CONFIG_V160.load(deps.storage)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            owner: config.owner,
+            asset_info: config.asset_info,
+            flash_loan_enabled: config.flash_loan_enabled,
+            deposit_enabled: config.deposit_enabled,
+            withdraw_enabled: config.withdraw_enabled,
+            liquidity_token: config.liquidity_token,
+            fee_collector_addr: config.fee_collector_addr,
+            fees: config.fees,
+            burn_fee_target: BurnFeeTarget::Burn,
+        },
+    )?;
+
+    Ok(())
+}
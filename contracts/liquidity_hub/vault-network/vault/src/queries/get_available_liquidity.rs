@@ -0,0 +1,83 @@
+use cosmwasm_std::{to_binary, Binary, Deps, Env};
+
+use crate::error::VaultError;
+use crate::state::{get_held_fees, CONFIG};
+
+/// Queries the amount of the vault asset that can currently be borrowed, i.e. the vault balance
+/// without the protocol fees that have not been collected yet and the burn fees to buy back with.
+pub fn get_available_liquidity(deps: Deps, env: Env) -> Result<Binary, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let balance = config
+        .asset_info
+        .query_pool(&deps.querier, deps.api, env.contract.address)?;
+    let held_fees = get_held_fees(deps.storage)?;
+
+    Ok(to_binary(&balance.checked_sub(held_fees)?)?)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{coins, from_binary, testing::mock_env, Addr, Uint128};
+    use pool_network::asset::{Asset, AssetInfo};
+    use pool_network::burn_fee::BurnFeeTarget;
+    use vault_network::vault::{Config, QueryMsg};
+
+    use crate::{
+        contract::query,
+        state::{COLLECTED_PROTOCOL_FEES, CONFIG},
+        tests::{get_fees, mock_creator, mock_dependencies_lp},
+    };
+
+    #[test]
+    fn does_get_available_liquidity() {
+        let env = mock_env();
+        let mut deps = mock_dependencies_lp(
+            &[(
+                &env.clone().contract.address.into_string(),
+                &coins(100_000, "uluna"),
+            )],
+            &[],
+            vec![],
+        );
+
+        let asset_info = AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        };
+
+        CONFIG
+            .save(
+                &mut deps.storage,
+                &Config {
+                    owner: mock_creator().sender,
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
+                    asset_info: asset_info.clone(),
+                    deposit_enabled: true,
+                    flash_loan_enabled: true,
+                    withdraw_enabled: true,
+                    fee_collector_addr: Addr::unchecked("fee_collector"),
+                    fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
+                },
+            )
+            .unwrap();
+
+        COLLECTED_PROTOCOL_FEES
+            .save(
+                &mut deps.storage,
+                &Asset {
+                    amount: Uint128::new(1_500),
+                    info: asset_info,
+                },
+            )
+            .unwrap();
+
+        let res: Uint128 =
+            from_binary(&query(deps.as_ref(), env, QueryMsg::AvailableLiquidity {}).unwrap())
+                .unwrap();
+
+        assert_eq!(res, Uint128::new(98_500));
+    }
+}
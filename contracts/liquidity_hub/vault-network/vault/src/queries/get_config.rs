@@ -15,6 +15,7 @@ mod test {
         Addr,
     };
     use pool_network::asset::AssetInfo;
+    use pool_network::burn_fee::BurnFeeTarget;
     use vault_network::vault::Config;
 
     use crate::{
@@ -30,7 +31,9 @@ mod test {
 
         let config = Config {
             owner: mock_creator().sender,
-            liquidity_token: Addr::unchecked("lp_token"),
+            liquidity_token: AssetInfo::Token {
+                contract_addr: "lp_token".to_string(),
+            },
             asset_info: AssetInfo::NativeToken {
                 denom: "uluna".to_string(),
             },
@@ -39,6 +42,7 @@ mod test {
             withdraw_enabled: false,
             fee_collector_addr: Addr::unchecked("fee_collector"),
             fees: get_fees(),
+            burn_fee_target: BurnFeeTarget::Burn,
         };
 
         CONFIG.save(&mut deps.storage, &config).unwrap();
@@ -0,0 +1,58 @@
+use cosmwasm_std::{to_binary, Binary, Deps, Env, Uint128};
+use vault_network::vault::LiquidityStrategyResponse;
+
+use crate::error::VaultError;
+use crate::state::{CONFIG, LIQUIDITY_STRATEGY};
+use crate::strategy::{get_position_value, ValuationPrice};
+
+pub fn get_liquidity_strategy(deps: Deps, env: Env) -> Result<Binary, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+    let strategy = LIQUIDITY_STRATEGY.may_load(deps.storage)?;
+
+    let position_value = match &strategy {
+        Some(strategy) => get_position_value(
+            deps,
+            &env,
+            &config.asset_info,
+            strategy,
+            ValuationPrice::Lowest,
+        )?,
+        None => Uint128::zero(),
+    };
+
+    Ok(to_binary(&LiquidityStrategyResponse {
+        strategy,
+        position_value,
+    })?)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{from_binary, Uint128};
+    use pool_network::asset::AssetInfo;
+    use vault_network::vault::{LiquidityStrategyResponse, QueryMsg};
+
+    use crate::{contract::query, tests::mock_instantiate::mock_instantiate};
+
+    #[test]
+    fn does_get_disabled_strategy() {
+        let (deps, env) = mock_instantiate(
+            1,
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        );
+
+        let res: LiquidityStrategyResponse =
+            from_binary(&query(deps.as_ref(), env, QueryMsg::LiquidityStrategy {}).unwrap())
+                .unwrap();
+
+        assert_eq!(
+            res,
+            LiquidityStrategyResponse {
+                strategy: None,
+                position_value: Uint128::zero(),
+            }
+        );
+    }
+}
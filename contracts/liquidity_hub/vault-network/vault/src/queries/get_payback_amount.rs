@@ -34,6 +34,7 @@ mod test {
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
     use cosmwasm_std::{from_binary, Addr, Decimal, Uint128};
     use pool_network::asset::AssetInfo;
+    use pool_network::burn_fee::BurnFeeTarget;
     use vault_network::vault::{Config, PaybackAmountResponse, QueryMsg};
     use nico_teen::fee::{Fee, VaultFee};
 
@@ -46,7 +47,9 @@ mod test {
                 &mut deps.storage,
                 &Config {
                     owner: mock_creator().sender,
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::NativeToken {
                         denom: "uluna".to_string(),
                     },
@@ -65,6 +68,7 @@ mod test {
                             share: Decimal::permille(1),
                         },
                     },
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -5,6 +5,7 @@ use pool_network::asset::Asset;
 use vault_network::vault::ProtocolFeesResponse;
 
 use crate::error::VaultError;
+use crate::state::{ALL_TIME_BOUGHT_BACK_FEES, BUY_BACK_FEES};
 
 /// Queries fees on the pool
 pub fn get_fees(
@@ -24,6 +25,20 @@ pub fn get_fees(
     Ok(to_binary(&ProtocolFeesResponse { fees })?)
 }
 
+/// Queries the burn fees accrued by the vault, waiting to be bought back
+pub fn get_buy_back_fees(deps: Deps) -> Result<Binary, VaultError> {
+    let fees = BUY_BACK_FEES.may_load(deps.storage)?.unwrap_or_default();
+    Ok(to_binary(&fees)?)
+}
+
+/// Queries the tokens bought back with the burn fees, and burned, by the vault
+pub fn get_bought_back_fees(deps: Deps) -> Result<Binary, VaultError> {
+    let fees = ALL_TIME_BOUGHT_BACK_FEES
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    Ok(to_binary(&fees)?)
+}
+
 #[cfg(test)]
 mod test {
     use cosmwasm_std::{
@@ -0,0 +1,214 @@
+use cosmwasm_std::{to_binary, Binary, Deps, Env, Order, StdResult};
+use cw_storage_plus::Bound;
+
+use pool_network::asset::Asset;
+use pool_network::epoch::{validate_epoch_range, EpochFees, ProtocolFeesByEpochResponse};
+
+use crate::error::VaultError;
+use crate::state::{
+    BOUGHT_BACK_FEES_BY_EPOCH, BURNED_FEES_BY_EPOCH, CONFIG, EPOCH_CONFIG, PROTOCOL_FEES_BY_EPOCH,
+};
+
+/// Queries the protocol fees accrued and the fees burned by the vault in each epoch from
+/// `start_epoch` to `end_epoch`, both included. Epochs without fees are left out.
+pub fn get_protocol_fees_by_epoch(
+    deps: Deps,
+    env: Env,
+    start_epoch: u64,
+    end_epoch: u64,
+) -> Result<Binary, VaultError> {
+    validate_epoch_range(start_epoch, end_epoch)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let epoch_config = EPOCH_CONFIG.may_load(deps.storage)?.unwrap_or_default();
+
+    let to_fees = |amount| {
+        vec![Asset {
+            info: config.asset_info.clone(),
+            amount,
+        }]
+    };
+
+    let mut epochs: Vec<EpochFees> = PROTOCOL_FEES_BY_EPOCH
+        .range(
+            deps.storage,
+            Some(Bound::inclusive(start_epoch)),
+            Some(Bound::inclusive(end_epoch)),
+            Order::Ascending,
+        )
+        .map(|item| {
+            let (epoch, protocol_fee) = item?;
+            Ok(EpochFees {
+                epoch,
+                protocol_fees: to_fees(protocol_fee),
+                burned_fees: vec![],
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    // burn fees may be accrued in epochs without protocol fees, i.e. with a zero protocol fee
+    for item in BURNED_FEES_BY_EPOCH.range(
+        deps.storage,
+        Some(Bound::inclusive(start_epoch)),
+        Some(Bound::inclusive(end_epoch)),
+        Order::Ascending,
+    ) {
+        let (epoch, burned_fee) = item?;
+        match epochs
+            .iter_mut()
+            .find(|epoch_fees| epoch_fees.epoch == epoch)
+        {
+            Some(epoch_fees) => epoch_fees.burned_fees = to_fees(burned_fee),
+            None => epochs.push(EpochFees {
+                epoch,
+                protocol_fees: vec![],
+                burned_fees: to_fees(burned_fee),
+            }),
+        }
+    }
+    // the tokens bought back with the burn fees are burned in place of the vault asset
+    for item in BOUGHT_BACK_FEES_BY_EPOCH.range(
+        deps.storage,
+        Some(Bound::inclusive(start_epoch)),
+        Some(Bound::inclusive(end_epoch)),
+        Order::Ascending,
+    ) {
+        let (epoch, bought_back_fees) = item?;
+        match epochs
+            .iter_mut()
+            .find(|epoch_fees| epoch_fees.epoch == epoch)
+        {
+            Some(epoch_fees) => epoch_fees.burned_fees.extend(bought_back_fees),
+            None => epochs.push(EpochFees {
+                epoch,
+                protocol_fees: vec![],
+                burned_fees: bought_back_fees,
+            }),
+        }
+    }
+    epochs.sort_by_key(|epoch_fees| epoch_fees.epoch);
+
+    Ok(to_binary(&ProtocolFeesByEpochResponse {
+        current_epoch: epoch_config.epoch(env.block.time),
+        epoch_config,
+        epochs,
+    })?)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{
+        from_binary,
+        testing::{mock_dependencies, mock_env},
+        Addr, Uint128,
+    };
+
+    use pool_network::asset::{Asset, AssetInfo};
+    use pool_network::burn_fee::BurnFeeTarget;
+    use pool_network::epoch::{EpochConfig, EpochFees, ProtocolFeesByEpochResponse};
+    use vault_network::vault::{Config, QueryMsg};
+
+    use crate::{
+        contract::query,
+        state::{BURNED_FEES_BY_EPOCH, CONFIG, EPOCH_CONFIG, PROTOCOL_FEES_BY_EPOCH},
+        tests::{get_fees, mock_creator},
+    };
+
+    #[test]
+    fn returns_fees_by_epoch() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let asset_info = AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        };
+
+        CONFIG
+            .save(
+                &mut deps.storage,
+                &Config {
+                    owner: mock_creator().sender,
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
+                    asset_info: asset_info.clone(),
+                    deposit_enabled: true,
+                    flash_loan_enabled: true,
+                    withdraw_enabled: true,
+                    fee_collector_addr: Addr::unchecked("fee_collector"),
+                    fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
+                },
+            )
+            .unwrap();
+
+        let epoch_config = EpochConfig {
+            epoch_length: 86_400,
+            genesis_time: env.block.time.minus_seconds(3 * 86_400),
+        };
+        EPOCH_CONFIG.save(&mut deps.storage, &epoch_config).unwrap();
+
+        PROTOCOL_FEES_BY_EPOCH
+            .save(&mut deps.storage, 0, &Uint128::new(1_000))
+            .unwrap();
+        PROTOCOL_FEES_BY_EPOCH
+            .save(&mut deps.storage, 2, &Uint128::new(2_000))
+            .unwrap();
+        BURNED_FEES_BY_EPOCH
+            .save(&mut deps.storage, 1, &Uint128::new(500))
+            .unwrap();
+        BURNED_FEES_BY_EPOCH
+            .save(&mut deps.storage, 2, &Uint128::new(700))
+            .unwrap();
+
+        let to_fees = |amount: u128| {
+            vec![Asset {
+                info: asset_info.clone(),
+                amount: Uint128::new(amount),
+            }]
+        };
+
+        let res: ProtocolFeesByEpochResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::ProtocolFeesByEpoch {
+                    start_epoch: 1,
+                    end_epoch: 3,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            ProtocolFeesByEpochResponse {
+                current_epoch: 3,
+                epoch_config,
+                epochs: vec![
+                    EpochFees {
+                        epoch: 1,
+                        protocol_fees: vec![],
+                        burned_fees: to_fees(500),
+                    },
+                    EpochFees {
+                        epoch: 2,
+                        protocol_fees: to_fees(2_000),
+                        burned_fees: to_fees(700),
+                    },
+                ],
+            }
+        );
+
+        // invalid range
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ProtocolFeesByEpoch {
+                start_epoch: 3,
+                end_epoch: 1,
+            },
+        )
+        .unwrap_err();
+    }
+}
@@ -1,42 +1,49 @@
 use cosmwasm_std::{to_binary, Binary, Decimal, Deps, Env, Uint128};
-use cw20::{BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
+use cw20::{BalanceResponse, Cw20QueryMsg};
 
 use pool_network::asset::AssetInfo;
+use pool_network::lp_token::query_lp_total_supply;
 
 use crate::error::VaultError;
-use crate::state::COLLECTED_PROTOCOL_FEES;
+use crate::state::get_held_fees;
 use crate::state::CONFIG;
+use crate::strategy::{get_strategy_value, ValuationPrice};
 
 pub fn get_share(deps: Deps, env: Env, amount: Uint128) -> Result<Binary, VaultError> {
     let config = CONFIG.load(deps.storage)?;
 
-    let lp_amount: TokenInfoResponse = deps
-        .querier
-        .query_wasm_smart(config.liquidity_token, &Cw20QueryMsg::TokenInfo {})?;
+    let lp_amount = query_lp_total_supply(&deps.querier, &config.liquidity_token)?;
 
-    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(deps.storage)?;
+    let held_fees = get_held_fees(deps.storage)?;
 
-    let balance = match config.asset_info {
+    let balance = match &config.asset_info {
         AssetInfo::NativeToken { denom } => {
             deps.querier
-                .query_balance(env.contract.address, denom)?
+                .query_balance(env.contract.address.clone(), denom)?
                 .amount
         }
         AssetInfo::Token { contract_addr } => {
             let balance: BalanceResponse = deps.querier.query_wasm_smart(
                 contract_addr,
                 &Cw20QueryMsg::Balance {
-                    address: env.contract.address.into_string(),
+                    address: env.contract.address.clone().into_string(),
                 },
             )?;
             balance.balance
         }
-    } // deduct protocol fees
-    .checked_sub(collected_protocol_fees.amount)?;
+    } // deduct protocol fees and burn fees to buy back
+    .checked_sub(held_fees)?
+    // add the value of the liquidity strategy position
+    .checked_add(get_strategy_value(
+        deps,
+        &env,
+        &config.asset_info,
+        ValuationPrice::Lowest,
+    )?)?;
 
     // lp_share = amount / lp_amount
     // asset_share = lp_share * balance
-    let asset_share = Decimal::from_ratio(amount, lp_amount.total_supply) * balance;
+    let asset_share = Decimal::from_ratio(amount, lp_amount) * balance;
     Ok(to_binary(&asset_share)?)
 }
 
@@ -44,6 +51,7 @@ pub fn get_share(deps: Deps, env: Env, amount: Uint128) -> Result<Binary, VaultE
 mod test {
     use cosmwasm_std::{coins, from_binary, testing::mock_env, Addr, Uint128};
     use pool_network::asset::{Asset, AssetInfo};
+    use pool_network::burn_fee::BurnFeeTarget;
 
     use vault_network::vault::Config;
 
@@ -80,7 +88,9 @@ mod test {
                 &mut deps.storage,
                 &Config {
                     owner: mock_creator().sender,
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::NativeToken {
                         denom: "uluna".to_string(),
                     },
@@ -89,6 +99,7 @@ mod test {
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -149,7 +160,9 @@ mod test {
                 &mut deps.storage,
                 &Config {
                     owner: mock_creator().sender,
-                    liquidity_token: Addr::unchecked("lp_token"),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "lp_token".to_string(),
+                    },
                     asset_info: AssetInfo::Token {
                         contract_addr: "vault_token".to_string(),
                     },
@@ -158,6 +171,7 @@ mod test {
                     withdraw_enabled: true,
                     fee_collector_addr: Addr::unchecked("fee_collector"),
                     fees: get_fees(),
+                    burn_fee_target: BurnFeeTarget::Burn,
                 },
             )
             .unwrap();
@@ -1,9 +1,15 @@
+mod get_available_liquidity;
 mod get_config;
+mod get_liquidity_strategy;
 mod get_payback_amount;
 mod get_protocol_fees;
+mod get_protocol_fees_by_epoch;
 mod get_share;
 
+pub use get_available_liquidity::get_available_liquidity;
 pub use get_config::get_config;
+pub use get_liquidity_strategy::get_liquidity_strategy;
 pub use get_payback_amount::get_payback_amount;
-pub use get_protocol_fees::get_fees;
+pub use get_protocol_fees::{get_bought_back_fees, get_buy_back_fees, get_fees};
+pub use get_protocol_fees_by_epoch::get_protocol_fees_by_epoch;
 pub use get_share::get_share;
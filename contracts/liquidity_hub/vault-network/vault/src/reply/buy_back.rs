@@ -0,0 +1,51 @@
+use cosmwasm_std::{DepsMut, Env, Reply, Response, StdError, StdResult};
+use pool_network::asset::Asset;
+use pool_network::burn_fee::{get_bought_back_amount, BurnFeeTarget};
+
+use crate::state::{store_bought_back_fee, CONFIG, EPOCH_CONFIG};
+
+/// Burns the tokens bought back with the burn fees of a flash-loan.
+pub fn buy_back(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
+    let asset_info = match CONFIG.load(deps.storage)?.burn_fee_target {
+        BurnFeeTarget::BuyBackAndBurn { asset_info, .. } => asset_info,
+        _ => {
+            return Err(StdError::GenericErr {
+                msg: "The vault is not buying back".to_string(),
+            })
+        }
+    };
+
+    let events = msg
+        .result
+        .into_result()
+        .map_err(|_| StdError::GenericErr {
+            msg: "Failed to get result of buy-back".to_string(),
+        })?
+        .events;
+    let bought_back_amount =
+        get_bought_back_amount(&events, env.contract.address.as_str(), &asset_info)?;
+
+    let mut response = Response::new().add_attributes(vec![
+        ("action", "reply_buy_back".to_string()),
+        ("burned_asset", asset_info.to_string()),
+        ("burned_amount", bought_back_amount.to_string()),
+    ]);
+
+    // the tokens bought back are the ones burned, hence accounted as such
+    if !bought_back_amount.is_zero() {
+        let burned_asset = Asset {
+            info: asset_info,
+            amount: bought_back_amount,
+        };
+
+        let epoch = EPOCH_CONFIG
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .epoch(env.block.time);
+        store_bought_back_fee(deps.storage, epoch, burned_asset.clone())?;
+
+        response = response.add_message(burned_asset.into_burn_msg()?);
+    }
+
+    Ok(response)
+}
@@ -1,4 +1,5 @@
 use cosmwasm_std::{DepsMut, Reply, Response, StdError, StdResult};
+use pool_network::asset::AssetInfo;
 use protobuf::Message;
 
 use crate::{response::MsgInstantiateContractResponse, state::CONFIG};
@@ -26,7 +27,9 @@ pub fn lp_instantiate(deps: DepsMut, msg: Reply) -> StdResult<Response> {
     let token_address = deps.api.addr_validate(&res.contract_address)?;
 
     CONFIG.update::<_, StdError>(deps.storage, |mut config| {
-        config.liquidity_token = token_address.clone();
+        config.liquidity_token = AssetInfo::Token {
+            contract_addr: token_address.to_string(),
+        };
 
         Ok(config)
     })?;
@@ -1,8 +1,9 @@
 use cosmwasm_std::{StdError, StdResult, Storage, Uint128};
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 
 use pool_network::asset::{Asset, AssetInfo};
-use vault_network::vault::Config;
+use pool_network::epoch::{add_epoch_fee, EpochConfig};
+use vault_network::vault::{Config, LiquidityStrategy};
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
@@ -14,9 +15,36 @@ pub const ALL_TIME_COLLECTED_PROTOCOL_FEES: Item<Asset> =
 // Fees that have been burned by the vault since the vault's inception
 pub const ALL_TIME_BURNED_FEES: Item<Asset> = Item::new("all_time_burned_fees");
 
+// Burn fees accrued by the vault, waiting to be bought back by the keeper of the buy-back burn fee
+// target
+pub const BUY_BACK_FEES: Item<Uint128> = Item::new("buy_back_fees");
+// Tokens bought back with the burn fees, and burned, since the vault's inception
+pub const ALL_TIME_BOUGHT_BACK_FEES: Item<Vec<Asset>> = Item::new("all_time_bought_back_fees");
+
+// The epochs the fees of the vault are bucketed by, the default epoch config if not set
+pub const EPOCH_CONFIG: Item<EpochConfig> = Item::new("epoch_config");
+// Protocol fees accrued by the vault, by epoch
+pub const PROTOCOL_FEES_BY_EPOCH: Map<u64, Uint128> = Map::new("protocol_fees_by_epoch");
+// Fees burned by the vault, by epoch
+pub const BURNED_FEES_BY_EPOCH: Map<u64, Uint128> = Map::new("burned_fees_by_epoch");
+// Tokens bought back with the burn fees, and burned, by the vault, by epoch
+pub const BOUGHT_BACK_FEES_BY_EPOCH: Map<u64, Vec<Asset>> = Map::new("bought_back_fees_by_epoch");
+
 // A counter for how many active loans are being performed
 pub const LOAN_COUNTER: Item<u32> = Item::new("loan_counter");
 
+// The liquidity strategy of the vault, not present if the strategy is disabled
+pub const LIQUIDITY_STRATEGY: Item<LiquidityStrategy> = Item::new("liquidity_strategy");
+
+/// Gets the fees held by the vault which are not part of its liquidity, i.e. the protocol fees not
+/// collected yet and the burn fees waiting to be bought back
+pub fn get_held_fees(storage: &dyn Storage) -> StdResult<Uint128> {
+    let collected_protocol_fees = COLLECTED_PROTOCOL_FEES.load(storage)?;
+    let buy_back_fees = BUY_BACK_FEES.may_load(storage)?.unwrap_or_default();
+
+    Ok(collected_protocol_fees.amount.checked_add(buy_back_fees)?)
+}
+
 /// Stores a fee in the given fees_storage_item
 pub fn store_fee(
     storage: &mut dyn Storage,
@@ -43,3 +71,42 @@ pub fn initialize_fee(
         },
     )
 }
+
+/// Stores a fee in the given epoch of the fees_by_epoch map
+pub fn store_epoch_fee(
+    storage: &mut dyn Storage,
+    epoch: u64,
+    fee: Uint128,
+    fees_by_epoch: Map<u64, Uint128>,
+) -> StdResult<()> {
+    if fee.is_zero() {
+        return Ok(());
+    }
+
+    fees_by_epoch.update(storage, epoch, |fees| -> StdResult<_> {
+        Ok(fees.unwrap_or_default().checked_add(fee)?)
+    })?;
+
+    Ok(())
+}
+
+/// Stores the tokens bought back with the burn fees, and burned, in the given epoch
+pub fn store_bought_back_fee(storage: &mut dyn Storage, epoch: u64, fee: Asset) -> StdResult<()> {
+    if fee.amount.is_zero() {
+        return Ok(());
+    }
+
+    let mut all_time_fees = ALL_TIME_BOUGHT_BACK_FEES
+        .may_load(storage)?
+        .unwrap_or_default();
+    add_epoch_fee(&mut all_time_fees, fee.clone())?;
+    ALL_TIME_BOUGHT_BACK_FEES.save(storage, &all_time_fees)?;
+
+    BOUGHT_BACK_FEES_BY_EPOCH.update(storage, epoch, |fees| -> StdResult<_> {
+        let mut fees = fees.unwrap_or_default();
+        add_epoch_fee(&mut fees, fee)?;
+        Ok(fees)
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,288 @@
+use cosmwasm_std::{
+    coins, to_binary, CosmosMsg, Decimal, Decimal256, Deps, Env, Fraction, Isqrt, StdResult,
+    Uint128, Uint256, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+
+use pool_network::asset::{Asset, AssetInfo};
+use pool_network::pair::{
+    Cw20HookMsg as PairCw20HookMsg, ExecuteMsg as PairExecuteMsg, PoolResponse,
+    QueryMsg as PairQueryMsg,
+};
+use pool_network::querier::{query_pair_info_from_pair, query_token_balance};
+use vault_network::vault::{CallbackMsg, ExecuteMsg, LiquidityStrategy};
+
+use crate::error::VaultError;
+use crate::state::LIQUIDITY_STRATEGY;
+
+/// Queries the amount of liquidity tokens and counter asset held by the vault.
+pub fn query_position(
+    deps: Deps,
+    env: &Env,
+    strategy: &LiquidityStrategy,
+) -> StdResult<(Uint128, Uint128)> {
+    let lp_amount = query_token_balance(
+        &deps.querier,
+        strategy.lp_token.clone(),
+        env.contract.address.clone(),
+    )?;
+    let counter_asset_amount =
+        strategy
+            .counter_asset
+            .query_pool(&deps.querier, deps.api, env.contract.address.clone())?;
+
+    Ok((lp_amount, counter_asset_amount))
+}
+
+/// Queries the price of the counter asset in vault asset on the strategy pair, i.e. the ratio of
+/// the pair reserves. Returns zero if the pair holds no counter asset.
+pub fn query_pair_price(
+    deps: Deps,
+    asset_info: &AssetInfo,
+    strategy: &LiquidityStrategy,
+) -> StdResult<Decimal> {
+    let pool: PoolResponse = deps
+        .querier
+        .query_wasm_smart(strategy.pair_addr.clone(), &PairQueryMsg::Pool {})?;
+
+    Ok(pool_price(&pool, asset_info))
+}
+
+/// Gets the price of the counter asset in vault asset from the reserves of the pool.
+fn pool_price(pool: &PoolResponse, asset_info: &AssetInfo) -> Decimal {
+    let (vault_asset_reserve, counter_asset_reserve) = if pool.assets[0].info.equal(asset_info) {
+        (pool.assets[0].amount, pool.assets[1].amount)
+    } else {
+        (pool.assets[1].amount, pool.assets[0].amount)
+    };
+
+    if counter_asset_reserve.is_zero() {
+        return Decimal::zero();
+    }
+
+    Decimal::from_ratio(vault_asset_reserve, counter_asset_reserve)
+}
+
+/// The price the liquidity strategy position is valued at. As the pair price can be moved within a
+/// transaction, the position is valued at the pair or reference price least favorable to whoever
+/// deposits or withdraws against it.
+pub enum ValuationPrice {
+    /// The lowest of the pair and reference prices, for withdrawals and queries
+    Lowest,
+    /// The highest of the pair and reference prices, for deposits
+    Highest,
+}
+
+/// Gets the value of the liquidity strategy position, denominated in the vault asset.
+///
+/// The position is made of the vault's share of the pair liquidity plus any counter asset held by
+/// the vault. The liquidity is valued from the constant product of the pair rather than from its
+/// reserves, which can be skewed within a transaction, i.e. at the reserves the constant product
+/// would have at the valuation price.
+pub fn get_position_value(
+    deps: Deps,
+    env: &Env,
+    asset_info: &AssetInfo,
+    strategy: &LiquidityStrategy,
+    valuation_price: ValuationPrice,
+) -> Result<Uint128, VaultError> {
+    let (lp_amount, counter_asset_amount) = query_position(deps, env, strategy)?;
+    if lp_amount.is_zero() && counter_asset_amount.is_zero() {
+        return Ok(Uint128::zero());
+    }
+
+    let pool: PoolResponse = deps
+        .querier
+        .query_wasm_smart(strategy.pair_addr.clone(), &PairQueryMsg::Pool {})?;
+
+    let pair_price = pool_price(&pool, asset_info);
+    let price = match valuation_price {
+        ValuationPrice::Lowest => pair_price.min(strategy.reference_price),
+        ValuationPrice::Highest => pair_price.max(strategy.reference_price),
+    };
+
+    // the liquidity of a constant product pair at price p is worth 2 * sqrt(k * p) vault asset
+    let mut vault_asset_amount = Uint128::zero();
+    if !lp_amount.is_zero() && !pool.total_share.is_zero() {
+        let constant_product =
+            Uint256::from(pool.assets[0].amount).checked_mul(pool.assets[1].amount.into())?;
+        let liquidity_value = (constant_product * Decimal256::from(price))
+            .isqrt()
+            .checked_mul(Uint256::from(2u8))?
+            .multiply_ratio(lp_amount, pool.total_share);
+        vault_asset_amount = liquidity_value.try_into()?;
+    }
+
+    vault_asset_amount = vault_asset_amount.checked_add(counter_asset_amount * price)?;
+
+    Ok(vault_asset_amount)
+}
+
+/// Gets the value of the liquidity strategy position, or zero if the strategy is disabled.
+pub fn get_strategy_value(
+    deps: Deps,
+    env: &Env,
+    asset_info: &AssetInfo,
+    valuation_price: ValuationPrice,
+) -> Result<Uint128, VaultError> {
+    match LIQUIDITY_STRATEGY.may_load(deps.storage)? {
+        Some(strategy) => get_position_value(deps, env, asset_info, &strategy, valuation_price),
+        None => Ok(Uint128::zero()),
+    }
+}
+
+/// Asserts the pair price is within the maximum deviation from the reference price of the
+/// strategy, before unwinding the position.
+pub fn assert_price_deviation(
+    deps: Deps,
+    asset_info: &AssetInfo,
+    strategy: &LiquidityStrategy,
+) -> Result<(), VaultError> {
+    let price = query_pair_price(deps, asset_info, strategy)?;
+    let deviation = if price > strategy.reference_price {
+        price - strategy.reference_price
+    } else {
+        strategy.reference_price - price
+    };
+
+    if price.is_zero() || deviation > strategy.reference_price * strategy.max_price_deviation {
+        return Err(VaultError::StrategyPriceDeviation {
+            price,
+            reference_price: strategy.reference_price,
+        });
+    }
+
+    Ok(())
+}
+
+/// Creates the messages withdrawing `lp_amount` of the liquidity strategy position from the pair,
+/// or the whole position if not provided, and swapping the counter asset back into the vault
+/// asset. Returns no messages if there is no position to unwind.
+pub fn unwind_position_msgs(
+    deps: Deps,
+    env: &Env,
+    strategy: &LiquidityStrategy,
+    lp_amount: Option<Uint128>,
+) -> StdResult<Vec<CosmosMsg>> {
+    let (position_lp_amount, counter_asset_amount) = query_position(deps, env, strategy)?;
+    let lp_amount = lp_amount.map_or(position_lp_amount, |lp_amount| {
+        lp_amount.min(position_lp_amount)
+    });
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if !lp_amount.is_zero() {
+        messages.push(
+            WasmMsg::Execute {
+                contract_addr: strategy.lp_token.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: strategy.pair_addr.to_string(),
+                    amount: lp_amount,
+                    msg: to_binary(&PairCw20HookMsg::WithdrawLiquidity {})?,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        );
+    }
+
+    if !lp_amount.is_zero() || !counter_asset_amount.is_zero() {
+        messages.push(callback_msg(env, CallbackMsg::SwapStrategyAsset {})?);
+    }
+
+    Ok(messages)
+}
+
+/// Creates the message swapping the `offer_asset` on the liquidity strategy pair. The swap is
+/// bounded by the reference price of the strategy, tolerating the maximum price deviation plus the
+/// maximum spread of the strategy.
+pub fn swap_msg(
+    deps: Deps,
+    asset_info: &AssetInfo,
+    strategy: &LiquidityStrategy,
+    offer_asset: Asset,
+) -> StdResult<CosmosMsg> {
+    let belief_price = belief_price(deps, asset_info, strategy, &offer_asset.info)?;
+    let max_spread = Some(strategy.max_price_deviation + strategy.max_spread.unwrap_or_default());
+
+    Ok(match offer_asset.info.clone() {
+        AssetInfo::NativeToken { denom } => WasmMsg::Execute {
+            contract_addr: strategy.pair_addr.to_string(),
+            msg: to_binary(&PairExecuteMsg::Swap {
+                offer_asset: offer_asset.clone(),
+                belief_price,
+                max_spread,
+                to: None,
+            })?,
+            funds: coins(offer_asset.amount.u128(), denom),
+        },
+        AssetInfo::Token { contract_addr } => WasmMsg::Execute {
+            contract_addr,
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                contract: strategy.pair_addr.to_string(),
+                amount: offer_asset.amount,
+                msg: to_binary(&PairCw20HookMsg::Swap {
+                    belief_price,
+                    max_spread,
+                    to: None,
+                })?,
+            })?,
+            funds: vec![],
+        },
+    }
+    .into())
+}
+
+/// Gets the price of the ask asset in `offer_asset_info` at the reference price of the strategy,
+/// scaled by the decimals of the assets the way the pair asserts the spread of swaps.
+fn belief_price(
+    deps: Deps,
+    asset_info: &AssetInfo,
+    strategy: &LiquidityStrategy,
+    offer_asset_info: &AssetInfo,
+) -> StdResult<Option<Decimal>> {
+    let price = if offer_asset_info.equal(asset_info) {
+        Some(strategy.reference_price)
+    } else {
+        strategy.reference_price.inv()
+    };
+    let Some(price) = price.filter(|price| !price.is_zero()) else {
+        return Ok(None);
+    };
+
+    let pair_info = query_pair_info_from_pair(&deps.querier, strategy.pair_addr.clone())?;
+    let (offer_decimals, ask_decimals) = if pair_info.asset_infos[0].equal(offer_asset_info) {
+        (pair_info.asset_decimals[0], pair_info.asset_decimals[1])
+    } else {
+        (pair_info.asset_decimals[1], pair_info.asset_decimals[0])
+    };
+
+    Ok(Some(price.checked_mul(Decimal::from_ratio(
+        10u128.pow(ask_decimals.into()),
+        10u128.pow(offer_decimals.into()),
+    ))?))
+}
+
+/// Creates a message calling the given callback on the vault.
+pub fn callback_msg(env: &Env, msg: CallbackMsg) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::Callback(msg))?,
+        funds: vec![],
+    }
+    .into())
+}
+
+/// Returns the asset of the pair that is not the vault asset, or `None` if the pair does not
+/// contain the vault asset.
+pub fn get_counter_asset(
+    asset_infos: &[AssetInfo; 2],
+    asset_info: &AssetInfo,
+) -> Option<AssetInfo> {
+    if asset_infos[0].equal(asset_info) {
+        Some(asset_infos[1].clone())
+    } else if asset_infos[1].equal(asset_info) {
+        Some(asset_infos[0].clone())
+    } else {
+        None
+    }
+}
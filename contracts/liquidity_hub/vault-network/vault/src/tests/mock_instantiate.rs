@@ -34,6 +34,8 @@ pub fn mock_instantiate(
             asset_info,
             vault_fees: get_fees(),
             fee_collector_addr: "fee_collector".to_string(),
+            lp_token_type: None,
+            lp_token_info: None,
         },
     )
     .unwrap();
@@ -68,6 +70,8 @@ pub fn app_mock_instantiate(app: &mut App, asset_info: AssetInfo) -> Addr {
             asset_info,
             fee_collector_addr: fee_collector_addr.into_string(),
             vault_fees: get_fees(),
+            lp_token_type: None,
+            lp_token_info: None,
         },
         &[],
         "vault",
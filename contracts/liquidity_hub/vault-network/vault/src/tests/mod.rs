@@ -5,6 +5,7 @@ mod mock_dependencies_lp;
 mod mock_execute;
 pub mod mock_instantiate;
 pub mod store_code;
+mod strategy;
 
 pub use get_fees::get_fees;
 pub use mock_creator::mock_creator;
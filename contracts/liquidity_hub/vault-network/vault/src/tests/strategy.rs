@@ -0,0 +1,363 @@
+use cosmwasm_std::{coin, coins, to_binary, Addr, Decimal, Empty, Uint128};
+use cw20::Cw20ExecuteMsg;
+use cw_multi_test::Executor;
+
+use liquidity_hub_testing::contracts::vault_contract;
+use liquidity_hub_testing::{native, PairBuilder, TestSuite};
+use pool_network::asset::{Asset, AssetInfo};
+use pool_network::pair::ExecuteMsg as PairExecuteMsg;
+use nico_teen::fee::{Fee, VaultFee};
+use vault_network::vault::{
+    Config, Cw20HookMsg, ExecuteMsg, InstantiateMsg, LiquidityStrategyParams,
+    LiquidityStrategyResponse, QueryMsg,
+};
+
+use crate::error::VaultError;
+
+/// The amount of each asset provided to the strategy pair
+const POOL_LIQUIDITY: u128 = 1_000_000_000u128;
+
+struct StrategySuite {
+    suite: TestSuite,
+    vault_addr: Addr,
+    pair_addr: Addr,
+    liquidity_token: AssetInfo,
+}
+
+/// Creates a uluna-uwhale pair and a uluna vault owned by the creator, with a liquidity strategy
+/// allocating half of the vault assets to the pair. alice deposits into the vault before the
+/// strategy is set.
+fn mock_suite() -> StrategySuite {
+    let funds = vec![
+        coin(10 * POOL_LIQUIDITY, "uluna"),
+        coin(10 * POOL_LIQUIDITY, "uwhale"),
+    ];
+    let mut suite = TestSuite::builder()
+        .with_funded_user("alice", funds.clone())
+        .with_funded_user("bob", funds)
+        .build();
+
+    let pair = suite.create_pair(
+        PairBuilder::new([native("uluna"), native("uwhale")])
+            .with_liquidity("alice", [Uint128::new(POOL_LIQUIDITY); 2]),
+    );
+
+    // the vault is instantiated by the creator to own it, as the vault factory doesn't manage the
+    // liquidity strategies
+    let code_id = suite.app.store_code(vault_contract());
+    let vault_addr = suite
+        .app
+        .instantiate_contract(
+            code_id,
+            suite.creator.clone(),
+            &InstantiateMsg {
+                owner: suite.creator.to_string(),
+                asset_info: native("uluna"),
+                token_id: suite.token_code_id,
+                vault_fees: VaultFee {
+                    protocol_fee: Fee {
+                        share: Decimal::zero(),
+                    },
+                    flash_loan_fee: Fee {
+                        share: Decimal::zero(),
+                    },
+                    burn_fee: Fee {
+                        share: Decimal::zero(),
+                    },
+                },
+                fee_collector_addr: suite.fee_collector_addr.to_string(),
+                lp_token_type: None,
+                lp_token_info: None,
+            },
+            &[],
+            "vault",
+            None,
+        )
+        .unwrap();
+    let config: Config = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&vault_addr, &QueryMsg::Config {})
+        .unwrap();
+
+    suite.deposit(
+        "alice",
+        &vault_addr,
+        &native("uluna"),
+        Uint128::new(1_000_000),
+    );
+
+    let mut suite = StrategySuite {
+        suite,
+        vault_addr,
+        pair_addr: Addr::unchecked(pair.contract_addr),
+        liquidity_token: config.liquidity_token,
+    };
+
+    let creator = suite.suite.creator.to_string();
+    suite
+        .execute(
+            &creator,
+            &ExecuteMsg::UpdateLiquidityStrategy {
+                strategy: Some(LiquidityStrategyParams {
+                    pair_addr: suite.pair_addr.to_string(),
+                    allocation: Decimal::percent(50),
+                    max_spread: Some(Decimal::percent(1)),
+                    max_price_deviation: Decimal::percent(5),
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+
+    suite
+}
+
+impl StrategySuite {
+    fn execute(
+        &mut self,
+        sender: &str,
+        msg: &ExecuteMsg,
+        funds: &[cosmwasm_std::Coin],
+    ) -> Result<(), String> {
+        self.suite
+            .app
+            .execute_contract(Addr::unchecked(sender), self.vault_addr.clone(), msg, funds)
+            .map(|_| ())
+            .map_err(|err| err.root_cause().to_string())
+    }
+
+    fn deposit(&mut self, depositor: &str, amount: u128) -> Result<(), String> {
+        self.execute(
+            depositor,
+            &ExecuteMsg::Deposit {
+                amount: Uint128::new(amount),
+                receiver: None,
+            },
+            &coins(amount, "uluna"),
+        )
+    }
+
+    fn withdraw(&mut self, owner: &str, amount: Uint128) -> Result<(), String> {
+        let AssetInfo::Token { contract_addr } = self.liquidity_token.clone() else {
+            panic!("the vault should issue cw20 LP tokens");
+        };
+
+        self.suite
+            .app
+            .execute_contract(
+                Addr::unchecked(owner),
+                Addr::unchecked(contract_addr),
+                &Cw20ExecuteMsg::Send {
+                    contract: self.vault_addr.to_string(),
+                    amount,
+                    msg: to_binary(&Cw20HookMsg::Withdraw {}).unwrap(),
+                },
+                &[],
+            )
+            .map(|_| ())
+            .map_err(|err| err.root_cause().to_string())
+    }
+
+    fn rebalance(&mut self) {
+        let creator = self.suite.creator.to_string();
+        self.execute(&creator, &ExecuteMsg::Rebalance {}, &[])
+            .unwrap();
+    }
+
+    fn lp_balance(&self, owner: &str) -> Uint128 {
+        self.suite.query_balance(owner, &self.liquidity_token)
+    }
+
+    fn uluna_balance(&self, owner: &str) -> Uint128 {
+        self.suite.query_balance(owner, &native("uluna"))
+    }
+
+    fn query_strategy(&self) -> LiquidityStrategyResponse {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.vault_addr, &QueryMsg::LiquidityStrategy {})
+            .unwrap()
+    }
+
+    fn query_available_liquidity(&self) -> Uint128 {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.vault_addr, &QueryMsg::AvailableLiquidity {})
+            .unwrap()
+    }
+
+    fn query_share(&self, amount: Uint128) -> Uint128 {
+        self.suite
+            .app
+            .wrap()
+            .query_wasm_smart(&self.vault_addr, &QueryMsg::Share { amount })
+            .unwrap()
+    }
+
+    /// Swaps `amount` uwhale for uluna in the strategy pair, lowering the uwhale price
+    fn buy_uluna(&mut self, amount: u128) {
+        self.suite
+            .app
+            .execute_contract(
+                Addr::unchecked("alice"),
+                self.pair_addr.clone(),
+                &PairExecuteMsg::Swap {
+                    offer_asset: Asset {
+                        info: native("uwhale"),
+                        amount: Uint128::new(amount),
+                    },
+                    belief_price: None,
+                    max_spread: None,
+                    to: None,
+                },
+                &coins(amount, "uwhale"),
+            )
+            .unwrap();
+    }
+}
+
+#[test]
+fn only_the_owner_can_rebalance() {
+    let mut suite = mock_suite();
+
+    let err = suite
+        .execute("alice", &ExecuteMsg::Rebalance {}, &[])
+        .unwrap_err();
+    assert_eq!(err, VaultError::Unauthorized {}.to_string());
+
+    suite.rebalance();
+
+    // half of the vault assets are provided to the pair, minus the spread of the swap
+    let strategy = suite.query_strategy();
+    assert!(strategy.position_value > Uint128::new(499_000));
+    assert!(strategy.position_value <= Uint128::new(500_000));
+    assert_eq!(strategy.strategy.unwrap().reference_price, Decimal::one());
+}
+
+#[test]
+fn deposit_and_withdraw_with_an_active_strategy() {
+    let mut suite = mock_suite();
+    suite.rebalance();
+
+    let alice_lp = suite.lp_balance("alice");
+    let alice_share = suite.query_share(alice_lp);
+
+    // bob's share is priced with the value of the strategy position, at the price most favorable
+    // to the vault, which the rebalancing swap moved slightly above the reference price
+    suite.deposit("bob", 1_000_000).unwrap();
+    let bob_lp = suite.lp_balance("bob");
+    assert!(bob_lp <= alice_lp.multiply_ratio(1_000_000u128, alice_share));
+    let bob_share = suite.query_share(bob_lp);
+    assert!(bob_share <= Uint128::new(1_000_000));
+    assert!(Uint128::new(1_000_000) - bob_share <= Uint128::new(1_000));
+    assert!(suite.query_share(alice_lp) >= alice_share);
+
+    // bob's withdrawal is covered by the idle assets of the vault
+    let bob_balance = suite.uluna_balance("bob");
+    suite.withdraw("bob", bob_lp).unwrap();
+    assert_eq!(suite.uluna_balance("bob"), bob_balance + bob_share);
+    assert!(!suite.query_strategy().position_value.is_zero());
+
+    // alice's withdrawal unwinds the strategy position first
+    let alice_balance = suite.uluna_balance("alice");
+    suite.withdraw("alice", alice_lp).unwrap();
+    assert_eq!(suite.query_strategy().position_value, Uint128::zero());
+    assert_eq!(
+        suite.uluna_balance(suite.vault_addr.as_str()),
+        Uint128::zero()
+    );
+    assert!(suite.uluna_balance("alice") > alice_balance + Uint128::new(998_000));
+    assert_eq!(suite.lp_balance("alice"), Uint128::zero());
+}
+
+#[test]
+fn withdrawals_only_unwind_the_missing_part_of_the_position() {
+    let mut suite = mock_suite();
+    suite.rebalance();
+
+    // half of the vault assets are idle, the withdrawal of three quarters of them unwinds half of
+    // the position
+    let alice_lp = suite.lp_balance("alice");
+    let alice_balance = suite.uluna_balance("alice");
+    suite
+        .withdraw("alice", alice_lp.multiply_ratio(3u128, 4u128))
+        .unwrap();
+
+    let position_value = suite.query_strategy().position_value;
+    assert!(position_value > Uint128::new(245_000));
+    assert!(position_value < Uint128::new(255_000));
+
+    // the withdrawal bears the cost of unwinding the position
+    let withdrawn = suite.uluna_balance("alice") - alice_balance;
+    assert!(withdrawn > Uint128::new(745_000));
+    assert!(withdrawn <= Uint128::new(750_000));
+    assert!(suite.query_available_liquidity() < Uint128::new(100));
+}
+
+#[test]
+fn flash_loans_are_limited_to_the_idle_assets() {
+    let mut suite = mock_suite();
+    suite.rebalance();
+
+    // the strategy position is not unwound to flash-loan more than the idle assets
+    let available = suite.query_available_liquidity();
+    let err = suite
+        .execute(
+            "alice",
+            &ExecuteMsg::FlashLoan {
+                amount: available + Uint128::one(),
+                msg: to_binary(&Empty {}).unwrap(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        VaultError::InsufficientLiquidity {
+            available,
+            requested: available + Uint128::one(),
+        }
+        .to_string()
+    );
+    assert!(!suite.query_strategy().position_value.is_zero());
+}
+
+#[test]
+fn the_position_is_valued_conservatively_once_the_pair_price_deviates() {
+    let mut suite = mock_suite();
+    suite.rebalance();
+    let position_value = suite.query_strategy().position_value;
+
+    // moving the uwhale price down by about 20% lowers the value of the position
+    suite.buy_uluna(POOL_LIQUIDITY / 10);
+    assert!(suite.query_strategy().position_value < position_value * Decimal::percent(95));
+
+    // withdrawals covered by the idle assets go through, the position is not unwound at the
+    // deviating price
+    suite
+        .withdraw(
+            "alice",
+            suite.lp_balance("alice").multiply_ratio(1u128, 10u128),
+        )
+        .unwrap();
+    let err = suite.withdraw("alice", suite.lp_balance("alice"));
+    assert!(err
+        .unwrap_err()
+        .starts_with("The liquidity strategy pair price"));
+
+    // bob's deposit is still priced at the reference price, without diluting alice
+    let alice_lp = suite.lp_balance("alice");
+    let alice_share = suite.query_share(alice_lp);
+    suite.deposit("bob", 1_000_000).unwrap();
+    assert!(suite.query_share(suite.lp_balance("bob")) < Uint128::new(1_000_000));
+    assert!(suite.query_share(alice_lp) > alice_share);
+
+    // the owner rebalancing sets the reference price to the new price
+    suite.rebalance();
+    let reference_price = suite.query_strategy().strategy.unwrap().reference_price;
+    assert!(reference_price < Decimal::percent(85));
+    suite.withdraw("alice", alice_lp).unwrap();
+}
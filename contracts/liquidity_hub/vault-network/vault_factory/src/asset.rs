@@ -13,3 +13,28 @@ impl AssetReference for AssetInfo {
         }
     }
 }
+
+/// Separates the asset reference from the label in the key of a labeled vault. Asset references
+/// never contain a null byte, so the keys can't collide.
+const VAULT_LABEL_SEPARATOR: u8 = 0;
+
+/// Gets the key a vault is stored under, given the asset it manages and its label.
+///
+/// The default vault of an asset, i.e. the one without a label, is stored under the asset
+/// reference, while labeled vaults are stored under the asset reference followed by the label.
+pub fn get_vault_key(asset_info: &AssetInfo, label: Option<&str>) -> Vec<u8> {
+    let mut key = asset_info.get_reference().to_vec();
+
+    if let Some(label) = label {
+        key.push(VAULT_LABEL_SEPARATOR);
+        key.extend_from_slice(label.as_bytes());
+    }
+
+    key
+}
+
+/// Gets the label of a vault from its key, given the asset it manages.
+pub fn get_vault_label(asset_info: &AssetInfo, key: &[u8]) -> Option<String> {
+    key.get(asset_info.get_reference().len() + 1..)
+        .map(|label| String::from_utf8_lossy(label).to_string())
+}
@@ -7,9 +7,13 @@ use vault_network::vault_factory::{Config, ExecuteMsg, InstantiateMsg, MigrateMs
 use crate::err::{StdResult, VaultFactoryError};
 use crate::execute::{
     create_vault, migrate_vaults, remove_vault, update_config, update_vault_config,
+    update_vault_lp_token_info,
 };
 use crate::migrations;
-use crate::queries::{get_config, get_vault, get_vaults};
+use crate::queries::{
+    get_all_available_liquidity, get_asset_vaults, get_children_versions, get_config,
+    get_migration_status, get_vault, get_vaults,
+};
 use crate::state::CONFIG;
 
 const CONTRACT_NAME: &str = "nico_teen-vault_factory";
@@ -44,15 +48,35 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
     }
 
     match msg {
-        ExecuteMsg::CreateVault { asset_info, fees } => create_vault(deps, env, asset_info, fees),
+        ExecuteMsg::CreateVault {
+            asset_info,
+            fees,
+            label,
+            lp_token_type,
+            lp_token_info,
+        } => create_vault(
+            deps,
+            env,
+            asset_info,
+            fees,
+            label,
+            lp_token_type,
+            lp_token_info,
+        ),
         ExecuteMsg::UpdateVaultConfig { vault_addr, params } => {
             update_vault_config(deps, vault_addr, params)
         }
+        ExecuteMsg::UpdateVaultLpTokenInfo {
+            vault_addr,
+            lp_token_info,
+        } => update_vault_lp_token_info(deps, vault_addr, lp_token_info),
         ExecuteMsg::MigrateVaults {
             vault_addr,
             vault_code_id,
-        } => migrate_vaults(deps, vault_addr, vault_code_id),
-        ExecuteMsg::RemoveVault { asset_info } => remove_vault(deps, asset_info),
+            start_after,
+            limit,
+        } => migrate_vaults(deps, vault_addr, vault_code_id, start_after, limit),
+        ExecuteMsg::RemoveVault { asset_info, label } => remove_vault(deps, asset_info, label),
         ExecuteMsg::UpdateConfig {
             owner,
             fee_collector_addr,
@@ -111,7 +135,17 @@ mod test {
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => get_config(deps),
-        QueryMsg::Vault { asset_info } => get_vault(deps, asset_info),
+        QueryMsg::Vault { asset_info, label } => get_vault(deps, asset_info, label),
+        QueryMsg::AssetVaults { asset_info } => get_asset_vaults(deps, asset_info),
         QueryMsg::Vaults { start_after, limit } => get_vaults(deps, start_after, limit),
+        QueryMsg::AllAvailableLiquidity { start_after, limit } => {
+            get_all_available_liquidity(deps, start_after, limit)
+        }
+        QueryMsg::MigrationStatus { start_after, limit } => {
+            get_migration_status(deps, start_after, limit)
+        }
+        QueryMsg::ChildrenVersions { start_after, limit } => {
+            get_children_versions(deps, start_after, limit)
+        }
     }
 }
@@ -26,6 +26,9 @@ pub enum VaultFactoryError {
 
     #[error("Vault doesn't exist given the vault address provided")]
     NonExistentVault {},
+
+    #[error("Invalid vault label \"{label}\"")]
+    InvalidVaultLabel { label: String },
 }
 
 impl From<semver::Error> for VaultFactoryError {
@@ -1,25 +1,46 @@
 use cosmwasm_std::{to_binary, DepsMut, Env, ReplyOn, Response, SubMsg, WasmMsg};
 use pool_network::asset::AssetInfo;
+use pool_network::lp_token::LpTokenType;
+use pool_network::token::LpTokenInfo;
 use vault_network::{vault::InstantiateMsg, vault_factory::INSTANTIATE_VAULT_REPLY_ID};
 use nico_teen::fee::VaultFee;
 
 use crate::{
-    asset::AssetReference,
+    asset::get_vault_key,
     err::{StdResult, VaultFactoryError},
     state::{CONFIG, TMP_VAULT_ASSET, VAULTS},
 };
 
+/// The maximum length of a vault label
+const MAX_LABEL_LENGTH: usize = 32;
+
 pub fn create_vault(
     deps: DepsMut,
     env: Env,
     asset_info: AssetInfo,
     fees: VaultFee,
+    label: Option<String>,
+    lp_token_type: Option<LpTokenType>,
+    lp_token_info: Option<LpTokenInfo>,
 ) -> StdResult<Response> {
     // check that owner is creating vault
     let config = CONFIG.load(deps.storage)?;
 
+    // check the label is valid
+    if let Some(label) = &label {
+        if label.is_empty()
+            || label.len() > MAX_LABEL_LENGTH
+            || !label.chars().all(|c| c.is_ascii_graphic())
+        {
+            return Err(VaultFactoryError::InvalidVaultLabel {
+                label: label.clone(),
+            });
+        }
+    }
+
     // check that existing vault does not exist
-    let existing_addr = VAULTS.may_load(deps.storage, asset_info.get_reference())?;
+    let vault_key = get_vault_key(&asset_info, label.as_deref());
+    let existing_addr = VAULTS.may_load(deps.storage, &vault_key)?;
     if let Some((addr, _)) = existing_addr {
         return Err(VaultFactoryError::ExistingVault { addr });
     }
@@ -28,6 +49,12 @@ pub fn create_vault(
     fees.flash_loan_fee.is_valid()?;
     fees.protocol_fee.is_valid()?;
 
+    let asset_label = asset_info.clone().get_label(&deps.as_ref())?;
+    let vault_label = match &label {
+        Some(label) => format!("White Whale {} {} Vault", asset_label, label),
+        None => format!("White Whale {} Vault", asset_label),
+    };
+
     // create a new vault
     let vault_instantiate_msg: SubMsg = SubMsg {
         id: INSTANTIATE_VAULT_REPLY_ID,
@@ -40,12 +67,11 @@ pub fn create_vault(
                 token_id: config.token_id,
                 fee_collector_addr: config.fee_collector_addr.into_string(),
                 vault_fees: fees,
+                lp_token_type,
+                lp_token_info,
             })?,
             funds: vec![],
-            label: format!(
-                "White Whale {} Vault",
-                asset_info.clone().get_label(&deps.as_ref())?
-            ),
+            label: vault_label,
         }
         .into(),
         gas_limit: None,
@@ -53,10 +79,7 @@ pub fn create_vault(
     };
 
     // store asset for use in reply callback
-    TMP_VAULT_ASSET.save(
-        deps.storage,
-        &(asset_info.get_reference().to_vec(), asset_info),
-    )?;
+    TMP_VAULT_ASSET.save(deps.storage, &(vault_key, asset_info))?;
 
     Ok(Response::new()
         .add_submessage(vault_instantiate_msg)
@@ -95,6 +118,9 @@ mod tests {
             vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
                 fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
         );
 
@@ -114,7 +140,9 @@ mod tests {
                             asset_info,
                             token_id: 6,
                             vault_fees: get_fees(),
-                            fee_collector_addr: "fee_collector".to_string()
+                            fee_collector_addr: "fee_collector".to_string(),
+                            lp_token_type: None,
+                            lp_token_info: None,
                         })
                         .unwrap(),
                         funds: vec![],
@@ -143,6 +171,9 @@ mod tests {
             vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info,
                 fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
         );
 
@@ -168,6 +199,9 @@ mod tests {
             &vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
                 fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
             &[],
         )
@@ -180,6 +214,7 @@ mod tests {
                 factory_addr.clone(),
                 &vault_network::vault_factory::QueryMsg::Vault {
                     asset_info: asset_info.clone(),
+                    label: None,
                 },
             )
             .unwrap();
@@ -191,6 +226,9 @@ mod tests {
             &vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info,
                 fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
             &[],
         );
@@ -206,6 +244,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn can_create_labeled_vaults_for_same_asset() {
+        let mut app = mock_app();
+
+        let factory_addr = app_mock_instantiate(&mut app);
+
+        let asset_info = AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        };
+
+        let creator = mock_creator();
+
+        // create the default vault and a labeled one for the same asset
+        for label in [None, Some("low-fee".to_string())] {
+            app.execute_contract(
+                creator.sender.clone(),
+                factory_addr.clone(),
+                &vault_network::vault_factory::ExecuteMsg::CreateVault {
+                    asset_info: asset_info.clone(),
+                    fees: get_fees(),
+                    label,
+                    lp_token_type: None,
+                    lp_token_info: None,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        let default_vault: Option<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr.clone(),
+                &vault_network::vault_factory::QueryMsg::Vault {
+                    asset_info: asset_info.clone(),
+                    label: None,
+                },
+            )
+            .unwrap();
+        let labeled_vault: Option<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr,
+                &vault_network::vault_factory::QueryMsg::Vault {
+                    asset_info,
+                    label: Some("low-fee".to_string()),
+                },
+            )
+            .unwrap();
+
+        assert!(default_vault.is_some());
+        assert!(labeled_vault.is_some());
+        assert_ne!(default_vault, labeled_vault);
+    }
+
+    #[test]
+    fn does_error_if_invalid_label() {
+        for label in ["", "has space", "a-label-that-is-way-too-long-to-be-valid"] {
+            let (res, ..) = mock_execute(
+                5,
+                6,
+                vault_network::vault_factory::ExecuteMsg::CreateVault {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                    fees: get_fees(),
+                    label: Some(label.to_string()),
+                    lp_token_type: None,
+                    lp_token_info: None,
+                },
+            );
+
+            assert_eq!(
+                res.unwrap_err(),
+                VaultFactoryError::InvalidVaultLabel {
+                    label: label.to_string()
+                }
+            );
+        }
+    }
+
     #[test]
     fn does_error_if_invalid_fee() {
         let (mut deps, env) = mock_instantiate(1, 2);
@@ -230,6 +349,9 @@ mod tests {
                         share: Decimal::zero(),
                     },
                 },
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
         );
         assert_eq!(
@@ -259,6 +381,9 @@ mod tests {
                         share: Decimal::zero(),
                     },
                 },
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
         );
         assert_eq!(
@@ -283,6 +408,9 @@ mod tests {
             vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
                 fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
         );
 
@@ -302,7 +430,9 @@ mod tests {
                             asset_info,
                             token_id: 6,
                             vault_fees: get_fees(),
-                            fee_collector_addr: "fee_collector".to_string()
+                            fee_collector_addr: "fee_collector".to_string(),
+                            lp_token_type: None,
+                            lp_token_info: None,
                         })
                         .unwrap(),
                         funds: vec![],
@@ -327,6 +457,9 @@ mod tests {
             vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
                 fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
         );
 
@@ -346,7 +479,9 @@ mod tests {
                             asset_info,
                             token_id: 6,
                             vault_fees: get_fees(),
-                            fee_collector_addr: "fee_collector".to_string()
+                            fee_collector_addr: "fee_collector".to_string(),
+                            lp_token_type: None,
+                            lp_token_info: None,
                         })
                         .unwrap(),
                         funds: vec![],
@@ -3,37 +3,68 @@ use cosmwasm_std::{to_binary, Addr, CosmosMsg, DepsMut, Response, WasmMsg};
 use vault_network::vault::MigrateMsg;
 
 use crate::err::StdResult;
-use crate::state::read_vaults;
+use crate::state::{read_vaults, MIGRATION_CURSOR};
 
 pub fn migrate_vaults(
     deps: DepsMut,
     vault_addr: Option<String>,
     vault_code_id: u64,
+    start_after: Option<Vec<u8>>,
+    limit: Option<u32>,
 ) -> StdResult<Response> {
-    // migrate only the provided vault address, otherwise migrate all vaults
+    // migrate only the provided vault address, otherwise migrate a batch of vaults
     let mut res = Response::new().add_attributes(vec![
         ("method", "migrate_vaults".to_string()),
         ("code_id", vault_code_id.to_string()),
     ]);
     if let Some(vault_addr) = vault_addr {
-        Ok(res
+        return Ok(res
             .add_attribute("vault", vault_addr.clone())
             .add_message(migrate_vault_msg(
                 deps.api.addr_validate(vault_addr.as_str())?,
                 vault_code_id,
-            )?))
-    } else {
-        let vaults = read_vaults(deps.storage, deps.api, None, Some(30u32))?;
-        for vault in vaults {
-            res = res
-                .add_attribute("vault", &vault.clone().vault)
-                .add_message(migrate_vault_msg(
-                    deps.api.addr_validate(vault.vault.as_str())?,
-                    vault_code_id,
-                )?)
+            )?));
+    }
+
+    // resume the migration to the same code id if no start_after is provided
+    let start_after = match start_after {
+        Some(start_after) => Some(start_after),
+        None => MIGRATION_CURSOR
+            .may_load(deps.storage)?
+            .filter(|(code_id, _)| *code_id == vault_code_id)
+            .map(|(_, last_migrated)| last_migrated),
+    };
+
+    let vaults = read_vaults(deps.storage, deps.api, start_after, limit)?;
+    for vault in &vaults {
+        res = res
+            .add_attribute("vault", &vault.vault)
+            .add_message(migrate_vault_msg(
+                deps.api.addr_validate(vault.vault.as_str())?,
+                vault_code_id,
+            )?)
+    }
+
+    // store where the migration stopped so it can be resumed, or clear it if all vaults are migrated
+    let last_migrated = vaults
+        .last()
+        .map(|vault| vault.asset_info_reference.clone());
+    let remaining_vaults = match &last_migrated {
+        Some(last_migrated) => {
+            read_vaults(deps.storage, deps.api, Some(last_migrated.clone()), Some(1))?
         }
+        None => vec![],
+    };
 
-        Ok(res)
+    match last_migrated {
+        Some(last_migrated) if !remaining_vaults.is_empty() => {
+            MIGRATION_CURSOR.save(deps.storage, &(vault_code_id, last_migrated))?;
+            Ok(res.add_attribute("migration_complete", "false"))
+        }
+        _ => {
+            MIGRATION_CURSOR.remove(deps.storage);
+            Ok(res.add_attribute("migration_complete", "true"))
+        }
     }
 }
 
@@ -48,14 +79,18 @@ fn migrate_vault_msg(vault_addr: Addr, code_id: u64) -> StdResult<CosmosMsg> {
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{testing::mock_info, Attribute};
+    use cosmwasm_std::{testing::mock_info, Addr, Attribute};
+    use pool_network::asset::AssetInfo;
 
     use crate::{
         contract::execute,
         err::VaultFactoryError,
+        state::{MIGRATION_CURSOR, VAULTS},
         tests::{mock_creator, mock_instantiate::mock_instantiate},
     };
 
+    use super::migrate_vault_msg;
+
     #[test]
     fn cannot_migrate_vault_unauthorized() {
         let (mut deps, env) = mock_instantiate(5, 6);
@@ -70,6 +105,8 @@ mod tests {
             vault_network::vault_factory::ExecuteMsg::MigrateVaults {
                 vault_addr: None,
                 vault_code_id: 7,
+                start_after: None,
+                limit: None,
             },
         );
 
@@ -90,6 +127,8 @@ mod tests {
             vault_network::vault_factory::ExecuteMsg::MigrateVaults {
                 vault_addr: Some("outdated_vault".to_string()),
                 vault_code_id: 7,
+                start_after: None,
+                limit: None,
             },
         )
         .unwrap();
@@ -111,4 +150,79 @@ mod tests {
 
         assert_eq!(res.attributes, expected_attributes);
     }
+
+    #[test]
+    fn can_migrate_vaults_in_batches() {
+        let (mut deps, env) = mock_instantiate(5, 6);
+
+        for denom in ["uatom", "uluna", "uwhale"] {
+            VAULTS
+                .save(
+                    &mut deps.storage,
+                    denom.as_bytes(),
+                    &(
+                        Addr::unchecked(format!("{}_vault", denom)),
+                        AssetInfo::NativeToken {
+                            denom: denom.to_string(),
+                        },
+                    ),
+                )
+                .unwrap();
+        }
+
+        // migrate the first batch
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            vault_network::vault_factory::ExecuteMsg::MigrateVaults {
+                vault_addr: None,
+                vault_code_id: 7,
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|msg| msg.msg)
+                .collect::<Vec<_>>(),
+            vec![
+                migrate_vault_msg(Addr::unchecked("uatom_vault"), 7).unwrap(),
+                migrate_vault_msg(Addr::unchecked("uluna_vault"), 7).unwrap(),
+            ]
+        );
+        assert_eq!(
+            MIGRATION_CURSOR.load(&deps.storage).unwrap(),
+            (7, b"uluna".to_vec())
+        );
+
+        // resume the migration
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            vault_network::vault_factory::ExecuteMsg::MigrateVaults {
+                vault_addr: None,
+                vault_code_id: 7,
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|msg| msg.msg)
+                .collect::<Vec<_>>(),
+            vec![migrate_vault_msg(Addr::unchecked("uwhale_vault"), 7).unwrap()]
+        );
+        assert!(res
+            .attributes
+            .contains(&Attribute::new("migration_complete", "true")));
+        assert_eq!(MIGRATION_CURSOR.may_load(&deps.storage).unwrap(), None);
+    }
 }
@@ -3,9 +3,11 @@ mod migrate_vaults;
 mod remove_vault;
 mod update_config;
 mod update_vault_config;
+mod update_vault_lp_token_info;
 
 pub use create_vault::create_vault;
 pub use migrate_vaults::migrate_vaults;
 pub use remove_vault::remove_vault;
 pub use update_config::update_config;
 pub use update_vault_config::update_vault_config;
+pub use update_vault_lp_token_info::update_vault_lp_token_info;
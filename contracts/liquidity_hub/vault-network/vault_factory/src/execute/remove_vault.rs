@@ -2,16 +2,21 @@ use cosmwasm_std::{DepsMut, Response};
 
 use pool_network::asset::AssetInfo;
 
-use crate::asset::AssetReference;
+use crate::asset::get_vault_key;
 use crate::err::{StdResult, VaultFactoryError};
 use crate::state::VAULTS;
 
-pub fn remove_vault(deps: DepsMut, asset_info: AssetInfo) -> StdResult<Response> {
-    if let Ok(None) = VAULTS.may_load(deps.storage, asset_info.get_reference()) {
+pub fn remove_vault(
+    deps: DepsMut,
+    asset_info: AssetInfo,
+    label: Option<String>,
+) -> StdResult<Response> {
+    let vault_key = get_vault_key(&asset_info, label.as_deref());
+    if let Ok(None) = VAULTS.may_load(deps.storage, &vault_key) {
         return Err(VaultFactoryError::NonExistentVault {});
     }
 
-    VAULTS.remove(deps.storage, asset_info.get_reference());
+    VAULTS.remove(deps.storage, &vault_key);
 
     Ok(Response::new().add_attributes(vec![("method", "remove_vault")]))
 }
@@ -48,6 +53,9 @@ mod tests {
             &vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info_1.clone(),
                 fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
             &[],
         )
@@ -60,6 +68,7 @@ mod tests {
                 factory_addr.clone(),
                 &vault_network::vault_factory::ExecuteMsg::RemoveVault {
                     asset_info: asset_info_1,
+                    label: None,
                 },
                 &[],
             )
@@ -99,7 +108,10 @@ mod tests {
             deps.as_mut(),
             env,
             bad_actor,
-            vault_network::vault_factory::ExecuteMsg::RemoveVault { asset_info },
+            vault_network::vault_factory::ExecuteMsg::RemoveVault {
+                asset_info,
+                label: None,
+            },
         );
 
         assert_eq!(res.unwrap_err(), VaultFactoryError::Unauthorized {})
@@ -118,7 +130,10 @@ mod tests {
             deps.as_mut(),
             env,
             creator,
-            vault_network::vault_factory::ExecuteMsg::RemoveVault { asset_info },
+            vault_network::vault_factory::ExecuteMsg::RemoveVault {
+                asset_info,
+                label: None,
+            },
         );
 
         assert_eq!(res.unwrap_err(), VaultFactoryError::NonExistentVault {})
@@ -47,6 +47,9 @@ mod tests {
             &vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
                 fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
             &[],
         )
@@ -57,7 +60,10 @@ mod tests {
             .wrap()
             .query_wasm_smart(
                 factory_addr.clone(),
-                &vault_network::vault_factory::QueryMsg::Vault { asset_info },
+                &vault_network::vault_factory::QueryMsg::Vault {
+                    asset_info,
+                    label: None,
+                },
             )
             .unwrap();
 
@@ -89,6 +95,8 @@ mod tests {
                     new_owner: None,
                     new_vault_fees: None,
                     new_fee_collector_addr: None,
+                    new_burn_fee_target: None,
+                    new_epoch_config: None,
                 },
             },
             &[],
@@ -126,6 +134,9 @@ mod tests {
             &vault_network::vault_factory::ExecuteMsg::CreateVault {
                 asset_info: asset_info.clone(),
                 fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
             },
             &[],
         )
@@ -136,7 +147,10 @@ mod tests {
             .wrap()
             .query_wasm_smart(
                 factory_addr.clone(),
-                &vault_network::vault_factory::QueryMsg::Vault { asset_info },
+                &vault_network::vault_factory::QueryMsg::Vault {
+                    asset_info,
+                    label: None,
+                },
             )
             .unwrap();
 
@@ -156,6 +170,8 @@ mod tests {
                     new_owner: Some("new_owner".to_string()),
                     new_vault_fees: None,
                     new_fee_collector_addr: None,
+                    new_burn_fee_target: None,
+                    new_epoch_config: None,
                 },
             },
             &[],
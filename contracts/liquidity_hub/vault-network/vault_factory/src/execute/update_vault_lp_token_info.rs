@@ -0,0 +1,142 @@
+use cosmwasm_std::{wasm_execute, DepsMut, Response};
+
+use pool_network::token::LpTokenInfo;
+
+use crate::err::StdResult;
+
+pub fn update_vault_lp_token_info(
+    deps: DepsMut,
+    vault_addr: String,
+    lp_token_info: LpTokenInfo,
+) -> StdResult<Response> {
+    Ok(Response::new()
+        .add_message(wasm_execute(
+            deps.api.addr_validate(vault_addr.as_str())?.to_string(),
+            &vault_network::vault::ExecuteMsg::UpdateLpTokenInfo { lp_token_info },
+            vec![],
+        )?)
+        .add_attribute("method", "update_vault_lp_token_info"))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+    use cw20::{LogoInfo, MarketingInfoResponse};
+    use cw_multi_test::Executor;
+    use pool_network::asset::AssetInfo;
+    use pool_network::token::LpTokenInfo;
+
+    use crate::{
+        err::VaultFactoryError,
+        tests::{get_fees, mock_app, mock_creator, mock_instantiate::app_mock_instantiate},
+    };
+
+    #[test]
+    fn can_update_vault_lp_token_info() {
+        let mut app = mock_app();
+
+        let factory_addr = app_mock_instantiate(&mut app);
+
+        let asset_info = AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        };
+
+        // create a vault
+        let creator = mock_creator();
+
+        app.execute_contract(
+            creator.sender.clone(),
+            factory_addr.clone(),
+            &vault_network::vault_factory::ExecuteMsg::CreateVault {
+                asset_info: asset_info.clone(),
+                fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: Some(LpTokenInfo {
+                    project: Some("White Whale".to_string()),
+                    ..LpTokenInfo::default()
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // get vault and LP token addresses
+        let vault_addr: Option<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr.clone(),
+                &vault_network::vault_factory::QueryMsg::Vault {
+                    asset_info,
+                    label: None,
+                },
+            )
+            .unwrap();
+        let vault_addr = vault_addr.unwrap();
+
+        let vault_config: vault_network::vault::Config = app
+            .wrap()
+            .query_wasm_smart(
+                vault_addr.clone(),
+                &vault_network::vault::QueryMsg::Config {},
+            )
+            .unwrap();
+        let lp_token = vault_config.liquidity_token.to_string();
+
+        let token_info: cw20::TokenInfoResponse = app
+            .wrap()
+            .query_wasm_smart(lp_token.clone(), &cw20::Cw20QueryMsg::TokenInfo {})
+            .unwrap();
+        assert_eq!(token_info.symbol, "LUNA-LP");
+
+        // unauthorized tries updating the LP token of the vault
+        let res = app.execute_contract(
+            Addr::unchecked("unauthorized"),
+            factory_addr.clone(),
+            &vault_network::vault_factory::ExecuteMsg::UpdateVaultLpTokenInfo {
+                vault_addr: vault_addr.to_string(),
+                lp_token_info: LpTokenInfo::default(),
+            },
+            &[],
+        );
+        assert_eq!(
+            res.unwrap_err()
+                .root_cause()
+                .downcast_ref::<VaultFactoryError>()
+                .unwrap(),
+            &VaultFactoryError::Unauthorized {}
+        );
+
+        // update the marketing info of the LP token
+        app.execute_contract(
+            creator.sender,
+            factory_addr,
+            &vault_network::vault_factory::ExecuteMsg::UpdateVaultLpTokenInfo {
+                vault_addr: vault_addr.to_string(),
+                lp_token_info: LpTokenInfo {
+                    description: Some("White Whale LUNA vault LP token".to_string()),
+                    logo_url: Some("https://whitewhale.money/logo.png".to_string()),
+                    ..LpTokenInfo::default()
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+        let marketing_info: MarketingInfoResponse = app
+            .wrap()
+            .query_wasm_smart(lp_token, &cw20::Cw20QueryMsg::MarketingInfo {})
+            .unwrap();
+        assert_eq!(
+            marketing_info,
+            MarketingInfoResponse {
+                project: Some("White Whale".to_string()),
+                description: Some("White Whale LUNA vault LP token".to_string()),
+                marketing: Some(vault_addr),
+                logo: Some(LogoInfo::Url(
+                    "https://whitewhale.money/logo.png".to_string()
+                )),
+            }
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{to_binary, Binary, Deps};
+
+use vault_network::vault_factory::{
+    ChildVersion, ChildrenVersionsResponse, CodeIdVaults, MigrationStatusResponse,
+};
+
+use crate::err::StdResult;
+use crate::state::{read_vaults, MIGRATION_CURSOR};
+
+/// Retrieves the in progress batch migration and the code ids a page of vaults are on.
+pub fn get_migration_status(
+    deps: Deps,
+    start_after: Option<Vec<u8>>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let cursor = MIGRATION_CURSOR.may_load(deps.storage)?;
+
+    let mut code_ids: BTreeMap<u64, Vec<String>> = BTreeMap::new();
+    for vault_info in read_vaults(deps.storage, deps.api, start_after, limit)? {
+        let contract_info = deps.querier.query_wasm_contract_info(&vault_info.vault)?;
+        code_ids
+            .entry(contract_info.code_id)
+            .or_default()
+            .push(vault_info.vault);
+    }
+
+    Ok(to_binary(&MigrationStatusResponse {
+        code_id: cursor.as_ref().map(|(code_id, _)| *code_id),
+        last_migrated: cursor.map(|(_, last_migrated)| last_migrated),
+        code_ids: code_ids
+            .into_iter()
+            .map(|(code_id, vaults)| CodeIdVaults { code_id, vaults })
+            .collect(),
+    })?)
+}
+
+/// Retrieves the code id and the cw2 contract version of the vaults.
+pub fn get_children_versions(
+    deps: Deps,
+    start_after: Option<Vec<u8>>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let children = read_vaults(deps.storage, deps.api, start_after, limit)?
+        .into_iter()
+        .map(|vault_info| {
+            let vault_addr = deps.api.addr_validate(&vault_info.vault)?;
+            let contract_info = deps.querier.query_wasm_contract_info(&vault_addr)?;
+            let contract_version = cw2::CONTRACT.query(&deps.querier, vault_addr)?;
+
+            Ok(ChildVersion {
+                vault: vault_info.vault,
+                asset_info: vault_info.asset_info,
+                asset_info_reference: vault_info.asset_info_reference,
+                label: vault_info.label,
+                code_id: contract_info.code_id,
+                contract: contract_version.contract,
+                version: contract_version.version,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(to_binary(&ChildrenVersionsResponse { children })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+    use cw_multi_test::Executor;
+    use pool_network::asset::AssetInfo;
+
+    use vault_network::vault_factory::{
+        ChildrenVersionsResponse, CodeIdVaults, Config, ExecuteMsg, MigrationStatusResponse,
+        QueryMsg,
+    };
+
+    use crate::tests::{get_fees, mock_app, mock_creator, mock_instantiate::app_mock_instantiate};
+
+    #[test]
+    fn does_get_migration_status() {
+        let mut app = mock_app();
+        let factory_addr = app_mock_instantiate(&mut app);
+
+        let creator = mock_creator();
+
+        // create some vaults
+        let mut vaults: Vec<String> = vec![];
+        for denom in ["uluna", "uwhale"] {
+            app.execute_contract(
+                creator.sender.clone(),
+                factory_addr.clone(),
+                &ExecuteMsg::CreateVault {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: denom.to_string(),
+                    },
+                    fees: get_fees(),
+                    label: None,
+                    lp_token_type: None,
+                    lp_token_info: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+            let vault: Option<Addr> = app
+                .wrap()
+                .query_wasm_smart(
+                    factory_addr.clone(),
+                    &QueryMsg::Vault {
+                        asset_info: AssetInfo::NativeToken {
+                            denom: denom.to_string(),
+                        },
+                        label: None,
+                    },
+                )
+                .unwrap();
+            vaults.push(vault.unwrap().into_string());
+        }
+
+        let config: Config = app
+            .wrap()
+            .query_wasm_smart(factory_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+
+        let res: MigrationStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr,
+                &QueryMsg::MigrationStatus {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            res,
+            MigrationStatusResponse {
+                code_id: None,
+                last_migrated: None,
+                code_ids: vec![CodeIdVaults {
+                    code_id: config.vault_id,
+                    vaults,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn does_get_children_versions() {
+        let mut app = mock_app();
+        let factory_addr = app_mock_instantiate(&mut app);
+
+        app.execute_contract(
+            mock_creator().sender,
+            factory_addr.clone(),
+            &ExecuteMsg::CreateVault {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: get_fees(),
+                label: None,
+                lp_token_type: None,
+                lp_token_info: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let config: Config = app
+            .wrap()
+            .query_wasm_smart(factory_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+
+        let res: ChildrenVersionsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr,
+                &QueryMsg::ChildrenVersions {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(res.children.len(), 1);
+        assert_eq!(res.children[0].code_id, config.vault_id);
+        assert_eq!(res.children[0].contract, "nico_teen-vault");
+        assert!(!res.children[0].version.is_empty());
+    }
+}
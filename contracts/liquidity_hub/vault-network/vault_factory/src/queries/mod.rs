@@ -1,6 +1,10 @@
 mod config;
+mod migration;
 mod vault;
 
+pub use self::vault::get_all_available_liquidity;
+pub use self::vault::get_asset_vaults;
 pub use self::vault::get_vault;
 pub use self::vault::get_vaults;
 pub use config::get_config;
+pub use migration::{get_children_versions, get_migration_status};
@@ -1,13 +1,17 @@
-use cosmwasm_std::{to_binary, Binary, Deps};
+use cosmwasm_std::{to_binary, Binary, Deps, Uint128};
 
 use pool_network::asset::AssetInfo;
-use vault_network::vault_factory::{VaultInfo, VaultsResponse};
+use vault_network::vault;
+use vault_network::vault_factory::{
+    AvailableLiquidityResponse, VaultInfo, VaultLiquidityInfo, VaultsResponse,
+};
 
-use crate::state::read_vaults;
-use crate::{asset::AssetReference, err::StdResult, state::VAULTS};
+use crate::state::{read_asset_vaults, read_vaults};
+use crate::{asset::get_vault_key, err::StdResult, state::VAULTS};
 
-pub fn get_vault(deps: Deps, asset_info: AssetInfo) -> StdResult<Binary> {
-    let vault_option = VAULTS.may_load(deps.storage, asset_info.get_reference())?;
+pub fn get_vault(deps: Deps, asset_info: AssetInfo, label: Option<String>) -> StdResult<Binary> {
+    let vault_option =
+        VAULTS.may_load(deps.storage, &get_vault_key(&asset_info, label.as_deref()))?;
     if let Some((vault_addr, _)) = vault_option {
         return Ok(to_binary(&vault_addr)?);
     }
@@ -24,12 +28,49 @@ pub fn get_vaults(
     Ok(to_binary(&VaultsResponse { vaults })?)
 }
 
+pub fn get_asset_vaults(deps: Deps, asset_info: AssetInfo) -> StdResult<Binary> {
+    let vaults: Vec<VaultInfo> = read_asset_vaults(deps.storage, &asset_info)?;
+    Ok(to_binary(&VaultsResponse { vaults })?)
+}
+
+pub fn get_all_available_liquidity(
+    deps: Deps,
+    start_after: Option<Vec<u8>>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let vaults = read_vaults(deps.storage, deps.api, start_after, limit)?
+        .into_iter()
+        .map(|vault_info| {
+            let config: vault::Config = deps
+                .querier
+                .query_wasm_smart(&vault_info.vault, &vault::QueryMsg::Config {})?;
+            let available_liquidity: Uint128 = deps
+                .querier
+                .query_wasm_smart(&vault_info.vault, &vault::QueryMsg::AvailableLiquidity {})?;
+
+            Ok(VaultLiquidityInfo {
+                vault: vault_info.vault,
+                asset_info: vault_info.asset_info,
+                asset_info_reference: vault_info.asset_info_reference,
+                label: vault_info.label,
+                available_liquidity,
+                fees: config.fees,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(to_binary(&AvailableLiquidityResponse { vaults })?)
+}
+
 #[cfg(test)]
 mod tests {
+    use cosmwasm_std::{coins, Addr, Uint128};
     use cw_multi_test::Executor;
     use pool_network::asset::AssetInfo;
 
-    use vault_network::vault_factory::{ExecuteMsg, QueryMsg, VaultsResponse};
+    use vault_network::vault_factory::{
+        AvailableLiquidityResponse, ExecuteMsg, QueryMsg, VaultsResponse,
+    };
 
     use crate::tests::{
         get_fees, mock_app, mock_creator, mock_instantiate::app_mock_instantiate, mock_query,
@@ -44,6 +85,7 @@ mod tests {
                 asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                label: None,
             },
         );
 
@@ -69,6 +111,9 @@ mod tests {
                 &ExecuteMsg::CreateVault {
                     asset_info: asset_info.clone(),
                     fees: get_fees(),
+                    label: None,
+                    lp_token_type: None,
+                    lp_token_info: None,
                 },
                 &[],
             )
@@ -84,7 +129,13 @@ mod tests {
         // check that the address was stored
         let vault_addr: Option<String> = app
             .wrap()
-            .query_wasm_smart(factory_addr, &QueryMsg::Vault { asset_info })
+            .query_wasm_smart(
+                factory_addr,
+                &QueryMsg::Vault {
+                    asset_info,
+                    label: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(vault_addr, Some(created_vault_addr.value.clone()));
@@ -111,6 +162,9 @@ mod tests {
                     &ExecuteMsg::CreateVault {
                         asset_info: asset_info.clone(),
                         fees: get_fees().clone(),
+                        label: None,
+                        lp_token_type: None,
+                        lp_token_info: None,
                     },
                     &[],
                 )
@@ -187,4 +241,134 @@ mod tests {
 
         assert_eq!(paginated_vaults, vaults);
     }
+
+    #[test]
+    fn does_get_asset_vaults() {
+        let mut app = mock_app();
+        let factory_addr = app_mock_instantiate(&mut app);
+
+        let creator = mock_creator();
+
+        let asset_info = AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        };
+
+        // create vaults for the asset, and one for another asset sharing the denom prefix
+        for (asset_info, label) in [
+            (asset_info.clone(), None),
+            (asset_info.clone(), Some("b".to_string())),
+            (asset_info.clone(), Some("a".to_string())),
+            (
+                AssetInfo::NativeToken {
+                    denom: "ulunax".to_string(),
+                },
+                None,
+            ),
+        ] {
+            app.execute_contract(
+                creator.sender.clone(),
+                factory_addr.clone(),
+                &ExecuteMsg::CreateVault {
+                    asset_info,
+                    fees: get_fees(),
+                    label,
+                    lp_token_type: None,
+                    lp_token_info: None,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        let vaults_response: VaultsResponse = app
+            .wrap()
+            .query_wasm_smart(factory_addr, &QueryMsg::AssetVaults { asset_info })
+            .unwrap();
+
+        assert_eq!(
+            vaults_response
+                .vaults
+                .into_iter()
+                .map(|v| v.label)
+                .collect::<Vec<_>>(),
+            vec![None, Some("a".to_string()), Some("b".to_string())]
+        );
+    }
+
+    #[test]
+    fn does_get_all_available_liquidity() {
+        let mut app = mock_app();
+        let factory_addr = app_mock_instantiate(&mut app);
+
+        let creator = mock_creator();
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &creator.sender, coins(10_000, "uluna"))
+        })
+        .unwrap();
+
+        // create two vaults
+        let mut vault_addrs = vec![];
+        for denom in ["uluna", "uwhale"] {
+            let res = app
+                .execute_contract(
+                    creator.sender.clone(),
+                    factory_addr.clone(),
+                    &ExecuteMsg::CreateVault {
+                        asset_info: AssetInfo::NativeToken {
+                            denom: denom.to_string(),
+                        },
+                        fees: get_fees(),
+                        label: None,
+                        lp_token_type: None,
+                        lp_token_info: None,
+                    },
+                    &[],
+                )
+                .unwrap();
+
+            let created_vault_addr = res
+                .events
+                .iter()
+                .flat_map(|event| &event.attributes)
+                .find(|attribute| attribute.key == "vault_address")
+                .unwrap();
+            vault_addrs.push(created_vault_addr.value.clone());
+        }
+
+        // deposit into the luna vault
+        app.execute_contract(
+            creator.sender.clone(),
+            Addr::unchecked(vault_addrs[0].clone()),
+            &vault_network::vault::ExecuteMsg::Deposit {
+                amount: Uint128::new(5_000),
+                receiver: None,
+            },
+            &coins(5_000, "uluna"),
+        )
+        .unwrap();
+
+        let res: AvailableLiquidityResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr,
+                &QueryMsg::AllAvailableLiquidity {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            res.vaults
+                .into_iter()
+                .map(|v| (v.vault, v.available_liquidity, v.fees))
+                .collect::<Vec<_>>(),
+            vec![
+                (vault_addrs[0].clone(), Uint128::new(5_000), get_fees()),
+                (vault_addrs[1].clone(), Uint128::zero(), get_fees()),
+            ]
+        );
+    }
 }
@@ -4,13 +4,20 @@ use pool_network::asset::AssetInfo;
 
 use vault_network::vault_factory::{Config, VaultInfo};
 
+use crate::asset::{get_vault_label, AssetReference};
+
 pub const CONFIG: Item<Config> = Item::new("config");
 
 pub const VAULTS: Map<&[u8], (Addr, AssetInfo)> = Map::new("vaults");
 
-/// Used to temporarily store the asset being instantiated between `create_vault` and `reply` callback
+/// Used to temporarily store the key and asset of the vault being instantiated between
+/// `create_vault` and `reply` callback
 pub const TMP_VAULT_ASSET: Item<(Vec<u8>, AssetInfo)> = Item::new("tmp_vault_asset");
 
+/// The code id and the key of the last vault migrated by the in progress batch migration, used to
+/// resume it
+pub const MIGRATION_CURSOR: Item<(u64, Vec<u8>)> = Item::new("migration_cursor");
+
 // settings for pagination
 const MAX_LIMIT: u32 = 30;
 const DEFAULT_LIMIT: u32 = 10;
@@ -22,29 +29,44 @@ pub fn read_vaults(
     limit: Option<u32>,
 ) -> StdResult<Vec<VaultInfo>> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
-    let start = calc_range_start(start_after).map(Bound::ExclusiveRaw);
+    let start = start_after.map(Bound::ExclusiveRaw);
 
     VAULTS
         .range(storage, start, None, Order::Ascending)
         .take(limit)
-        .map(|item| {
-            let (key, vault_data) = item?;
-            let (vault_addr, asset_info) = vault_data;
-
-            Ok(VaultInfo {
-                vault: vault_addr.to_string(),
-                asset_info,
-                asset_info_reference: key,
-            })
-        })
+        .map(|item| to_vault_info(item?))
+        .collect()
+}
+
+/// Reads all the vaults managing the given asset, i.e. the default vault and the labeled ones.
+pub fn read_asset_vaults(
+    storage: &dyn Storage,
+    asset_info: &AssetInfo,
+) -> StdResult<Vec<VaultInfo>> {
+    // the keys of the asset vaults are the asset reference, optionally followed by the null
+    // separator and the label, so they all sort before the reference followed by a 1 byte
+    let start = asset_info.get_reference().to_vec();
+    let mut end = start.clone();
+    end.push(1);
+
+    VAULTS
+        .range(
+            storage,
+            Some(Bound::InclusiveRaw(start)),
+            Some(Bound::ExclusiveRaw(end)),
+            Order::Ascending,
+        )
+        .map(|item| to_vault_info(item?))
         .collect()
 }
 
-// this will set the first key after the provided key, by appending a 1 byte
-fn calc_range_start(start_after: Option<Vec<u8>>) -> Option<Vec<u8>> {
-    start_after.map(|asset_info| {
-        let mut v = asset_info;
-        v.push(1);
-        v
+fn to_vault_info(
+    (key, (vault_addr, asset_info)): (Vec<u8>, (Addr, AssetInfo)),
+) -> StdResult<VaultInfo> {
+    Ok(VaultInfo {
+        vault: vault_addr.to_string(),
+        label: get_vault_label(&asset_info, &key),
+        asset_info,
+        asset_info_reference: key,
     })
 }
@@ -1,6 +1,10 @@
-use cosmwasm_std::{to_binary, CosmosMsg, DepsMut, MessageInfo, Response, WasmMsg};
+use cosmwasm_std::{
+    to_binary, Addr, CosmosMsg, Deps, DepsMut, MessageInfo, Response, Uint128, WasmMsg,
+};
 
 use pool_network::asset::Asset;
+use vault_network::vault::PaybackAmountResponse;
+use vault_network::vault_factory::VaultsResponse;
 use vault_network::vault_router::ExecuteMsg;
 
 use crate::{
@@ -27,19 +31,7 @@ pub fn flash_loan(
     let vaults = assets
         .into_iter()
         .map(|asset| {
-            // query factory for address
-            let address: Option<String> = deps.querier.query_wasm_smart(
-                config.vault_factory.clone(),
-                &vault_network::vault_factory::QueryMsg::Vault {
-                    asset_info: asset.info.clone(),
-                },
-            )?;
-
-            // return InvalidAsset if address doesn't exist
-            let address = address.ok_or(VaultRouterError::InvalidAsset {
-                asset: asset.clone(),
-            })?;
-
+            let address = select_vault(deps.as_ref(), &config.vault_factory, &asset)?;
             Ok((address, asset))
         })
         .collect::<StdResult<Vec<_>>>()?;
@@ -73,21 +65,73 @@ pub fn flash_loan(
         .add_attributes(vec![("method", "flash_loan")]))
 }
 
+/// Selects the vault to loan the given `asset` from. Among the vaults managing the asset, the one
+/// with the lowest payback amount that has enough liquidity available, i.e. without the fees it
+/// holds, is selected. If no vault has enough liquidity available, the cheapest vault is selected,
+/// as it may still be able to provide the funds by unwinding its liquidity strategy.
+fn select_vault(deps: Deps, vault_factory: &Addr, asset: &Asset) -> StdResult<String> {
+    let asset_vaults: VaultsResponse = deps.querier.query_wasm_smart(
+        vault_factory,
+        &vault_network::vault_factory::QueryMsg::AssetVaults {
+            asset_info: asset.info.clone(),
+        },
+    )?;
+
+    let mut candidates = asset_vaults
+        .vaults
+        .into_iter()
+        .map(|vault| {
+            let payback: PaybackAmountResponse = deps.querier.query_wasm_smart(
+                vault.vault.clone(),
+                &vault_network::vault::QueryMsg::GetPaybackAmount {
+                    amount: asset.amount,
+                },
+            )?;
+            let available_liquidity: Uint128 = deps.querier.query_wasm_smart(
+                vault.vault.clone(),
+                &vault_network::vault::QueryMsg::AvailableLiquidity {},
+            )?;
+
+            Ok((
+                vault.vault,
+                payback.payback_amount,
+                available_liquidity >= asset.amount,
+            ))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // vaults with enough liquidity available first, then the cheapest ones
+    candidates.sort_by(|(_, a_payback, a_liquid), (_, b_payback, b_liquid)| {
+        b_liquid.cmp(a_liquid).then(a_payback.cmp(b_payback))
+    });
+
+    // return InvalidAsset if no vault exists for the asset
+    candidates
+        .into_iter()
+        .next()
+        .map(|(vault, ..)| vault)
+        .ok_or(VaultRouterError::InvalidAsset {
+            asset: asset.clone(),
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::{
-        coins, from_binary, to_binary, Attribute, BankMsg, CosmosMsg, Event, Response, Uint128,
-        WasmMsg,
+        coins, from_binary, to_binary, Addr, Attribute, BankMsg, CosmosMsg, Decimal, Event,
+        Response, Uint128, WasmMsg,
     };
-    use cw_multi_test::Executor;
+    use cw_multi_test::{App, AppResponse, Executor};
 
     use pool_network::asset::{Asset, AssetInfo};
+    use nico_teen::fee::{Fee, VaultFee};
     use vault_network::vault_router::ExecuteMsg;
 
     use crate::{
         err::VaultRouterError,
         tests::{
-            create_dummy_contract, mock_admin, mock_app_with_balance, mock_creator, mock_execute,
+            create_dummy_contract, get_fees, mock_admin, mock_app_with_balance, mock_creator,
+            mock_execute,
             mock_instantiate::{app_mock_instantiate, AppInstantiateResponse},
         },
     };
@@ -447,4 +491,120 @@ mod tests {
 
         assert_eq!(events, expected_events);
     }
+    #[test]
+    fn does_select_vault_by_available_liquidity() {
+        let mut app = mock_app_with_balance(vec![(mock_admin(), coins(100_000, "uluna"))]);
+        let AppInstantiateResponse {
+            router_addr,
+            native_vault_addr,
+            factory_addr,
+            ..
+        } = app_mock_instantiate(&mut app);
+
+        let dummy_contract_addr = create_dummy_contract(&mut app);
+        app.send_tokens(
+            mock_admin(),
+            dummy_contract_addr.clone(),
+            &coins(5_000, "uluna"),
+        )
+        .unwrap();
+        let flash_loan = |app: &mut App, amount: u128, fee_amount: u128| {
+            app.execute_contract(
+                mock_creator().sender,
+                router_addr.clone(),
+                &ExecuteMsg::FlashLoan {
+                    assets: vec![Asset {
+                        amount: Uint128::new(amount),
+                        info: AssetInfo::NativeToken {
+                            denom: "uluna".to_string(),
+                        },
+                    }],
+                    msgs: vec![WasmMsg::Execute {
+                        contract_addr: dummy_contract_addr.clone().into_string(),
+                        msg: to_binary(&crate::tests::ExecuteMsg::Send {
+                            to_address: router_addr.clone(),
+                            amount: coins(fee_amount, "uluna"),
+                        })
+                        .unwrap(),
+                        funds: vec![],
+                    }
+                    .into()],
+                },
+                &[],
+            )
+        };
+
+        // the native vault holds fees after a first loan
+        flash_loan(&mut app, 1_000, 66).unwrap();
+
+        // a more expensive vault of the same asset
+        app.execute_contract(
+            mock_admin(),
+            factory_addr.clone(),
+            &vault_network::vault_factory::ExecuteMsg::CreateVault {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: VaultFee {
+                    flash_loan_fee: Fee {
+                        share: Decimal::percent(10),
+                    },
+                    ..get_fees()
+                },
+                label: Some("expensive".to_string()),
+                lp_token_type: None,
+                lp_token_info: None,
+            },
+            &[],
+        )
+        .unwrap();
+        let expensive_vault_addr: Addr = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr,
+                &vault_network::vault_factory::QueryMsg::Vault {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                    label: Some("expensive".to_string()),
+                },
+            )
+            .unwrap();
+        app.send_tokens(
+            mock_admin(),
+            expensive_vault_addr.clone(),
+            &coins(20_000, "uluna"),
+        )
+        .unwrap();
+
+        let loaned_from = |res: &AppResponse| {
+            res.events
+                .iter()
+                .find(|event| {
+                    event.ty == "wasm"
+                        && event
+                            .attributes
+                            .contains(&Attribute::new("method", "flash_loan"))
+                        && event.attributes.iter().any(|attr| attr.key == "amount")
+                })
+                .and_then(|event| {
+                    event
+                        .attributes
+                        .iter()
+                        .find(|attr| attr.key == "_contract_addr")
+                })
+                .unwrap()
+                .value
+                .clone()
+        };
+
+        // the cheaper native vault is selected while it has enough liquidity available, i.e. its
+        // 10_066 uluna without the 33 uluna of protocol fees it holds
+        let res = flash_loan(&mut app, 10_033, 2_000).unwrap();
+        assert_eq!(loaned_from(&res), native_vault_addr.to_string());
+
+        // its balance of 10_734 uluna covers the loan, but not without the 367 uluna of fees
+        let res = flash_loan(&mut app, 10_500, 2_000).unwrap();
+        assert_eq!(loaned_from(&res), expensive_vault_addr.to_string());
+    }
 }
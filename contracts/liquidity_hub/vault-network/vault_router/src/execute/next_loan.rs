@@ -1,6 +1,7 @@
 use cosmwasm_std::{to_binary, Addr, CosmosMsg, DepsMut, Env, MessageInfo, Response, WasmMsg};
 
 use pool_network::asset::{Asset, AssetInfo};
+use vault_network::vault_factory::VaultsResponse;
 use vault_network::vault_router::ExecuteMsg;
 
 use crate::err::{StdResult, VaultRouterError};
@@ -21,19 +22,20 @@ pub fn next_loan(
     // check that the source vault is executing this message and it is a vault created by the WW vault factory
     let config = CONFIG.load(deps.storage)?;
 
-    let Some(queried_vault) = deps.querier.query_wasm_smart::<Option<String>>(
+    let asset_vaults: VaultsResponse = deps.querier.query_wasm_smart(
         config.vault_factory,
-        &vault_network::vault_factory::QueryMsg::Vault {
+        &vault_network::vault_factory::QueryMsg::AssetVaults {
             asset_info: source_vault_asset,
         },
-    )? else {
-        return Err(VaultRouterError::Unauthorized {});
-    };
+    )?;
 
     let validated_source_vault = deps.api.addr_validate(&source_vault)?;
 
     if info.sender != validated_source_vault
-        || deps.api.addr_validate(&queried_vault)? != validated_source_vault
+        || !asset_vaults
+            .vaults
+            .iter()
+            .any(|vault| vault.vault == validated_source_vault.as_str())
     {
         return Err(VaultRouterError::Unauthorized {});
     }
@@ -138,6 +140,7 @@ mod tests {
                     asset_info: AssetInfo::NativeToken {
                         denom: "uluna".to_string(),
                     },
+                    label: None,
                 },
             )
             .unwrap();
@@ -92,6 +92,9 @@ pub fn app_mock_instantiate(app: &mut App) -> AppInstantiateResponse {
                 denom: "uluna".to_string(),
             },
             fees: get_fees(),
+            label: None,
+            lp_token_type: None,
+            lp_token_info: None,
         },
         &[],
     )
@@ -105,6 +108,7 @@ pub fn app_mock_instantiate(app: &mut App) -> AppInstantiateResponse {
                 asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                label: None,
             },
         )
         .unwrap();
@@ -146,6 +150,9 @@ pub fn app_mock_instantiate(app: &mut App) -> AppInstantiateResponse {
                 contract_addr: token_addr.clone().into_string(),
             },
             fees: get_fees(),
+            label: None,
+            lp_token_type: None,
+            lp_token_info: None,
         },
         &[],
     )
@@ -158,6 +165,7 @@ pub fn app_mock_instantiate(app: &mut App) -> AppInstantiateResponse {
                 asset_info: AssetInfo::Token {
                     contract_addr: token_addr.clone().into_string(),
                 },
+                label: None,
             },
         )
         .unwrap();
@@ -179,6 +187,7 @@ pub fn app_mock_instantiate(app: &mut App) -> AppInstantiateResponse {
         token_vault_addr.clone(),
         &vault_network::vault::ExecuteMsg::Deposit {
             amount: Uint128::new(10_000),
+            receiver: None,
         },
         &[],
     )
@@ -0,0 +1,101 @@
+use cosmwasm_std::Empty;
+use cw_multi_test::{Contract, ContractWrapper};
+
+/// The pool factory contract
+pub fn pool_factory_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            terraswap_factory::contract::execute,
+            terraswap_factory::contract::instantiate,
+            terraswap_factory::contract::query,
+        )
+        .with_reply(terraswap_factory::contract::reply)
+        .with_migrate(terraswap_factory::contract::migrate),
+    )
+}
+
+/// The pair contract
+pub fn pair_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            terraswap_pair::contract::execute,
+            terraswap_pair::contract::instantiate,
+            terraswap_pair::contract::query,
+        )
+        .with_reply(terraswap_pair::contract::reply)
+        .with_migrate(terraswap_pair::contract::migrate),
+    )
+}
+
+/// The cw20 token contract, used for the LP tokens and the tokens created with
+/// [crate::TestSuite::create_token]
+pub fn token_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        terraswap_token::contract::execute,
+        terraswap_token::contract::instantiate,
+        terraswap_token::contract::query,
+    ))
+}
+
+/// The pool router contract
+pub fn pool_router_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            terraswap_router::contract::execute,
+            terraswap_router::contract::instantiate,
+            terraswap_router::contract::query,
+        )
+        .with_migrate(terraswap_router::contract::migrate),
+    )
+}
+
+/// The vault factory contract
+pub fn vault_factory_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            vault_factory::contract::execute,
+            vault_factory::contract::instantiate,
+            vault_factory::contract::query,
+        )
+        .with_reply(vault_factory::reply::reply)
+        .with_migrate(vault_factory::contract::migrate),
+    )
+}
+
+/// The vault contract
+pub fn vault_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            vault::contract::execute,
+            vault::contract::instantiate,
+            vault::contract::query,
+        )
+        .with_reply(vault::reply::reply)
+        .with_migrate(vault::contract::migrate),
+    )
+}
+
+/// The vault router contract
+pub fn vault_router_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            vault_router::contract::execute,
+            vault_router::contract::instantiate,
+            vault_router::contract::query,
+        )
+        .with_migrate(vault_router::contract::migrate),
+    )
+}
+
+/// The fee collector contract
+pub fn fee_collector_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            fee_collector::contract::execute,
+            fee_collector::contract::instantiate,
+            fee_collector::contract::query,
+        )
+        .with_reply(fee_collector::contract::reply)
+        .with_migrate(fee_collector::contract::migrate),
+    )
+}
@@ -0,0 +1,11 @@
+//! A multi-test harness wiring up the liquidity hub contracts in a single `App`, with builders for
+//! pairs, vaults and funded users.
+
+pub mod contracts;
+mod pair;
+mod suite;
+mod vault;
+
+pub use pair::PairBuilder;
+pub use suite::{native, TestSuite, TestSuiteBuilder, DEFAULT_NATIVE_DECIMALS};
+pub use vault::VaultBuilder;
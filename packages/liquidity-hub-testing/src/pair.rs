@@ -0,0 +1,137 @@
+use cosmwasm_std::{coin, Addr, Coin, Decimal, Uint128};
+use cw_multi_test::Executor;
+
+use pool_network::asset::{Asset, AssetInfo, PairInfo, PairType};
+use pool_network::factory::{ExecuteMsg as FactoryExecuteMsg, QueryMsg as FactoryQueryMsg};
+use pool_network::pair::{ExecuteMsg as PairExecuteMsg, PoolFee};
+use nico_teen::fee::Fee;
+
+use crate::suite::TestSuite;
+
+/// Builds a pair to be created with [TestSuite::create_pair]
+pub struct PairBuilder {
+    asset_infos: [AssetInfo; 2],
+    pool_fees: PoolFee,
+    pair_type: PairType,
+    liquidity: Option<(String, [Uint128; 2])>,
+}
+
+impl PairBuilder {
+    /// A constant product pair of the given assets, without fees
+    pub fn new(asset_infos: [AssetInfo; 2]) -> Self {
+        PairBuilder {
+            asset_infos,
+            pool_fees: PoolFee {
+                protocol_fee: Fee {
+                    share: Decimal::zero(),
+                },
+                swap_fee: Fee {
+                    share: Decimal::zero(),
+                },
+                burn_fee: Fee {
+                    share: Decimal::zero(),
+                },
+            },
+            pair_type: PairType::ConstantProduct,
+            liquidity: None,
+        }
+    }
+
+    pub fn with_pool_fees(mut self, pool_fees: PoolFee) -> Self {
+        self.pool_fees = pool_fees;
+        self
+    }
+
+    pub fn with_pair_type(mut self, pair_type: PairType) -> Self {
+        self.pair_type = pair_type;
+        self
+    }
+
+    /// Provides the given amounts of the assets, in the order of the asset infos, from `provider`
+    /// once the pair is created
+    pub fn with_liquidity(mut self, provider: &str, amounts: [Uint128; 2]) -> Self {
+        self.liquidity = Some((provider.to_string(), amounts));
+        self
+    }
+}
+
+impl TestSuite {
+    /// Creates a pair through the pool factory, returning its info
+    pub fn create_pair(&mut self, pair: PairBuilder) -> PairInfo {
+        self.app
+            .execute_contract(
+                self.creator.clone(),
+                self.pool_factory_addr.clone(),
+                &FactoryExecuteMsg::CreatePair {
+                    asset_infos: pair.asset_infos.clone(),
+                    pool_fees: pair.pool_fees.clone(),
+                    pair_type: pair.pair_type.clone(),
+                    lp_token_type: None,
+                    lp_token_info: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let pair_info: PairInfo = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.pool_factory_addr,
+                &FactoryQueryMsg::Pair {
+                    asset_infos: pair.asset_infos.clone(),
+                    pair_type: Some(pair.pair_type),
+                    fee_tier: Some(pair.pool_fees.swap_fee.share),
+                },
+            )
+            .unwrap();
+
+        if let Some((provider, amounts)) = pair.liquidity {
+            let [asset_info_0, asset_info_1] = pair.asset_infos;
+            self.provide_liquidity(
+                &provider,
+                &Addr::unchecked(&pair_info.contract_addr),
+                [
+                    Asset {
+                        info: asset_info_0,
+                        amount: amounts[0],
+                    },
+                    Asset {
+                        info: asset_info_1,
+                        amount: amounts[1],
+                    },
+                ],
+            );
+        }
+
+        pair_info
+    }
+
+    /// Provides liquidity to a pair from `provider`, increasing the allowance of the cw20 assets
+    /// beforehand
+    pub fn provide_liquidity(&mut self, provider: &str, pair_addr: &Addr, assets: [Asset; 2]) {
+        let mut funds: Vec<Coin> = vec![];
+        for asset in assets.iter() {
+            match &asset.info {
+                AssetInfo::NativeToken { denom } => funds.push(coin(asset.amount.u128(), denom)),
+                AssetInfo::Token { contract_addr } => {
+                    self.increase_allowance(provider, contract_addr, pair_addr, asset.amount)
+                }
+            }
+        }
+        funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        self.app
+            .execute_contract(
+                Addr::unchecked(provider),
+                pair_addr.clone(),
+                &PairExecuteMsg::ProvideLiquidity {
+                    assets,
+                    slippage_tolerance: None,
+                    receiver: None,
+                },
+                &funds,
+            )
+            .unwrap();
+    }
+}
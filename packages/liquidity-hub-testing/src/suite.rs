@@ -0,0 +1,317 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{coins, Addr, Coin, Empty, Uint128};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, MinterResponse};
+use cw_multi_test::{App, BankSudo, Contract, Executor, SudoMsg};
+
+use pool_network::asset::AssetInfo;
+use pool_network::factory::{ExecuteMsg as FactoryExecuteMsg, NativeTokenDecimals};
+
+use crate::contracts::{
+    fee_collector_contract, pair_contract, pool_factory_contract, pool_router_contract,
+    token_contract, vault_contract, vault_factory_contract, vault_router_contract,
+};
+
+/// The decimals the native tokens are registered with in the pool factory, unless given otherwise
+pub const DEFAULT_NATIVE_DECIMALS: u8 = 6u8;
+
+/// Builds a [TestSuite], funding users and registering native tokens in the pool factory
+pub struct TestSuiteBuilder {
+    creator: Addr,
+    funded_users: Vec<(Addr, Vec<Coin>)>,
+    native_token_decimals: BTreeMap<String, u8>,
+    pool_router_contract: Option<Box<dyn Contract<Empty>>>,
+}
+
+impl TestSuiteBuilder {
+    /// Sets the address instantiating and owning the liquidity hub contracts, `creator` by default
+    pub fn with_creator(mut self, creator: &str) -> Self {
+        self.creator = Addr::unchecked(creator);
+        self
+    }
+
+    /// Funds `user` with the given native coins. Their denoms are registered in the pool factory.
+    pub fn with_funded_user(mut self, user: &str, funds: Vec<Coin>) -> Self {
+        for coin in funds.iter() {
+            self.native_token_decimals
+                .entry(coin.denom.clone())
+                .or_insert(DEFAULT_NATIVE_DECIMALS);
+        }
+        self.funded_users.push((Addr::unchecked(user), funds));
+        self
+    }
+
+    /// Registers a native token in the pool factory with the given decimals
+    pub fn with_native_token_decimals(mut self, denom: &str, decimals: u8) -> Self {
+        self.native_token_decimals
+            .insert(denom.to_string(), decimals);
+        self
+    }
+
+    /// Uses the given pool router contract instead of [pool_router_contract]. Lets the router
+    /// tests run the router built along them, so the errors it returns can be downcast.
+    pub fn with_pool_router_contract(mut self, contract: Box<dyn Contract<Empty>>) -> Self {
+        self.pool_router_contract = Some(contract);
+        self
+    }
+
+    pub fn build(self) -> TestSuite {
+        let mut app = App::default();
+        let creator = self.creator;
+
+        let pool_factory_code_id = app.store_code(pool_factory_contract());
+        let pair_code_id = app.store_code(pair_contract());
+        let token_code_id = app.store_code(token_contract());
+        let pool_router_code_id = app.store_code(
+            self.pool_router_contract
+                .unwrap_or_else(pool_router_contract),
+        );
+        let vault_factory_code_id = app.store_code(vault_factory_contract());
+        let vault_code_id = app.store_code(vault_contract());
+        let vault_router_code_id = app.store_code(vault_router_contract());
+        let fee_collector_code_id = app.store_code(fee_collector_contract());
+
+        let fee_collector_addr = app
+            .instantiate_contract(
+                fee_collector_code_id,
+                creator.clone(),
+                &fee_collector::msg::InstantiateMsg {},
+                &[],
+                "fee_collector",
+                Some(creator.to_string()),
+            )
+            .unwrap();
+
+        let pool_factory_addr = app
+            .instantiate_contract(
+                pool_factory_code_id,
+                creator.clone(),
+                &pool_network::factory::InstantiateMsg {
+                    pair_code_id,
+                    token_code_id,
+                    fee_collector_addr: fee_collector_addr.to_string(),
+                },
+                &[],
+                "pool_factory",
+                Some(creator.to_string()),
+            )
+            .unwrap();
+
+        let pool_router_addr = app
+            .instantiate_contract(
+                pool_router_code_id,
+                creator.clone(),
+                &pool_network::router::InstantiateMsg {
+                    terraswap_factory: pool_factory_addr.to_string(),
+                },
+                &[],
+                "pool_router",
+                Some(creator.to_string()),
+            )
+            .unwrap();
+
+        let vault_factory_addr = app
+            .instantiate_contract(
+                vault_factory_code_id,
+                creator.clone(),
+                &vault_network::vault_factory::InstantiateMsg {
+                    owner: creator.to_string(),
+                    vault_id: vault_code_id,
+                    token_id: token_code_id,
+                    fee_collector_addr: fee_collector_addr.to_string(),
+                },
+                &[],
+                "vault_factory",
+                Some(creator.to_string()),
+            )
+            .unwrap();
+
+        let vault_router_addr = app
+            .instantiate_contract(
+                vault_router_code_id,
+                creator.clone(),
+                &vault_network::vault_router::InstantiateMsg {
+                    owner: creator.to_string(),
+                    vault_factory_addr: vault_factory_addr.to_string(),
+                },
+                &[],
+                "vault_router",
+                Some(creator.to_string()),
+            )
+            .unwrap();
+
+        // let the fee collector aggregate the fees through the pool router
+        app.execute_contract(
+            creator.clone(),
+            fee_collector_addr.clone(),
+            &fee_collector::msg::ExecuteMsg::UpdateConfig {
+                owner: None,
+                pool_router: Some(pool_router_addr.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let mut suite = TestSuite {
+            app,
+            creator,
+            token_code_id,
+            pool_factory_addr,
+            pool_router_addr,
+            vault_factory_addr,
+            vault_router_addr,
+            fee_collector_addr,
+        };
+
+        for (user, funds) in self.funded_users {
+            suite.fund(user.as_str(), funds);
+        }
+
+        if !self.native_token_decimals.is_empty() {
+            // the pool factory verifies it holds the native tokens it registers
+            let factory_addr = suite.pool_factory_addr.to_string();
+            let mut native_tokens: Vec<NativeTokenDecimals> = vec![];
+            for (denom, decimals) in self.native_token_decimals {
+                suite.fund(&factory_addr, coins(1u128, &denom));
+                native_tokens.push(NativeTokenDecimals { denom, decimals });
+            }
+
+            suite
+                .app
+                .execute_contract(
+                    suite.creator.clone(),
+                    suite.pool_factory_addr.clone(),
+                    &FactoryExecuteMsg::AddNativeTokenDecimalsBatch { native_tokens },
+                    &[],
+                )
+                .unwrap();
+        }
+
+        suite
+    }
+}
+
+/// The liquidity hub contracts, instantiated by and owned by `creator`, in a single [App]
+pub struct TestSuite {
+    pub app: App,
+    pub creator: Addr,
+    pub token_code_id: u64,
+    pub pool_factory_addr: Addr,
+    pub pool_router_addr: Addr,
+    pub vault_factory_addr: Addr,
+    pub vault_router_addr: Addr,
+    pub fee_collector_addr: Addr,
+}
+
+impl TestSuite {
+    pub fn builder() -> TestSuiteBuilder {
+        TestSuiteBuilder {
+            creator: Addr::unchecked("creator"),
+            funded_users: vec![],
+            native_token_decimals: BTreeMap::new(),
+            pool_router_contract: None,
+        }
+    }
+
+    /// Mints the given native coins to `recipient`
+    pub fn fund(&mut self, recipient: &str, funds: Vec<Coin>) {
+        self.app
+            .sudo(SudoMsg::Bank(BankSudo::Mint {
+                to_address: recipient.to_string(),
+                amount: funds,
+            }))
+            .unwrap();
+    }
+
+    /// Creates a cw20 token with 6 decimals and the given initial balances, mintable by the
+    /// creator
+    pub fn create_token(&mut self, symbol: &str, initial_balances: &[(&str, u128)]) -> AssetInfo {
+        let token_addr = self
+            .app
+            .instantiate_contract(
+                self.token_code_id,
+                self.creator.clone(),
+                &pool_network::token::InstantiateMsg {
+                    name: symbol.to_string(),
+                    symbol: symbol.to_string(),
+                    decimals: 6u8,
+                    initial_balances: initial_balances
+                        .iter()
+                        .map(|(address, amount)| Cw20Coin {
+                            address: address.to_string(),
+                            amount: Uint128::new(*amount),
+                        })
+                        .collect(),
+                    mint: Some(MinterResponse {
+                        minter: self.creator.to_string(),
+                        cap: None,
+                    }),
+                    marketing: None,
+                },
+                &[],
+                symbol,
+                None,
+            )
+            .unwrap();
+
+        AssetInfo::Token {
+            contract_addr: token_addr.to_string(),
+        }
+    }
+
+    /// Increases the allowance `owner` gives `spender` on the given cw20 token
+    pub fn increase_allowance(
+        &mut self,
+        owner: &str,
+        token_addr: &str,
+        spender: &Addr,
+        amount: Uint128,
+    ) {
+        self.app
+            .execute_contract(
+                Addr::unchecked(owner),
+                Addr::unchecked(token_addr),
+                &Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: spender.to_string(),
+                    amount,
+                    expires: None,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    /// Queries the balance `address` holds of the given asset
+    pub fn query_balance(&self, address: &str, asset_info: &AssetInfo) -> Uint128 {
+        match asset_info {
+            AssetInfo::NativeToken { denom } => {
+                self.app
+                    .wrap()
+                    .query_balance(address, denom)
+                    .unwrap()
+                    .amount
+            }
+            AssetInfo::Token { contract_addr } => {
+                let res: BalanceResponse = self
+                    .app
+                    .wrap()
+                    .query_wasm_smart(
+                        contract_addr,
+                        &Cw20QueryMsg::Balance {
+                            address: address.to_string(),
+                        },
+                    )
+                    .unwrap();
+
+                res.balance
+            }
+        }
+    }
+}
+
+/// Shorthand for the [AssetInfo] of a native token
+pub fn native(denom: &str) -> AssetInfo {
+    AssetInfo::NativeToken {
+        denom: denom.to_string(),
+    }
+}
@@ -0,0 +1,126 @@
+use cosmwasm_std::{coins, Addr, Decimal, Uint128};
+use cw_multi_test::Executor;
+
+use pool_network::asset::AssetInfo;
+use nico_teen::fee::{Fee, VaultFee};
+use vault_network::vault::ExecuteMsg as VaultExecuteMsg;
+use vault_network::vault_factory::{
+    ExecuteMsg as VaultFactoryExecuteMsg, QueryMsg as VaultFactoryQueryMsg,
+};
+
+use crate::suite::TestSuite;
+
+/// Builds a vault to be created with [TestSuite::create_vault]
+pub struct VaultBuilder {
+    asset_info: AssetInfo,
+    fees: VaultFee,
+    label: Option<String>,
+    deposit: Option<(String, Uint128)>,
+}
+
+impl VaultBuilder {
+    /// The default vault of the given asset, without fees
+    pub fn new(asset_info: AssetInfo) -> Self {
+        VaultBuilder {
+            asset_info,
+            fees: VaultFee {
+                protocol_fee: Fee {
+                    share: Decimal::zero(),
+                },
+                flash_loan_fee: Fee {
+                    share: Decimal::zero(),
+                },
+                burn_fee: Fee {
+                    share: Decimal::zero(),
+                },
+            },
+            label: None,
+            deposit: None,
+        }
+    }
+
+    pub fn with_fees(mut self, fees: VaultFee) -> Self {
+        self.fees = fees;
+        self
+    }
+
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Deposits the given amount from `depositor` once the vault is created
+    pub fn with_deposit(mut self, depositor: &str, amount: Uint128) -> Self {
+        self.deposit = Some((depositor.to_string(), amount));
+        self
+    }
+}
+
+impl TestSuite {
+    /// Creates a vault through the vault factory, returning its address
+    pub fn create_vault(&mut self, vault: VaultBuilder) -> Addr {
+        self.app
+            .execute_contract(
+                self.creator.clone(),
+                self.vault_factory_addr.clone(),
+                &VaultFactoryExecuteMsg::CreateVault {
+                    asset_info: vault.asset_info.clone(),
+                    fees: vault.fees,
+                    label: vault.label.clone(),
+                    lp_token_type: None,
+                    lp_token_info: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let vault_addr: Option<String> = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.vault_factory_addr,
+                &VaultFactoryQueryMsg::Vault {
+                    asset_info: vault.asset_info.clone(),
+                    label: vault.label,
+                },
+            )
+            .unwrap();
+        let vault_addr = Addr::unchecked(vault_addr.unwrap());
+
+        if let Some((depositor, amount)) = vault.deposit {
+            self.deposit(&depositor, &vault_addr, &vault.asset_info, amount);
+        }
+
+        vault_addr
+    }
+
+    /// Deposits the given amount of the vault asset from `depositor`, increasing the allowance
+    /// beforehand if the asset is a cw20 token
+    pub fn deposit(
+        &mut self,
+        depositor: &str,
+        vault_addr: &Addr,
+        asset_info: &AssetInfo,
+        amount: Uint128,
+    ) {
+        let funds = match asset_info {
+            AssetInfo::NativeToken { denom } => coins(amount.u128(), denom),
+            AssetInfo::Token { contract_addr } => {
+                self.increase_allowance(depositor, contract_addr, vault_addr, amount);
+                vec![]
+            }
+        };
+
+        self.app
+            .execute_contract(
+                Addr::unchecked(depositor),
+                vault_addr.clone(),
+                &VaultExecuteMsg::Deposit {
+                    amount,
+                    receiver: None,
+                },
+                &funds,
+            )
+            .unwrap();
+    }
+}
@@ -362,7 +362,7 @@ impl AssetInfoRaw {
 pub struct PairInfo {
     pub asset_infos: [AssetInfo; 2],
     pub contract_addr: String,
-    pub liquidity_token: String,
+    pub liquidity_token: AssetInfo,
     pub asset_decimals: [u8; 2],
     pub pair_type: PairType,
 }
@@ -371,7 +371,7 @@ pub struct PairInfo {
 pub struct PairInfoRaw {
     pub asset_infos: [AssetInfoRaw; 2],
     pub contract_addr: CanonicalAddr,
-    pub liquidity_token: CanonicalAddr,
+    pub liquidity_token: AssetInfoRaw,
     pub asset_decimals: [u8; 2],
     pub pair_type: PairType,
 }
@@ -379,7 +379,7 @@ pub struct PairInfoRaw {
 impl PairInfoRaw {
     pub fn to_normal(&self, api: &dyn Api) -> StdResult<PairInfo> {
         Ok(PairInfo {
-            liquidity_token: api.addr_humanize(&self.liquidity_token)?.to_string(),
+            liquidity_token: self.liquidity_token.to_normal(api)?,
             contract_addr: api.addr_humanize(&self.contract_addr)?.to_string(),
             asset_infos: [
                 self.asset_infos[0].to_normal(api)?,
@@ -0,0 +1,129 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+use crate::asset::AssetInfo;
+use crate::router::SwapOperation;
+
+pub const INSTANTIATE_SHARE_TOKEN_REPLY_ID: u64 = 1;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub owner: String,
+    /// The pair whose LP tokens are compounded
+    pub pair_address: String,
+    /// The pool router the rewards are swapped through
+    pub pool_router: String,
+    /// The code id of the cw20 token contract the share token is instantiated from
+    pub token_code_id: u64,
+    /// The contract the rewards are claimed from when harvesting, if any
+    pub incentive_contract: Option<String>,
+    pub reward_routes: Vec<RewardRoute>,
+    /// The amount of the second asset of the pair per unit of the first one the harvested assets
+    /// are provided at
+    pub reference_price: Decimal,
+    /// The slippage tolerance of the pair price from the reference price when providing liquidity
+    pub slippage_tolerance: Decimal,
+}
+
+/// The routes a reward asset is swapped through into the assets of the pair
+#[cw_serde]
+pub struct RewardRoute {
+    pub reward_asset_info: AssetInfo,
+    /// The swap operations from the reward asset to each asset of the pair, in the order of the
+    /// pair's asset infos. The route to the reward asset itself is empty.
+    pub swap_operations: [Vec<SwapOperation>; 2],
+    /// The minimum amount of each asset of the pair the swaps return per unit of reward, in the
+    /// order of the pair's asset infos. The minimum price of the empty route is ignored.
+    pub min_prices: [Decimal; 2],
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Used to trigger the [Cw20HookMsg] messages
+    Receive(Cw20ReceiveMsg),
+    /// Deposits the native LP tokens sent along, minting shares to the sender. cw20 LP tokens are
+    /// deposited with [Cw20HookMsg::Deposit] instead. The first deposit has to be over
+    /// [crate::asset::MINIMUM_LIQUIDITY_AMOUNT], which is minted to the contract itself.
+    Deposit {
+        amount: Uint128,
+    },
+    /// Claims the rewards from the incentive contract, if any, swaps the rewards held by the
+    /// contract into the assets of the pair and provides them as liquidity. Anyone can harvest, the
+    /// swaps have to return at least the minimum prices of the reward routes and the pair price
+    /// has to be within the slippage tolerance of the reference price.
+    Harvest {},
+    /// Updates the configuration of the contract. Only the owner can update it.
+    UpdateConfig {
+        owner: Option<String>,
+        pool_router: Option<String>,
+        incentive_contract: Option<String>,
+        reward_routes: Option<Vec<RewardRoute>>,
+        reference_price: Option<Decimal>,
+        slippage_tolerance: Option<Decimal>,
+    },
+    Callback(CallbackMsg),
+}
+
+/// The callback messages available. Only callable by the contract itself.
+#[cw_serde]
+pub enum CallbackMsg {
+    /// Swaps the reward assets held by the contract into the assets of the pair, half into each.
+    SwapRewards {},
+    /// Provides the assets of the pair held by the contract as liquidity to the pair, at the
+    /// reference price.
+    ProvideLiquidity {},
+}
+
+#[cw_serde]
+pub enum Cw20HookMsg {
+    /// Deposits the LP tokens sent, minting shares to the sender
+    Deposit {},
+    /// Withdraws the LP tokens the shares sent are entitled to, burning the shares
+    Withdraw {},
+}
+
+/// The message the incentive contract is expected to handle, sending the rewards accrued by the
+/// sender to it
+#[cw_serde]
+pub enum IncentiveExecuteMsg {
+    Claim {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Retrieves the configuration of the contract.
+    #[returns(ConfigResponse)]
+    Config {},
+    /// Retrieves the amount of LP tokens held by the contract that a given `amount` of shares is
+    /// entitled to.
+    #[returns(Uint128)]
+    Share { amount: Uint128 },
+}
+
+#[cw_serde]
+pub struct Config {
+    pub owner: Addr,
+    pub pair_address: Addr,
+    /// The assets of the pair
+    pub asset_infos: [AssetInfo; 2],
+    /// The LP token of the pair, deposited into the contract
+    pub liquidity_token: AssetInfo,
+    /// The share token issued by the contract
+    pub share_token: AssetInfo,
+    pub pool_router: Addr,
+    pub incentive_contract: Option<Addr>,
+    pub reward_routes: Vec<RewardRoute>,
+    /// The amount of the second asset of the pair per unit of the first one the harvested assets
+    /// are provided at
+    pub reference_price: Decimal,
+    /// The slippage tolerance of the pair price from the reference price when providing liquidity
+    pub slippage_tolerance: Decimal,
+}
+
+pub type ConfigResponse = Config;
+
+/// We currently take no arguments for migrations
+#[cw_serde]
+pub struct MigrateMsg {}
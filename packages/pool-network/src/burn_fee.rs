@@ -0,0 +1,207 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_binary, Addr, Coin, CosmosMsg, Deps, Event, QuerierWrapper, StdError, StdResult, SubMsg,
+    Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+
+use crate::asset::{Asset, AssetInfo};
+use crate::router::{
+    Cw20HookMsg as RouterCw20HookMsg, ExecuteMsg as RouterExecuteMsg, QueryMsg as RouterQueryMsg,
+    SwapOperation,
+};
+
+/// Defines what happens with the burn fees collected by a pool or a vault.
+#[cw_serde]
+#[derive(Default)]
+pub enum BurnFeeTarget {
+    /// The burn fees are burned. This is the default target.
+    #[default]
+    Burn,
+    /// The burn fees are sent to the given address, e.g. a treasury, instead of being burned.
+    Send { address: String },
+    /// The burn fees are accrued, then swapped to `asset_info` through the router by the `keeper`,
+    /// who sets the minimum amount to buy back. The tokens bought are burned. Burn fees already
+    /// denominated in `asset_info` are burned directly.
+    BuyBackAndBurn {
+        router_addr: String,
+        asset_info: AssetInfo,
+        keeper: String,
+    },
+}
+
+impl BurnFeeTarget {
+    /// Validates the target. For [BurnFeeTarget::BuyBackAndBurn], checks the router has a swap
+    /// route from each of the given fee assets to the buy-back asset.
+    pub fn validate(&self, deps: Deps, fee_asset_infos: &[AssetInfo]) -> StdResult<()> {
+        match self {
+            BurnFeeTarget::Burn => {}
+            BurnFeeTarget::Send { address } => {
+                deps.api.addr_validate(address)?;
+            }
+            BurnFeeTarget::BuyBackAndBurn {
+                router_addr,
+                asset_info,
+                keeper,
+            } => {
+                deps.api.addr_validate(router_addr)?;
+                deps.api.addr_validate(keeper)?;
+                for fee_asset_info in fee_asset_infos.iter().filter(|info| *info != asset_info) {
+                    query_buy_back_route(&deps.querier, router_addr, fee_asset_info, asset_info)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the burn fees in the given asset are burned right away, i.e. whether they count
+    /// towards the burned fees when taken. Burn fees bought back count once bought back.
+    pub fn burns_directly(&self, fee_asset_info: &AssetInfo) -> bool {
+        match self {
+            BurnFeeTarget::Burn => true,
+            BurnFeeTarget::Send { .. } => false,
+            BurnFeeTarget::BuyBackAndBurn { asset_info, .. } => asset_info == fee_asset_info,
+        }
+    }
+
+    /// Whether the burn fees in the given asset are bought back, in which case they are accrued
+    /// until the keeper buys back with them, see [BurnFeeTarget::buy_back_msg].
+    pub fn buys_back(&self, fee_asset_info: &AssetInfo) -> bool {
+        matches!(self, BurnFeeTarget::BuyBackAndBurn { asset_info, .. } if asset_info != fee_asset_info)
+    }
+
+    /// Creates the messages disposing of the given burn fee right away. The burn fees bought back
+    /// are not disposed of here, see [BurnFeeTarget::buys_back].
+    pub fn into_msgs(&self, burn_fee: Asset) -> StdResult<Vec<CosmosMsg>> {
+        if burn_fee.amount.is_zero() || self.buys_back(&burn_fee.info) {
+            return Ok(vec![]);
+        }
+
+        match self {
+            // the address is validated when the target is set
+            BurnFeeTarget::Send { address } => {
+                Ok(vec![burn_fee.into_msg(Addr::unchecked(address))?])
+            }
+            _ => Ok(vec![burn_fee.into_burn_msg()?]),
+        }
+    }
+
+    /// Creates the swap buying back the buy-back asset with the given accrued burn fees, receiving
+    /// at least `minimum_receive`. It is dispatched as a submessage replying on success with
+    /// `reply_id`, which is expected to burn the tokens bought, see [get_bought_back_amount].
+    pub fn buy_back_msg(
+        &self,
+        querier: &QuerierWrapper,
+        burn_fees: Asset,
+        minimum_receive: Uint128,
+        reply_id: u64,
+    ) -> StdResult<SubMsg> {
+        let BurnFeeTarget::BuyBackAndBurn {
+            router_addr,
+            asset_info,
+            ..
+        } = self
+        else {
+            return Err(StdError::generic_err(
+                "The burn fee target is not buying back",
+            ));
+        };
+
+        let operations = query_buy_back_route(querier, router_addr, &burn_fees.info, asset_info)?;
+
+        let swap_msg = match burn_fees.info {
+            AssetInfo::NativeToken { denom } => WasmMsg::Execute {
+                contract_addr: router_addr.to_string(),
+                msg: to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                    operations,
+                    minimum_receive: Some(minimum_receive),
+                    to: None,
+                })?,
+                funds: vec![Coin {
+                    denom,
+                    amount: burn_fees.amount,
+                }],
+            },
+            AssetInfo::Token { contract_addr } => WasmMsg::Execute {
+                contract_addr,
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: router_addr.to_string(),
+                    amount: burn_fees.amount,
+                    msg: to_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+                        operations,
+                        minimum_receive: Some(minimum_receive),
+                        to: None,
+                    })?,
+                })?,
+                funds: vec![],
+            },
+        };
+
+        Ok(SubMsg::reply_on_success(swap_msg, reply_id))
+    }
+
+    /// Whether the given address is the keeper of the [BurnFeeTarget::BuyBackAndBurn] target.
+    pub fn is_keeper(&self, sender: &Addr) -> bool {
+        matches!(self, BurnFeeTarget::BuyBackAndBurn { keeper, .. } if keeper == sender)
+    }
+}
+
+/// Queries the router for the swap route used to buy back `ask_asset_info` with `offer_asset_info`.
+fn query_buy_back_route(
+    querier: &QuerierWrapper,
+    router_addr: &str,
+    offer_asset_info: &AssetInfo,
+    ask_asset_info: &AssetInfo,
+) -> StdResult<Vec<SwapOperation>> {
+    querier
+        .query_wasm_smart(
+            router_addr,
+            &RouterQueryMsg::SwapRoute {
+                offer_asset_info: offer_asset_info.clone(),
+                ask_asset_info: ask_asset_info.clone(),
+            },
+        )
+        .map_err(|_| {
+            StdError::generic_err(format!(
+                "No buy-back route from {} to {}",
+                offer_asset_info, ask_asset_info
+            ))
+        })
+}
+
+/// Gets the amount of `asset_info` bought back by `receiver`, out of the events emitted by the
+/// pools the router swapped through.
+///
+/// The amount is read from the swap events rather than from a balance difference, as the route
+/// may swap through the very pool performing the buy-back.
+pub fn get_bought_back_amount(
+    events: &[Event],
+    receiver: &str,
+    asset_info: &AssetInfo,
+) -> StdResult<Uint128> {
+    let asset_label = asset_info.to_string();
+
+    events
+        .iter()
+        .filter(|event| event.ty == "wasm")
+        .filter(|event| {
+            get_attribute(event, "action") == Some("swap")
+                && get_attribute(event, "receiver") == Some(receiver)
+                && get_attribute(event, "ask_asset") == Some(asset_label.as_str())
+        })
+        .try_fold(Uint128::zero(), |total, event| -> StdResult<Uint128> {
+            let return_amount = get_attribute(event, "return_amount")
+                .ok_or_else(|| StdError::generic_err("Missing return_amount in swap event"))?
+                .parse::<Uint128>()?;
+            Ok(total.checked_add(return_amount)?)
+        })
+}
+
+fn get_attribute<'a>(event: &'a Event, key: &str) -> Option<&'a str> {
+    event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.as_str())
+}
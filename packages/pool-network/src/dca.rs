@@ -0,0 +1,153 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+use crate::asset::{Asset, AssetInfo};
+use crate::router::SwapOperation;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The pool router the swaps of the positions are executed through
+    pub pool_router: String,
+    /// The share of the proceeds of each swap paid to the keeper executing it
+    pub keeper_tip: Decimal,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Used to trigger the [Cw20HookMsg] messages
+    Receive(Cw20ReceiveMsg),
+    /// Creates a position swapping the native `deposit` sent along through `operations`,
+    /// `amount_per_swap` at a time every `interval` seconds, at no less than `min_price`. Positions
+    /// depositing cw20 tokens are created with [Cw20HookMsg::CreatePosition] instead.
+    CreatePosition {
+        deposit: Asset,
+        operations: Vec<SwapOperation>,
+        amount_per_swap: Uint128,
+        interval: u64,
+        min_price: Decimal,
+    },
+    /// Executes the due swap of a position, paying the keeper tip to the sender out of the
+    /// proceeds. Anyone can execute a position, the swap has to return at least the minimum price
+    /// of the position.
+    ExecutePosition { position_id: u64 },
+    /// Sends the proceeds of an executed swap to the owner of the position and the keeper tip to the
+    /// keeper, recording the execution in the history of the position. This message can only be
+    /// called internally by the contract.
+    SettleExecution {
+        position_id: u64,
+        offer_amount: Uint128,
+        prev_balance: Uint128,
+        keeper: String,
+    },
+    /// Pauses a position, so its swaps can't be executed until it is resumed. Only the owner of
+    /// the position can pause it.
+    PausePosition { position_id: u64 },
+    /// Resumes a paused position. Only the owner of the position can resume it.
+    ResumePosition { position_id: u64 },
+    /// Cancels a position, refunding what's left of the deposit. Only the owner of the position can
+    /// cancel it.
+    CancelPosition { position_id: u64 },
+    /// Updates the configuration of the contract. Only the owner can update it.
+    UpdateConfig {
+        owner: Option<String>,
+        pool_router: Option<String>,
+        keeper_tip: Option<Decimal>,
+    },
+}
+
+#[cw_serde]
+pub enum Cw20HookMsg {
+    /// Creates a position swapping the tokens sent through `operations`, `amount_per_swap` at a
+    /// time every `interval` seconds, at no less than `min_price`
+    CreatePosition {
+        operations: Vec<SwapOperation>,
+        amount_per_swap: Uint128,
+        interval: u64,
+        min_price: Decimal,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Retrieves the configuration of the contract.
+    #[returns(ConfigResponse)]
+    Config {},
+    /// Retrieves a position.
+    #[returns(Position)]
+    Position { position_id: u64 },
+    /// Retrieves the positions created by `owner`.
+    #[returns(PositionsResponse)]
+    PositionsByOwner {
+        owner: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the executions of a position, from the oldest to the newest.
+    #[returns(PositionHistoryResponse)]
+    PositionHistory {
+        position_id: u64,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct Config {
+    pub owner: Addr,
+    /// The pool router the swaps of the positions are executed through
+    pub pool_router: Addr,
+    /// The share of the proceeds of each swap paid to the keeper executing it
+    pub keeper_tip: Decimal,
+}
+
+pub type ConfigResponse = Config;
+
+/// A position swapping a deposit into the ask asset over time
+#[cw_serde]
+pub struct Position {
+    pub position_id: u64,
+    pub owner: Addr,
+    /// What's left of the deposit to swap
+    pub deposit: Asset,
+    pub ask_asset_info: AssetInfo,
+    /// The swap route from the deposit to the ask asset
+    pub operations: Vec<SwapOperation>,
+    pub amount_per_swap: Uint128,
+    /// The time between two swaps, in seconds
+    pub interval: u64,
+    /// The minimum amount of ask asset per unit of deposit each swap is executed at, before the
+    /// keeper tip
+    pub min_price: Decimal,
+    /// The time from which the next swap can be executed
+    pub next_execution: Timestamp,
+    pub paused: bool,
+    /// The number of swaps executed so far
+    pub executions: u64,
+}
+
+/// A swap executed for a position
+#[cw_serde]
+pub struct PositionExecution {
+    /// The index of the execution in the history of the position, starting from 1
+    pub execution_id: u64,
+    pub executed_at: Timestamp,
+    pub offer_amount: Uint128,
+    pub return_amount: Uint128,
+    pub keeper_tip: Uint128,
+}
+
+#[cw_serde]
+pub struct PositionsResponse {
+    pub positions: Vec<Position>,
+}
+
+#[cw_serde]
+pub struct PositionHistoryResponse {
+    pub executions: Vec<PositionExecution>,
+}
+
+/// We currently take no arguments for migrations
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,83 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{StdError, StdResult, Timestamp};
+
+use crate::asset::Asset;
+
+/// The default length of an epoch, a week.
+pub const DEFAULT_EPOCH_LENGTH: u64 = 604_800u64;
+
+/// Defines the epochs the fees of a contract are bucketed by. Epoch `n` spans from
+/// `genesis_time + n * epoch_length` to `genesis_time + (n + 1) * epoch_length`.
+///
+/// Contracts whose fees are aggregated by epoch, i.e. by the fee collector, are expected to share
+/// the same epoch config.
+#[cw_serde]
+pub struct EpochConfig {
+    /// The length of an epoch, in seconds
+    pub epoch_length: u64,
+    /// The time the first epoch starts at
+    pub genesis_time: Timestamp,
+}
+
+impl Default for EpochConfig {
+    fn default() -> Self {
+        EpochConfig {
+            epoch_length: DEFAULT_EPOCH_LENGTH,
+            genesis_time: Timestamp::from_seconds(0u64),
+        }
+    }
+}
+
+impl EpochConfig {
+    pub fn validate(&self) -> StdResult<()> {
+        if self.epoch_length == 0u64 {
+            return Err(StdError::generic_err("The epoch length must be over zero"));
+        }
+
+        Ok(())
+    }
+
+    /// Gets the epoch the given time falls in. Times before the genesis fall in the first epoch.
+    pub fn epoch(&self, time: Timestamp) -> u64 {
+        time.seconds().saturating_sub(self.genesis_time.seconds()) / self.epoch_length
+    }
+}
+
+/// The fees accrued by a contract in an epoch
+#[cw_serde]
+pub struct EpochFees {
+    pub epoch: u64,
+    pub protocol_fees: Vec<Asset>,
+    pub burned_fees: Vec<Asset>,
+}
+
+#[cw_serde]
+pub struct ProtocolFeesByEpochResponse {
+    /// The epoch the contract is currently in
+    pub current_epoch: u64,
+    pub epoch_config: EpochConfig,
+    /// The fees accrued in the epochs queried, for the epochs any fees were accrued in
+    pub epochs: Vec<EpochFees>,
+}
+
+/// Validates the range of epochs to query, `start_epoch` and `end_epoch` included.
+pub fn validate_epoch_range(start_epoch: u64, end_epoch: u64) -> StdResult<()> {
+    if start_epoch > end_epoch {
+        return Err(StdError::generic_err(format!(
+            "Invalid epoch range, start epoch {} is after end epoch {}",
+            start_epoch, end_epoch
+        )));
+    }
+
+    Ok(())
+}
+
+/// Adds the `fee` to the `fees` of an epoch, accumulating the amounts of the same asset.
+pub fn add_epoch_fee(fees: &mut Vec<Asset>, fee: Asset) -> StdResult<()> {
+    match fees.iter_mut().find(|asset| asset.info == fee.info) {
+        Some(asset) => asset.amount = asset.amount.checked_add(fee.amount)?,
+        None => fees.push(fee),
+    }
+
+    Ok(())
+}
@@ -1,7 +1,12 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Uint128};
 
-use crate::asset::{AssetInfo, PairInfo, PairType};
+use crate::asset::{Asset, AssetInfo, PairInfo, PairType};
+use crate::burn_fee::BurnFeeTarget;
+use crate::epoch::EpochConfig;
+use crate::lp_token::LpTokenType;
 use crate::pair::{FeatureToggle, PoolFee};
+use crate::token::LpTokenInfo;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -20,31 +25,67 @@ pub enum ExecuteMsg {
         token_code_id: Option<u64>,
         pair_code_id: Option<u64>,
     },
-    /// Updates a pair config
+    /// Updates a pair config. The `epoch_config` of a pair can only be set once.
     UpdatePairConfig {
         pair_addr: String,
         owner: Option<String>,
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
+        burn_fee_target: Option<BurnFeeTarget>,
+        epoch_config: Option<EpochConfig>,
     },
-    /// Instantiates pair contract
+    /// Updates the name, symbol and/or marketing info of the LP token of a pair
+    UpdatePairLpTokenInfo {
+        pair_addr: String,
+        lp_token_info: LpTokenInfo,
+    },
+    /// Instantiates pair contract. Multiple pairs can be created for the same assets as long as
+    /// they differ in pair type or fee tier, i.e. the swap fee of the pair at creation.
     CreatePair {
         /// Asset infos
         asset_infos: [AssetInfo; 2],
         pool_fees: PoolFee,
         /// The variant of pair to create
         pair_type: PairType,
+        /// The kind of LP token of the pair, a cw20 token if not provided
+        lp_token_type: Option<LpTokenType>,
+        /// The name, symbol and marketing info of the LP token. The name and symbol are derived
+        /// from the assets if not provided.
+        lp_token_info: Option<LpTokenInfo>,
     },
-    /// Adds native token info to the contract so it can instantiate pair contracts that include it
+    /// Adds native token info to the contract so it can instantiate pair contracts that include it.
+    /// Adding a native token that was already added updates its decimals.
     AddNativeTokenDecimals { denom: String, decimals: u8 },
+    /// Adds the info of multiple native tokens to the contract at once
+    AddNativeTokenDecimalsBatch {
+        native_tokens: Vec<NativeTokenDecimals>,
+    },
+    /// Removes native token info from the contract
+    RemoveNativeTokenDecimals { denom: String },
     /// Migrates a pair contract to a given code_id
     MigratePair {
         contract: String,
         code_id: Option<u64>,
     },
-    /// Removes pair contract given asset infos
-    RemovePair { asset_infos: [AssetInfo; 2] },
+    /// Migrates a batch of `limit` pair contracts to a given code_id, starting after the pair with
+    /// the given `start_after` asset infos. If no `start_after` is provided, the migration resumes
+    /// from the last pair migrated to the same code_id, or starts from the first pair.
+    MigratePairs {
+        code_id: Option<u64>,
+        start_after: Option<[AssetInfo; 2]>,
+        limit: Option<u32>,
+    },
+    /// Moves a batch of `limit` pairs to the key of their fee tier, resuming where the previous
+    /// batch stopped. Required once after migrating the factory from a version prior to v1.5.0.
+    MigratePairKeys { limit: Option<u32> },
+    /// Removes pair contract given asset infos. If the assets have multiple pairs, the pair type
+    /// and fee tier are required to match a single one.
+    RemovePair {
+        asset_infos: [AssetInfo; 2],
+        pair_type: Option<PairType>,
+        fee_tier: Option<Decimal>,
+    },
 }
 
 #[cw_serde]
@@ -53,9 +94,25 @@ pub enum QueryMsg {
     /// Retrieves the configuration of the factory.
     #[returns(ConfigResponse)]
     Config {},
-    /// Retrieves the info for the pair with the given asset_infos.
+    /// Retrieves the info for the pair with the given asset_infos. If the assets have multiple
+    /// pairs, the pair type and fee tier are required to match a single one.
     #[returns(PairInfo)]
-    Pair { asset_infos: [AssetInfo; 2] },
+    Pair {
+        asset_infos: [AssetInfo; 2],
+        pair_type: Option<PairType>,
+        fee_tier: Option<Decimal>,
+    },
+    /// Retrieves the pairs containing the given asset. This query has pagination enabled, same as
+    /// [QueryMsg::Pairs].
+    #[returns(PairsResponse)]
+    PairsByAsset {
+        asset_info: AssetInfo,
+        start_after: Option<[AssetInfo; 2]>,
+        limit: Option<u32>,
+    },
+    /// Retrieves all the pairs for the given asset_infos, along with their fee tiers.
+    #[returns(AssetPairsResponse)]
+    AssetPairs { asset_infos: [AssetInfo; 2] },
     /// Retrieves the pairs created by the factory. This query has pagination enabled, querying ten
     /// items by default if not specified otherwise. The max amount of items that can be queried at
     /// once is 30. `start_after` is the last asset_info of a page.
@@ -64,9 +121,33 @@ pub enum QueryMsg {
         start_after: Option<[AssetInfo; 2]>,
         limit: Option<u32>,
     },
-    /// Retrieves the decimals for the given native or ibc denom.
+    /// Retrieves the pairs created by the factory along with their reserves, total LP supply,
+    /// fees, feature toggle and accrued protocol fees. The pairs whose state can't be queried are
+    /// returned with the error instead. This query has pagination enabled, same as
+    /// [QueryMsg::Pairs].
+    #[returns(PairsDetailedResponse)]
+    PairsDetailed {
+        start_after: Option<[AssetInfo; 2]>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the decimals for the given native or ibc denom, resolved from the decimals added to
+    /// the contract if any, or from the bank metadata of the denom otherwise.
     #[returns(NativeTokenDecimalsResponse)]
     NativeTokenDecimals { denom: String },
+    /// Retrieves the status of the pairs batch migration and the code ids the pairs are on. The
+    /// code ids are paginated, same as [QueryMsg::Pairs].
+    #[returns(MigrationStatusResponse)]
+    MigrationStatus {
+        start_after: Option<[AssetInfo; 2]>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the code id and the cw2 contract name and version of the pairs created by the
+    /// factory. This query has pagination enabled, same as [QueryMsg::Pairs].
+    #[returns(ChildrenVersionsResponse)]
+    ChildrenVersions {
+        start_after: Option<[AssetInfo; 2]>,
+        limit: Option<u32>,
+    },
 }
 
 // We define a custom struct for each query response
@@ -88,7 +169,86 @@ pub struct PairsResponse {
     pub pairs: Vec<PairInfo>,
 }
 
+#[cw_serde]
+pub struct PairsDetailedResponse {
+    pub pairs: Vec<PairDetailed>,
+}
+
+#[cw_serde]
+pub struct PairDetailed {
+    pub pair_info: PairInfo,
+    /// The state of the pair, or `None` if it couldn't be queried
+    pub details: Option<PairDetails>,
+    /// The error querying the state of the pair, if it couldn't be queried, e.g. when the pair is
+    /// on a version answering the queries differently
+    pub error: Option<String>,
+}
+
+#[cw_serde]
+pub struct PairDetails {
+    /// The reserves of the pair
+    pub assets: Vec<Asset>,
+    /// The total supply of the LP token of the pair
+    pub total_share: Uint128,
+    pub pool_fees: PoolFee,
+    pub feature_toggle: FeatureToggle,
+    /// The protocol fees accrued by the pair that have not been collected yet
+    pub protocol_fees: Vec<Asset>,
+}
+
+#[cw_serde]
+pub struct AssetPairsResponse {
+    pub pairs: Vec<AssetPair>,
+}
+
+#[cw_serde]
+pub struct AssetPair {
+    pub pair_info: PairInfo,
+    /// The swap fee of the pair at creation, distinguishing it from other pairs of the same assets
+    /// and pair type
+    pub fee_tier: Decimal,
+}
+
+#[cw_serde]
+pub struct NativeTokenDecimals {
+    pub denom: String,
+    pub decimals: u8,
+}
+
 #[cw_serde]
 pub struct NativeTokenDecimalsResponse {
     pub decimals: u8,
 }
+
+#[cw_serde]
+pub struct MigrationStatusResponse {
+    /// The code id the in progress batch migration is migrating the pairs to, if any
+    pub code_id: Option<u64>,
+    /// The asset infos of the last pair migrated by the in progress batch migration
+    pub last_migrated: Option<[AssetInfo; 2]>,
+    /// The pairs grouped by the code id they are on
+    pub code_ids: Vec<CodeIdPairs>,
+}
+
+#[cw_serde]
+pub struct CodeIdPairs {
+    pub code_id: u64,
+    pub pairs: Vec<String>,
+}
+
+#[cw_serde]
+pub struct ChildrenVersionsResponse {
+    pub children: Vec<ChildVersion>,
+}
+
+#[cw_serde]
+pub struct ChildVersion {
+    pub contract_addr: String,
+    pub asset_infos: [AssetInfo; 2],
+    /// The code id the pair is on
+    pub code_id: u64,
+    /// The cw2 contract name of the pair
+    pub contract: String,
+    /// The cw2 contract version of the pair
+    pub version: String,
+}
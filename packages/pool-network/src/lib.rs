@@ -1,5 +1,11 @@
 pub mod asset;
+pub mod auto_compounder;
+pub mod burn_fee;
+pub mod dca;
+pub mod epoch;
 pub mod factory;
+pub mod limit_order;
+pub mod lp_token;
 pub mod pair;
 pub mod querier;
 pub mod router;
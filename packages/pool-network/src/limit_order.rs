@@ -0,0 +1,121 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+use crate::asset::{Asset, AssetInfo};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The pool factory the pairs orders are placed against have to be registered in
+    pub factory_addr: String,
+    /// The share of the proceeds of an order paid to the keeper executing it
+    pub keeper_tip: Decimal,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Used to trigger the [Cw20HookMsg] messages
+    Receive(Cw20ReceiveMsg),
+    /// Places an order selling the native `offer_asset` sent along in the pair at `pair_address`,
+    /// once the pair returns at least `price` ask asset per unit of offer asset. The pair has to be
+    /// registered in the pool factory. Orders selling cw20 tokens are placed with
+    /// [Cw20HookMsg::PlaceOrder] instead.
+    PlaceOrder {
+        offer_asset: Asset,
+        pair_address: String,
+        price: Decimal,
+    },
+    /// Cancels an order, refunding the escrowed offer asset. Only the owner of the order can
+    /// cancel it.
+    CancelOrder { order_id: u64 },
+    /// Executes an order whose target price is met by the pair, paying the keeper tip to the sender
+    /// out of the proceeds. Anyone can execute an order.
+    ExecuteOrder { order_id: u64 },
+    /// Sends the proceeds of an executed order to its owner and the keeper tip to the keeper. This
+    /// message can only be called internally by the contract.
+    SettleOrder {
+        order_id: u64,
+        prev_balance: Uint128,
+        keeper: String,
+    },
+    /// Updates the configuration of the contract. Only the owner can update it.
+    UpdateConfig {
+        owner: Option<String>,
+        keeper_tip: Option<Decimal>,
+    },
+}
+
+#[cw_serde]
+pub enum Cw20HookMsg {
+    /// Places an order selling the tokens sent in the pair at `pair_address`, once the pair returns
+    /// at least `price` ask asset per unit of offer asset
+    PlaceOrder {
+        pair_address: String,
+        price: Decimal,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Retrieves the configuration of the contract.
+    #[returns(ConfigResponse)]
+    Config {},
+    /// Retrieves an order.
+    #[returns(Order)]
+    Order { order_id: u64 },
+    /// Retrieves the orders placed by `owner`.
+    #[returns(OrdersResponse)]
+    OrdersByOwner {
+        owner: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the orders placed against the pair at `pair_address`.
+    #[returns(OrdersResponse)]
+    OrdersByPair {
+        pair_address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct Config {
+    pub owner: Addr,
+    /// The pool factory the pairs orders are placed against have to be registered in
+    pub factory_addr: Addr,
+    /// The share of the proceeds of an order paid to the keeper executing it
+    pub keeper_tip: Decimal,
+}
+
+pub type ConfigResponse = Config;
+
+/// A resting order selling the escrowed `offer_asset` in a pair
+#[cw_serde]
+pub struct Order {
+    pub order_id: u64,
+    pub owner: Addr,
+    pub pair_address: Addr,
+    pub offer_asset: Asset,
+    pub ask_asset_info: AssetInfo,
+    /// The minimum amount of ask asset per unit of offer asset the order is executed at, before the
+    /// keeper tip
+    pub price: Decimal,
+}
+
+impl Order {
+    /// The minimum amount of ask asset the swap of the order has to return
+    pub fn minimum_return(&self) -> Uint128 {
+        self.offer_asset.amount * self.price
+    }
+}
+
+#[cw_serde]
+pub struct OrdersResponse {
+    pub orders: Vec<Order>,
+}
+
+/// We currently take no arguments for migrations
+#[cw_serde]
+pub struct MigrateMsg {}
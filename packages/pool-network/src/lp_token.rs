@@ -0,0 +1,236 @@
+use cosmwasm_schema::cw_serde;
+#[cfg(not(feature = "token_factory"))]
+use cosmwasm_std::StdError;
+#[cfg(feature = "token_factory")]
+use cosmwasm_std::{coins, BankMsg, Binary};
+use cosmwasm_std::{to_binary, Addr, CosmosMsg, QuerierWrapper, StdResult, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+
+use crate::asset::AssetInfo;
+use crate::querier::query_token_info;
+
+/// The subdenom of the LP tokens created with the token factory module
+pub const LP_SUBDENOM: &str = "uLP";
+
+#[cfg(all(feature = "token_factory", not(feature = "injective")))]
+const TOKEN_FACTORY_PREFIX: &str = "/osmosis.tokenfactory.v1beta1";
+#[cfg(all(feature = "token_factory", feature = "injective"))]
+const TOKEN_FACTORY_PREFIX: &str = "/injective.tokenfactory.v1beta1";
+
+/// The kind of LP token issued by a pool or a vault
+#[cw_serde]
+pub enum LpTokenType {
+    /// A cw20 token instantiated from the given token code id. This is the default.
+    Cw20,
+    /// A native token created with the token factory module of the chain. Requires the
+    /// `token_factory` feature.
+    TokenFactory,
+}
+
+/// Gets the denom of the LP token created by the given contract with the token factory module
+pub fn get_lp_denom(contract_addr: &str) -> String {
+    format!("factory/{}/{}", contract_addr, LP_SUBDENOM)
+}
+
+/// Queries the total supply of the given LP token
+pub fn query_lp_total_supply(
+    querier: &QuerierWrapper,
+    liquidity_token: &AssetInfo,
+) -> StdResult<Uint128> {
+    match liquidity_token {
+        AssetInfo::Token { contract_addr } => {
+            Ok(query_token_info(querier, Addr::unchecked(contract_addr))?.total_supply)
+        }
+        #[cfg(feature = "token_factory")]
+        AssetInfo::NativeToken { denom } => Ok(querier.query_supply(denom)?.amount),
+        #[cfg(not(feature = "token_factory"))]
+        AssetInfo::NativeToken { .. } => Err(token_factory_not_enabled()),
+    }
+}
+
+/// Creates the messages minting `amount` LP tokens to the recipient. Token factory LP tokens are
+/// minted to the contract and then sent to the recipient, as not all chains support minting to a
+/// different address.
+#[cfg_attr(not(feature = "token_factory"), allow(unused_variables))]
+pub fn mint_lp_token_msgs(
+    liquidity_token: &AssetInfo,
+    contract_addr: &Addr,
+    recipient: String,
+    amount: Uint128,
+) -> StdResult<Vec<CosmosMsg>> {
+    match liquidity_token {
+        AssetInfo::Token {
+            contract_addr: lp_token_addr,
+        } => Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: lp_token_addr.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Mint { recipient, amount })?,
+            funds: vec![],
+        })]),
+        #[cfg(feature = "token_factory")]
+        AssetInfo::NativeToken { denom } => {
+            let mut messages = vec![mint_denom_msg(contract_addr, denom, amount)];
+            if recipient != contract_addr.as_str() {
+                messages.push(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: recipient,
+                    amount: coins(amount.u128(), denom),
+                }));
+            }
+
+            Ok(messages)
+        }
+        #[cfg(not(feature = "token_factory"))]
+        AssetInfo::NativeToken { .. } => Err(token_factory_not_enabled()),
+    }
+}
+
+/// Creates the message burning `amount` LP tokens held by the contract
+#[cfg_attr(not(feature = "token_factory"), allow(unused_variables))]
+pub fn burn_lp_token_msg(
+    liquidity_token: &AssetInfo,
+    contract_addr: &Addr,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    match liquidity_token {
+        AssetInfo::Token {
+            contract_addr: lp_token_addr,
+        } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: lp_token_addr.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Burn { amount })?,
+            funds: vec![],
+        })),
+        #[cfg(feature = "token_factory")]
+        AssetInfo::NativeToken { denom } => Ok(burn_denom_msg(contract_addr, denom, amount)),
+        #[cfg(not(feature = "token_factory"))]
+        AssetInfo::NativeToken { .. } => Err(token_factory_not_enabled()),
+    }
+}
+
+/// Creates the token factory message creating the LP denom of the given contract, i.e.
+/// `factory/{contract_addr}/uLP`
+#[cfg(feature = "token_factory")]
+pub fn create_lp_denom_msg(contract_addr: &Addr) -> StdResult<CosmosMsg> {
+    let mut value = encode_string(1, contract_addr.as_str());
+    value.extend(encode_string(2, LP_SUBDENOM));
+
+    Ok(CosmosMsg::Stargate {
+        type_url: format!("{}.MsgCreateDenom", TOKEN_FACTORY_PREFIX),
+        value: Binary(value),
+    })
+}
+
+/// Creates the message creating the LP denom of the given contract. Fails as the token factory
+/// is not enabled on this chain.
+#[cfg(not(feature = "token_factory"))]
+pub fn create_lp_denom_msg(_contract_addr: &Addr) -> StdResult<CosmosMsg> {
+    Err(token_factory_not_enabled())
+}
+
+#[cfg(not(feature = "token_factory"))]
+fn token_factory_not_enabled() -> StdError {
+    StdError::generic_err("Token factory LP tokens are not supported on this chain")
+}
+
+#[cfg(feature = "token_factory")]
+fn mint_denom_msg(sender: &Addr, denom: &str, amount: Uint128) -> CosmosMsg {
+    CosmosMsg::Stargate {
+        type_url: format!("{}.MsgMint", TOKEN_FACTORY_PREFIX),
+        value: Binary(encode_sender_and_coin(sender, denom, amount)),
+    }
+}
+
+#[cfg(feature = "token_factory")]
+fn burn_denom_msg(sender: &Addr, denom: &str, amount: Uint128) -> CosmosMsg {
+    CosmosMsg::Stargate {
+        type_url: format!("{}.MsgBurn", TOKEN_FACTORY_PREFIX),
+        value: Binary(encode_sender_and_coin(sender, denom, amount)),
+    }
+}
+
+/// Encodes the body shared by MsgMint and MsgBurn, i.e. the sender as field 1 and the coin as
+/// field 2
+#[cfg(feature = "token_factory")]
+fn encode_sender_and_coin(sender: &Addr, denom: &str, amount: Uint128) -> Vec<u8> {
+    let mut coin = encode_string(1, denom);
+    coin.extend(encode_string(2, &amount.to_string()));
+
+    let mut value = encode_string(1, sender.as_str());
+    value.extend(encode_bytes(2, &coin));
+    value
+}
+
+#[cfg(feature = "token_factory")]
+fn encode_string(field: u8, value: &str) -> Vec<u8> {
+    encode_bytes(field, value.as_bytes())
+}
+
+/// Encodes a length-delimited protobuf field
+#[cfg(feature = "token_factory")]
+fn encode_bytes(field: u8, value: &[u8]) -> Vec<u8> {
+    let mut data = vec![field << 3 | 2];
+
+    // length of the value as a varint
+    let mut len = value.len();
+    while len >= 0x80 {
+        data.push((len as u8 & 0x7f) | 0x80);
+        len >>= 7;
+    }
+    data.push(len as u8);
+
+    data.extend_from_slice(value);
+    data
+}
+
+#[cfg(all(test, feature = "token_factory"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_token_factory_messages() {
+        let contract_addr = Addr::unchecked("pair");
+
+        match create_lp_denom_msg(&contract_addr).unwrap() {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert!(type_url.ends_with(".MsgCreateDenom"));
+                assert_eq!(
+                    value,
+                    Binary(vec![
+                        0x0a, 4, b'p', b'a', b'i', b'r', 0x12, 3, b'u', b'L', b'P'
+                    ])
+                );
+            }
+            _ => panic!("unexpected message"),
+        }
+
+        let denom = get_lp_denom(contract_addr.as_str());
+        assert_eq!(denom, "factory/pair/uLP");
+
+        let msgs = mint_lp_token_msgs(
+            &AssetInfo::NativeToken {
+                denom: denom.clone(),
+            },
+            &contract_addr,
+            "alice".to_string(),
+            Uint128::new(10),
+        )
+        .unwrap();
+        assert_eq!(msgs.len(), 2);
+        match &msgs[0] {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert!(type_url.ends_with(".MsgMint"));
+
+                let mut expected = vec![0x0a, 4, b'p', b'a', b'i', b'r', 0x12, 22, 0x0a, 16];
+                expected.extend_from_slice(denom.as_bytes());
+                expected.extend_from_slice(&[0x12, 2, b'1', b'0']);
+                assert_eq!(value.0, expected);
+            }
+            _ => panic!("unexpected message"),
+        }
+        assert_eq!(
+            msgs[1],
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "alice".to_string(),
+                amount: coins(10u128, denom),
+            })
+        );
+    }
+}
@@ -1,17 +1,24 @@
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    from_binary, from_slice, to_binary, Coin, ContractInfoResponse, ContractResult, Empty,
-    OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
+    from_binary, from_slice, to_binary, Addr, Coin, ContractInfoResponse, ContractResult, Decimal,
+    Decimal256, Empty, OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult,
+    Uint128, WasmQuery,
 };
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::panic;
 
-use crate::asset::{AssetInfo, PairInfo, PairType};
+use crate::asset::{Asset, AssetInfo, PairInfo, PairType};
+use crate::burn_fee::BurnFeeTarget;
 use crate::factory::{NativeTokenDecimalsResponse, QueryMsg as FactoryQueryMsg};
 use crate::pair::QueryMsg as PairQueryMsg;
-use crate::pair::{ReverseSimulationResponse, SimulationResponse};
+use crate::pair::{
+    Config as PairConfig, FeatureToggle, PoolFee, PoolResponse, ProtocolFeesResponse,
+    ReverseSimulationResponse, SimulationResponse,
+};
+use crate::router::{QueryMsg as RouterQueryMsg, SwapOperation};
 use cw20::{BalanceResponse as Cw20BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
+use nico_teen::fee::Fee;
 
 use std::iter::FromIterator;
 
@@ -35,6 +42,7 @@ pub struct WasmMockQuerier {
     base: MockQuerier,
     token_querier: TokenQuerier,
     pool_factory_querier: PoolFactoryQuerier,
+    unavailable_contracts: Vec<String>,
 }
 
 #[derive(Clone, Default)]
@@ -121,8 +129,15 @@ impl Querier for WasmMockQuerier {
 impl WasmMockQuerier {
     pub fn handle_query(&self, request: &QueryRequest<Empty>) -> QuerierResult {
         match &request {
+            QueryRequest::Wasm(WasmQuery::Smart { contract_addr, .. })
+                if self.unavailable_contracts.contains(contract_addr) =>
+            {
+                SystemResult::Err(SystemError::NoSuchContract {
+                    addr: contract_addr.to_string(),
+                })
+            }
             QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => match from_binary(msg) {
-                Ok(FactoryQueryMsg::Pair { asset_infos }) => {
+                Ok(FactoryQueryMsg::Pair { asset_infos, .. }) => {
                     let key = [asset_infos[0].to_string(), asset_infos[1].to_string()].join("");
                     let mut sort_key: Vec<char> = key.chars().collect();
                     sort_key.sort_by(|a, b| b.cmp(a));
@@ -165,10 +180,64 @@ impl WasmMockQuerier {
                             ],
                             asset_decimals: [6u8, 6u8],
                             contract_addr: "pair0000".to_string(),
-                            liquidity_token: "liquidity0000".to_string(),
+                            liquidity_token: AssetInfo::Token {
+                                contract_addr: "liquidity0000".to_string(),
+                            },
                             pair_type: PairType::ConstantProduct,
                         })))
                     }
+                    Ok(PairQueryMsg::Pool {}) => {
+                        SystemResult::Ok(ContractResult::from(to_binary(&PoolResponse {
+                            assets: vec![
+                                Asset {
+                                    info: AssetInfo::NativeToken {
+                                        denom: "uluna".to_string(),
+                                    },
+                                    amount: Uint128::new(1_000_000u128),
+                                },
+                                Asset {
+                                    info: AssetInfo::NativeToken {
+                                        denom: "uluna".to_string(),
+                                    },
+                                    amount: Uint128::new(1_000_000u128),
+                                },
+                            ],
+                            total_share: Uint128::new(1_000_000u128),
+                        })))
+                    }
+                    Ok(PairQueryMsg::Config {}) => {
+                        SystemResult::Ok(ContractResult::from(to_binary(&PairConfig {
+                            owner: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                            fee_collector_addr: Addr::unchecked("collector"),
+                            pool_fees: PoolFee {
+                                protocol_fee: Fee {
+                                    share: Decimal::permille(1u64),
+                                },
+                                swap_fee: Fee {
+                                    share: Decimal::permille(3u64),
+                                },
+                                burn_fee: Fee {
+                                    share: Decimal::zero(),
+                                },
+                            },
+                            feature_toggle: FeatureToggle {
+                                withdrawals_enabled: true,
+                                deposits_enabled: true,
+                                swaps_enabled: true,
+                            },
+                            burn_fee_target: BurnFeeTarget::Burn,
+                        })))
+                    }
+                    Ok(PairQueryMsg::ProtocolFees { .. }) => {
+                        SystemResult::Ok(ContractResult::from(to_binary(&ProtocolFeesResponse {
+                            fees: vec![Asset {
+                                info: AssetInfo::NativeToken {
+                                    denom: "uluna".to_string(),
+                                },
+                                amount: Uint128::new(100u128),
+                            }],
+                        })))
+                    }
                     Ok(PairQueryMsg::Simulation { offer_asset }) => {
                         SystemResult::Ok(ContractResult::from(to_binary(&SimulationResponse {
                             return_amount: offer_asset.amount,
@@ -176,6 +245,9 @@ impl WasmMockQuerier {
                             spread_amount: Uint128::zero(),
                             protocol_fee_amount: Uint128::zero(),
                             burn_fee_amount: Uint128::zero(),
+                            pre_trade_spot_price: Some(Decimal256::one()),
+                            post_trade_spot_price: Some(Decimal256::one()),
+                            price_impact: Some(Decimal256::zero()),
                         })))
                     }
                     Ok(PairQueryMsg::ReverseSimulation { ask_asset }) => SystemResult::Ok(
@@ -185,76 +257,100 @@ impl WasmMockQuerier {
                             spread_amount: Uint128::zero(),
                             protocol_fee_amount: Uint128::zero(),
                             burn_fee_amount: Uint128::zero(),
+                            pre_trade_spot_price: Some(Decimal256::one()),
+                            post_trade_spot_price: Some(Decimal256::one()),
+                            price_impact: Some(Decimal256::zero()),
                         })),
                     ),
-                    _ => match from_binary(msg).unwrap() {
-                        Cw20QueryMsg::TokenInfo {} => {
-                            let balances: &HashMap<String, Uint128> =
-                                match self.token_querier.balances.get(contract_addr) {
-                                    Some(balances) => balances,
-                                    None => {
-                                        return SystemResult::Err(SystemError::InvalidRequest {
-                                            error: format!(
-                                                "No balance info exists for the contract {}",
-                                                contract_addr
-                                            ),
-                                            request: msg.as_slice().into(),
-                                        })
-                                    }
-                                };
-
-                            let mut total_supply = Uint128::zero();
+                    _ => match from_binary(msg) {
+                        Ok(RouterQueryMsg::SwapRoute {
+                            offer_asset_info,
+                            ask_asset_info,
+                        }) => SystemResult::Ok(ContractResult::from(to_binary(&vec![
+                            SwapOperation::TerraSwap {
+                                offer_asset_info,
+                                ask_asset_info,
+                                pair_type: None,
+                                fee_tier: None,
+                            },
+                        ]))),
+                        _ => match from_binary(msg).unwrap() {
+                            Cw20QueryMsg::TokenInfo {} => {
+                                let balances: &HashMap<String, Uint128> =
+                                    match self.token_querier.balances.get(contract_addr) {
+                                        Some(balances) => balances,
+                                        None => {
+                                            return SystemResult::Err(SystemError::InvalidRequest {
+                                                error: format!(
+                                                    "No balance info exists for the contract {}",
+                                                    contract_addr
+                                                ),
+                                                request: msg.as_slice().into(),
+                                            })
+                                        }
+                                    };
+
+                                let mut total_supply = Uint128::zero();
+
+                                for balance in balances {
+                                    total_supply += *balance.1;
+                                }
 
-                            for balance in balances {
-                                total_supply += *balance.1;
+                                SystemResult::Ok(ContractResult::Ok(
+                                    to_binary(&TokenInfoResponse {
+                                        name: "mAAPL".to_string(),
+                                        symbol: "mAAPL".to_string(),
+                                        decimals: 8,
+                                        total_supply,
+                                    })
+                                    .unwrap(),
+                                ))
                             }
-
-                            SystemResult::Ok(ContractResult::Ok(
-                                to_binary(&TokenInfoResponse {
-                                    name: "mAAPL".to_string(),
-                                    symbol: "mAAPL".to_string(),
-                                    decimals: 8,
-                                    total_supply,
-                                })
-                                .unwrap(),
-                            ))
-                        }
-                        Cw20QueryMsg::Balance { address } => {
-                            let balances: &HashMap<String, Uint128> =
-                                match self.token_querier.balances.get(contract_addr) {
-                                    Some(balances) => balances,
+                            Cw20QueryMsg::Balance { address } => {
+                                let balances: &HashMap<String, Uint128> =
+                                    match self.token_querier.balances.get(contract_addr) {
+                                        Some(balances) => balances,
+                                        None => {
+                                            return SystemResult::Err(SystemError::InvalidRequest {
+                                                error: format!(
+                                                    "No balance info exists for the contract {}",
+                                                    contract_addr
+                                                ),
+                                                request: msg.as_slice().into(),
+                                            })
+                                        }
+                                    };
+
+                                let balance = match balances.get(&address) {
+                                    Some(v) => *v,
                                     None => {
-                                        return SystemResult::Err(SystemError::InvalidRequest {
-                                            error: format!(
-                                                "No balance info exists for the contract {}",
-                                                contract_addr
-                                            ),
-                                            request: msg.as_slice().into(),
-                                        })
+                                        return SystemResult::Ok(ContractResult::Ok(
+                                            to_binary(&Cw20BalanceResponse {
+                                                balance: Uint128::zero(),
+                                            })
+                                            .unwrap(),
+                                        ));
                                     }
                                 };
 
-                            let balance = match balances.get(&address) {
-                                Some(v) => *v,
-                                None => {
-                                    return SystemResult::Ok(ContractResult::Ok(
-                                        to_binary(&Cw20BalanceResponse {
-                                            balance: Uint128::zero(),
-                                        })
-                                        .unwrap(),
-                                    ));
-                                }
-                            };
-
-                            SystemResult::Ok(ContractResult::Ok(
-                                to_binary(&Cw20BalanceResponse { balance }).unwrap(),
-                            ))
-                        }
+                                SystemResult::Ok(ContractResult::Ok(
+                                    to_binary(&Cw20BalanceResponse { balance }).unwrap(),
+                                ))
+                            }
 
-                        _ => panic!("DO NOT ENTER HERE"),
+                            _ => panic!("DO NOT ENTER HERE"),
+                        },
                     },
                 },
             },
+            QueryRequest::Wasm(WasmQuery::Raw { key, .. })
+                if key.as_slice() == b"contract_info" =>
+            {
+                // the cw2 contract version
+                SystemResult::Ok(ContractResult::Ok(
+                    br#"{"contract":"mock_contract","version":"1.0.0"}"#.to_vec().into(),
+                ))
+            }
             QueryRequest::Wasm(WasmQuery::ContractInfo { .. }) => {
                 let mut contract_info_response = ContractInfoResponse::new(0, "creator");
                 contract_info_response.admin = Some("creator".to_string());
@@ -274,6 +370,7 @@ impl WasmMockQuerier {
             base,
             token_querier: TokenQuerier::default(),
             pool_factory_querier: PoolFactoryQuerier::default(),
+            unavailable_contracts: vec![],
         }
     }
 
@@ -291,6 +388,11 @@ impl WasmMockQuerier {
         self.pool_factory_querier = PoolFactoryQuerier::new(pairs, native_token_decimals);
     }
 
+    // configure the contracts failing the smart queries, e.g. pairs on an incompatible version
+    pub fn with_unavailable_contracts(&mut self, contracts: &[&str]) {
+        self.unavailable_contracts = contracts.iter().map(|addr| addr.to_string()).collect();
+    }
+
     pub fn with_balance(&mut self, balances: &[(&String, Vec<Coin>)]) {
         for (addr, balance) in balances {
             self.base.update_balance(addr.to_string(), balance.clone());
@@ -329,6 +431,8 @@ mod mock_exception {
                     denom: "ulunc".to_string(),
                 },
             ],
+            pair_type: None,
+            fee_tier: None,
         })
         .unwrap();
         assert_eq!(
@@ -1,10 +1,14 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal, StdError, StdResult, Uint128};
+use cosmwasm_std::{Addr, Decimal, Decimal256, StdError, StdResult, Uint128};
 use cw20::Cw20ReceiveMsg;
 
 use nico_teen::fee::Fee;
 
 use crate::asset::{Asset, AssetInfo, PairInfo, PairType};
+use crate::burn_fee::BurnFeeTarget;
+use crate::epoch::{EpochConfig, ProtocolFeesByEpochResponse};
+use crate::lp_token::LpTokenType;
+use crate::token::LpTokenInfo;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -17,6 +21,12 @@ pub struct InstantiateMsg {
     pub fee_collector_addr: String,
     /// The type of pair to create
     pub pair_type: PairType,
+    /// The kind of LP token to issue, a cw20 token instantiated from `token_code_id` if not
+    /// provided
+    pub lp_token_type: Option<LpTokenType>,
+    /// The name, symbol and marketing info of the cw20 LP token. The name and symbol are derived
+    /// from the assets of the pair if not provided.
+    pub lp_token_info: Option<LpTokenInfo>,
 }
 
 #[cw_serde]
@@ -36,15 +46,35 @@ pub enum ExecuteMsg {
         max_spread: Option<Decimal>,
         to: Option<String>,
     },
-    /// Updates the pair pool config
+    /// Updates the pair pool config. The `epoch_config` can only be set once.
     UpdateConfig {
         owner: Option<String>,
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
+        burn_fee_target: Option<BurnFeeTarget>,
+        epoch_config: Option<EpochConfig>,
     },
     /// Collects the Protocol fees accrued by the pool
     CollectProtocolFees {},
+    /// Withdraws liquidity with the native LP tokens sent along, for pools issuing token factory
+    /// LP tokens. Pools issuing cw20 LP tokens use [Cw20HookMsg::WithdrawLiquidity] instead.
+    WithdrawLiquidity {},
+    /// Updates the name, symbol and/or marketing info of the cw20 LP token. Only the owner of the
+    /// pair can update them.
+    UpdateLpTokenInfo { lp_token_info: LpTokenInfo },
+    /// Adds the tokens sent to the pool outside of its messages, e.g. donations, to its reserves.
+    Sync {},
+    /// Sends the tokens sent to the pool outside of its messages, e.g. donations, to `to`, so that
+    /// the balances of the pool match its reserves again.
+    Skim { to: String },
+    /// Buys back with the burn fees accrued in `asset_info`, when the burn fee target is
+    /// [BurnFeeTarget::BuyBackAndBurn], receiving at least `minimum_receive` of the buy-back asset.
+    /// Only the keeper of the burn fee target can buy back.
+    BuyBack {
+        asset_info: AssetInfo,
+        minimum_receive: Uint128,
+    },
 }
 
 #[cw_serde]
@@ -80,6 +110,14 @@ pub enum QueryMsg {
     /// Retrieves the fees that have been burned by the pool.
     #[returns(ProtocolFeesResponse)]
     BurnedFees { asset_id: Option<String> },
+    /// Retrieves the burn fees accrued by the pool, waiting to be bought back by the keeper of the
+    /// [BurnFeeTarget::BuyBackAndBurn] burn fee target.
+    #[returns(ProtocolFeesResponse)]
+    BuyBackFees {},
+    /// Retrieves the protocol and burned fees accrued by the pool in each epoch from `start_epoch`
+    /// to `end_epoch`, both included.
+    #[returns(ProtocolFeesByEpochResponse)]
+    ProtocolFeesByEpoch { start_epoch: u64, end_epoch: u64 },
     /// Retrieves the pool information.
     #[returns(PoolResponse)]
     Pool {},
@@ -135,6 +173,8 @@ pub struct Config {
     pub fee_collector_addr: Addr,
     pub pool_fees: PoolFee,
     pub feature_toggle: FeatureToggle,
+    /// Where the burn fees of the pool go
+    pub burn_fee_target: BurnFeeTarget,
 }
 
 pub type ConfigResponse = Config;
@@ -154,6 +194,16 @@ pub struct SimulationResponse {
     pub swap_fee_amount: Uint128,
     pub protocol_fee_amount: Uint128,
     pub burn_fee_amount: Uint128,
+    /// The spot price of the offer asset in terms of the ask asset before the swap, adjusted by the
+    /// decimals of the assets. `None` if the pair is on a version not computing the spot prices.
+    #[serde(default)]
+    pub pre_trade_spot_price: Option<Decimal256>,
+    /// The spot price of the offer asset in terms of the ask asset after the swap
+    #[serde(default)]
+    pub post_trade_spot_price: Option<Decimal256>,
+    /// How much worse the execution price of the swap, before fees, is than the pre-trade spot price
+    #[serde(default)]
+    pub price_impact: Option<Decimal256>,
 }
 
 /// ReverseSimulationResponse returns reverse swap simulation response
@@ -170,6 +220,16 @@ pub struct ReverseSimulationResponse {
     pub swap_fee_amount: Uint128,
     pub protocol_fee_amount: Uint128,
     pub burn_fee_amount: Uint128,
+    /// The spot price of the offer asset in terms of the ask asset before the swap, adjusted by the
+    /// decimals of the assets. `None` if the pair is on a version not computing the spot prices.
+    #[serde(default)]
+    pub pre_trade_spot_price: Option<Decimal256>,
+    /// The spot price of the offer asset in terms of the ask asset after the swap
+    #[serde(default)]
+    pub post_trade_spot_price: Option<Decimal256>,
+    /// How much worse the execution price of the swap, before fees, is than the pre-trade spot price
+    #[serde(default)]
+    pub price_impact: Option<Decimal256>,
 }
 
 /// We currently take no arguments for migrations
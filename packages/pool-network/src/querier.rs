@@ -1,9 +1,9 @@
-use crate::asset::{Asset, AssetInfo, PairInfo};
+use crate::asset::{Asset, AssetInfo, PairInfo, PairType};
 use crate::factory::{NativeTokenDecimalsResponse, QueryMsg as FactoryQueryMsg};
 use crate::pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse};
 
 use cosmwasm_std::{
-    to_binary, Addr, AllBalanceResponse, BalanceResponse, BankQuery, Coin, QuerierWrapper,
+    to_binary, Addr, AllBalanceResponse, BalanceResponse, BankQuery, Coin, Decimal, QuerierWrapper,
     QueryRequest, StdResult, Uint128, WasmQuery,
 };
 
@@ -76,11 +76,15 @@ pub fn query_pair_info(
     querier: &QuerierWrapper,
     factory_contract: Addr,
     asset_infos: &[AssetInfo; 2],
+    pair_type: Option<PairType>,
+    fee_tier: Option<Decimal>,
 ) -> StdResult<PairInfo> {
     querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
         contract_addr: factory_contract.to_string(),
         msg: to_binary(&FactoryQueryMsg::Pair {
             asset_infos: asset_infos.clone(),
+            pair_type,
+            fee_tier,
         })?,
     }))
 }
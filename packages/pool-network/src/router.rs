@@ -1,10 +1,10 @@
 use std::fmt;
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Decimal, Decimal256, Uint128};
 use cw20::Cw20ReceiveMsg;
 
-use crate::asset::AssetInfo;
+use crate::asset::{Asset, AssetInfo, PairType};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -13,9 +13,13 @@ pub struct InstantiateMsg {
 
 #[cw_serde]
 pub enum SwapOperation {
+    /// Swaps through the pair of the given assets. If the assets have multiple pairs, the pair type
+    /// and fee tier are required to select one.
     TerraSwap {
         offer_asset_info: AssetInfo,
         ask_asset_info: AssetInfo,
+        pair_type: Option<PairType>,
+        fee_tier: Option<Decimal>,
     },
 }
 
@@ -33,6 +37,7 @@ impl fmt::Display for SwapOperation {
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+                ..
             } => write!(
                 f,
                 "TerraSwap {{ offer_asset_info: {}, ask_asset_info: {} }}",
@@ -133,6 +138,35 @@ pub struct ConfigResponse {
 #[cw_serde]
 pub struct SimulateSwapOperationsResponse {
     pub amount: Uint128,
+    /// The spot price of the first offer asset in terms of the last ask asset before the swaps,
+    /// i.e. the product of the spot prices of the pools along the route. `None` if any pair along
+    /// the route is on a version not computing the spot prices.
+    #[serde(default)]
+    pub pre_trade_spot_price: Option<Decimal256>,
+    /// The spot price of the first offer asset in terms of the last ask asset after the swaps
+    #[serde(default)]
+    pub post_trade_spot_price: Option<Decimal256>,
+    /// The compounded price impact of the swaps along the route
+    #[serde(default)]
+    pub price_impact: Option<Decimal256>,
+    /// The simulation of each swap operation, in the order of the route
+    #[serde(default)]
+    pub operations: Vec<SwapOperationSimulation>,
+}
+
+/// The simulation of a single swap operation of a route. The spread and fees are denominated in
+/// the ask asset.
+#[cw_serde]
+pub struct SwapOperationSimulation {
+    /// The pair the swap goes through
+    pub pair_address: String,
+    pub offer_asset: Asset,
+    /// The ask asset returned by the pair, after fees
+    pub ask_asset: Asset,
+    pub spread_amount: Uint128,
+    pub swap_fee_amount: Uint128,
+    pub protocol_fee_amount: Uint128,
+    pub burn_fee_amount: Uint128,
 }
 
 /// We currently take no arguments for migrations
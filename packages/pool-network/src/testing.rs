@@ -1,13 +1,16 @@
 use crate::asset::{Asset, AssetInfo, AssetInfoRaw, AssetRaw, PairInfo, PairType};
+use crate::burn_fee::{get_bought_back_amount, BurnFeeTarget};
 use crate::mock_querier::mock_dependencies;
+use crate::pair::SimulationResponse;
 use crate::querier::{
     query_all_balances, query_balance, query_pair_info, query_token_balance, query_token_info,
 };
+use crate::router::{ExecuteMsg as RouterExecuteMsg, SwapOperation};
 
 use cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
 use cosmwasm_std::{
-    coin, to_binary, Addr, Api, BankMsg, Coin, CosmosMsg, MessageInfo, StdError, SubMsg, Uint128,
-    WasmMsg,
+    coin, from_slice, to_binary, Addr, Api, BankMsg, Coin, CosmosMsg, Event, MessageInfo, StdError,
+    SubMsg, Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
 
@@ -392,7 +395,9 @@ fn query_terraswap_pair_contract() {
                     },
                 ],
                 contract_addr: "pair0000".to_string(),
-                liquidity_token: "liquidity0000".to_string(),
+                liquidity_token: AssetInfo::Token {
+                    contract_addr: "liquidity0000".to_string(),
+                },
                 asset_decimals: [6u8, 6u8],
                 pair_type: PairType::ConstantProduct,
             },
@@ -411,11 +416,18 @@ fn query_terraswap_pair_contract() {
                 denom: "uusd".to_string(),
             },
         ],
+        None,
+        None,
     )
     .unwrap();
 
     assert_eq!(pair_info.contract_addr, Addr::unchecked("pair0000"),);
-    assert_eq!(pair_info.liquidity_token, Addr::unchecked("liquidity0000"),);
+    assert_eq!(
+        pair_info.liquidity_token,
+        AssetInfo::Token {
+            contract_addr: "liquidity0000".to_string(),
+        }
+    );
 }
 
 #[test]
@@ -469,3 +481,165 @@ fn get_peggy_asset_label() {
     let asset_label = not_peggy_asset_info.get_label(&deps.as_ref()).unwrap();
     assert_eq!(asset_label, "ibc/E8AC...BF89");
 }
+
+#[test]
+fn bought_back_amount_from_swap_events() {
+    let swap_event = |receiver: &str, ask_asset: &str, return_amount: &str| {
+        Event::new("wasm").add_attributes(vec![
+            ("action", "swap"),
+            ("receiver", receiver),
+            ("ask_asset", ask_asset),
+            ("return_amount", return_amount),
+        ])
+    };
+
+    let events = vec![
+        Event::new("execute"),
+        // intermediate hop, paid out to the router
+        swap_event("router0000", "uusd", "500"),
+        // last hops, paid out to the pool buying back
+        swap_event("pool0000", "uwhale", "200"),
+        swap_event("pool0000", "uwhale", "100"),
+        // unrelated swap
+        swap_event("pool0000", "uluna", "1000"),
+    ];
+
+    let bought_back = get_bought_back_amount(
+        &events,
+        "pool0000",
+        &AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(bought_back, Uint128::new(300u128));
+
+    let bought_back = get_bought_back_amount(
+        &events,
+        "another_pool",
+        &AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(bought_back, Uint128::zero());
+}
+
+#[test]
+fn burn_fee_target_msgs() {
+    let deps = mock_dependencies(&[]);
+    let burn_fee = Asset {
+        info: AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        },
+        amount: Uint128::new(100u128),
+    };
+
+    assert!(BurnFeeTarget::Burn.burns_directly(&burn_fee.info));
+    let msgs = BurnFeeTarget::Burn.into_msgs(burn_fee.clone()).unwrap();
+    assert_eq!(
+        msgs,
+        vec![CosmosMsg::Bank(BankMsg::Burn {
+            amount: vec![coin(100u128, "uluna")],
+        })]
+    );
+
+    let send_target = BurnFeeTarget::Send {
+        address: "treasury".to_string(),
+    };
+    assert!(!send_target.burns_directly(&burn_fee.info));
+    let msgs = send_target.into_msgs(burn_fee.clone()).unwrap();
+    assert_eq!(
+        msgs,
+        vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "treasury".to_string(),
+            amount: vec![coin(100u128, "uluna")],
+        })]
+    );
+
+    // burn fees already in the buy-back asset are burned without swapping
+    let buy_back_target = BurnFeeTarget::BuyBackAndBurn {
+        router_addr: "router0000".to_string(),
+        asset_info: AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        },
+        keeper: "keeper".to_string(),
+    };
+    assert!(buy_back_target.burns_directly(&burn_fee.info));
+    assert!(!buy_back_target.buys_back(&burn_fee.info));
+    let msgs = buy_back_target.into_msgs(burn_fee.clone()).unwrap();
+    assert_eq!(
+        msgs,
+        vec![CosmosMsg::Bank(BankMsg::Burn {
+            amount: vec![coin(100u128, "uluna")],
+        })]
+    );
+
+    // nothing to dispose of
+    let msgs = BurnFeeTarget::Burn
+        .into_msgs(Asset {
+            info: burn_fee.info.clone(),
+            amount: Uint128::zero(),
+        })
+        .unwrap();
+    assert!(msgs.is_empty());
+
+    // burn fees in other assets are accrued, and bought back by the keeper with a minimum
+    let buy_back_target = BurnFeeTarget::BuyBackAndBurn {
+        router_addr: "router0000".to_string(),
+        asset_info: AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+        keeper: "keeper".to_string(),
+    };
+    assert!(!buy_back_target.burns_directly(&burn_fee.info));
+    assert!(buy_back_target.buys_back(&burn_fee.info));
+    assert!(buy_back_target
+        .into_msgs(burn_fee.clone())
+        .unwrap()
+        .is_empty());
+    assert!(buy_back_target.is_keeper(&Addr::unchecked("keeper")));
+    assert!(!BurnFeeTarget::Burn.is_keeper(&Addr::unchecked("keeper")));
+
+    let msg = buy_back_target
+        .buy_back_msg(&deps.as_ref().querier, burn_fee, Uint128::new(90u128), 1u64)
+        .unwrap();
+    assert_eq!(
+        msg,
+        SubMsg::reply_on_success(
+            WasmMsg::Execute {
+                contract_addr: "router0000".to_string(),
+                msg: to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                    operations: vec![SwapOperation::TerraSwap {
+                        offer_asset_info: AssetInfo::NativeToken {
+                            denom: "uluna".to_string(),
+                        },
+                        ask_asset_info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        pair_type: None,
+                        fee_tier: None,
+                    }],
+                    minimum_receive: Some(Uint128::new(90u128)),
+                    to: None,
+                })
+                .unwrap(),
+                funds: vec![coin(100u128, "uluna")],
+            },
+            1u64,
+        )
+    );
+}
+
+#[test]
+fn simulation_response_of_pair_predating_spot_prices() {
+    let res: SimulationResponse = from_slice(
+        br#"{"return_amount":"100","spread_amount":"1","swap_fee_amount":"2","protocol_fee_amount":"3","burn_fee_amount":"0"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(res.return_amount, Uint128::new(100));
+    assert_eq!(res.pre_trade_spot_price, None);
+    assert_eq!(res.post_trade_spot_price, None);
+    assert_eq!(res.price_impact, None);
+}
@@ -1,6 +1,12 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{StdError, StdResult, Uint128};
-use cw20::{Cw20Coin, MinterResponse};
+use cosmwasm_std::{to_binary, Binary, CosmosMsg, StdError, StdResult, Uint128, WasmMsg};
+use cw20::{Cw20Coin, Cw20ExecuteMsg, Logo, MinterResponse};
+
+use crate::asset::IBC_PREFIX;
+
+/// The maximum number of characters taken from each asset to build an LP token symbol, keeping
+/// the symbol within the 12 characters allowed for cw20 tokens
+const LP_SYMBOL_PART_MAX_LENGTH: usize = 4;
 
 /// TokenContract InstantiateMsg
 #[cw_serde]
@@ -10,6 +16,141 @@ pub struct InstantiateMsg {
     pub decimals: u8,
     pub initial_balances: Vec<Cw20Coin>,
     pub mint: Option<MinterResponse>,
+    pub marketing: Option<InstantiateMarketingInfo>,
+}
+
+/// Marketing info of the token, as in cw20-base
+#[cw_serde]
+pub struct InstantiateMarketingInfo {
+    pub project: Option<String>,
+    pub description: Option<String>,
+    /// The address allowed to update the marketing info
+    pub marketing: Option<String>,
+    pub logo: Option<Logo>,
+}
+
+/// TokenContract messages on top of the cw20 ones
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Updates the name and/or the symbol of the token. Only the minter can update them.
+    UpdateTokenInfo {
+        name: Option<String>,
+        symbol: Option<String>,
+    },
+}
+
+/// Metadata of an LP token. When creating a pool or a vault, the name and symbol default to the
+/// ones derived from its assets if not provided. When updating the LP token, the fields not
+/// provided are left unchanged.
+#[cw_serde]
+#[derive(Default)]
+pub struct LpTokenInfo {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub project: Option<String>,
+    pub description: Option<String>,
+    pub logo_url: Option<String>,
+}
+
+impl LpTokenInfo {
+    /// Gets the marketing info of the LP token. The contract issuing the LP token is set as the
+    /// marketing address, so it can update the marketing info later on.
+    pub fn marketing_info(&self, issuer: &str) -> InstantiateMarketingInfo {
+        InstantiateMarketingInfo {
+            project: self.project.clone(),
+            description: self.description.clone(),
+            marketing: Some(issuer.to_string()),
+            logo: self.logo_url.clone().map(Logo::Url),
+        }
+    }
+
+    /// Creates the messages updating the given LP token with the provided fields
+    pub fn update_msgs(self, lp_token: &str) -> StdResult<Vec<CosmosMsg>> {
+        let mut messages = vec![];
+
+        if self.name.is_some() || self.symbol.is_some() {
+            messages.push(execute_msg(
+                lp_token,
+                to_binary(&ExecuteMsg::UpdateTokenInfo {
+                    name: self.name,
+                    symbol: self.symbol,
+                })?,
+            ));
+        }
+
+        if self.project.is_some() || self.description.is_some() {
+            messages.push(execute_msg(
+                lp_token,
+                to_binary(&Cw20ExecuteMsg::UpdateMarketing {
+                    project: self.project,
+                    description: self.description,
+                    marketing: None,
+                })?,
+            ));
+        }
+
+        if let Some(logo_url) = self.logo_url {
+            messages.push(execute_msg(
+                lp_token,
+                to_binary(&Cw20ExecuteMsg::UploadLogo(Logo::Url(logo_url)))?,
+            ));
+        }
+
+        Ok(messages)
+    }
+}
+
+fn execute_msg(contract_addr: &str, msg: Binary) -> CosmosMsg {
+    CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: contract_addr.to_string(),
+        msg,
+        funds: vec![],
+    })
+}
+
+/// Gets the symbol of an LP token given the labels of its assets, e.g. `LUNA-WHAL-LP` for a
+/// uluna/WHALE pool or `LUNA-LP` for a uluna vault
+pub fn get_lp_token_symbol(asset_labels: &[String]) -> String {
+    // a single asset can take the room of the separator and the second asset
+    let max_length = match asset_labels.len() {
+        1 => LP_SYMBOL_PART_MAX_LENGTH * 2 + 1,
+        _ => LP_SYMBOL_PART_MAX_LENGTH,
+    };
+
+    let mut parts: Vec<String> = asset_labels
+        .iter()
+        .map(|label| get_lp_symbol_part(label, max_length))
+        .collect();
+    parts.push("LP".to_string());
+
+    parts.join("-")
+}
+
+/// Gets the ticker of an asset label to build an LP token symbol with, i.e. the leading letters of
+/// the denom uppercased, without the micro prefix of native denoms
+fn get_lp_symbol_part(label: &str, max_length: usize) -> String {
+    // ibc hashes are meaningless, and token factory denoms are named after their subdenom
+    let denom = if label.starts_with(&format!("{}/", IBC_PREFIX)) {
+        IBC_PREFIX
+    } else {
+        label.rsplit('/').next().unwrap_or(label)
+    };
+
+    // stop at the first non alphabetic character, i.e. the address of peggy denoms
+    let mut ticker: String = denom
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect();
+
+    if ticker.len() > 1 && ticker.starts_with('u') && ticker.chars().all(|c| c.is_lowercase()) {
+        ticker.remove(0);
+    }
+
+    if ticker.is_empty() {
+        ticker = "X".to_string();
+    }
+
+    ticker.to_uppercase().chars().take(max_length).collect()
 }
 
 impl InstantiateMsg {
@@ -36,7 +177,7 @@ impl InstantiateMsg {
     }
 }
 
-fn is_valid_name(name: &str) -> bool {
+pub fn is_valid_name(name: &str) -> bool {
     let bytes = name.as_bytes();
     if bytes.len() < 3 || bytes.len() > 50 {
         return false;
@@ -44,7 +185,7 @@ fn is_valid_name(name: &str) -> bool {
     true
 }
 
-fn is_valid_symbol(symbol: &str) -> bool {
+pub fn is_valid_symbol(symbol: &str) -> bool {
     let bytes = symbol.as_bytes();
     if bytes.len() < 3 || bytes.len() > 12 {
         return false;
@@ -72,6 +213,7 @@ mod test {
             }),
             name: "test_token".to_string(),
             symbol: "TNT".to_string(),
+            marketing: None,
         };
 
         assert_eq!(msg.get_cap(), Some(Uint128::from(1u128)))
@@ -88,6 +230,7 @@ mod test {
             }),
             name: "test_token".to_string(),
             symbol: "TNT".to_string(),
+            marketing: None,
         };
 
         assert_eq!(valid_msg.validate(), Ok(()));
@@ -101,6 +244,7 @@ mod test {
             }),
             name: "a".to_string(),
             symbol: "TNT".to_string(),
+            marketing: None,
         };
 
         assert_eq!(
@@ -119,6 +263,7 @@ mod test {
             }),
             name: "test_token".to_string(),
             symbol: "TN".to_string(),
+            marketing: None,
         };
 
         assert_eq!(
@@ -137,6 +282,7 @@ mod test {
             }),
             name: "test_token".to_string(),
             symbol: "TNT".to_string(),
+            marketing: None,
         };
 
         assert_eq!(
@@ -144,4 +290,31 @@ mod test {
             Err(StdError::generic_err("Decimals must not exceed 18"))
         );
     }
+
+    #[test]
+    fn lp_token_symbol() {
+        let labels = |labels: &[&str]| labels.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            get_lp_token_symbol(&labels(&["uluna", "WHALE"])),
+            "LUNA-WHAL-LP"
+        );
+        assert_eq!(
+            get_lp_token_symbol(&labels(&["ibc/27394...3B1B", "uwhale"])),
+            "IBC-WHAL-LP"
+        );
+        assert_eq!(
+            get_lp_token_symbol(&labels(&["factory/creator/uabc", "peggy0xdAC1"])),
+            "ABC-PEGG-LP"
+        );
+        assert_eq!(get_lp_token_symbol(&labels(&["uluna"])), "LUNA-LP");
+        assert_eq!(get_lp_token_symbol(&labels(&["u"])), "U-LP");
+
+        for symbol in [
+            get_lp_token_symbol(&labels(&["ustakedluna", "ustakedwhale"])),
+            get_lp_token_symbol(&labels(&["ustakedwhale"])),
+        ] {
+            assert!(is_valid_symbol(&symbol), "{}", symbol);
+        }
+    }
 }
@@ -1,6 +1,10 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
 use pool_network::asset::{Asset, AssetInfo};
+use pool_network::burn_fee::BurnFeeTarget;
+use pool_network::epoch::{EpochConfig, ProtocolFeesByEpochResponse};
+use pool_network::lp_token::LpTokenType;
+use pool_network::token::LpTokenInfo;
 use nico_teen::fee::VaultFee;
 
 #[cw_serde]
@@ -15,6 +19,11 @@ pub struct InstantiateMsg {
     pub vault_fees: VaultFee,
     /// The address of the fee collector
     pub fee_collector_addr: String,
+    /// The kind of LP token to issue, a cw20 token instantiated from `token_id` if not provided
+    pub lp_token_type: Option<LpTokenType>,
+    /// The name, symbol and marketing info of the cw20 LP token. The name and symbol are derived
+    /// from the vault asset if not provided.
+    pub lp_token_info: Option<LpTokenInfo>,
 }
 
 /// The callback messages available. Only callable by the vault contract itself.
@@ -24,12 +33,24 @@ pub enum CallbackMsg {
         old_balance: Uint128,
         loan_amount: Uint128,
     },
+    /// Swaps the counter asset held by the vault back into the vault asset, after the liquidity
+    /// strategy position has been withdrawn from the pair.
+    SwapStrategyAsset {},
+    /// Provides the counter asset held by the vault, together with up to `max_amount` of the vault
+    /// asset, as liquidity to the liquidity strategy pair.
+    ProvideStrategyLiquidity { max_amount: Uint128 },
+    /// Performs a withdrawal for the `sender` once the part of the liquidity strategy position it
+    /// needs has been unwound.
+    Withdraw { sender: String, amount: Uint128 },
 }
 
 #[cw_serde]
 pub enum Cw20HookMsg {
     /// Withdraws a given amount from the vault.
     Withdraw {},
+    /// Deposits the sent amount of tokens into the vault, for vaults managing a cw20 token.
+    /// The LP tokens are minted to `receiver` if provided, otherwise to the sender.
+    Deposit { receiver: Option<String> },
 }
 
 #[cw_serde]
@@ -53,13 +74,37 @@ pub struct UpdateConfigParams {
     pub new_vault_fees: Option<VaultFee>,
     /// The new address of the fee collector
     pub new_fee_collector_addr: Option<String>,
+    /// Where the burn fees go from now on
+    pub new_burn_fee_target: Option<BurnFeeTarget>,
+    /// The epoch config the fees of the vault are bucketed by. It can only be set once.
+    pub new_epoch_config: Option<EpochConfig>,
+}
+
+/// The parameters of the liquidity strategy, where idle vault assets are provided as liquidity
+/// to a pool.
+#[cw_serde]
+pub struct LiquidityStrategyParams {
+    /// The address of the pair to provide liquidity to. The pair must contain the vault asset.
+    pub pair_addr: String,
+    /// The fraction of the vault assets to provide as liquidity to the pair.
+    pub allocation: Decimal,
+    /// The maximum spread allowed when swapping in and out of the pair, also used as slippage
+    /// tolerance when providing liquidity.
+    pub max_spread: Option<Decimal>,
+    /// The maximum relative deviation of the pair price from the reference price recorded when the
+    /// strategy is set or rebalanced. Beyond it withdrawals that need to unwind the position are
+    /// rejected until the owner rebalances. Swaps in and out of the pair are bounded by the
+    /// reference price, within this deviation plus `max_spread`.
+    pub max_price_deviation: Decimal,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Deposits a given amount into the vault.
+    /// Deposits a given amount into the vault. The LP tokens are minted to `receiver` if provided,
+    /// otherwise to the sender.
     Deposit {
         amount: Uint128,
+        receiver: Option<String>,
     },
     /// Flash-loans a given amount from the vault.
     FlashLoan {
@@ -68,9 +113,32 @@ pub enum ExecuteMsg {
     },
     /// Collects the Protocol fees
     CollectProtocolFees {},
+    /// Sets the liquidity strategy of the vault. If `None`, the strategy is disabled.
+    /// The strategy pair can only be changed or removed when no liquidity is provided to the pair.
+    UpdateLiquidityStrategy {
+        strategy: Option<LiquidityStrategyParams>,
+    },
+    /// Provides liquidity to the strategy pair until the position reaches the configured
+    /// allocation of the vault assets, or withdraws the position if it exceeds it. The reference
+    /// price of the strategy is set to the current pair price. Only the owner can rebalance.
+    Rebalance {},
     /// Updates the configuration of the contract.
     /// If a field is not specified, it will not be modified.
     UpdateConfig(UpdateConfigParams),
+    /// Updates the name, symbol and/or marketing info of the cw20 LP token. Only the owner of the
+    /// vault can update them.
+    UpdateLpTokenInfo {
+        lp_token_info: LpTokenInfo,
+    },
+    /// Withdraws with the native LP tokens sent along, for vaults issuing token factory LP tokens.
+    /// Vaults issuing cw20 LP tokens use [Cw20HookMsg::Withdraw] instead.
+    Withdraw {},
+    /// Buys back with the burn fees accrued by the vault, when the burn fee target is
+    /// [BurnFeeTarget::BuyBackAndBurn], receiving at least `minimum_receive` of the buy-back asset.
+    /// Only the keeper of the burn fee target can buy back.
+    BuyBack {
+        minimum_receive: Uint128,
+    },
     Receive(Cw20ReceiveMsg),
     Callback(CallbackMsg),
 }
@@ -90,9 +158,27 @@ pub enum QueryMsg {
     /// Retrieves the fees that have been burned by the vault.
     #[returns(ProtocolFeesResponse)]
     BurnedFees {},
+    /// Retrieves the [`Uint128`] amount of burn fees accrued by the vault, waiting to be bought
+    /// back by the keeper of the [BurnFeeTarget::BuyBackAndBurn] burn fee target.
+    #[returns(Uint128)]
+    BuyBackFees {},
+    /// Retrieves the tokens bought back with the burn fees, and burned, by the vault.
+    #[returns(Vec<Asset>)]
+    BoughtBackFees {},
     /// Retrieves the [`Uint128`] amount that must be sent back to the contract to pay off a loan taken out.
     #[returns(PaybackAmountResponse)]
     GetPaybackAmount { amount: Uint128 },
+    /// Retrieves the liquidity strategy of the vault and the value of its position.
+    #[returns(LiquidityStrategyResponse)]
+    LiquidityStrategy {},
+    /// Retrieves the [`Uint128`] amount of the vault asset that can currently be borrowed, i.e. the
+    /// vault balance without the uncollected protocol fees and the burn fees to buy back with.
+    #[returns(Uint128)]
+    AvailableLiquidity {},
+    /// Retrieves the protocol fees accrued and the fees burned by the vault in each epoch from
+    /// `start_epoch` to `end_epoch`, both included.
+    #[returns(ProtocolFeesByEpochResponse)]
+    ProtocolFeesByEpoch { start_epoch: u64, end_epoch: u64 },
 }
 
 #[cw_serde]
@@ -101,6 +187,9 @@ pub struct MigrateMsg {}
 /// The `reply` code ID for the submessage after instantiating the LP token.
 pub const INSTANTIATE_LP_TOKEN_REPLY_ID: u64 = 1;
 
+/// The `reply` code ID for the submessage after buying back with the burn fees.
+pub const BUY_BACK_REPLY_ID: u64 = 2;
+
 #[cw_serde]
 pub struct ProtocolFeesResponse {
     pub fees: Asset,
@@ -118,12 +207,14 @@ pub struct Config {
     pub deposit_enabled: bool,
     /// If withdrawals are enabled
     pub withdraw_enabled: bool,
-    /// The address of the liquidity token
-    pub liquidity_token: Addr,
+    /// The liquidity token of the vault, either a cw20 token or a token factory denom
+    pub liquidity_token: AssetInfo,
     /// The address of the fee collector
     pub fee_collector_addr: Addr,
     /// The fees associated with this vault
     pub fees: VaultFee,
+    /// Where the burn fees of the vault go
+    pub burn_fee_target: BurnFeeTarget,
 }
 
 #[cw_serde]
@@ -137,3 +228,31 @@ pub struct PaybackAmountResponse {
     /// The amount of fee to be burned
     pub burn_fee: Uint128,
 }
+
+#[cw_serde]
+pub struct LiquidityStrategy {
+    /// The pair the vault asset is provided to as liquidity
+    pub pair_addr: Addr,
+    /// The liquidity token of the pair
+    pub lp_token: Addr,
+    /// The asset the vault asset is paired with
+    pub counter_asset: AssetInfo,
+    /// The fraction of the vault assets to provide as liquidity to the pair
+    pub allocation: Decimal,
+    /// The maximum spread allowed when swapping in and out of the pair
+    pub max_spread: Option<Decimal>,
+    /// The maximum relative deviation of the pair price from `reference_price` at which the
+    /// position can be unwound for withdrawals
+    pub max_price_deviation: Decimal,
+    /// The price of the counter asset in vault asset when the strategy was set or last rebalanced
+    pub reference_price: Decimal,
+}
+
+#[cw_serde]
+pub struct LiquidityStrategyResponse {
+    /// The liquidity strategy, if enabled
+    pub strategy: Option<LiquidityStrategy>,
+    /// The value of the strategy position, denominated in the vault asset at the lower of the pair
+    /// and reference prices
+    pub position_value: Uint128,
+}
@@ -1,8 +1,10 @@
 use crate::vault::{self};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Uint128};
 use pool_network::asset::AssetInfo;
 use nico_teen::fee::VaultFee;
+use pool_network::lp_token::LpTokenType;
+use pool_network::token::LpTokenInfo;
 
 /// The instantiation message
 #[cw_serde]
@@ -21,24 +23,43 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Creates a new vault given the asset info the vault should manage deposits and withdrawals
-    /// for and the fees
+    /// for and the fees. Multiple vaults can be created for the same asset, e.g. with different
+    /// fee tiers, by giving them a distinct `label`.
     CreateVault {
         asset_info: AssetInfo,
         fees: VaultFee,
+        label: Option<String>,
+        /// The kind of LP token of the vault, a cw20 token if not provided
+        lp_token_type: Option<LpTokenType>,
+        /// The name, symbol and marketing info of the LP token. The name and symbol are derived
+        /// from the vault asset if not provided.
+        lp_token_info: Option<LpTokenInfo>,
     },
     /// Migrates vaults to the given code_id. If a [vault_addr] is provided, then migrates only that
-    /// vault.
+    /// vault. Otherwise migrates a batch of `limit` vaults starting after `start_after`. If no
+    /// `start_after` is provided, the batch migration resumes from the last vault migrated to the
+    /// same code_id, or starts from the first vault.
     MigrateVaults {
         vault_addr: Option<String>,
         vault_code_id: u64,
+        start_after: Option<Vec<u8>>,
+        limit: Option<u32>,
+    },
+    /// Removes a vault given its [AssetInfo] and label
+    RemoveVault {
+        asset_info: AssetInfo,
+        label: Option<String>,
     },
-    /// Removes a vault given its [AssetInfo]
-    RemoveVault { asset_info: AssetInfo },
     /// Updates a vault config
     UpdateVaultConfig {
         vault_addr: String,
         params: vault::UpdateConfigParams,
     },
+    /// Updates the name, symbol and/or marketing info of the LP token of a vault
+    UpdateVaultLpTokenInfo {
+        vault_addr: String,
+        lp_token_info: LpTokenInfo,
+    },
     /// Updates the configuration of the vault factory.
     /// If a field is not specified, it will not be modified.
     UpdateConfig {
@@ -56,15 +77,42 @@ pub enum QueryMsg {
     /// Retrieves the configuration of the vault.
     #[returns(Config)]
     Config {},
-    /// Retrieves the address of a given vault.
+    /// Retrieves the address of a given vault. If no `label` is provided, the default vault of
+    /// the asset is returned.
     #[returns(Option<String>)]
-    Vault { asset_info: AssetInfo },
+    Vault {
+        asset_info: AssetInfo,
+        label: Option<String>,
+    },
+    /// Retrieves all the vaults managing the given asset.
+    #[returns(VaultsResponse)]
+    AssetVaults { asset_info: AssetInfo },
     /// Retrieves the addresses for all the vaults.
     #[returns(VaultsResponse)]
     Vaults {
         start_after: Option<Vec<u8>>,
         limit: Option<u32>,
     },
+    /// Retrieves the liquidity available to be borrowed and the fees of all the vaults.
+    #[returns(AvailableLiquidityResponse)]
+    AllAvailableLiquidity {
+        start_after: Option<Vec<u8>>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the status of the vaults batch migration and the code ids the vaults are on. The
+    /// code ids are paginated, same as [QueryMsg::Vaults].
+    #[returns(MigrationStatusResponse)]
+    MigrationStatus {
+        start_after: Option<Vec<u8>>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the code id and the cw2 contract name and version of the vaults. This query has
+    /// pagination enabled, same as [QueryMsg::Vaults].
+    #[returns(ChildrenVersionsResponse)]
+    ChildrenVersions {
+        start_after: Option<Vec<u8>>,
+        limit: Option<u32>,
+    },
 }
 
 /// The migrate message
@@ -93,5 +141,68 @@ pub struct VaultsResponse {
 pub struct VaultInfo {
     pub vault: String,
     pub asset_info: AssetInfo,
+    /// The key the vault is stored under, to be used for pagination
+    pub asset_info_reference: Vec<u8>,
+    /// The label distinguishing the vault from other vaults of the same asset. `None` for the
+    /// default vault of the asset.
+    pub label: Option<String>,
+}
+
+/// Response for the all available liquidity query
+#[cw_serde]
+pub struct AvailableLiquidityResponse {
+    pub vaults: Vec<VaultLiquidityInfo>,
+}
+
+#[cw_serde]
+pub struct VaultLiquidityInfo {
+    pub vault: String,
+    pub asset_info: AssetInfo,
+    /// The key the vault is stored under, to be used for pagination
+    pub asset_info_reference: Vec<u8>,
+    /// The label distinguishing the vault from other vaults of the same asset
+    pub label: Option<String>,
+    /// The amount of the asset that can currently be borrowed from the vault
+    pub available_liquidity: Uint128,
+    /// The current fees of the vault
+    pub fees: VaultFee,
+}
+
+/// Response for the migration status query
+#[cw_serde]
+pub struct MigrationStatusResponse {
+    /// The code id the in progress batch migration is migrating the vaults to, if any
+    pub code_id: Option<u64>,
+    /// The key of the last vault migrated by the in progress batch migration
+    pub last_migrated: Option<Vec<u8>>,
+    /// The vaults grouped by the code id they are on
+    pub code_ids: Vec<CodeIdVaults>,
+}
+
+#[cw_serde]
+pub struct CodeIdVaults {
+    pub code_id: u64,
+    pub vaults: Vec<String>,
+}
+
+/// Response for the children versions query
+#[cw_serde]
+pub struct ChildrenVersionsResponse {
+    pub children: Vec<ChildVersion>,
+}
+
+#[cw_serde]
+pub struct ChildVersion {
+    pub vault: String,
+    pub asset_info: AssetInfo,
+    /// The key the vault is stored under, to be used for pagination
     pub asset_info_reference: Vec<u8>,
+    /// The label distinguishing the vault from other vaults of the same asset
+    pub label: Option<String>,
+    /// The code id the vault is on
+    pub code_id: u64,
+    /// The cw2 contract name of the vault
+    pub contract: String,
+    /// The cw2 contract version of the vault
+    pub version: String,
 }